@@ -15,7 +15,7 @@ use bevy_render::{
     view::{RenderLayers, VisibilitySystems},
 };
 use bevy_transform::{prelude::GlobalTransform, TransformSystem};
-use bevy_ui::{DefaultUiCamera, Display, Node, Style, TargetCamera, UiScale};
+use bevy_ui::{DefaultUiCamera, Display, Node, Style, TargetCamera, TargetUiScale};
 use bevy_utils::{default, warn_once};
 use bevy_window::{PrimaryWindow, Window, WindowRef};
 
@@ -158,7 +158,7 @@ struct OutlineParam<'w, 's> {
     children: Query<'w, 's, &'static Children>,
     nodes: Query<'w, 's, NodesQuery>,
     view_visibility: Query<'w, 's, &'static ViewVisibility>,
-    ui_scale: Res<'w, UiScale>,
+    ui_scale: TargetUiScale<'w, 's>,
 }
 
 type CameraQuery<'w, 's> = Query<'w, 's, &'static Camera, With<DebugOverlayCamera>>;
@@ -186,7 +186,7 @@ fn outline_roots(
         ),
         Without<Parent>,
     >,
-    window: Query<&Window, With<PrimaryWindow>>,
+    window: Query<(Entity, &Window), With<PrimaryWindow>>,
     nonprimary_windows: Query<&Window, Without<PrimaryWindow>>,
     options: Res<UiDebugOptions>,
 ) {
@@ -199,8 +199,13 @@ fn outline_roots(
             you might notice gaps between container lines"
         );
     }
-    let window_scale = window.get_single().map_or(1., Window::scale_factor);
-    let scale_factor = outline.ui_scale.0;
+    let (window_scale, scale_factor) =
+        window
+            .get_single()
+            .map_or((1., outline.ui_scale.camera(None)), |(entity, window)| {
+                let window_scale = window.scale_factor();
+                (window_scale, outline.ui_scale.window(entity, window_scale))
+            });
 
     // We let the line be defined by the window scale alone
     let line_width = outline
@@ -0,0 +1,51 @@
+//! In this example we time the systems of a schedule with the `SystemProfiling` resource,
+//! and print the slowest ones to the console.
+
+use bevy_ecs::{prelude::*, schedule::SystemProfiling};
+use std::{thread, time::Duration};
+
+fn main() {
+    // Create a world
+    let mut world = World::new();
+
+    // Systems are only timed while the `SystemProfiling` resource exists
+    world.init_resource::<SystemProfiling>();
+
+    // Create a schedule with systems taking more or less time to run
+    let mut schedule = Schedule::default();
+    schedule.add_systems((
+        move_players,
+        update_physics,
+        play_sounds,
+        render_shadows,
+        (|| work(1)).after(update_physics),
+    ));
+
+    schedule.run(&mut world);
+
+    println!("Slowest systems:");
+    for (name, duration) in world.resource::<SystemProfiling>().report().iter().take(10) {
+        println!("    {duration:>12?}  {name}");
+    }
+}
+
+// Pretend that a system does some expensive work
+fn work(milliseconds: u64) {
+    thread::sleep(Duration::from_millis(milliseconds));
+}
+
+fn move_players() {
+    work(2);
+}
+
+fn update_physics() {
+    work(8);
+}
+
+fn play_sounds() {
+    work(1);
+}
+
+fn render_shadows() {
+    work(5);
+}
@@ -202,6 +202,19 @@ impl<T> NodeConfigs<T> {
         }
     }
 
+    fn priority_inner(&mut self, priority: i32) {
+        match self {
+            Self::NodeConfig(config) => {
+                config.graph_info.priority = priority;
+            }
+            Self::Configs { configs, .. } => {
+                for config in configs {
+                    config.priority_inner(priority);
+                }
+            }
+        }
+    }
+
     fn ambiguous_with_all_inner(&mut self) {
         match self {
             Self::NodeConfig(config) => {
@@ -425,6 +438,16 @@ where
         self.into_configs().ambiguous_with_all()
     }
 
+    /// Breaks ties with the systems that aren't ordered with these systems, by running the systems
+    /// with the highest priority first. Systems have a priority of `0` by default.
+    ///
+    /// Priorities never override the order set by [`before`](Self::before), [`after`](Self::after)
+    /// or [`chain`](Self::chain). The single-threaded executors run systems in the resulting order,
+    /// while the multi-threaded executor uses it to pick which systems to start first.
+    fn priority(self, priority: i32) -> SystemConfigs {
+        self.into_configs().priority(priority)
+    }
+
     /// Treat this collection as a sequence of systems.
     ///
     /// Ordering constraints will be applied between the successive elements.
@@ -508,6 +531,11 @@ impl IntoSystemConfigs<()> for SystemConfigs {
         self
     }
 
+    fn priority(mut self, priority: i32) -> Self {
+        self.priority_inner(priority);
+        self
+    }
+
     fn chain(self) -> Self {
         self.chain_inner()
     }
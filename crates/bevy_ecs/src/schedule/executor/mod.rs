@@ -6,11 +6,15 @@ pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
 
+use std::{any::Any, borrow::Cow, sync::Arc, time::Duration};
+
+use bevy_utils::HashMap;
 use fixedbitset::FixedBitSet;
 
 use crate::{
+    self as bevy_ecs,
     schedule::{BoxedCondition, NodeId},
-    system::BoxedSystem,
+    system::{BoxedSystem, Resource},
     world::World,
 };
 
@@ -98,6 +102,116 @@ impl SystemSchedule {
     }
 }
 
+/// Records how long each system took the last time it ran.
+///
+/// Executors only measure their systems while this resource is in the [`World`], so
+/// profiling can be turned on and off by inserting and removing it.
+/// Systems are keyed by their [name](crate::system::System::name), so different systems
+/// sharing a name overwrite each other's timings.
+#[derive(Resource, Debug, Default)]
+pub struct SystemProfiling {
+    timings: HashMap<Cow<'static, str>, Duration>,
+}
+
+impl SystemProfiling {
+    /// Returns how long the system with the given name took the last time it ran.
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.timings.get(name).copied()
+    }
+
+    /// Returns the name and last duration of each system that ran, slowest first.
+    pub fn report(&self) -> Vec<(&str, Duration)> {
+        let mut report: Vec<_> = self
+            .timings
+            .iter()
+            .map(|(name, duration)| (&**name, *duration))
+            .collect();
+        report.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+        report
+    }
+
+    /// Forgets all the recorded timings.
+    pub fn clear(&mut self) {
+        self.timings.clear();
+    }
+
+    /// Records the timings measured by an executor for the systems of `schedule`.
+    pub(super) fn record(
+        world: &mut World,
+        schedule: &SystemSchedule,
+        timings: impl IntoIterator<Item = (usize, Duration)>,
+    ) {
+        let Some(mut profiling) = world.get_resource_mut::<SystemProfiling>() else {
+            return;
+        };
+        for (system_index, duration) in timings {
+            profiling
+                .timings
+                .insert(schedule.systems[system_index].name(), duration);
+        }
+    }
+}
+
+/// Opts into isolating the systems that panic, instead of propagating the panic out of the schedule.
+///
+/// While this resource is in the [`World`], a system that panics is logged with its name and
+/// passed to the [`on_system_panic`](Self::on_system_panic) callback, then the schedule carries on
+/// as if the system had returned. This is meant for tools and long-running apps that must stay up,
+/// not as a form of error handling: prefer returning errors from systems when possible.
+///
+/// # Caveats
+///
+/// Panics are caught with [`catch_unwind`](std::panic::catch_unwind) around each system, which
+/// asserts that the [`World`] is [unwind safe](std::panic::UnwindSafe). It isn't in general:
+/// - Anything the system did before panicking stays done. Components and resources can be left
+///   half-updated, and some of the events it meant to send may be missing.
+/// - Deferred system buffers, like [`Commands`](crate::system::Commands), only hold the commands
+///   queued before the panic, and are still applied.
+/// - Locks and other data shared with the system might be poisoned.
+///
+/// The bookkeeping of the [`World`] itself is kept consistent, so these caveats only affect the
+/// invariants of the data that systems store in it. Panics can't be caught when the app is built
+/// with `panic = "abort"`, nor if they happen while applying deferred buffers.
+#[derive(Resource, Clone, Default)]
+pub struct SystemPanicRecovery {
+    /// Whether a system that panicked is skipped by the following runs of its schedule,
+    /// until the schedule is rebuilt.
+    pub skip_panicked_systems: bool,
+    on_system_panic: Option<Arc<dyn Fn(&str, &(dyn Any + Send)) + Send + Sync>>,
+}
+
+impl SystemPanicRecovery {
+    /// Isolates the systems that panic, running them again on the following runs of their schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips the systems that panicked on the following runs of their schedule.
+    pub fn skipping_panicked_systems(mut self) -> Self {
+        self.skip_panicked_systems = true;
+        self
+    }
+
+    /// Calls `on_system_panic` with the name of each system that panics and its panic payload.
+    ///
+    /// The callback can be called from any of the threads running systems.
+    pub fn on_system_panic(
+        mut self,
+        on_system_panic: impl Fn(&str, &(dyn Any + Send)) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_system_panic = Some(Arc::new(on_system_panic));
+        self
+    }
+
+    /// Reports a panic of the system with the given name.
+    pub(super) fn recover(&self, name: &str, payload: &(dyn Any + Send)) {
+        eprintln!("Recovering from the panic in system `{name}`.");
+        if let Some(on_system_panic) = &self.on_system_panic {
+            on_system_panic(name, payload);
+        }
+    }
+}
+
 /// Instructs the executor to call [`System::apply_deferred`](crate::system::System::apply_deferred)
 /// on the systems that have run but not applied their [`Deferred`](crate::system::Deferred) system parameters
 /// (like [`Commands`](crate::prelude::Commands)) or other system buffers.
@@ -1,6 +1,7 @@
 use std::{
     any::Any,
     sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
 };
 
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
@@ -8,6 +9,7 @@ use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Span};
+use bevy_utils::Instant;
 use std::panic::AssertUnwindSafe;
 
 use concurrent_queue::ConcurrentQueue;
@@ -17,7 +19,10 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemPanicRecovery,
+        SystemProfiling, SystemSchedule,
+    },
     system::BoxedSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -77,6 +82,10 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// How long the system took to run, if it is profiled.
+    duration: Option<Duration>,
+    /// Whether the system panicked, with its panic isolated by [`SystemPanicRecovery`].
+    panicked: bool,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -90,6 +99,10 @@ pub struct MultiThreadedExecutor {
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
     starting_systems: FixedBitSet,
+    /// Whether the systems are timed for [`SystemProfiling`] during this run.
+    profiling: bool,
+    /// How the panics of systems are isolated during this run, if they are.
+    panic_recovery: Option<SystemPanicRecovery>,
     /// Cached tracing span
     #[cfg(feature = "trace")]
     executor_span: Span,
@@ -123,6 +136,10 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// How long the systems took to run, when they are profiled.
+    system_timings: Vec<(usize, Duration)>,
+    /// Systems that panicked while their panics were isolated by [`SystemPanicRecovery`].
+    panicked_systems: FixedBitSet,
 }
 
 /// References to data required by the executor.
@@ -161,6 +178,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         state.completed_systems = FixedBitSet::with_capacity(sys_count);
         state.skipped_systems = FixedBitSet::with_capacity(sys_count);
         state.unapplied_systems = FixedBitSet::with_capacity(sys_count);
+        state.panicked_systems = FixedBitSet::with_capacity(sys_count);
 
         state.system_task_metadata = Vec::with_capacity(sys_count);
         for index in 0..sys_count {
@@ -184,6 +202,8 @@ impl SystemExecutor for MultiThreadedExecutor {
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
     ) {
+        self.profiling = world.contains_resource::<SystemProfiling>();
+        self.panic_recovery = world.get_resource::<SystemPanicRecovery>().cloned();
         let state = self.state.get_mut().unwrap();
         // reset counts
         if schedule.systems.is_empty() {
@@ -211,6 +231,21 @@ impl SystemExecutor for MultiThreadedExecutor {
             }
         }
 
+        if self
+            .panic_recovery
+            .as_ref()
+            .is_some_and(|panic_recovery| panic_recovery.skip_panicked_systems)
+        {
+            // skip systems that panicked in previous runs, unless they were already skipped above
+            let mut panicked_systems = state.panicked_systems.clone();
+            panicked_systems.difference_with(&state.completed_systems);
+            state.completed_systems |= &panicked_systems;
+            for system_index in panicked_systems.ones() {
+                state.signal_dependents(system_index);
+                state.ready_systems.remove(system_index);
+            }
+        }
+
         let thread_executor = world
             .get_resource::<MainThreadExecutor>()
             .map(|e| e.0.clone());
@@ -245,6 +280,10 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        if self.profiling {
+            SystemProfiling::record(world, schedule, state.system_timings.drain(..));
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -270,21 +309,32 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &BoxedSystem,
+        duration: Option<Duration>,
     ) {
-        // tell the executor that the system finished
-        self.environment
-            .executor
-            .system_completion
-            .push(SystemResult { system_index })
-            .unwrap_or_else(|error| unreachable!("{}", error));
+        let mut panicked = false;
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
-            // set the payload to propagate the error
-            {
+            let panic_recovery = &self.environment.executor.panic_recovery;
+            // panics while applying deferred buffers are never isolated
+            if let (Some(panic_recovery), false) = (panic_recovery, is_apply_deferred(system)) {
+                panic_recovery.recover(&system.name(), &*payload);
+                panicked = true;
+            } else {
+                // set the payload to propagate the error
                 let mut panic_payload = self.environment.executor.panic_payload.lock().unwrap();
                 *panic_payload = Some(payload);
             }
         }
+        // tell the executor that the system finished
+        self.environment
+            .executor
+            .system_completion
+            .push(SystemResult {
+                system_index,
+                duration,
+                panicked,
+            })
+            .unwrap_or_else(|error| unreachable!("{}", error));
         self.tick_executor();
     }
 
@@ -327,6 +377,8 @@ impl MultiThreadedExecutor {
             starting_systems: FixedBitSet::new(),
             apply_final_deferred: true,
             panic_payload: Mutex::new(None),
+            profiling: false,
+            panic_recovery: None,
             #[cfg(feature = "trace")]
             executor_span: info_span!("multithreaded executor"),
         }
@@ -349,6 +401,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            system_timings: Vec::new(),
+            panicked_systems: FixedBitSet::new(),
         }
     }
 
@@ -584,6 +638,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.executor.profiling.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -596,7 +651,8 @@ impl ExecutorState {
                     );
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let duration = start.map(|start| start.elapsed());
+            context.system_completed(system_index, res, system, duration);
         };
 
         self.active_access
@@ -627,16 +683,18 @@ impl ExecutorState {
             self.unapplied_systems.clear();
             let task = async move {
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, None);
             };
 
             context.scope.spawn_on_scope(task);
         } else {
             let task = async move {
+                let start = context.environment.executor.profiling.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     __rust_begin_short_backtrace::run(&mut **system, world);
                 }));
-                context.system_completed(system_index, res, system);
+                let duration = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, duration);
             };
 
             context.scope.spawn_on_scope(task);
@@ -647,7 +705,19 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            duration,
+            panicked,
+        } = result;
+
+        if panicked {
+            self.panicked_systems.insert(system_index);
+        }
+
+        if let Some(duration) = duration {
+            self.system_timings.push((system_index, duration));
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -1,11 +1,13 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
-use std::panic::AssertUnwindSafe;
+use std::{panic::AssertUnwindSafe, time::Duration};
 
 use crate::{
     schedule::{
-        executor::is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+        executor::is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor,
+        SystemPanicRecovery, SystemProfiling, SystemSchedule,
     },
     world::World,
 };
@@ -20,6 +22,10 @@ pub struct SimpleExecutor {
     evaluated_sets: FixedBitSet,
     /// Systems that have run or been skipped.
     completed_systems: FixedBitSet,
+    /// How long the systems took to run, when they are profiled.
+    system_timings: Vec<(usize, Duration)>,
+    /// Systems that panicked while their panics were isolated by [`SystemPanicRecovery`].
+    panicked_systems: FixedBitSet,
 }
 
 impl SystemExecutor for SimpleExecutor {
@@ -32,6 +38,7 @@ impl SystemExecutor for SimpleExecutor {
         let set_count = schedule.set_ids.len();
         self.evaluated_sets = FixedBitSet::with_capacity(set_count);
         self.completed_systems = FixedBitSet::with_capacity(sys_count);
+        self.panicked_systems = FixedBitSet::with_capacity(sys_count);
     }
 
     fn run(
@@ -48,6 +55,16 @@ impl SystemExecutor for SimpleExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let profiling = world.contains_resource::<SystemProfiling>();
+        let panic_recovery = world.get_resource::<SystemPanicRecovery>().cloned();
+        if panic_recovery
+            .as_ref()
+            .is_some_and(|panic_recovery| panic_recovery.skip_panicked_systems)
+        {
+            // mark systems that panicked in previous runs as completed
+            self.completed_systems |= &self.panicked_systems;
+        }
+
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -94,15 +111,26 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            let start = profiling.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 __rust_begin_short_backtrace::run(&mut **system, world);
             }));
             if let Err(payload) = res {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
-                std::panic::resume_unwind(payload);
+                let Some(panic_recovery) = &panic_recovery else {
+                    std::panic::resume_unwind(payload);
+                };
+                panic_recovery.recover(&system.name(), &*payload);
+                self.panicked_systems.insert(system_index);
+            }
+            if let Some(start) = start {
+                self.system_timings.push((system_index, start.elapsed()));
             }
         }
 
+        if profiling {
+            SystemProfiling::record(world, schedule, self.system_timings.drain(..));
+        }
         self.evaluated_sets.clear();
         self.completed_systems.clear();
     }
@@ -119,6 +147,8 @@ impl SimpleExecutor {
         Self {
             evaluated_sets: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
+            system_timings: Vec::new(),
+            panicked_systems: FixedBitSet::new(),
         }
     }
 }
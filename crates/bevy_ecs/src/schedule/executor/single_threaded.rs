@@ -1,10 +1,14 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
-use std::panic::AssertUnwindSafe;
+use std::{panic::AssertUnwindSafe, time::Duration};
 
 use crate::{
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemPanicRecovery,
+        SystemProfiling, SystemSchedule,
+    },
     world::World,
 };
 
@@ -24,6 +28,10 @@ pub struct SingleThreadedExecutor {
     unapplied_systems: FixedBitSet,
     /// Setting when true applies deferred system buffers after all systems have run
     apply_final_deferred: bool,
+    /// How long the systems took to run, when they are profiled.
+    system_timings: Vec<(usize, Duration)>,
+    /// Systems that panicked while their panics were isolated by [`SystemPanicRecovery`].
+    panicked_systems: FixedBitSet,
 }
 
 impl SystemExecutor for SingleThreadedExecutor {
@@ -37,6 +45,7 @@ impl SystemExecutor for SingleThreadedExecutor {
         let set_count = schedule.set_ids.len();
         self.evaluated_sets = FixedBitSet::with_capacity(set_count);
         self.completed_systems = FixedBitSet::with_capacity(sys_count);
+        self.panicked_systems = FixedBitSet::with_capacity(sys_count);
         self.unapplied_systems = FixedBitSet::with_capacity(sys_count);
     }
 
@@ -54,6 +63,16 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        let profiling = world.contains_resource::<SystemProfiling>();
+        let panic_recovery = world.get_resource::<SystemPanicRecovery>().cloned();
+        if panic_recovery
+            .as_ref()
+            .is_some_and(|panic_recovery| panic_recovery.skip_panicked_systems)
+        {
+            // mark systems that panicked in previous runs as completed
+            self.completed_systems |= &self.panicked_systems;
+        }
+
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -101,6 +120,7 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            let start = profiling.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 if system.is_exclusive() {
                     __rust_begin_short_backtrace::run(&mut **system, world);
@@ -115,7 +135,14 @@ impl SystemExecutor for SingleThreadedExecutor {
             }));
             if let Err(payload) = res {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
-                std::panic::resume_unwind(payload);
+                let Some(panic_recovery) = &panic_recovery else {
+                    std::panic::resume_unwind(payload);
+                };
+                panic_recovery.recover(&system.name(), &*payload);
+                self.panicked_systems.insert(system_index);
+            }
+            if let Some(start) = start {
+                self.system_timings.push((system_index, start.elapsed()));
             }
             self.unapplied_systems.insert(system_index);
         }
@@ -123,6 +150,9 @@ impl SystemExecutor for SingleThreadedExecutor {
         if self.apply_final_deferred {
             self.apply_deferred(schedule, world);
         }
+        if profiling {
+            SystemProfiling::record(world, schedule, self.system_timings.drain(..));
+        }
         self.evaluated_sets.clear();
         self.completed_systems.clear();
     }
@@ -142,6 +172,8 @@ impl SingleThreadedExecutor {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             apply_final_deferred: true,
+            system_timings: Vec::new(),
+            panicked_systems: FixedBitSet::new(),
         }
     }
 
@@ -81,6 +81,8 @@ pub(crate) struct GraphInfo {
     /// the sets that the node depends on (must run before or after)
     pub(crate) dependencies: Vec<Dependency>,
     pub(crate) ambiguous_with: Ambiguity,
+    /// the priority of the node among the nodes it isn't ordered with
+    pub(crate) priority: i32,
 }
 
 /// Converts 2D row-major pair of indices into a 1D array index.
@@ -116,6 +116,134 @@ mod tests {
 
             schedule.run(&mut world);
         }
+
+        #[test]
+        fn executors_produce_the_same_results() {
+            let orders = [
+                ExecutorKind::Simple,
+                ExecutorKind::SingleThreaded,
+                ExecutorKind::MultiThreaded,
+            ]
+            .map(|executor| {
+                let mut world = World::default();
+                let mut schedule = Schedule::default();
+                schedule.set_executor_kind(executor);
+
+                world.init_resource::<SystemOrder>();
+                world.insert_resource(RunConditionBool(false));
+
+                schedule.add_systems(
+                    (
+                        make_function_system(0),
+                        make_exclusive_system(1),
+                        make_function_system(2)
+                            .run_if(|condition: Res<RunConditionBool>| condition.0),
+                        named_system,
+                    )
+                        .chain(),
+                );
+
+                schedule.run(&mut world);
+                world.resource_mut::<RunConditionBool>().0 = true;
+                schedule.run(&mut world);
+
+                world.remove_resource::<SystemOrder>().unwrap().0
+            });
+
+            assert_eq!(orders[0], vec![0, 1, u32::MAX, 0, 1, 2, u32::MAX]);
+            assert_eq!(orders[0], orders[1]);
+            assert_eq!(orders[1], orders[2]);
+        }
+
+        #[test]
+        fn profiled_executors_record_the_systems_that_ran() {
+            for executor in [
+                ExecutorKind::Simple,
+                ExecutorKind::SingleThreaded,
+                ExecutorKind::MultiThreaded,
+            ] {
+                let mut world = World::default();
+                let mut schedule = Schedule::default();
+                schedule.set_executor_kind(executor);
+
+                world.init_resource::<SystemOrder>();
+                schedule.add_systems((
+                    named_system,
+                    named_exclusive_system,
+                    counting_system.run_if(|| false),
+                ));
+
+                // systems aren't timed until profiling is enabled
+                schedule.run(&mut world);
+                world.init_resource::<SystemProfiling>();
+                schedule.run(&mut world);
+
+                let profiling = world.resource::<SystemProfiling>();
+                let mut names: Vec<_> = profiling
+                    .report()
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect();
+                names.sort_unstable();
+                assert_eq!(
+                    names,
+                    [
+                        std::any::type_name_of_val(&named_exclusive_system),
+                        std::any::type_name_of_val(&named_system),
+                    ],
+                    "{executor:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn isolated_panics_call_the_recovery_hook() {
+            use std::sync::Arc;
+
+            for executor in [
+                ExecutorKind::Simple,
+                ExecutorKind::SingleThreaded,
+                ExecutorKind::MultiThreaded,
+            ] {
+                for skip_panicked_systems in [false, true] {
+                    let mut world = World::default();
+                    let mut schedule = Schedule::default();
+                    schedule.set_executor_kind(executor);
+
+                    let panics = Arc::new(AtomicU32::new(0));
+                    let hook_panics = panics.clone();
+                    world.init_resource::<SystemOrder>();
+                    let mut panic_recovery =
+                        SystemPanicRecovery::new().on_system_panic(move |name, _| {
+                            assert!(name.ends_with("panicking_system"));
+                            hook_panics.fetch_add(1, Ordering::Relaxed);
+                        });
+                    panic_recovery.skip_panicked_systems = skip_panicked_systems;
+                    world.insert_resource(panic_recovery);
+
+                    fn panicking_system() {
+                        panic!("isolated panic");
+                    }
+                    schedule.add_systems((panicking_system, make_function_system(0)).chain());
+
+                    schedule.run(&mut world);
+                    schedule.run(&mut world);
+
+                    // the systems depending on the panicking system still run
+                    assert_eq!(
+                        world.resource::<SystemOrder>().0,
+                        vec![0, 0],
+                        "{executor:?}"
+                    );
+                    let expected_panics = if skip_panicked_systems { 1 } else { 2 };
+                    assert_eq!(
+                        panics.load(Ordering::Relaxed),
+                        expected_panics,
+                        "{executor:?}"
+                    );
+                }
+            }
+        }
     }
 
     mod system_ordering {
@@ -240,6 +368,33 @@ mod tests {
             );
             assert_eq!(order.len(), 11, "must have exactly 11 order entries");
         }
+
+        #[test]
+        fn priorities_break_ties_between_ambiguous_systems() {
+            for _ in 0..10 {
+                let mut world = World::new();
+                let mut schedule = Schedule::default();
+                schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+
+                world.init_resource::<SystemOrder>();
+
+                schedule.add_systems((
+                    make_function_system(0),
+                    make_function_system(1).priority(1),
+                    make_function_system(2).priority(2),
+                    make_exclusive_system(3).priority(-1),
+                ));
+                // priorities never override explicit orderings
+                schedule.add_systems(named_system.priority(3).after(make_exclusive_system(3)));
+
+                schedule.run(&mut world);
+                schedule.run(&mut world);
+                assert_eq!(
+                    world.resource::<SystemOrder>().0,
+                    [[2, 1, 0, 3, u32::MAX]; 2].concat()
+                );
+            }
+        }
     }
 
     mod conditions {
@@ -1,5 +1,6 @@
 use std::{
-    collections::BTreeSet,
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
     fmt::{Debug, Write},
 };
 
@@ -615,6 +616,8 @@ pub struct ScheduleGraph {
     /// Dependency edges that will **not** automatically insert an instance of `apply_deferred` on the edge.
     no_sync_edges: BTreeSet<(NodeId, NodeId)>,
     auto_sync_node_ids: HashMap<u32, NodeId>,
+    /// Priorities of the systems that don't have the default priority of `0`.
+    system_priorities: HashMap<NodeId, i32>,
 }
 
 impl ScheduleGraph {
@@ -637,6 +640,7 @@ impl ScheduleGraph {
             settings: default(),
             no_sync_edges: BTreeSet::new(),
             auto_sync_node_ids: HashMap::new(),
+            system_priorities: HashMap::new(),
         }
     }
 
@@ -991,9 +995,13 @@ impl ScheduleGraph {
             hierarchy: sets,
             dependencies,
             ambiguous_with,
-            ..
+            priority,
         } = graph_info;
 
+        if priority != 0 {
+            self.system_priorities.insert(id, priority);
+        }
+
         self.hierarchy.graph.add_node(id);
         self.dependency.graph.add_node(id);
 
@@ -1109,8 +1117,12 @@ impl ScheduleGraph {
         }
 
         // topsort
+        let mut topsort = self.topsort_graph(&dependency_flattened, ReportCycles::Dependency)?;
+        if !self.system_priorities.is_empty() {
+            topsort = self.sort_by_priority(&dependency_flattened, &topsort);
+        }
         let mut dependency_flattened_dag = Dag {
-            topsort: self.topsort_graph(&dependency_flattened, ReportCycles::Dependency)?,
+            topsort,
             graph: dependency_flattened,
         };
 
@@ -1379,6 +1391,35 @@ impl ScheduleGraph {
         conflicting_systems
     }
 
+    /// Reorders `topsort` so that, among the systems whose dependencies have all been sorted, the
+    /// ones with the highest priority come first, followed by their order in `topsort`.
+    fn sort_by_priority(&self, graph: &DiGraphMap<NodeId, ()>, topsort: &[NodeId]) -> Vec<NodeId> {
+        let mut remaining_dependencies = HashMap::with_capacity(topsort.len());
+        let mut ready = BinaryHeap::new();
+        for (position, &id) in topsort.iter().enumerate() {
+            let priority = self.system_priorities.get(&id).copied().unwrap_or(0);
+            let dependencies = graph.neighbors_directed(id, Incoming).count();
+            remaining_dependencies.insert(id, (dependencies, priority, position));
+            if dependencies == 0 {
+                ready.push((priority, Reverse(position), id));
+            }
+        }
+
+        let mut sorted = Vec::with_capacity(topsort.len());
+        while let Some((_, _, id)) = ready.pop() {
+            sorted.push(id);
+            for dependent in graph.neighbors_directed(id, Outgoing) {
+                let (dependencies, priority, position) =
+                    remaining_dependencies.get_mut(&dependent).unwrap();
+                *dependencies -= 1;
+                if *dependencies == 0 {
+                    ready.push((*priority, Reverse(*position), dependent));
+                }
+            }
+        }
+        sorted
+    }
+
     fn build_schedule_inner(
         &self,
         dependency_flattened_dag: Dag,
@@ -1,6 +1,6 @@
 use ab_glyph::{Font as _, FontArc, Glyph, PxScaleFont, ScaleFont as _};
 use bevy_asset::{AssetId, Assets};
-use bevy_math::{Rect, Vec2};
+use bevy_math::{FloatOrd, Rect, Vec2};
 use bevy_reflect::Reflect;
 use bevy_render::texture::Image;
 use bevy_sprite::TextureAtlasLayout;
@@ -38,18 +38,22 @@ impl GlyphBrush {
         bounds: Vec2,
         text_alignment: JustifyText,
         linebreak_behavior: BreakLineOn,
+        line_height: Option<f32>,
     ) -> Result<Vec<SectionGlyph>, TextError> {
         let geom = SectionGeometry {
-            bounds: (bounds.x, bounds.y),
+            bounds: (bounds.x, layout_height(bounds.y, line_height)),
             ..Default::default()
         };
 
         let lbb: BuiltInLineBreaker = linebreak_behavior.into();
 
-        let section_glyphs = Layout::default()
+        let mut section_glyphs = Layout::default()
             .h_align(text_alignment.into())
             .line_breaker(lbb)
             .calculate_glyphs(&self.fonts, &geom, sections);
+        if let Some(line_height) = line_height {
+            apply_line_height(&mut section_glyphs, line_height, bounds.y);
+        }
         Ok(section_glyphs)
     }
 
@@ -207,6 +211,45 @@ impl GlyphPlacementAdjuster {
     }
 }
 
+/// The height to lay out text in before [`apply_line_height`] moves its lines, which all have to be
+/// kept until then.
+pub(crate) fn layout_height(height: f32, line_height: Option<f32>) -> f32 {
+    match line_height {
+        Some(_) => f32::INFINITY,
+        None => height,
+    }
+}
+
+/// Moves the lines of `section_glyphs` so that their baselines are `line_height` apart, keeping the
+/// baseline of the first line.
+/// Like the layout does with the font metrics, the lines starting at or below `max_height` are
+/// removed, where each line starts `line_height` below the previous one.
+pub(crate) fn apply_line_height(
+    section_glyphs: &mut Vec<SectionGlyph>,
+    line_height: f32,
+    max_height: f32,
+) {
+    // the glyphs of a line share its baseline
+    let mut baselines = section_glyphs
+        .iter()
+        .map(|section_glyph| FloatOrd(section_glyph.glyph.position.y))
+        .collect::<Vec<_>>();
+    baselines.sort_unstable();
+    baselines.dedup();
+    let Some(&FloatOrd(first_baseline)) = baselines.first() else {
+        return;
+    };
+
+    section_glyphs.retain_mut(|section_glyph| {
+        let Ok(line) = baselines.binary_search(&FloatOrd(section_glyph.glyph.position.y)) else {
+            return true;
+        };
+        let line_top = line as f32 * line_height;
+        section_glyph.glyph.position.y = first_baseline + line_top;
+        line_top < max_height
+    });
+}
+
 /// Computes the minimal bounding rectangle for a block of text.
 /// Ignores empty trailing lines.
 pub(crate) fn compute_text_bounds<T>(
@@ -29,7 +29,9 @@ pub use text2d::*;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Font, JustifyText, Text, Text2dBundle, TextError, TextSection, TextStyle};
+    pub use crate::{
+        Font, JustifyText, LineHeight, Text, Text2dBundle, TextError, TextSection, TextStyle,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -1,19 +1,23 @@
 use crate::{
-    compute_text_bounds, error::TextError, glyph_brush::GlyphBrush, scale_value, BreakLineOn, Font,
-    FontAtlasSets, JustifyText, PositionedGlyph, Text, TextSection, TextSettings, YAxisOrientation,
+    apply_line_height, compute_text_bounds, error::TextError, glyph_brush::GlyphBrush,
+    layout_height, scale_value, BreakLineOn, Font, FontAtlasSets, JustifyText, LineHeight,
+    PositionedGlyph, Text, TextSection, TextSettings, YAxisOrientation,
 };
 use ab_glyph::PxScale;
 use bevy_asset::{AssetId, Assets, Handle};
 use bevy_ecs::component::Component;
 use bevy_ecs::prelude::ReflectComponent;
 use bevy_ecs::system::Resource;
-use bevy_math::Vec2;
+use bevy_math::{FloatOrd, Rect, Vec2};
 use bevy_reflect::prelude::ReflectDefault;
 use bevy_reflect::Reflect;
 use bevy_render::texture::Image;
 use bevy_sprite::TextureAtlasLayout;
 use bevy_utils::HashMap;
-use glyph_brush_layout::{FontId, GlyphPositioner, SectionGeometry, SectionText, ToSectionText};
+use glyph_brush_layout::{
+    FontId, GlyphPositioner, SectionGeometry, SectionGlyph, SectionText, ToSectionText,
+};
+use std::ops::Range;
 
 #[derive(Default, Resource)]
 pub struct TextPipeline {
@@ -31,6 +35,55 @@ pub struct TextLayoutInfo {
     pub logical_size: Vec2,
 }
 
+/// Returns the range in `layout.glyphs` of the glyphs of the line closest to `local_pos` vertically,
+/// which is empty if the layout has no glyphs.
+///
+/// `local_pos` is in the coordinates of the [`PositionedGlyph::position`]s of the layout. Glyphs are
+/// laid out left to right, so a glyph that isn't right of the previous one starts a new line.
+pub fn glyph_line_at(layout: &TextLayoutInfo, local_pos: Vec2) -> Range<usize> {
+    let glyph_rect = |glyph: &PositionedGlyph| Rect::from_center_size(glyph.position, glyph.size);
+
+    // the range of glyphs of each line, and the vertical span of the line
+    let mut lines: Vec<(Range<usize>, Rect)> = Vec::new();
+    for (index, glyph) in layout.glyphs.iter().enumerate() {
+        let rect = glyph_rect(glyph);
+        match lines.last_mut() {
+            Some((glyphs, line))
+                if glyph_rect(&layout.glyphs[glyphs.end - 1]).min.x < rect.min.x =>
+            {
+                glyphs.end = index + 1;
+                *line = line.union(rect);
+            }
+            _ => lines.push((index..index + 1, rect)),
+        }
+    }
+
+    let distance = |line: &Rect| {
+        (line.min.y - local_pos.y)
+            .max(local_pos.y - line.max.y)
+            .max(0.)
+    };
+    lines
+        .into_iter()
+        .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        .map_or(0..0, |(glyphs, _)| glyphs)
+}
+
+/// Returns the index in `layout.glyphs` of the glyph a caret placed at `local_pos` goes before,
+/// from `0` before the first glyph to `layout.glyphs.len()` after the last one.
+///
+/// The caret goes on the line found by [`glyph_line_at`], before the first glyph of the line whose
+/// center is right of `local_pos`, or after the last glyph of the line when there is none.
+///
+/// Use [`PositionedGlyph::section_index`] and [`PositionedGlyph::byte_index`] to find the character
+/// of the glyph at the returned index.
+pub fn glyph_index_at(layout: &TextLayoutInfo, local_pos: Vec2) -> usize {
+    let line = glyph_line_at(layout, local_pos);
+    line.clone()
+        .find(|index| layout.glyphs[*index].position.x > local_pos.x)
+        .unwrap_or(line.end)
+}
+
 impl TextPipeline {
     pub fn get_or_insert_font_id(&mut self, handle: &Handle<Font>, font: &Font) -> FontId {
         let brush = &mut self.brush;
@@ -48,6 +101,7 @@ impl TextPipeline {
         scale_factor: f32,
         text_alignment: JustifyText,
         linebreak_behavior: BreakLineOn,
+        line_height: LineHeight,
         bounds: Vec2,
         font_atlas_sets: &mut FontAtlasSets,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
@@ -55,6 +109,7 @@ impl TextPipeline {
         text_settings: &TextSettings,
         y_axis_orientation: YAxisOrientation,
     ) -> Result<TextLayoutInfo, TextError> {
+        let line_height = line_height.resolve_physical(sections, scale_factor);
         let mut scaled_fonts = Vec::with_capacity(sections.len());
         let sections = sections
             .iter()
@@ -77,9 +132,13 @@ impl TextPipeline {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let section_glyphs =
-            self.brush
-                .compute_glyphs(&sections, bounds, text_alignment, linebreak_behavior)?;
+        let section_glyphs = self.brush.compute_glyphs(
+            &sections,
+            bounds,
+            text_alignment,
+            linebreak_behavior,
+            line_height,
+        )?;
 
         if section_glyphs.is_empty() {
             return Ok(TextLayoutInfo::default());
@@ -132,6 +191,9 @@ pub struct TextMeasureInfo {
     pub sections: Box<[TextMeasureSection]>,
     pub justification: JustifyText,
     pub linebreak_behavior: glyph_brush_layout::BuiltInLineBreaker,
+    /// The distance between the baselines of consecutive lines in physical pixels, or `None` if
+    /// the lines are spaced by their font metrics.
+    pub line_height: Option<f32>,
     pub min: Vec2,
     pub max: Vec2,
 }
@@ -164,6 +226,7 @@ impl TextMeasureInfo {
             out_sections,
             text.justify,
             text.linebreak_behavior.into(),
+            text.line_height.resolve_physical(sections, scale_factor),
         ))
     }
     fn new(
@@ -171,12 +234,14 @@ impl TextMeasureInfo {
         sections: Vec<TextMeasureSection>,
         justification: JustifyText,
         linebreak_behavior: glyph_brush_layout::BuiltInLineBreaker,
+        line_height: Option<f32>,
     ) -> Self {
         let mut info = Self {
             fonts: fonts.into_boxed_slice(),
             sections: sections.into_boxed_slice(),
             justification,
             linebreak_behavior,
+            line_height,
             min: Vec2::ZERO,
             max: Vec2::ZERO,
         };
@@ -189,17 +254,53 @@ impl TextMeasureInfo {
     }
 
     pub fn compute_size(&self, bounds: Vec2) -> Vec2 {
-        let sections = &self.sections;
+        let section_glyphs = self.calculate_glyphs(bounds);
+        self.compute_bounds(&section_glyphs)
+    }
+
+    /// Computes the size of the text within `bounds` like [`Self::compute_size`], keeping only its
+    /// first `max_lines` lines. Also returns whether any line was left out.
+    pub fn compute_size_with_max_lines(&self, bounds: Vec2, max_lines: usize) -> (Vec2, bool) {
+        let mut section_glyphs = self.calculate_glyphs(bounds);
+        if section_glyphs.is_empty() {
+            return (self.compute_bounds(&section_glyphs), false);
+        }
+
+        // the glyphs of a line share its baseline
+        let mut baselines = section_glyphs
+            .iter()
+            .map(|section_glyph| FloatOrd(section_glyph.glyph.position.y))
+            .collect::<Vec<_>>();
+        baselines.sort_unstable();
+        baselines.dedup();
+        if baselines.len() <= max_lines {
+            return (self.compute_bounds(&section_glyphs), false);
+        }
+        let Some(last_baseline) = max_lines.checked_sub(1).map(|last| baselines[last]) else {
+            return (Vec2::ZERO, true);
+        };
+        section_glyphs
+            .retain(|section_glyph| FloatOrd(section_glyph.glyph.position.y) <= last_baseline);
+        (self.compute_bounds(&section_glyphs), true)
+    }
+
+    fn calculate_glyphs(&self, bounds: Vec2) -> Vec<SectionGlyph> {
         let geom = SectionGeometry {
-            bounds: (bounds.x, bounds.y),
+            bounds: (bounds.x, layout_height(bounds.y, self.line_height)),
             ..Default::default()
         };
-        let section_glyphs = glyph_brush_layout::Layout::default()
+        let mut section_glyphs = glyph_brush_layout::Layout::default()
             .h_align(self.justification.into())
             .line_breaker(self.linebreak_behavior)
-            .calculate_glyphs(&self.fonts, &geom, sections);
+            .calculate_glyphs(&self.fonts, &geom, &self.sections);
+        if let Some(line_height) = self.line_height {
+            apply_line_height(&mut section_glyphs, line_height, bounds.y);
+        }
+        section_glyphs
+    }
 
-        compute_text_bounds(&section_glyphs, |index| {
+    fn compute_bounds(&self, section_glyphs: &[SectionGlyph]) -> Vec2 {
+        compute_text_bounds(section_glyphs, |index| {
             let font = &self.fonts[index];
             let font_size = self.sections[index].scale;
             ab_glyph::Font::into_scaled(font, font_size)
@@ -217,3 +318,129 @@ impl ToSectionText for TextMeasureSection {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec2;
+
+    use bevy_asset::Assets;
+
+    use super::{
+        glyph_index_at, glyph_line_at, TextLayoutInfo, TextMeasureInfo, TextMeasureSection,
+    };
+    use crate::{Font, GlyphAtlasInfo, JustifyText, LineHeight, PositionedGlyph, Text, TextStyle};
+    use glyph_brush_layout::{BuiltInLineBreaker, FontId};
+
+    #[test]
+    fn max_lines_keep_the_first_lines() {
+        let font =
+            ab_glyph::FontArc::try_from_slice(include_bytes!("FiraMono-subset.ttf")).unwrap();
+        let info = TextMeasureInfo::new(
+            vec![font],
+            vec![TextMeasureSection {
+                text: "one\ntwo\nthree".into(),
+                scale: 20.,
+                font_id: FontId(0),
+            }],
+            JustifyText::Left,
+            BuiltInLineBreaker::UnicodeLineBreaker,
+            None,
+        );
+        let bounds = Vec2::INFINITY;
+        let size = info.compute_size(bounds);
+
+        assert_eq!(info.compute_size_with_max_lines(bounds, 3), (size, false));
+        let (two_lines, truncated) = info.compute_size_with_max_lines(bounds, 2);
+        assert!(truncated);
+        assert!(two_lines.y < size.y);
+        let (one_line, _) = info.compute_size_with_max_lines(bounds, 1);
+        assert!(one_line.y < two_lines.y);
+        // "three" was the widest line
+        assert!(two_lines.x < size.x);
+        assert_eq!(one_line.x, two_lines.x);
+        assert_eq!(
+            info.compute_size_with_max_lines(bounds, 0),
+            (Vec2::ZERO, true)
+        );
+    }
+
+    #[test]
+    fn glyph_index_at_finds_the_closest_caret_position() {
+        // "abc" above "de", in glyphs of 10 by 20 pixels
+        let glyph = |x: f32, y: f32, byte_index| PositionedGlyph {
+            position: Vec2::new(x, y),
+            size: Vec2::new(10., 20.),
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Default::default(),
+                texture: Default::default(),
+                glyph_index: 0,
+            },
+            section_index: 0,
+            byte_index,
+        };
+        let layout = TextLayoutInfo {
+            glyphs: vec![
+                glyph(5., 10., 0),
+                glyph(15., 10., 1),
+                glyph(25., 10., 2),
+                glyph(5., 30., 4),
+                glyph(15., 30., 5),
+            ],
+            logical_size: Vec2::new(30., 40.),
+        };
+        let index_at = |x, y| glyph_index_at(&layout, Vec2::new(x, y));
+
+        assert_eq!(index_at(-5., 10.), 0);
+        assert_eq!(index_at(4., 10.), 0);
+        assert_eq!(index_at(6., 10.), 1);
+        assert_eq!(index_at(24., 15.), 2);
+        // past the end of a line, the caret goes after its last glyph
+        assert_eq!(index_at(100., 10.), 3);
+        assert_eq!(index_at(-5., 30.), 3);
+        assert_eq!(index_at(12., 25.), 4);
+        assert_eq!(index_at(100., 30.), 5);
+        // above the first line and below the last one
+        assert_eq!(index_at(-10., -50.), 0);
+        assert_eq!(index_at(16., 100.), 5);
+        assert_eq!(glyph_index_at(&TextLayoutInfo::default(), Vec2::ZERO), 0);
+
+        // the end of a line and the start of the next one are told apart by their line
+        assert_eq!(glyph_line_at(&layout, Vec2::new(100., 10.)), 0..3);
+        assert_eq!(glyph_line_at(&layout, Vec2::new(-5., 30.)), 3..5);
+        assert_eq!(glyph_line_at(&TextLayoutInfo::default(), Vec2::ZERO), 0..0);
+    }
+
+    #[test]
+    fn line_height_spaces_the_lines() {
+        let mut fonts = Assets::<Font>::default();
+        let font = fonts
+            .add(Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap());
+        let text = Text::from_section(
+            "one\ntwo\nthree",
+            TextStyle {
+                font,
+                font_size: 20.,
+                ..Default::default()
+            },
+        );
+        let height = |line_height| {
+            let text = text.clone().with_line_height(line_height);
+            // the scale factor applies to pixel line heights too
+            TextMeasureInfo::from_text(&text, &fonts, 2.)
+                .unwrap()
+                .compute_size(Vec2::INFINITY)
+                .y
+        };
+
+        // three lines are two line heights apart
+        assert_eq!(
+            height(LineHeight::Relative(2.)) - height(LineHeight::Px(30.)),
+            40.
+        );
+        assert_eq!(
+            height(LineHeight::Px(40.)),
+            height(LineHeight::Relative(2.))
+        );
+        assert!(height(LineHeight::Normal) < height(LineHeight::Relative(2.)));
+    }
+}
@@ -5,7 +5,7 @@ use bevy_reflect::prelude::*;
 use bevy_utils::default;
 use serde::{Deserialize, Serialize};
 
-use crate::Font;
+use crate::{scale_value, Font};
 
 #[derive(Component, Debug, Clone, Default, Reflect)]
 #[reflect(Component, Default)]
@@ -16,6 +16,8 @@ pub struct Text {
     pub justify: JustifyText,
     /// How the text should linebreak when running out of the bounds determined by `max_size`
     pub linebreak_behavior: BreakLineOn,
+    /// The distance between the baselines of consecutive lines.
+    pub line_height: LineHeight,
 }
 
 impl Text {
@@ -104,6 +106,12 @@ impl Text {
         self.linebreak_behavior = BreakLineOn::NoWrap;
         self
     }
+
+    /// Returns this [`Text`] with a new [`LineHeight`].
+    pub const fn with_line_height(mut self, line_height: LineHeight) -> Self {
+        self.line_height = line_height;
+        self
+    }
 }
 
 #[derive(Debug, Default, Clone, Reflect)]
@@ -180,6 +188,42 @@ impl From<JustifyText> for glyph_brush_layout::HorizontalAlign {
     }
 }
 
+/// The distance between the baselines of consecutive lines of a [`Text`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum LineHeight {
+    /// Lines are spaced by the metrics of their fonts.
+    #[default]
+    Normal,
+    /// Lines are spaced by this many logical pixels.
+    Px(f32),
+    /// Lines are spaced by this multiple of the font size.
+    /// For text with several sections, this is the largest of their font sizes.
+    Relative(f32),
+}
+
+impl LineHeight {
+    /// Returns the distance between baselines for text of `font_size`, or `None` if the lines are
+    /// spaced by their font metrics.
+    pub fn resolve(self, font_size: f32) -> Option<f32> {
+        match self {
+            LineHeight::Normal => None,
+            LineHeight::Px(px) => Some(px),
+            LineHeight::Relative(scale) => Some(scale * font_size),
+        }
+    }
+
+    /// Resolves the line height of `sections` like [`Self::resolve`], in physical pixels.
+    pub fn resolve_physical(self, sections: &[TextSection], scale_factor: f32) -> Option<f32> {
+        let font_size = sections
+            .iter()
+            .map(|section| section.style.font_size)
+            .fold(0., f32::max);
+        self.resolve(font_size)
+            .map(|line_height| scale_value(line_height, scale_factor))
+    }
+}
+
 #[derive(Clone, Debug, Reflect)]
 pub struct TextStyle {
     /// If this is not specified, then
@@ -210,6 +210,7 @@ pub fn update_text2d_layout(
                 scale_factor,
                 text.justify,
                 text.linebreak_behavior,
+                text.line_height,
                 text_bounds,
                 &mut font_atlas_sets,
                 &mut texture_atlases,
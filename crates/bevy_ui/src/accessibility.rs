@@ -1,27 +1,35 @@
 use crate::{
     prelude::{Button, Label},
-    Node, UiImage,
+    widget::{Checkbox, Slider, SliderOrientation, Toggle},
+    AccessibilityLabel, FocusedEntity, Node, UiImage,
 };
 use bevy_a11y::{
-    accesskit::{NodeBuilder, Rect, Role},
-    AccessibilityNode,
+    accesskit::{NodeBuilder, Orientation, Rect, Role, Toggled},
+    AccessibilityNode, AccessibilitySystem, Focus,
 };
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_ecs::{
     prelude::{DetectChanges, Entity},
-    query::{Changed, Without},
+    query::{Added, Changed, Or, With, Without},
     schedule::IntoSystemConfigs,
-    system::{Commands, Query},
-    world::Ref,
+    system::{Commands, Query, Res, ResMut},
+    world::{Mut, Ref},
 };
 use bevy_hierarchy::Children;
 use bevy_render::{camera::CameraUpdateSystem, prelude::Camera};
 use bevy_text::Text;
 use bevy_transform::prelude::GlobalTransform;
 
-fn calc_name(texts: &Query<&Text>, children: &Children) -> Option<Box<str>> {
+fn calc_name(
+    texts: &Query<&Text>,
+    label: Option<&AccessibilityLabel>,
+    children: Option<&Children>,
+) -> Option<Box<str>> {
+    if let Some(label) = label {
+        return Some(label.0.clone().into_boxed_str());
+    }
     let mut name = None;
-    for child in children {
+    for child in children.into_iter().flatten() {
         if let Ok(text) = texts.get(*child) {
             let values = text
                 .sections
@@ -34,6 +42,44 @@ fn calc_name(texts: &Query<&Text>, children: &Children) -> Option<Box<str>> {
     name.map(|v| v.into_boxed_str())
 }
 
+/// Sets the role, name and any other properties of the entity's [`AccessibilityNode`],
+/// inserting one if it doesn't have it yet.
+fn update_accessibility_node(
+    commands: &mut Commands,
+    entity: Entity,
+    accessible: Option<Mut<AccessibilityNode>>,
+    role: Role,
+    name: Option<Box<str>>,
+    configure: impl FnOnce(&mut NodeBuilder),
+) {
+    if let Some(mut accessible) = accessible {
+        accessible.set_role(role);
+        if let Some(name) = name {
+            accessible.set_name(name);
+        } else {
+            accessible.clear_name();
+        }
+        configure(&mut accessible);
+    } else {
+        let mut node = NodeBuilder::new(role);
+        if let Some(name) = name {
+            node.set_name(name);
+        }
+        configure(&mut node);
+        commands
+            .entity(entity)
+            .try_insert(AccessibilityNode::from(node));
+    }
+}
+
+fn toggled(on: bool) -> Toggled {
+    if on {
+        Toggled::True
+    } else {
+        Toggled::False
+    }
+}
+
 fn calc_bounds(
     camera: Query<(&Camera, &GlobalTransform)>,
     mut nodes: Query<(&mut AccessibilityNode, Ref<Node>, Ref<GlobalTransform>)>,
@@ -59,86 +105,209 @@ fn calc_bounds(
 
 fn button_changed(
     mut commands: Commands,
-    mut query: Query<(Entity, &Children, Option<&mut AccessibilityNode>), Changed<Button>>,
+    mut query: Query<
+        (
+            Entity,
+            Option<&AccessibilityLabel>,
+            Option<&Children>,
+            Option<&mut AccessibilityNode>,
+        ),
+        (
+            With<Button>,
+            Or<(Changed<Button>, Changed<AccessibilityLabel>)>,
+        ),
+    >,
     texts: Query<&Text>,
 ) {
-    for (entity, children, accessible) in &mut query {
-        let name = calc_name(&texts, children);
-        if let Some(mut accessible) = accessible {
-            accessible.set_role(Role::Button);
-            if let Some(name) = name {
-                accessible.set_name(name);
-            } else {
-                accessible.clear_name();
-            }
-        } else {
-            let mut node = NodeBuilder::new(Role::Button);
-            if let Some(name) = name {
-                node.set_name(name);
-            }
-            commands
-                .entity(entity)
-                .try_insert(AccessibilityNode::from(node));
-        }
+    for (entity, label, children, accessible) in &mut query {
+        let name = calc_name(&texts, label, children);
+        update_accessibility_node(
+            &mut commands,
+            entity,
+            accessible,
+            Role::Button,
+            name,
+            |_| {},
+        );
     }
 }
 
 fn image_changed(
     mut commands: Commands,
     mut query: Query<
-        (Entity, &Children, Option<&mut AccessibilityNode>),
-        (Changed<UiImage>, Without<Button>),
+        (
+            Entity,
+            Option<&AccessibilityLabel>,
+            Option<&Children>,
+            Option<&mut AccessibilityNode>,
+        ),
+        (
+            With<UiImage>,
+            Or<(Changed<UiImage>, Changed<AccessibilityLabel>)>,
+            Without<Button>,
+            Without<Checkbox>,
+        ),
     >,
     texts: Query<&Text>,
 ) {
-    for (entity, children, accessible) in &mut query {
-        let name = calc_name(&texts, children);
-        if let Some(mut accessible) = accessible {
-            accessible.set_role(Role::Image);
-            if let Some(name) = name {
-                accessible.set_name(name);
-            } else {
-                accessible.clear_name();
-            }
-        } else {
-            let mut node = NodeBuilder::new(Role::Image);
-            if let Some(name) = name {
-                node.set_name(name);
-            }
-            commands
-                .entity(entity)
-                .try_insert(AccessibilityNode::from(node));
-        }
+    for (entity, label, children, accessible) in &mut query {
+        let name = calc_name(&texts, label, children);
+        update_accessibility_node(&mut commands, entity, accessible, Role::Image, name, |_| {});
     }
 }
 
 fn label_changed(
     mut commands: Commands,
-    mut query: Query<(Entity, &Text, Option<&mut AccessibilityNode>), Changed<Label>>,
+    mut query: Query<
+        (
+            Entity,
+            &Text,
+            Option<&AccessibilityLabel>,
+            Option<&mut AccessibilityNode>,
+        ),
+        (
+            With<Label>,
+            Or<(Changed<Label>, Changed<AccessibilityLabel>)>,
+        ),
+    >,
+) {
+    for (entity, text, label, accessible) in &mut query {
+        let name = match label {
+            Some(label) => label.0.clone(),
+            None => text
+                .sections
+                .iter()
+                .map(|v| v.value.to_string())
+                .collect::<Vec<String>>()
+                .join(" "),
+        };
+        update_accessibility_node(
+            &mut commands,
+            entity,
+            accessible,
+            Role::StaticText,
+            Some(name.into_boxed_str()),
+            |_| {},
+        );
+    }
+}
+
+fn checkbox_changed(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Checkbox,
+            Option<&AccessibilityLabel>,
+            Option<&Children>,
+            Option<&mut AccessibilityNode>,
+        ),
+        Or<(Changed<Checkbox>, Changed<AccessibilityLabel>)>,
+    >,
+    texts: Query<&Text>,
 ) {
-    for (entity, text, accessible) in &mut query {
-        let values = text
-            .sections
-            .iter()
-            .map(|v| v.value.to_string())
-            .collect::<Vec<String>>();
-        let name = Some(values.join(" ").into_boxed_str());
-        if let Some(mut accessible) = accessible {
-            accessible.set_role(Role::StaticText);
-            if let Some(name) = name {
-                accessible.set_name(name);
-            } else {
-                accessible.clear_name();
-            }
-        } else {
-            let mut node = NodeBuilder::new(Role::StaticText);
-            if let Some(name) = name {
-                node.set_name(name);
-            }
-            commands
-                .entity(entity)
-                .try_insert(AccessibilityNode::from(node));
-        }
+    for (entity, checkbox, label, children, accessible) in &mut query {
+        let name = calc_name(&texts, label, children);
+        update_accessibility_node(
+            &mut commands,
+            entity,
+            accessible,
+            Role::CheckBox,
+            name,
+            |node| node.set_toggled(toggled(checkbox.checked)),
+        );
+    }
+}
+
+fn toggle_changed(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Toggle,
+            Option<&AccessibilityLabel>,
+            Option<&Children>,
+            Option<&mut AccessibilityNode>,
+        ),
+        Or<(Changed<Toggle>, Changed<AccessibilityLabel>)>,
+    >,
+    texts: Query<&Text>,
+) {
+    for (entity, toggle, label, children, accessible) in &mut query {
+        let name = calc_name(&texts, label, children);
+        update_accessibility_node(
+            &mut commands,
+            entity,
+            accessible,
+            Role::Switch,
+            name,
+            |node| node.set_toggled(toggled(toggle.on)),
+        );
+    }
+}
+
+fn slider_changed(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Slider,
+            Option<&AccessibilityLabel>,
+            Option<&Children>,
+            Option<&mut AccessibilityNode>,
+        ),
+        Or<(Changed<Slider>, Changed<AccessibilityLabel>)>,
+    >,
+    texts: Query<&Text>,
+) {
+    for (entity, slider, label, children, accessible) in &mut query {
+        let name = calc_name(&texts, label, children);
+        update_accessibility_node(
+            &mut commands,
+            entity,
+            accessible,
+            Role::Slider,
+            name,
+            |node| {
+                node.set_numeric_value(slider.value.into());
+                node.set_min_numeric_value(slider.min.into());
+                node.set_max_numeric_value(slider.max.into());
+                if let Some(step) = slider.step {
+                    node.set_numeric_value_step(step.into());
+                } else {
+                    node.clear_numeric_value_step();
+                }
+                node.set_orientation(match slider.orientation {
+                    SliderOrientation::Horizontal => Orientation::Horizontal,
+                    SliderOrientation::Vertical => Orientation::Vertical,
+                });
+            },
+        );
+    }
+}
+
+/// Reports the [`FocusedEntity`] to assistive technologies, so screen readers announce the newly
+/// focused node.
+///
+/// `AccessKit` can only focus nodes in its tree, so the window stays focused while the focused entity
+/// doesn't have an [`AccessibilityNode`].
+fn focus_changed(
+    focused_entity: Res<FocusedEntity>,
+    focus: Option<ResMut<Focus>>,
+    accessible_query: Query<(), With<AccessibilityNode>>,
+    added_query: Query<(), Added<AccessibilityNode>>,
+) {
+    let Some(mut focus) = focus else {
+        return;
+    };
+    if !focused_entity.is_changed() && added_query.is_empty() {
+        return;
+    }
+    let target = focused_entity
+        .0
+        .filter(|entity| accessible_query.contains(*entity));
+    if focus.0 != target {
+        focus.0 = target;
     }
 }
 
@@ -159,7 +328,128 @@ impl Plugin for AccessibilityPlugin {
                 button_changed,
                 image_changed,
                 label_changed,
-            ),
+                checkbox_changed,
+                toggle_changed,
+                slider_changed,
+            )
+                .before(focus_changed),
+        )
+        .add_systems(
+            PostUpdate,
+            focus_changed.before(AccessibilitySystem::Update),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_a11y::{
+        accesskit::{Role, Toggled},
+        AccessibilityNode, Focus,
+    };
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::{schedule::IntoSystemConfigs, world::World};
+
+    use super::{button_changed, checkbox_changed, focus_changed, slider_changed};
+    use crate::{
+        widget::{Button, Checkbox, Slider},
+        AccessibilityLabel, FocusedEntity,
+    };
+
+    fn accessibility_node(world: &World, entity: bevy_ecs::entity::Entity) -> &AccessibilityNode {
+        world.get::<AccessibilityNode>(entity).unwrap()
+    }
+
+    #[test]
+    fn widgets_get_roles_and_follow_their_state() {
+        let mut app = App::new();
+        app.add_systems(
+            PostUpdate,
+            (button_changed, checkbox_changed, slider_changed),
         );
+
+        let button = app
+            .world_mut()
+            .spawn((Button, AccessibilityLabel::new("Close")))
+            .id();
+        let checkbox = app.world_mut().spawn(Checkbox { checked: false }).id();
+        let slider = app
+            .world_mut()
+            .spawn(Slider {
+                value: 2.,
+                min: 0.,
+                max: 10.,
+                step: Some(1.),
+                ..Default::default()
+            })
+            .id();
+        app.update();
+
+        let world = app.world();
+        let node = accessibility_node(world, button);
+        assert_eq!(node.role(), Role::Button);
+        assert_eq!(node.name(), Some("Close"));
+
+        let node = accessibility_node(world, checkbox);
+        assert_eq!(node.role(), Role::CheckBox);
+        assert_eq!(node.toggled(), Some(Toggled::False));
+
+        let node = accessibility_node(world, slider);
+        assert_eq!(node.role(), Role::Slider);
+        assert_eq!(node.numeric_value(), Some(2.));
+        assert_eq!(node.min_numeric_value(), Some(0.));
+        assert_eq!(node.max_numeric_value(), Some(10.));
+        assert_eq!(node.numeric_value_step(), Some(1.));
+
+        app.world_mut()
+            .get_mut::<Checkbox>(checkbox)
+            .unwrap()
+            .checked = true;
+        app.world_mut().get_mut::<Slider>(slider).unwrap().value = 7.;
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            accessibility_node(world, checkbox).toggled(),
+            Some(Toggled::True)
+        );
+        assert_eq!(accessibility_node(world, slider).numeric_value(), Some(7.));
+    }
+
+    #[test]
+    fn focused_entity_is_reported_once_it_is_accessible() {
+        let mut app = App::new();
+        app.init_resource::<FocusedEntity>()
+            .init_resource::<Focus>()
+            .add_systems(PostUpdate, (button_changed, focus_changed).chain());
+
+        let plain_node = app.world_mut().spawn_empty().id();
+        let button = app.world_mut().spawn(Button).id();
+
+        // the button's accessibility node is inserted in the same update
+        app.world_mut().resource_mut::<FocusedEntity>().0 = Some(button);
+        app.update();
+        assert_eq!(app.world().resource::<Focus>().0, Some(button));
+
+        // the window keeps the focus when the node isn't in the accessibility tree
+        app.world_mut().resource_mut::<FocusedEntity>().0 = Some(plain_node);
+        app.update();
+        assert_eq!(app.world().resource::<Focus>().0, None);
+
+        app.world_mut().resource_mut::<FocusedEntity>().0 = None;
+        app.update();
+        assert_eq!(app.world().resource::<Focus>().0, None);
+    }
+
+    #[test]
+    fn focus_changes_are_ignored_without_the_accessibility_plugin() {
+        let mut app = App::new();
+        app.init_resource::<FocusedEntity>()
+            .add_systems(PostUpdate, (button_changed, focus_changed).chain());
+
+        let button = app.world_mut().spawn(Button).id();
+        app.world_mut().resource_mut::<FocusedEntity>().0 = Some(button);
+        app.update();
+        assert!(app.world().get_resource::<Focus>().is_none());
     }
 }
@@ -1,21 +1,34 @@
-use crate::{CalculatedClip, DefaultUiCamera, Node, TargetCamera, UiScale, UiStack};
+use crate::{
+    render::resolve_border_radius, BorderRadius, CalculatedClip, DefaultUiCamera, Node,
+    TargetCamera, TargetUiScale, UiRect, UiStack, Val,
+};
 use bevy_ecs::{
-    change_detection::DetectChangesMut,
+    change_detection::{DetectChanges, DetectChangesMut},
     entity::Entity,
+    entity::{EntityHashMap, EntityHashSet},
+    event::{Event, EventReader, EventWriter},
     prelude::{Component, With},
-    query::QueryData,
-    reflect::ReflectComponent,
-    system::{Local, Query, Res},
+    query::{Has, QueryData},
+    reflect::{ReflectComponent, ReflectResource},
+    system::{Commands, Local, Query, Res, ResMut, Resource, SystemParam},
+    world::{Mut, Ref},
+};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_input::{
+    mouse::{MouseButton, MouseScrollUnit, MouseWheel},
+    touch::Touches,
+    ButtonInput,
 };
-use bevy_input::{mouse::MouseButton, touch::Touches, ButtonInput};
 use bevy_math::{Rect, Vec2};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{camera::NormalizedRenderTarget, prelude::Camera, view::ViewVisibility};
+use bevy_time::Time;
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashMap;
-use bevy_window::{PrimaryWindow, Window};
+use bevy_window::{CursorIcon, PrimaryWindow, Window};
 
 use smallvec::SmallVec;
+use std::time::Duration;
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -31,6 +44,7 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 /// when [`ViewVisibility::get()`] is false.
 /// This ensures that hidden UI nodes are not interactable,
 /// and do not end up stuck in an active state if hidden at the wrong time.
+/// The same applies to nodes that are [`Disabled`], or not [`Interactable`].
 ///
 /// Note that you can also control the visibility of a node using the [`Display`](crate::ui_node::Display) property,
 /// which fully collapses it during layout calculations.
@@ -67,6 +81,212 @@ impl Default for Interaction {
     }
 }
 
+/// The [`Interaction`] of a UI node in the previous frame, to find out how it just changed.
+///
+/// It is updated by [`ui_focus_system`] before it changes the [`Interaction`], including when the
+/// node is hidden or disabled and reset to [`Interaction::None`], so it holds the value the node
+/// had in the systems of the previous frame. Add it next to the [`Interaction`] of the node.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{Interaction, PrevInteraction};
+/// fn button_system(query: Query<(&Interaction, &PrevInteraction)>) {
+///     for (interaction, previous) in &query {
+///         if previous.just_clicked(*interaction) {
+///             println!("clicked");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PrevInteraction(pub Interaction);
+
+impl PrevInteraction {
+    /// Returns true if the node wasn't pressed in the previous frame, and is now.
+    pub fn just_pressed(&self, interaction: Interaction) -> bool {
+        self.0 != Interaction::Pressed && interaction == Interaction::Pressed
+    }
+
+    /// Returns true if the node was pressed in the previous frame, and isn't anymore, whether the
+    /// press was released, cancelled, or the node was hidden.
+    pub fn just_released(&self, interaction: Interaction) -> bool {
+        self.0 == Interaction::Pressed && interaction != Interaction::Pressed
+    }
+
+    /// Returns true if the node was pressed in the previous frame, and the press was released
+    /// with the cursor still over the node.
+    ///
+    /// Touches leave the node when they are released, so use [`UiClick`] events to also catch
+    /// taps.
+    pub fn just_clicked(&self, interaction: Interaction) -> bool {
+        self.0 == Interaction::Pressed && interaction == Interaction::Hovered
+    }
+
+    /// Returns true if the cursor wasn't over the node in the previous frame, and is now.
+    pub fn just_hovered(&self, interaction: Interaction) -> bool {
+        self.0 == Interaction::None && interaction != Interaction::None
+    }
+
+    /// Returns true if the cursor was over the node in the previous frame, and isn't anymore.
+    pub fn just_unhovered(&self, interaction: Interaction) -> bool {
+        self.0 != Interaction::None && interaction == Interaction::None
+    }
+}
+
+/// Marks an interactive UI node as disabled.
+///
+/// The [`Interaction`] of a disabled node stays [`Interaction::None`], so it is never pressed or
+/// clicked, and widgets such as [`Checkbox`](crate::widget::Checkbox) also ignore the keyboard while
+/// they are disabled. Unlike hidden nodes, disabled nodes still block the cursor according to their
+/// [`FocusPolicy`].
+///
+/// Nodes with [`ButtonColors`](crate::widget::ButtonColors) display their disabled color.
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Disabled;
+
+/// Controls whether a UI node takes part in interactions, while it stays rendered and laid out.
+///
+/// A node with `Interactable(false)` is ignored by [`ui_focus_system`] and [`UiHitTest`], as if it
+/// wasn't there: its [`Interaction`] stays [`Interaction::None`], and the cursor reaches the nodes
+/// below it whatever its [`FocusPolicy`]. Use it for decorative overlays drawn over interactive nodes.
+/// [`Disabled`] nodes, on the other hand, still block the cursor.
+///
+/// Nodes without this component are interactable. When
+/// [`UiInteractionConfig::interactive_nodes_only`] is set, only the nodes that react to the cursor
+/// are tested against it, and `Interactable(true)` marks a node without an [`Interaction`] that
+/// should still be tested, like the backdrop of a dialog blocking the cursor.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Interactable(pub bool);
+
+impl Interactable {
+    const DEFAULT: Self = Self(true);
+}
+
+impl Default for Interactable {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Marks a UI node as a modal, such as a dialog or the backdrop drawn behind it, making the rest of
+/// the UI non-interactive.
+///
+/// While a node with this component is visible, [`ui_focus_system`] ignores every node that isn't
+/// the modal or one of its descendants, as if they weren't [`Interactable`]. When several modals are
+/// visible, only the topmost one in the [`UiStack`] is interactive, so opening a dialog from another
+/// dialog disables the first one until the second is hidden or despawned.
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Modal;
+
+/// Sent by [`ui_focus_system`] when the cursor enters a UI node with an [`Interaction`].
+///
+/// A node is hovered while its [`Interaction`] is [`Interaction::Hovered`], or [`Interaction::Pressed`]
+/// with the cursor over it. Each frame, the hovered nodes are compared to those of the previous frame,
+/// so a cursor jumping from one node to another sends a [`HoverExit`] and a [`HoverEnter`] in the same frame.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct HoverEnter {
+    /// The node that is now hovered.
+    pub entity: Entity,
+}
+
+/// Sent by [`ui_focus_system`] when the cursor leaves a UI node, which stops being hovered as described
+/// for [`HoverEnter`].
+///
+/// This is also sent when a hovered node is hidden or disabled, as its [`Interaction`] is reset to [`Interaction::None`],
+/// but not when it is despawned.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct HoverExit {
+    /// The node that is no longer hovered.
+    pub entity: Entity,
+}
+
+/// Sent by [`ui_focus_system`] when a UI node becomes [`Interaction::Pressed`].
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct UiPress {
+    /// The node that was pressed.
+    pub entity: Entity,
+    /// The position of the cursor relative to the node when it was pressed.
+    pub position: RelativeCursorPosition,
+}
+
+/// Sent by [`ui_focus_system`] when a pressed UI node is released, wherever the cursor is.
+///
+/// Nodes that stop being [`Interaction::Pressed`] for other reasons, such as being hidden, are not released.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct UiRelease {
+    /// The node that was released.
+    pub entity: Entity,
+    /// The position of the cursor relative to the node when it was released.
+    pub position: RelativeCursorPosition,
+}
+
+/// Sent by [`ui_focus_system`] when a UI node is both pressed and released while under the cursor.
+///
+/// Unlike [`Interaction::Pressed`], which is set for as long as the button is held down,
+/// this is only sent when the press has been completed.
+/// Releasing the button over another node doesn't click either of them.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct UiClick {
+    /// The node that was clicked.
+    pub entity: Entity,
+    /// The position of the cursor relative to the node when it was pressed.
+    pub press_position: RelativeCursorPosition,
+    /// The position of the cursor relative to the node when it was released.
+    pub release_position: RelativeCursorPosition,
+}
+
+/// The events sent by [`ui_focus_system`]
+#[derive(SystemParam)]
+pub struct UiFocusEvents<'w> {
+    hover_enter: EventWriter<'w, HoverEnter>,
+    hover_exit: EventWriter<'w, HoverExit>,
+    press: EventWriter<'w, UiPress>,
+    release: EventWriter<'w, UiRelease>,
+    click: EventWriter<'w, UiClick>,
+}
+
+/// The queries used by [`ui_focus_system`] and [`UiHitTest`] to find the nodes outside of the
+/// topmost [`Modal`]
+#[derive(SystemParam)]
+pub struct UiModals<'w, 's> {
+    modal_query: Query<'w, 's, &'static ViewVisibility, With<Modal>>,
+    parent_query: Query<'w, 's, &'static Parent>,
+}
+
+impl UiModals<'_, '_> {
+    /// Returns the topmost visible [`Modal`] of the [`UiStack`].
+    fn topmost(&self, ui_stack: &UiStack) -> Option<Entity> {
+        ui_stack.uinodes.iter().rev().copied().find(|entity| {
+            self.modal_query
+                .get(*entity)
+                .is_ok_and(|view_visibility| view_visibility.get())
+        })
+    }
+
+    /// Returns true if `entity` is neither `modal` nor one of its descendants.
+    fn is_outside(&self, modal: Entity, entity: Entity) -> bool {
+        entity != modal
+            && !self
+                .parent_query
+                .iter_ancestors(entity)
+                .any(|ancestor| ancestor == modal)
+    }
+}
+
 /// A component storing the position of the mouse relative to the node, (0., 0.) being the top-left corner and (1., 1.) being the bottom-right
 /// If the mouse is not over the node, the value will go beyond the range of (0., 0.) to (1., 1.)
 ///
@@ -86,6 +306,11 @@ pub struct RelativeCursorPosition {
     /// Cursor position relative to the size and position of the Node.
     /// A None value indicates that the cursor position is unknown.
     pub normalized: Option<Vec2>,
+    /// Cursor position relative to the top-left corner of the Node, with y pointing down, in logical
+    /// UI pixels: the logical pixels of the window divided by [`UiScale`](crate::UiScale), like
+    /// [`Node::size`]. This is `normalized` multiplied by the size of the Node.
+    /// A None value indicates that the cursor position is unknown.
+    pub pixels: Option<Vec2>,
 }
 
 impl RelativeCursorPosition {
@@ -97,7 +322,206 @@ impl RelativeCursorPosition {
     }
 }
 
+/// The shape of the area of a UI node that reacts to the cursor.
+///
+/// Nodes without this component cover the whole node, except for the corners rounded by their
+/// [`BorderRadius`], so they react to the cursor the way they are drawn.
+/// The hit area is always cut by the node's clipping rect.
+///
+/// Used by [`ui_focus_system`] to decide whether the cursor is over a node.
+/// [`RelativeCursorPosition::mouse_over`] doesn't take it into account.
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum HitArea {
+    /// The whole node rectangle
+    #[default]
+    Rect,
+    /// The node rectangle with rounded corners
+    RoundedRect {
+        /// The corner radius in logical pixels, clamped to half of the node's smaller side
+        radius: f32,
+    },
+    /// The ellipse inscribed in the node rectangle
+    Ellipse,
+}
+
+impl HitArea {
+    /// Returns true if the `normalized` position lies within the hit area of a node of the given logical `size`.
+    ///
+    /// (0., 0.) is the top-left corner of the node and (1., 1.) the bottom-right corner,
+    /// as in [`RelativeCursorPosition::normalized`].
+    pub fn contains(&self, normalized: Vec2, size: Vec2) -> bool {
+        if !Rect::new(0., 0., 1., 1.).contains(normalized) {
+            return false;
+        }
+        match *self {
+            HitArea::Rect => true,
+            HitArea::RoundedRect { radius } => rounded_rect_contains(normalized, size, [radius; 4]),
+            HitArea::Ellipse => ((normalized - 0.5) * 2.).length_squared() <= 1.,
+        }
+    }
+}
+
+/// Returns true if the `normalized` position inside a node of the given logical `size` isn't cut
+/// off by the rounded corners.
+///
+/// The radii are in logical pixels, ordered top left, top right, bottom right, bottom left.
+fn rounded_rect_contains(normalized: Vec2, size: Vec2, radii: [f32; 4]) -> bool {
+    let half_size = 0.5 * size;
+    let position = (normalized - 0.5) * size;
+    let radius = match (position.x < 0., position.y < 0.) {
+        (true, true) => radii[0],
+        (false, true) => radii[1],
+        (false, false) => radii[2],
+        (true, false) => radii[3],
+    }
+    .clamp(0., half_size.min_element());
+    // distance from the center of the nearest corner circle, when in a corner region
+    let corner_offset = position.abs() - (half_size - Vec2::splat(radius));
+    corner_offset.max(Vec2::ZERO).length_squared() <= radius * radius
+}
+
+/// Expands the area of a UI node that reacts to the cursor beyond the edges of the node, to make
+/// small nodes easier to press, especially with touches. The layout and the rendering of the node
+/// are unaffected.
+///
+/// The [`HitArea`] of the node, or its [`BorderRadius`], is fitted to the padded rectangle. The padding
+/// is still cut by the node's clipping rect, and the node keeps its place in the stack, so nodes
+/// above it capture the cursor over its padding according to their [`FocusPolicy`].
+///
+/// [`RelativeCursorPosition::normalized`] stays relative to the node, going below 0 or above 1 over the
+/// padding, and [`RelativeCursorPosition::normalized_visible_node_rect`] includes the padding.
+///
+/// Percentages are relative to the width of the node for the left and right padding, and to its
+/// height for the top and bottom padding.
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HitPadding(pub UiRect);
+
+impl HitPadding {
+    /// Creates a padding of `value` on every side of the node.
+    pub const fn all(value: Val) -> Self {
+        Self(UiRect::all(value))
+    }
+}
+
+/// The distance scrolled with the mouse wheel while the cursor was over a UI node, in logical pixels.
+///
+/// Only the topmost node with this component under the cursor receives the scroll, and nodes
+/// below a node with [`FocusPolicy::Block`] don't receive it at all, the same way they can't be pressed.
+/// Both the vertical and the horizontal wheel are reported.
+///
+/// The delta is reset to zero on frames without any scrolling.
+///
+/// Updated in [`ui_focus_system`].
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ScrolledOver {
+    /// The distance scrolled this frame
+    pub delta: Vec2,
+}
+
+impl ScrolledOver {
+    /// The number of logical pixels scrolled for each line of a [`MouseScrollUnit::Line`] wheel event
+    pub const LINE_HEIGHT: f32 = 20.;
+}
+
+/// Describes which mouse buttons can press a UI node.
+///
+/// Nodes without this component can only be pressed with [`MouseButton::Left`].
+///
+/// Touch input acts as the primary button, which is the first button in the list.
+/// A node with no buttons at all can't be pressed.
+///
+/// To react differently to each of the buttons, check which one was pressed with
+/// [`ButtonInput::just_pressed`] when the node's [`Interaction`] changes to [`Interaction::Pressed`].
+#[derive(Component, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InteractionButtons(pub SmallVec<[MouseButton; 2]>);
+
+impl InteractionButtons {
+    /// Only the left mouse button presses the node, which is also the behavior of nodes without [`InteractionButtons`].
+    pub const LEFT: &'static [MouseButton] = &[MouseButton::Left];
+
+    /// Creates a new [`InteractionButtons`] from a list of buttons, the first of which is the primary button.
+    pub fn new(buttons: impl IntoIterator<Item = MouseButton>) -> Self {
+        Self(buttons.into_iter().collect())
+    }
+
+    /// The button that touch input acts as, if there is one.
+    pub fn primary(&self) -> Option<MouseButton> {
+        self.0.first().copied()
+    }
+}
+
+impl Default for InteractionButtons {
+    fn default() -> Self {
+        Self::new(Self::LEFT.iter().copied())
+    }
+}
+
+/// The buttons that can press a node, falling back to [`InteractionButtons::LEFT`].
+fn node_buttons(interaction_buttons: Option<&InteractionButtons>) -> &[MouseButton] {
+    interaction_buttons.map_or(InteractionButtons::LEFT, |buttons| buttons.0.as_slice())
+}
+
+/// Returns true if any of the `buttons`, or any touch if there is a primary button, was just pressed.
+fn buttons_just_pressed(
+    buttons: &[MouseButton],
+    mouse_button_input: &ButtonInput<MouseButton>,
+    touches_input: &Touches,
+) -> bool {
+    !buttons.is_empty()
+        && (mouse_button_input.any_just_pressed(buttons.iter().copied())
+            || touches_input.any_just_pressed())
+}
+
+/// Returns true if any of the `buttons`, or any touch if there is a primary button, was just released.
+fn buttons_just_released(
+    buttons: &[MouseButton],
+    mouse_button_input: &ButtonInput<MouseButton>,
+    touches_input: &Touches,
+) -> bool {
+    !buttons.is_empty()
+        && (mouse_button_input.any_just_released(buttons.iter().copied())
+            || touches_input.any_just_released())
+}
+
 /// Describes whether the node should block interactions with lower nodes
+///
+/// Hovers and presses are blocked separately. The node itself reacts to the cursor whatever its
+/// policy, and the policy decides what the nodes below it, under the cursor, can do:
+///
+/// | Policy                                                | Nodes below are hovered | Nodes below are pressed |
+/// |-------------------------------------------------------|-------------------------|-------------------------|
+/// | [`Block`](Self::Block)                                | no                      | no                      |
+/// | [`Pass`](Self::Pass)                                  | yes                     | yes                     |
+/// | [`HoverPassClickBlock`](Self::HoverPassClickBlock)    | yes                     | no                      |
+/// | [`HoverBlockClickPass`](Self::HoverBlockClickPass)    | no                      | yes                     |
+///
+/// A node reached by the presses but not by the hover is [`Interaction::Pressed`] while pressed,
+/// and [`Interaction::None`] otherwise. A node reached by the hover but not by the presses is
+/// [`Interaction::Hovered`], and never pressed.
 #[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 #[cfg_attr(
@@ -110,10 +534,32 @@ pub enum FocusPolicy {
     Block,
     /// Lets interaction pass through
     Pass,
+    /// Lets interaction pass through, and is hovered whenever the cursor is over the node,
+    /// even below a [`Block`](Self::Block) node.
+    ///
+    /// Presses are still captured by the blocking node, so the node is only pressed when
+    /// no node above it blocks.
+    PassAll,
+    /// Lets the hover pass through, but captures the presses, so the nodes below are hovered and
+    /// never pressed
+    HoverPassClickBlock,
+    /// Lets the presses pass through, but captures the hover, so the nodes below are pressed and
+    /// never hovered, like a decorative label drawn over a button that should still be clicked
+    HoverBlockClickPass,
 }
 
 impl FocusPolicy {
     const DEFAULT: Self = Self::Pass;
+
+    /// Returns true if the nodes below a node with this policy can be hovered.
+    pub const fn passes_hover(self) -> bool {
+        matches!(self, Self::Pass | Self::PassAll | Self::HoverPassClickBlock)
+    }
+
+    /// Returns true if the nodes below a node with this policy can be pressed.
+    pub const fn passes_presses(self) -> bool {
+        matches!(self, Self::Pass | Self::PassAll | Self::HoverBlockClickPass)
+    }
 }
 
 impl Default for FocusPolicy {
@@ -122,10 +568,175 @@ impl Default for FocusPolicy {
     }
 }
 
+/// Describes what happens to a [`Interaction::Pressed`] node when the cursor leaves it before the press is released.
+///
+/// | Policy                              | Cursor leaves the pressed node                | Cursor comes back          |
+/// |-------------------------------------|-----------------------------------------------|----------------------------|
+/// | [`Hold`](Self::Hold)                | stays [`Interaction::Pressed`]                | stays pressed              |
+/// | [`Release`](Self::Release)          | the press is cancelled, [`Interaction::None`] | [`Interaction::Hovered`]   |
+/// | [`HoldOutside`](Self::HoldOutside)  | stays pressed, [`DraggingOutside`] is added   | [`DraggingOutside`] is removed |
+///
+/// A cancelled press doesn't send a [`UiRelease`] or [`UiClick`]. Nodes without this component use [`InteractionPolicy::Hold`].
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InteractionPolicy {
+    /// The node stays pressed until the press is released, wherever the cursor is
+    Hold,
+    /// Moving the cursor out of the node cancels the press
+    Release,
+    /// Like [`InteractionPolicy::Hold`], but the node is marked with [`DraggingOutside`] while the cursor is outside of it
+    HoldOutside,
+}
+
+impl InteractionPolicy {
+    const DEFAULT: Self = Self::Hold;
+}
+
+impl Default for InteractionPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Marks a [`Interaction::Pressed`] node with [`InteractionPolicy::HoldOutside`] while the cursor is outside of it.
+///
+/// Added and removed by [`ui_focus_system`].
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct DraggingOutside;
+
+/// Marks a node that was [`Interaction::Pressed`] with the keyboard, like a focused
+/// [`Button`](crate::widget::Button) activated by [`button_keyboard_system`](crate::widget::button_keyboard_system).
+///
+/// [`ui_focus_system`] doesn't release the node while it has this component, so moving the cursor
+/// away or releasing a mouse button leaves it pressed. The system that pressed the node releases it
+/// and removes the component once the key is released.
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct KeyboardPressed;
+
 /// Contains entities whose Interaction should be set to None
 #[derive(Default)]
 pub struct State {
     entities_to_reset: SmallVec<[Entity; 1]>,
+    /// The cursor position relative to each pressed node at the time it was pressed
+    press_positions: EntityHashMap<RelativeCursorPosition>,
+    /// Nodes released this frame
+    released: SmallVec<[Entity; 1]>,
+    /// The relative cursor positions of the pressed and hovered nodes, computed this frame
+    relative_cursor_positions: EntityHashMap<RelativeCursorPosition>,
+    /// Hover transitions of nodes with an [`InteractionDebounce`] waiting to be committed,
+    /// and the number of consecutive frames they were requested for
+    pending_hovers: EntityHashMap<(Interaction, u8)>,
+    /// The nodes hovered this frame, as sent in [`HoverEnter`] and [`HoverExit`] events
+    hovered_entities: EntityHashSet,
+    /// The nodes hovered in the previous frame, kept to reuse its allocation
+    previous_hovered_entities: EntityHashSet,
+}
+
+/// Delays the hover transitions of a UI node's [`Interaction`] until they have been stable for a number of frames.
+///
+/// This smooths out [`Interaction::Hovered`] flickering on and off when the cursor sits on the edge of a node,
+/// which would otherwise trigger change detection every frame.
+/// Presses and releases are never delayed.
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InteractionDebounce {
+    /// The number of consecutive frames the cursor has to enter or leave the node for for the hover
+    /// to change. Values of 0 and 1 change it immediately.
+    pub frames: u8,
+}
+
+impl InteractionDebounce {
+    const DEFAULT: Self = Self { frames: 3 };
+}
+
+impl Default for InteractionDebounce {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Moves `interaction` to `hover`, which is either [`Interaction::Hovered`] or [`Interaction::None`],
+/// once the transition has been requested for [`InteractionDebounce::frames`] consecutive frames.
+///
+/// Transitions still waiting are moved from `previous_pending` to `pending`.
+fn debounce_hover(
+    interaction: &mut Mut<Interaction>,
+    entity: Entity,
+    hover: Interaction,
+    debounce: Option<&InteractionDebounce>,
+    previous_pending: &EntityHashMap<(Interaction, u8)>,
+    pending: &mut EntityHashMap<(Interaction, u8)>,
+) {
+    let frames = previous_pending
+        .get(&entity)
+        .filter(|(pending_hover, _)| *pending_hover == hover)
+        .map_or(1, |(_, frames)| frames.saturating_add(1));
+    if frames >= debounce.map_or(0, |debounce| debounce.frames) {
+        interaction.set_if_neq(hover);
+    } else {
+        pending.insert(entity, (hover, frames));
+    }
+}
+
+/// The UI node under the cursor, updated every frame by [`ui_focus_system`].
+///
+/// Use [`HoveredUiEntity::is_over_ui`] to ignore clicks meant for the UI in systems reacting to
+/// clicks in the world, such as picking.
+#[derive(Resource, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct HoveredUiEntity {
+    /// The topmost node with an [`Interaction`] that is reached by the cursor,
+    /// stopping at the first node with [`FocusPolicy::Block`]
+    pub entity: Option<Entity>,
+    /// Whether the cursor is over any visible UI node, with or without an [`Interaction`]
+    pub is_over_ui: bool,
+}
+
+/// The node capturing the pointer, which is the only node [`ui_focus_system`] hovers or presses.
+///
+/// While a node is captured, every other node has its [`Interaction`] set to [`Interaction::None`],
+/// so moving the cursor quickly during a drag doesn't hover or click the nodes it passes over.
+/// [`ui_drag_system`] captures the pointer when a [`Draggable`] node starts dragging.
+///
+/// The capture is released once the captured node is no longer [`Interaction::Pressed`],
+/// including when it is despawned.
+#[derive(Resource, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct PointerCapture(pub Option<Entity>);
+
+/// Configures how [`ui_focus_system`] gathers the cursor positions the UI reacts to.
+#[derive(Resource, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct UiInteractionConfig {
+    /// Whether the UI of a window only reacts to the cursor while the window is focused.
+    ///
+    /// By default the UI of every window under the cursor is hovered, so tooltips and hover
+    /// effects keep working in the other windows of a multi-window tool, or while another
+    /// application is focused. Set this to `true` for UI that shouldn't react to a cursor passing
+    /// over its window before the window is focused.
+    pub require_focused_window: bool,
+    /// Whether [`ui_focus_system`] skips the nodes that don't react to the cursor.
+    ///
+    /// By default every visible node of the [`UiStack`] is tested against the cursor, so layout and
+    /// decoration nodes also block the cursor according to their [`FocusPolicy`], and count towards
+    /// [`HoveredUiEntity::is_over_ui`]. Set this to `true` for UI made of thousands of such nodes to
+    /// only test the nodes with an [`Interaction`], a [`RelativeCursorPosition`], a [`ScrolledOver`]
+    /// or an explicit `Interactable(true)`, and ignore the others as if they weren't there. Add
+    /// [`Interactable(true)`](Interactable) to the nodes without those components that should
+    /// still block the cursor, like the backdrop of a dialog.
+    pub interactive_nodes_only: bool,
 }
 
 /// Main query for [`ui_focus_system`]
@@ -133,102 +744,458 @@ pub struct State {
 #[query_data(mutable)]
 pub struct NodeQuery {
     entity: Entity,
-    node: &'static Node,
-    global_transform: &'static GlobalTransform,
+    hit_test: HitTestQuery,
     interaction: Option<&'static mut Interaction>,
+    prev_interaction: Option<&'static mut PrevInteraction>,
     relative_cursor_position: Option<&'static mut RelativeCursorPosition>,
+    interaction_buttons: Option<&'static InteractionButtons>,
+    interaction_policy: Option<&'static InteractionPolicy>,
+    interaction_debounce: Option<&'static InteractionDebounce>,
+    dragging_outside: Has<DraggingOutside>,
+    keyboard_pressed: Has<KeyboardPressed>,
+    disabled: Has<Disabled>,
+    scrolled_over: Option<&'static mut ScrolledOver>,
+}
+
+impl NodeQueryItem<'_> {
+    /// Returns true if the node has one of the components updated by [`ui_focus_system`], or an
+    /// explicit `Interactable(true)`.
+    fn is_interactive(&self) -> bool {
+        self.hit_test
+            .interactable
+            .is_some_and(|interactable| interactable.0)
+            || self.interaction.is_some()
+            || self.relative_cursor_position.is_some()
+            || self.scrolled_over.is_some()
+    }
+}
+
+/// The components used to test whether a point is over a UI node, see [`UiHitTest`].
+#[derive(QueryData)]
+pub struct HitTestQuery {
+    node: &'static Node,
+    global_transform: &'static GlobalTransform,
     focus_policy: Option<&'static FocusPolicy>,
+    hit_area: Option<&'static HitArea>,
+    hit_padding: Option<&'static HitPadding>,
+    border_radius: Option<&'static BorderRadius>,
     calculated_clip: Option<&'static CalculatedClip>,
     view_visibility: Option<&'static ViewVisibility>,
     target_camera: Option<&'static TargetCamera>,
+    interactable: Option<&'static Interactable>,
 }
 
-/// The system that sets Interaction for all UI elements based on the mouse cursor activity
-///
-/// Entities with a hidden [`ViewVisibility`] are always treated as released.
-#[allow(clippy::too_many_arguments)]
-pub fn ui_focus_system(
-    mut state: Local<State>,
-    camera_query: Query<(Entity, &Camera)>,
-    default_ui_camera: DefaultUiCamera,
-    primary_window: Query<Entity, With<PrimaryWindow>>,
-    windows: Query<&Window>,
-    mouse_button_input: Res<ButtonInput<MouseButton>>,
-    touches_input: Res<Touches>,
-    ui_scale: Res<UiScale>,
-    ui_stack: Res<UiStack>,
-    mut node_query: Query<NodeQuery>,
-) {
-    let primary_window = primary_window.iter().next();
+impl HitTestQueryItem<'_> {
+    /// Returns the camera rendering the node, which is the default UI camera for nodes without a
+    /// [`TargetCamera`].
+    pub(crate) fn camera(&self, default_ui_camera: Option<Entity>) -> Option<Entity> {
+        self.target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera)
+    }
 
-    // reset entities that were both clicked and released in the last frame
-    for entity in state.entities_to_reset.drain(..) {
-        if let Ok(NodeQueryItem {
-            interaction: Some(mut interaction),
-            ..
-        }) = node_query.get_mut(entity)
-        {
-            *interaction = Interaction::None;
+    /// Returns the rect of the node expanded by its [`HitPadding`], in UI coordinates of the node's camera.
+    ///
+    /// `viewport_size` is the size of the camera's viewport in UI coordinates.
+    fn hit_rect(&self, viewport_size: Vec2) -> Rect {
+        let node_rect = self.node.logical_rect(self.global_transform);
+        let Some(HitPadding(padding)) = self.hit_padding else {
+            return node_rect;
+        };
+        let size = node_rect.size();
+        let resolve = |val: Val, length: f32| val.resolve(length, viewport_size).unwrap_or(0.);
+        Rect {
+            min: node_rect.min
+                - Vec2::new(resolve(padding.left, size.x), resolve(padding.top, size.y)),
+            max: node_rect.max
+                + Vec2::new(
+                    resolve(padding.right, size.x),
+                    resolve(padding.bottom, size.y),
+                ),
         }
     }
 
-    let mouse_released =
-        mouse_button_input.just_released(MouseButton::Left) || touches_input.any_just_released();
-    if mouse_released {
-        for node in &mut node_query {
-            if let Some(mut interaction) = node.interaction {
-                if *interaction == Interaction::Pressed {
-                    *interaction = Interaction::None;
-                }
-            }
+    /// Returns the position of `position`, in UI coordinates of the node's camera, relative to the node.
+    ///
+    /// `viewport_size` is the size of the camera's viewport in UI coordinates, used to resolve the
+    /// [`HitPadding`] of the node.
+    pub(crate) fn relative_cursor_position(
+        &self,
+        position: Option<Vec2>,
+        viewport_size: Vec2,
+    ) -> RelativeCursorPosition {
+        let node_rect = self.node.logical_rect(self.global_transform);
+        let hit_rect = self.hit_rect(viewport_size);
+
+        // Intersect with the calculated clip rect to find the bounds of the visible region of the node
+        let visible_rect = self
+            .calculated_clip
+            .map(|clip| hit_rect.intersect(clip.clip))
+            .unwrap_or(hit_rect);
+
+        // The mouse position relative to the node
+        // (0., 0.) is the top-left corner, (1., 1.) is the bottom-right corner
+        // Coordinates are relative to the entire node, not just the visible region.
+        let normalized = position.and_then(|position| {
+            // ensure node size is non-zero in all dimensions, otherwise relative position will be
+            // +/-inf. if the node is hidden, the visible rect min/max will also be -inf leading to
+            // false positives for mouse_over (#12395)
+            (node_rect.size().cmpgt(Vec2::ZERO).all())
+                .then_some((position - node_rect.min) / node_rect.size())
+        });
+
+        RelativeCursorPosition {
+            normalized_visible_node_rect: visible_rect.normalize(node_rect),
+            normalized,
+            pixels: position.map(|position| position - node_rect.min),
         }
     }
 
-    let mouse_clicked =
-        mouse_button_input.just_pressed(MouseButton::Left) || touches_input.any_just_pressed();
+    /// Returns true if the node is visible and [`Interactable`].
+    fn is_interactable(&self) -> bool {
+        self.view_visibility
+            .is_some_and(|view_visibility| view_visibility.get())
+            && self
+                .interactable
+                .map_or(true, |interactable| interactable.0)
+    }
 
-    let camera_cursor_positions: HashMap<Entity, Vec2> = camera_query
-        .iter()
-        .filter_map(|(entity, camera)| {
-            // Interactions are only supported for cameras rendering to a window.
-            let Some(NormalizedRenderTarget::Window(window_ref)) =
-                camera.target.normalize(primary_window)
-            else {
-                return None;
-            };
+    /// Returns true if the node doesn't let presses pass through to the nodes below it: its
+    /// [`FocusPolicy`] doesn't [pass presses](FocusPolicy::passes_presses), or it doesn't have one.
+    pub(crate) fn blocks(&self) -> bool {
+        !self
+            .focus_policy
+            .is_some_and(|focus_policy| focus_policy.passes_presses())
+    }
 
-            let viewport_position = camera
-                .logical_viewport_rect()
-                .map(|rect| rect.min)
-                .unwrap_or_default();
-            windows
-                .get(window_ref.entity())
-                .ok()
-                .and_then(|window| window.cursor_position())
-                .or_else(|| touches_input.first_pressed_position())
-                .map(|cursor_position| (entity, cursor_position - viewport_position))
-        })
-        // The cursor position returned by `Window` only takes into account the window scale factor and not `UiScale`.
-        // To convert the cursor position to logical UI viewport coordinates we have to divide it by `UiScale`.
-        .map(|(entity, cursor_position)| (entity, cursor_position / ui_scale.0))
-        .collect();
+    /// Returns true if `relative_cursor_position` is over the visible part of the node's hit area.
+    ///
+    /// `viewport_size` is the size of the camera's viewport in UI coordinates, used to resolve the
+    /// [`HitPadding`] and the [`BorderRadius`] of the node.
+    fn contains(
+        &self,
+        relative_cursor_position: &RelativeCursorPosition,
+        viewport_size: Vec2,
+    ) -> bool {
+        let node_size = self.node.size();
+        // the hit area is fitted to the node expanded by its padding
+        let hit_rect = self
+            .hit_rect(viewport_size)
+            .normalize(self.node.logical_rect(self.global_transform));
+        let size = hit_rect.size() * node_size;
+        relative_cursor_position.mouse_over()
+            && relative_cursor_position.normalized.is_some_and(|position| {
+                let position = (position - hit_rect.min) / hit_rect.size();
+                match self.hit_area {
+                    Some(hit_area) => hit_area.contains(position, size),
+                    None => self.border_radius.map_or(true, |border_radius| {
+                        let radii =
+                            resolve_border_radius(border_radius, node_size, viewport_size, 1.);
+                        rounded_rect_contains(position, size, radii)
+                    }),
+                }
+            })
+    }
+}
+
+/// Finds the UI nodes under a point, the way [`ui_focus_system`] does for the cursor.
+///
+/// Use it from systems that need to know what part of the UI is at a point of the screen,
+/// like picking or context menus.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_render::camera::Camera;
+/// # use bevy_window::{PrimaryWindow, Window};
+/// # use bevy_ui::UiHitTest;
+/// fn log_node_under_cursor(
+///     hit_test: UiHitTest,
+///     windows: Query<&Window, With<PrimaryWindow>>,
+///     cameras: Query<Entity, With<Camera>>,
+/// ) {
+///     let Some(cursor_position) = windows.get_single().ok().and_then(Window::cursor_position) else {
+///         return;
+///     };
+///     for camera in &cameras {
+///         if let Some(entity) = hit_test.top_node_at(camera, cursor_position) {
+///             println!("{entity:?} is under the cursor");
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(log_node_under_cursor);
+/// ```
+#[derive(SystemParam)]
+pub struct UiHitTest<'w, 's> {
+    ui_stack: Res<'w, UiStack>,
+    node_query: Query<'w, 's, HitTestQuery>,
+    camera_query: Query<'w, 's, &'static Camera>,
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+    ui_scale: TargetUiScale<'w, 's>,
+    modals: UiModals<'w, 's>,
+}
+
+impl<'w, 's> UiHitTest<'w, 's> {
+    /// Returns the visible nodes rendered by `camera` whose hit area contains `position`,
+    /// from the topmost to the bottommost, regardless of their [`FocusPolicy`].
+    /// While a [`Modal`] is visible, only the topmost one and its descendants are returned.
+    ///
+    /// `position` is in logical pixels from the top-left corner of the camera's render target,
+    /// with y pointing down, like [`Window::cursor_position`]. For cameras rendering to an image,
+    /// it is in pixels of the image, like [`UiTextureCursor::position`].
+    /// Positions outside of the camera's viewport aren't over any node.
+    pub fn nodes_at(&self, camera: Entity, position: Vec2) -> Vec<Entity> {
+        let Ok(camera_component) = self.camera_query.get(camera) else {
+            return Vec::new();
+        };
+        let Some(ui_position) = ui_position(camera_component, position, &self.ui_scale) else {
+            return Vec::new();
+        };
+        let viewport_size = ui_viewport_size(camera_component, &self.ui_scale);
+        let default_ui_camera = self.default_ui_camera.get();
+        let modal = self.modals.topmost(&self.ui_stack);
+
+        self.ui_stack
+            .uinodes
+            .iter()
+            .rev()
+            .copied()
+            .filter(|entity| {
+                self.node_query.get(*entity).is_ok_and(|node| {
+                    node.is_interactable()
+                        && !modal.is_some_and(|modal| self.modals.is_outside(modal, *entity))
+                        && node.camera(default_ui_camera) == Some(camera)
+                        && node.contains(
+                            &node.relative_cursor_position(Some(ui_position), viewport_size),
+                            viewport_size,
+                        )
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the node capturing `position`, which is the topmost node under it that doesn't let
+    /// presses pass through: its [`FocusPolicy`] doesn't [pass presses](FocusPolicy::passes_presses),
+    /// or it doesn't have one.
+    ///
+    /// `position` is in the same coordinates as for [`UiHitTest::nodes_at`].
+    pub fn top_node_at(&self, camera: Entity, position: Vec2) -> Option<Entity> {
+        self.nodes_at(camera, position)
+            .into_iter()
+            .find(|entity| self.node_query.get(*entity).is_ok_and(|node| node.blocks()))
+    }
+}
+
+/// The position of the cursor over the image rendered by a camera with a
+/// [`RenderTarget::Image`](bevy_render::camera::RenderTarget::Image) target, used by [`ui_focus_system`]
+/// to interact with UI rendered to a texture.
+///
+/// The UI systems only know where the cursor is over windows. When the image is displayed somewhere
+/// else, e.g. on a mesh in the world, set `position` to the point of the image under the cursor each
+/// frame, and to `None` when the cursor isn't over it. The position is in pixels of the image, from its
+/// top-left corner, so it is `uv * image_size` for the texture coordinates `uv` under the cursor, as
+/// found by a raycast against the mesh. Like window cursor positions, it is then divided by
+/// [`UiScale`](crate::UiScale) to get UI coordinates.
+///
+/// Add it to the camera that renders the UI to the image.
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct UiTextureCursor {
+    /// The position of the cursor in pixels of the image, or `None` if the cursor isn't over it
+    pub position: Option<Vec2>,
+}
+
+type CursorCameraQuery<'w, 's> =
+    Query<'w, 's, (Entity, &'static Camera, Option<&'static UiTextureCursor>)>;
+
+/// Computes the cursor position of each active camera rendering to a window, in logical UI viewport coordinates.
+///
+/// Each camera only gets the cursor of its own window, and only while the cursor is inside its viewport,
+/// so split-screen and multi-window setups have their UI react to the right cursor.
+/// Falls back to the first pressed touch when the window has no cursor.
+/// Windows that aren't focused give no position if [`UiInteractionConfig::require_focused_window`] is set.
+///
+/// Cameras rendering to an image get the position of their [`UiTextureCursor`] instead.
+fn camera_cursor_positions(
+    camera_query: &CursorCameraQuery,
+    primary_window: Option<Entity>,
+    windows: &Query<&Window>,
+    touches_input: &Touches,
+    ui_scale: &TargetUiScale,
+    config: &UiInteractionConfig,
+) -> HashMap<Entity, Vec2> {
+    camera_query
+        .iter()
+        .filter_map(|(entity, camera, texture_cursor)| {
+            if !camera.is_active {
+                return None;
+            }
+            // Interactions are only supported for cameras rendering to a window, or to an image with a known cursor.
+            let cursor_position = match camera.target.normalize(primary_window)? {
+                NormalizedRenderTarget::Window(window_ref) => windows
+                    .get(window_ref.entity())
+                    .ok()
+                    .filter(|window| window.focused || !config.require_focused_window)?
+                    .cursor_position()
+                    .or_else(|| touches_input.first_pressed_position())?,
+                NormalizedRenderTarget::Image(_) => texture_cursor?.position?,
+                NormalizedRenderTarget::TextureView(_) => return None,
+            };
+
+            Some((entity, ui_position(camera, cursor_position, ui_scale)?))
+        })
+        .collect()
+}
+
+/// Converts a position in logical pixels of the camera's render target to the logical UI
+/// coordinates of its viewport, or `None` if it is outside of the viewport.
+///
+/// Window cursor positions, [`UiTextureCursor`] positions and UI coordinates all start at the
+/// top-left corner with y pointing down, so the position is never flipped, whatever the target.
+pub(crate) fn ui_position(
+    camera: &Camera,
+    position: Vec2,
+    ui_scale: &TargetUiScale,
+) -> Option<Vec2> {
+    let viewport_rect = camera.logical_viewport_rect();
+    if viewport_rect.is_some_and(|rect| !rect.contains(position)) {
+        return None;
+    }
+    let viewport_position = viewport_rect.map(|rect| rect.min).unwrap_or_default();
+    // The cursor position returned by `Window` only takes into account the window scale factor and not `UiScale`.
+    // To convert the cursor position to logical UI viewport coordinates we have to divide it by `UiScale`.
+    Some((position - viewport_position) / ui_scale.camera(Some(camera)))
+}
+
+/// The size of the camera's viewport in logical UI coordinates.
+pub(crate) fn ui_viewport_size(camera: &Camera, ui_scale: &TargetUiScale) -> Vec2 {
+    camera
+        .logical_viewport_size()
+        .map_or(Vec2::ZERO, |size| size / ui_scale.camera(Some(camera)))
+}
+
+/// The system that sets Interaction for all UI elements based on the mouse cursor activity
+///
+/// Entities with a hidden [`ViewVisibility`] or that are [`Disabled`] are always treated as released,
+/// as are the entities outside of the topmost visible [`Modal`].
+#[allow(clippy::too_many_arguments)]
+pub fn ui_focus_system(
+    mut state: Local<State>,
+    camera_query: CursorCameraQuery,
+    default_ui_camera: DefaultUiCamera,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    touches_input: Res<Touches>,
+    ui_scale: TargetUiScale,
+    interaction_config: Res<UiInteractionConfig>,
+    mut pointer_capture: ResMut<PointerCapture>,
+    (ui_stack, modals): (Res<UiStack>, UiModals),
+    mut node_query: Query<NodeQuery>,
+    mut focus_events: UiFocusEvents,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut hovered_ui_entity: ResMut<HoveredUiEntity>,
+    mut commands: Commands,
+) {
+    let primary_window = primary_window.iter().next();
+
+    // keep the interactions of the previous frame before anything changes them
+    for node in &mut node_query {
+        if let (Some(interaction), Some(mut prev_interaction)) =
+            (node.interaction, node.prev_interaction)
+        {
+            prev_interaction.set_if_neq(PrevInteraction(*interaction));
+        }
+    }
+
+    // reset entities that were both clicked and released in the last frame
+    for entity in state.entities_to_reset.drain(..) {
+        if let Ok(NodeQueryItem {
+            interaction: Some(mut interaction),
+            ..
+        }) = node_query.get_mut(entity)
+        {
+            *interaction = Interaction::None;
+        }
+    }
+
+    if mouse_button_input.get_just_released().next().is_some() || touches_input.any_just_released()
+    {
+        for node in &mut node_query {
+            if let Some(mut interaction) = node.interaction {
+                if *interaction == Interaction::Pressed
+                    && !node.keyboard_pressed
+                    && buttons_just_released(
+                        node_buttons(node.interaction_buttons),
+                        &mouse_button_input,
+                        &touches_input,
+                    )
+                {
+                    *interaction = Interaction::None;
+                    state.released.push(node.entity);
+                }
+            }
+        }
+    }
+
+    // release the capture of nodes that were released or despawned
+    if let Some(captured) = pointer_capture.0 {
+        let pressed = node_query.get(captured).is_ok_and(|node| {
+            node.interaction
+                .is_some_and(|interaction| *interaction == Interaction::Pressed)
+        });
+        if !pressed {
+            pointer_capture.0 = None;
+        }
+    }
+
+    let camera_cursor_positions = camera_cursor_positions(
+        &camera_query,
+        primary_window,
+        &windows,
+        &touches_input,
+        &ui_scale,
+        &interaction_config,
+    );
+
+    let State {
+        entities_to_reset,
+        press_positions,
+        released,
+        relative_cursor_positions,
+        pending_hovers,
+        hovered_entities,
+        previous_hovered_entities,
+    } = &mut *state;
+    relative_cursor_positions.clear();
+    std::mem::swap(hovered_entities, previous_hovered_entities);
+    hovered_entities.clear();
+    // transitions that aren't requested again this frame are dropped
+    let previous_pending_hovers = std::mem::take(pending_hovers);
+
+    let modal = modals.topmost(&ui_stack);
+    let outside_modal =
+        |entity: Entity| modal.is_some_and(|modal| modals.is_outside(modal, entity));
 
     // prepare an iterator that contains all the nodes that have the cursor in their rect,
     // from the top node to the bottom one. this will also reset the interaction to `None`
     // for all nodes encountered that are no longer hovered.
-    let mut hovered_nodes = ui_stack
+    let mut hovered_nodes: Vec<Entity> = ui_stack
         .uinodes
         .iter()
         // reverse the iterator to traverse the tree from closest nodes to furthest
         .rev()
         .filter_map(|entity| {
-            let Ok(node) = node_query.get_mut(*entity) else {
+            let Ok(mut node) = node_query.get_mut(*entity) else {
                 return None;
             };
+            if interaction_config.interactive_nodes_only && !node.is_interactive() {
+                return None;
+            }
 
-            let view_visibility = node.view_visibility?;
-            // Nodes that are not rendered should not be interactable
-            if !view_visibility.get() {
+            // Nodes without a `ViewVisibility` are left untouched
+            node.hit_test.view_visibility?;
+            // Nodes that are not rendered, not interactable, or outside of the modal, are skipped
+            if !node.hit_test.is_interactable() || outside_modal(*entity) {
                 // Reset their interaction to None to avoid strange stuck state
                 if let Some(mut interaction) = node.interaction {
                     // We cannot simply set the interaction to None, as that will trigger change detection repeatedly
@@ -236,41 +1203,35 @@ pub fn ui_focus_system(
                 }
                 return None;
             }
-            let camera_entity = node
-                .target_camera
-                .map(TargetCamera::entity)
-                .or(default_ui_camera.get())?;
-
-            let node_rect = node.node.logical_rect(node.global_transform);
-
-            // Intersect with the calculated clip rect to find the bounds of the visible region of the node
-            let visible_rect = node
-                .calculated_clip
-                .map(|clip| node_rect.intersect(clip.clip))
-                .unwrap_or(node_rect);
-
-            // The mouse position relative to the node
-            // (0., 0.) is the top-left corner, (1., 1.) is the bottom-right corner
-            // Coordinates are relative to the entire node, not just the visible region.
-            let relative_cursor_position =
-                camera_cursor_positions
-                    .get(&camera_entity)
-                    .and_then(|cursor_position| {
-                        // ensure node size is non-zero in all dimensions, otherwise relative position will be
-                        // +/-inf. if the node is hidden, the visible rect min/max will also be -inf leading to
-                        // false positives for mouse_over (#12395)
-                        (node_rect.size().cmpgt(Vec2::ZERO).all())
-                            .then_some((*cursor_position - node_rect.min) / node_rect.size())
-                    });
+            // Disabled nodes are released too, but still capture the cursor below
+            if node.disabled {
+                if let Some(interaction) = node.interaction.as_mut() {
+                    interaction.set_if_neq(Interaction::None);
+                }
+                press_positions.remove(entity);
+            }
+            let camera_entity = node.hit_test.camera(default_ui_camera.get())?;
+
+            let viewport_size = camera_query
+                .get(camera_entity)
+                .map_or(Vec2::ZERO, |(_, camera, _)| {
+                    ui_viewport_size(camera, &ui_scale)
+                });
+            let relative_cursor_position_component = node.hit_test.relative_cursor_position(
+                camera_cursor_positions.get(&camera_entity).copied(),
+                viewport_size,
+            );
+            let relative_cursor_position = relative_cursor_position_component.normalized;
 
             // If the current cursor position is within the bounds of the node's visible area, consider it for
             // clicking
-            let relative_cursor_position_component = RelativeCursorPosition {
-                normalized_visible_node_rect: visible_rect.normalize(node_rect),
-                normalized: relative_cursor_position,
-            };
+            let contains_cursor = node
+                .hit_test
+                .contains(&relative_cursor_position_component, viewport_size);
 
-            let contains_cursor = relative_cursor_position_component.mouse_over();
+            if contains_cursor || press_positions.contains_key(entity) {
+                relative_cursor_positions.insert(*entity, relative_cursor_position_component);
+            }
 
             // Save the relative cursor position to the correct component
             if let Some(mut node_relative_cursor_position_component) = node.relative_cursor_position
@@ -281,54 +1242,2208 @@ pub fn ui_focus_system(
             if contains_cursor {
                 Some(*entity)
             } else {
-                if let Some(mut interaction) = node.interaction {
-                    if *interaction == Interaction::Hovered || (relative_cursor_position.is_none())
+                if let Some(mut interaction) = node.interaction.filter(|_| !node.keyboard_pressed) {
+                    if *interaction == Interaction::Hovered
+                        || relative_cursor_position.is_none()
+                        || node.interaction_policy == Some(&InteractionPolicy::Release)
                     {
-                        interaction.set_if_neq(Interaction::None);
+                        let previous = *interaction;
+                        if previous == Interaction::Hovered {
+                            debounce_hover(
+                                &mut interaction,
+                                *entity,
+                                Interaction::None,
+                                node.interaction_debounce,
+                                &previous_pending_hovers,
+                                pending_hovers,
+                            );
+                        } else {
+                            interaction.set_if_neq(Interaction::None);
+                        }
                     }
                 }
                 None
             }
         })
-        .collect::<Vec<Entity>>()
-        .into_iter();
+        .collect();
+    let is_over_ui = !hovered_nodes.is_empty();
+
+    // while the pointer is captured, the other nodes are neither hovered nor pressed
+    if let Some(captured) = pointer_capture.0 {
+        for node in &mut node_query {
+            if node.entity == captured || node.keyboard_pressed {
+                continue;
+            }
+            if let Some(mut interaction) = node.interaction {
+                interaction.set_if_neq(Interaction::None);
+            }
+            press_positions.remove(&node.entity);
+        }
+        hovered_nodes.retain(|entity| *entity == captured);
+    }
+
+    let scroll_delta: Vec2 = mouse_wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => Vec2::new(event.x, event.y) * ScrolledOver::LINE_HEIGHT,
+            MouseScrollUnit::Pixel => Vec2::new(event.x, event.y),
+        })
+        .sum();
+    let mut scrolled_node = None;
+    let mut hovered = HoveredUiEntity {
+        entity: None,
+        is_over_ui,
+    };
 
-    // set Pressed or Hovered on top nodes. as soon as a node with a `Block` focus policy is detected,
-    // the iteration will stop on it because it "captures" the interaction.
-    let mut iter = node_query.iter_many_mut(hovered_nodes.by_ref());
+    // set Pressed or Hovered on top nodes. the hover and the presses stop at the first node
+    // capturing them according to its `FocusPolicy`, and the nodes below it are reset to `None`,
+    // except for `PassAll` nodes that are hovered through the nodes blocking the hover.
+    let mut hover_blocked = false;
+    let mut presses_blocked = false;
+    let mut iter = node_query.iter_many_mut(hovered_nodes);
     while let Some(node) = iter.fetch_next() {
-        if let Some(mut interaction) = node.interaction {
-            if mouse_clicked {
-                // only consider nodes with Interaction "pressed"
-                if *interaction != Interaction::Pressed {
-                    *interaction = Interaction::Pressed;
-                    // if the mouse was simultaneously released, reset this Interaction in the next
-                    // frame
-                    if mouse_released {
-                        state.entities_to_reset.push(node.entity);
+        let focus_policy = node
+            .hit_test
+            .focus_policy
+            .copied()
+            .unwrap_or(FocusPolicy::Block);
+        let hover_reaches = !hover_blocked || focus_policy == FocusPolicy::PassAll;
+        let presses_reach = !presses_blocked;
+        hover_blocked |= !focus_policy.passes_hover();
+        presses_blocked |= !focus_policy.passes_presses();
+
+        if hover_reaches {
+            if scrolled_node.is_none() && node.scrolled_over.is_some() {
+                scrolled_node = Some(node.entity);
+            }
+            if hovered.entity.is_none() && node.interaction.is_some() {
+                hovered.entity = Some(node.entity);
+            }
+        }
+
+        let relative_cursor_position = relative_cursor_positions
+            .get(&node.entity)
+            .copied()
+            .unwrap_or_default();
+
+        // the node was released while under the cursor, completing a click
+        if presses_reach && released.contains(&node.entity) {
+            if let Some(press_position) = press_positions.get(&node.entity) {
+                focus_events.click.send(UiClick {
+                    entity: node.entity,
+                    press_position: *press_position,
+                    release_position: relative_cursor_position,
+                });
+            }
+        }
+
+        let Some(mut interaction) = node.interaction.filter(|_| !node.disabled) else {
+            continue;
+        };
+        let buttons = node_buttons(node.interaction_buttons);
+        if presses_reach && buttons_just_pressed(buttons, &mouse_button_input, &touches_input) {
+            // only consider nodes with Interaction "pressed"
+            if *interaction != Interaction::Pressed {
+                *interaction = Interaction::Pressed;
+                press_positions.insert(node.entity, relative_cursor_position);
+                focus_events.press.send(UiPress {
+                    entity: node.entity,
+                    position: relative_cursor_position,
+                });
+                // if the mouse was simultaneously released, reset this Interaction in the next
+                // frame
+                if buttons_just_released(buttons, &mouse_button_input, &touches_input) {
+                    entities_to_reset.push(node.entity);
+                    press_positions.remove(&node.entity);
+                    focus_events.release.send(UiRelease {
+                        entity: node.entity,
+                        position: relative_cursor_position,
+                    });
+                    focus_events.click.send(UiClick {
+                        entity: node.entity,
+                        press_position: relative_cursor_position,
+                        release_position: relative_cursor_position,
+                    });
+                }
+            }
+        } else {
+            let target = if hover_reaches {
+                Interaction::Hovered
+            } else {
+                Interaction::None
+            };
+            // don't reset pressed nodes because they're handled separately
+            if *interaction != Interaction::Pressed && *interaction != target {
+                debounce_hover(
+                    &mut interaction,
+                    node.entity,
+                    target,
+                    node.interaction_debounce,
+                    &previous_pending_hovers,
+                    pending_hovers,
+                );
+            }
+        }
+        // pressed nodes are hovered while the cursor is over them
+        if hover_reaches && *interaction == Interaction::Pressed {
+            hovered_entities.insert(node.entity);
+        }
+    }
+
+    hovered_ui_entity.set_if_neq(hovered);
+
+    for node in &mut node_query {
+        let interaction = node.interaction.as_deref().copied();
+        if node.interaction_policy == Some(&InteractionPolicy::HoldOutside) || node.dragging_outside
+        {
+            let dragging_outside = node.interaction_policy == Some(&InteractionPolicy::HoldOutside)
+                && interaction == Some(Interaction::Pressed)
+                && !relative_cursor_positions
+                    .get(&node.entity)
+                    .is_some_and(RelativeCursorPosition::mouse_over);
+            if dragging_outside && !node.dragging_outside {
+                commands.entity(node.entity).try_insert(DraggingOutside);
+            } else if !dragging_outside && node.dragging_outside {
+                commands.entity(node.entity).remove::<DraggingOutside>();
+            }
+        }
+
+        if let Some(mut scrolled_over) = node.scrolled_over {
+            let delta = if scrolled_node == Some(node.entity) {
+                scroll_delta
+            } else {
+                Vec2::ZERO
+            };
+            scrolled_over.set_if_neq(ScrolledOver { delta });
+        }
+
+        if interaction == Some(Interaction::Hovered) {
+            hovered_entities.insert(node.entity);
+        }
+    }
+
+    // nodes entering or leaving the hovered set, which despawned nodes leave silently
+    for &entity in previous_hovered_entities.difference(hovered_entities) {
+        if node_query.contains(entity) {
+            focus_events.hover_exit.send(HoverExit { entity });
+        }
+    }
+    for &entity in hovered_entities.difference(previous_hovered_entities) {
+        focus_events.hover_enter.send(HoverEnter { entity });
+    }
+
+    for entity in released.drain(..) {
+        if let Some(press_position) = press_positions.remove(&entity) {
+            focus_events.release.send(UiRelease {
+                entity,
+                position: relative_cursor_positions
+                    .get(&entity)
+                    .copied()
+                    .unwrap_or(press_position),
+            });
+        }
+    }
+
+    // forget about presses that were cancelled without being released, e.g. by hiding the node
+    press_positions.retain(|entity, _| {
+        node_query.get(*entity).is_ok_and(|node| {
+            node.interaction
+                .is_some_and(|interaction| *interaction == Interaction::Pressed)
+        })
+    });
+}
+
+/// Marks a UI node as draggable, making [`ui_drag_system`] track its [`DragState`].
+///
+/// A drag starts once the node is [`Interaction::Pressed`] and the cursor has moved further than
+/// [`Draggable::threshold`] from where the press started.
+#[derive(Component, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Draggable {
+    /// The distance in logical pixels the cursor has to move before the drag starts
+    pub threshold: f32,
+}
+
+impl Draggable {
+    const DEFAULT: Self = Self { threshold: 4. };
+}
+
+impl Default for Draggable {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The state of an ongoing drag of a [`Draggable`] node.
+///
+/// Inserted by [`ui_drag_system`] when a drag starts and removed when it ends, at which point a
+/// [`DragEnd`] event is sent. Positions are in logical pixels of the node's camera viewport.
+///
+/// The drag keeps being tracked while the cursor is outside of the node,
+/// as long as the node stays [`Interaction::Pressed`].
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct DragState {
+    /// The cursor position when the node was pressed
+    pub start: Vec2,
+    /// The current cursor position
+    pub current: Vec2,
+    /// How far the cursor moved since the last update
+    pub delta: Vec2,
+}
+
+/// Sent by [`ui_drag_system`] when the drag of a [`Draggable`] node ends.
+///
+/// A drag ends when the node is released, or when its window loses focus.
+#[derive(Event, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct DragEnd {
+    /// The node that was dragged
+    pub entity: Entity,
+    /// The last state of the drag
+    pub state: DragState,
+}
+
+/// Press positions of [`Draggable`] nodes that haven't started dragging yet
+#[derive(Default)]
+pub struct PendingDrags {
+    starts: EntityHashMap<Vec2>,
+    /// Nodes whose drag was cancelled and that must be released before dragging again
+    cancelled: EntityHashSet,
+}
+
+/// The system that tracks the [`DragState`] of [`Draggable`] nodes.
+///
+/// A node captures the [`PointerCapture`] while it is dragged.
+/// Runs after [`ui_focus_system`], as it follows the [`Interaction`] of the nodes.
+#[allow(clippy::too_many_arguments)]
+pub fn ui_drag_system(
+    mut commands: Commands,
+    mut pending_drags: Local<PendingDrags>,
+    camera_query: CursorCameraQuery,
+    default_ui_camera: DefaultUiCamera,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    touches_input: Res<Touches>,
+    ui_scale: TargetUiScale,
+    interaction_config: Res<UiInteractionConfig>,
+    mut pointer_capture: ResMut<PointerCapture>,
+    mut drag_query: Query<(
+        Entity,
+        &Draggable,
+        &Interaction,
+        Option<&TargetCamera>,
+        Option<&mut DragState>,
+    )>,
+    mut drag_end_events: EventWriter<DragEnd>,
+) {
+    let primary_window = primary_window.iter().next();
+    let camera_cursor_positions = camera_cursor_positions(
+        &camera_query,
+        primary_window,
+        &windows,
+        &touches_input,
+        &ui_scale,
+        &interaction_config,
+    );
+
+    // forget about nodes that were released or despawned
+    let PendingDrags { starts, cancelled } = &mut *pending_drags;
+    let is_pressed = |entity: &Entity| {
+        drag_query
+            .get(*entity)
+            .is_ok_and(|(_, _, interaction, ..)| *interaction == Interaction::Pressed)
+    };
+    starts.retain(|entity, _| is_pressed(entity));
+    cancelled.retain(is_pressed);
+
+    for (entity, draggable, interaction, target_camera, drag_state) in &mut drag_query {
+        let camera_entity = target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get());
+        // cameras rendering to an image are focused while their texture cursor is over it
+        let target_focused = camera_entity
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+            .is_some_and(|(_, camera, texture_cursor)| {
+                match camera.target.normalize(primary_window) {
+                    Some(NormalizedRenderTarget::Window(window_ref)) => windows
+                        .get(window_ref.entity())
+                        .is_ok_and(|window| window.focused),
+                    Some(NormalizedRenderTarget::Image(_)) => {
+                        texture_cursor.is_some_and(|cursor| cursor.position.is_some())
                     }
+                    _ => false,
                 }
-            } else if *interaction == Interaction::None {
-                *interaction = Interaction::Hovered;
+            });
+
+        if *interaction != Interaction::Pressed || !target_focused {
+            starts.remove(&entity);
+            if let Some(drag_state) = drag_state {
+                if pointer_capture.0 == Some(entity) {
+                    pointer_capture.0 = None;
+                }
+                commands.entity(entity).remove::<DragState>();
+                drag_end_events.send(DragEnd {
+                    entity,
+                    state: *drag_state,
+                });
+            }
+            // don't restart the drag until the node is pressed again
+            if *interaction == Interaction::Pressed {
+                cancelled.insert(entity);
+            }
+            continue;
+        }
+
+        if cancelled.contains(&entity) {
+            continue;
+        }
+
+        // keep the last known state while the cursor position is unknown
+        let Some(cursor_position) = camera_entity
+            .and_then(|camera_entity| camera_cursor_positions.get(&camera_entity))
+            .copied()
+        else {
+            continue;
+        };
+
+        if let Some(mut drag_state) = drag_state {
+            let current = *drag_state;
+            drag_state.set_if_neq(DragState {
+                delta: cursor_position - current.current,
+                current: cursor_position,
+                ..current
+            });
+        } else {
+            let start = *starts.entry(entity).or_insert(cursor_position);
+            if start.distance(cursor_position) > draggable.threshold {
+                starts.remove(&entity);
+                pointer_capture.0 = Some(entity);
+                commands.entity(entity).try_insert(DragState {
+                    start,
+                    current: cursor_position,
+                    delta: cursor_position - start,
+                });
             }
         }
+    }
+}
+
+/// Makes [`ui_double_click_system`] send [`DoubleClicked`] events for a UI node.
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct DoubleClick {
+    /// The maximum time between two presses of the node for them to count as a double click
+    pub threshold: Duration,
+}
+
+impl DoubleClick {
+    const DEFAULT: Self = Self {
+        threshold: Duration::from_millis(300),
+    };
+}
 
-        match node.focus_policy.unwrap_or(&FocusPolicy::Block) {
-            FocusPolicy::Block => {
-                break;
+impl Default for DoubleClick {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Sent by [`ui_double_click_system`] when a node with [`DoubleClick`] is pressed twice within its threshold.
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct DoubleClicked {
+    /// The node that was double clicked
+    pub entity: Entity,
+}
+
+/// The system that detects double clicks on nodes with a [`DoubleClick`] component.
+///
+/// A double click is two transitions to [`Interaction::Pressed`] within [`DoubleClick::threshold`].
+/// The second press of a double click doesn't count as the first press of another one,
+/// so a triple click only sends a single [`DoubleClicked`] event.
+pub fn ui_double_click_system(
+    mut last_presses: Local<EntityHashMap<Duration>>,
+    time: Res<Time>,
+    double_click_query: Query<(Entity, &DoubleClick, Ref<Interaction>)>,
+    mut double_clicked_events: EventWriter<DoubleClicked>,
+) {
+    let now = time.elapsed();
+
+    for (entity, double_click, interaction) in &double_click_query {
+        if !interaction.is_changed() || *interaction != Interaction::Pressed {
+            continue;
+        }
+        match last_presses.get(&entity) {
+            Some(last_press) if now - *last_press <= double_click.threshold => {
+                last_presses.remove(&entity);
+                double_clicked_events.send(DoubleClicked { entity });
+            }
+            _ => {
+                last_presses.insert(entity, now);
             }
-            FocusPolicy::Pass => { /* allow the next node to be hovered/pressed */ }
         }
     }
-    // reset `Interaction` for the remaining lower nodes to `None`. those are the nodes that remain in
-    // `moused_over_nodes` after the previous loop is exited.
-    let mut iter = node_query.iter_many_mut(hovered_nodes);
-    while let Some(node) = iter.fetch_next() {
-        if let Some(mut interaction) = node.interaction {
-            // don't reset pressed nodes because they're handled separately
-            if *interaction != Interaction::Pressed {
-                interaction.set_if_neq(Interaction::None);
+
+    // forget about presses that are too old to be part of a double click
+    last_presses.retain(|entity, last_press| {
+        double_click_query
+            .get(*entity)
+            .is_ok_and(|(_, double_click, _)| now - *last_press <= double_click.threshold)
+    });
+}
+
+/// Keeps the clicks of a UI node until they are taken, for systems that don't run every frame.
+///
+/// Systems in [`FixedUpdate`](bevy_app::FixedUpdate) can miss a [`UiClick`], or an
+/// [`Interaction::Pressed`] that is reset in the next frame, when no fixed step runs in between.
+/// [`ui_click_buffer_system`] counts every [`UiClick`] of the node, and they stay buffered until
+/// [`BufferedClicks::take_click`] consumes them, however long it takes.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::BufferedClicks;
+/// fn fixed_update_system(mut query: Query<&mut BufferedClicks>) {
+///     for mut clicks in &mut query {
+///         while clicks.take_click() {
+///             println!("clicked");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct BufferedClicks {
+    clicks: u32,
+}
+
+impl BufferedClicks {
+    /// Returns the number of clicks that haven't been taken yet.
+    pub fn pending(&self) -> u32 {
+        self.clicks
+    }
+
+    /// Consumes a click and returns true, or returns false if there is none.
+    pub fn take_click(&mut self) -> bool {
+        let clicked = self.clicks > 0;
+        self.clicks = self.clicks.saturating_sub(1);
+        clicked
+    }
+
+    /// Consumes all of the clicks, and returns how many there were.
+    pub fn take_clicks(&mut self) -> u32 {
+        std::mem::take(&mut self.clicks)
+    }
+}
+
+/// The system that adds the [`UiClick`]s sent this frame to the [`BufferedClicks`] of their nodes.
+///
+/// It runs after [`UiSystem::Focus`](crate::UiSystem::Focus), so clicks from the keyboard are buffered too.
+pub fn ui_click_buffer_system(
+    mut click_events: EventReader<UiClick>,
+    mut buffer_query: Query<&mut BufferedClicks>,
+) {
+    for click in click_events.read() {
+        if let Ok(mut buffered_clicks) = buffer_query.get_mut(click.entity) {
+            buffered_clicks.clicks = buffered_clicks.clicks.saturating_add(1);
+        }
+    }
+}
+
+/// Makes [`ui_long_press_system`] send a [`LongPressed`] event when a UI node is held down.
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct LongPress {
+    /// How long the node has to stay [`Interaction::Pressed`] for the press to count as a long press
+    pub duration: Duration,
+}
+
+impl LongPress {
+    const DEFAULT: Self = Self {
+        duration: Duration::from_millis(500),
+    };
+}
+
+impl Default for LongPress {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Sent by [`ui_long_press_system`] when a node with [`LongPress`] has been held down for [`LongPress::duration`].
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct LongPressed {
+    /// The node that was long pressed
+    pub entity: Entity,
+}
+
+/// An ongoing hold of a [`LongPress`] node
+pub struct LongPressHold {
+    /// When the node was pressed
+    start: Duration,
+    /// Set once [`LongPressed`] has been sent or the hold was cancelled, until the node is released
+    finished: bool,
+}
+
+/// The system that detects long presses on nodes with a [`LongPress`] component.
+///
+/// A long press is sent once per hold, when the node has stayed [`Interaction::Pressed`]
+/// for [`LongPress::duration`]. Both mouse and touch presses count.
+///
+/// If the node also has a [`RelativeCursorPosition`], moving the cursor out of the node cancels the hold
+/// until the node is pressed again.
+pub fn ui_long_press_system(
+    mut holds: Local<EntityHashMap<LongPressHold>>,
+    time: Res<Time>,
+    long_press_query: Query<(
+        Entity,
+        &LongPress,
+        &Interaction,
+        Option<&RelativeCursorPosition>,
+    )>,
+    mut long_pressed_events: EventWriter<LongPressed>,
+) {
+    let now = time.elapsed();
+
+    for (entity, long_press, interaction, relative_cursor_position) in &long_press_query {
+        if *interaction != Interaction::Pressed {
+            holds.remove(&entity);
+            continue;
+        }
+        let hold = holds.entry(entity).or_insert(LongPressHold {
+            start: now,
+            finished: false,
+        });
+        if hold.finished {
+            continue;
+        }
+        if !relative_cursor_position.map_or(true, RelativeCursorPosition::mouse_over) {
+            hold.finished = true;
+        } else if now - hold.start >= long_press.duration {
+            hold.finished = true;
+            long_pressed_events.send(LongPressed { entity });
+        }
+    }
+
+    // forget about despawned nodes
+    holds.retain(|entity, _| long_press_query.contains(*entity));
+}
+
+/// Sets the cursor icon of the primary window while the cursor is over a UI node.
+///
+/// The icon is applied by [`ui_hover_cursor_system`] when the node is the topmost node with a [`HoverCursor`]
+/// that is [`Interaction::Hovered`] or [`Interaction::Pressed`], so the node also needs an [`Interaction`].
+/// A node with [`FocusPolicy::Block`] on top of it prevents the icon from being applied.
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct HoverCursor(pub CursorIcon);
+
+impl HoverCursor {
+    const DEFAULT: Self = Self(CursorIcon::Pointer);
+}
+
+impl Default for HoverCursor {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The system that sets the cursor icon of the primary window from the [`HoverCursor`] of the hovered node.
+///
+/// The window's icon is only written when the icon to show changes. Once no node with a [`HoverCursor`]
+/// is hovered anymore, the icon is set back to [`CursorIcon::Default`].
+pub fn ui_hover_cursor_system(
+    mut applied_icon: Local<Option<CursorIcon>>,
+    ui_stack: Res<UiStack>,
+    hover_cursor_query: Query<(&HoverCursor, &Interaction)>,
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let icon = ui_stack
+        .uinodes
+        .iter()
+        .rev()
+        .filter_map(|entity| hover_cursor_query.get(*entity).ok())
+        .find(|(_, interaction)| **interaction != Interaction::None)
+        .map(|(hover_cursor, _)| hover_cursor.0);
+
+    if icon == *applied_icon {
+        return;
+    }
+    let Ok(mut window) = primary_window.get_single_mut() else {
+        return;
+    };
+    window.cursor.icon = icon.unwrap_or_default();
+    *applied_icon = icon;
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets, Handle};
+    use bevy_core_pipeline::core_2d::Camera2dBundle;
+    use bevy_ecs::{
+        entity::Entity,
+        event::{Event, Events},
+        query::With,
+        schedule::{IntoSystemConfigs, Schedule},
+        system::RunSystemOnce,
+        world::{EntityWorldMut, World},
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_input::{
+        keyboard::KeyCode,
+        mouse::{MouseButton, MouseScrollUnit, MouseWheel},
+        touch::Touches,
+        ButtonInput,
+    };
+    use bevy_math::{Rect, UVec2, Vec2};
+    use bevy_render::{
+        camera::{
+            camera_system, Camera, ManualTextureViews, OrthographicProjection, RenderTarget,
+            Viewport,
+        },
+        texture::Image,
+        view::ViewVisibility,
+    };
+    use bevy_time::Time;
+    use bevy_transform::components::GlobalTransform;
+    use bevy_utils::default;
+    use bevy_window::{
+        CursorIcon, PrimaryWindow, Window, WindowCreated, WindowRef, WindowResized,
+        WindowResolution, WindowScaleFactorChanged,
+    };
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        ui_click_buffer_system, ui_double_click_system, ui_drag_system, ui_focus_system,
+        ui_hover_cursor_system, ui_long_press_system,
+        widget::{button_keyboard_system, Button},
+        BorderRadius, BufferedClicks, CalculatedClip, Disabled, DoubleClick, DoubleClicked,
+        DragEnd, DragState, Draggable, DraggingOutside, FocusPolicy, FocusedEntity, HitArea,
+        HitPadding, HoverCursor, HoverEnter, HoverExit, HoveredUiEntity, Interactable, Interaction,
+        InteractionButtons, InteractionDebounce, InteractionPolicy, KeyboardPressed, LongPress,
+        LongPressed, Modal, Node, PointerCapture, PrevInteraction, RelativeCursorPosition,
+        ScrolledOver, TargetCamera, UiClick, UiHitTest, UiInteractionConfig, UiPress, UiRelease,
+        UiScale, UiScaleMode, UiStack, UiTextureCursor, Val,
+    };
+
+    const WINDOW_WIDTH: f32 = 1000.;
+    const WINDOW_HEIGHT: f32 = 100.;
+
+    fn setup_focus_test_world() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<UiStack>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<Events<HoverEnter>>();
+        world.init_resource::<Events<HoverExit>>();
+        world.init_resource::<Events<UiPress>>();
+        world.init_resource::<Events<UiRelease>>();
+        world.init_resource::<Events<UiClick>>();
+        world.init_resource::<Events<DragEnd>>();
+        world.init_resource::<Events<MouseWheel>>();
+        world.init_resource::<HoveredUiEntity>();
+        world.init_resource::<UiInteractionConfig>();
+        world.init_resource::<PointerCapture>();
+
+        let mut window = Window {
+            resolution: WindowResolution::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+            ..default()
+        };
+        window.set_cursor_position(Some(Vec2::new(50., 50.)));
+        world.spawn((window, PrimaryWindow));
+        world.spawn(Camera2dBundle::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((ui_focus_system, ui_drag_system).chain());
+
+        (world, schedule)
+    }
+
+    /// Spawns a visible node covering `rect_min..rect_min + size` and pushes it to the top of the [`UiStack`]
+    fn spawn_node(world: &mut World, rect_min: Vec2, size: Vec2) -> EntityWorldMut {
+        let mut view_visibility = ViewVisibility::HIDDEN;
+        view_visibility.set();
+        let entity = world
+            .spawn((
+                Node {
+                    calculated_size: size,
+                    unrounded_size: size,
+                    ..default()
+                },
+                GlobalTransform::from_translation((rect_min + 0.5 * size).extend(0.)),
+                view_visibility,
+                Interaction::None,
+            ))
+            .id();
+        world.resource_mut::<UiStack>().uinodes.push(entity);
+        world.entity_mut(entity)
+    }
+
+    fn press(world: &mut World, button: MouseButton) {
+        let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+        input.clear();
+        input.press(button);
+    }
+
+    fn set_cursor_position(world: &mut World, position: Vec2) {
+        let mut windows = world.query::<&mut Window>();
+        windows
+            .single_mut(world)
+            .set_cursor_position(Some(position));
+    }
+
+    fn release(world: &mut World, button: MouseButton) {
+        let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+        input.clear();
+        input.release(button);
+    }
+
+    fn read_events<E: Event + Copy>(world: &World) -> Vec<E> {
+        let events = world.resource::<Events<E>>();
+        events.get_reader().read(events).copied().collect()
+    }
+
+    fn interaction(world: &World, entity: Entity) -> Interaction {
+        *world.get::<Interaction>(entity).unwrap()
+    }
+
+    #[test]
+    fn only_left_button_presses_by_default() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+
+        press(&mut world, MouseButton::Right);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+    }
+
+    #[test]
+    fn clipped_parts_of_nodes_dont_react_to_the_cursor() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let below = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        // only the left half of the node is visible, as if its parent clipped the rest
+        let clipped = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((
+                CalculatedClip {
+                    clip: Rect::new(0., 0., 50., 100.),
+                },
+                RelativeCursorPosition::default(),
+            ))
+            .id();
+
+        set_cursor_position(&mut world, Vec2::new(25., 50.));
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, clipped), Interaction::Pressed);
+        assert_eq!(interaction(&world, below), Interaction::None);
+
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        set_cursor_position(&mut world, Vec2::new(75., 50.));
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, clipped), Interaction::None);
+        assert_eq!(interaction(&world, below), Interaction::Pressed);
+
+        // the relative position is still measured against the whole node
+        let relative_cursor_position = world.get::<RelativeCursorPosition>(clipped).unwrap();
+        assert_eq!(
+            relative_cursor_position.normalized,
+            Some(Vec2::new(0.75, 0.5))
+        );
+        assert!(!relative_cursor_position.mouse_over());
+    }
+
+    #[test]
+    fn pixel_position_is_relative_to_the_top_left_corner_of_the_node() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        world.insert_resource(UiScale(2.));
+        let node = spawn_node(&mut world, Vec2::new(100., 0.), Vec2::new(200., 40.))
+            .insert(RelativeCursorPosition::default())
+            .id();
+
+        set_cursor_position(&mut world, Vec2::new(300., 60.));
+        schedule.run(&mut world);
+        let relative_cursor_position = world.get::<RelativeCursorPosition>(node).unwrap();
+        // the cursor is at (150., 30.) in UI pixels
+        assert_eq!(relative_cursor_position.pixels, Some(Vec2::new(50., 30.)));
+        assert_eq!(
+            relative_cursor_position.normalized,
+            Some(Vec2::new(0.25, 0.75))
+        );
+    }
+
+    #[test]
+    fn hit_test_finds_the_visible_nodes_of_the_camera_under_a_point() {
+        let (mut world, _) = setup_focus_test_world();
+        let camera = world
+            .query_filtered::<Entity, With<Camera>>()
+            .single(&world);
+        let bottom = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let panel = spawn_node(&mut world, Vec2::ZERO, Vec2::new(200., 100.))
+            .insert(FocusPolicy::Pass)
+            .id();
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).insert(ViewVisibility::HIDDEN);
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).insert(Interactable(false));
+        let other_camera = world.spawn_empty().id();
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).insert(TargetCamera(other_camera));
+
+        let mut hit_test = |position: Vec2| {
+            world.run_system_once(move |hit_test: UiHitTest| {
+                (
+                    hit_test.nodes_at(camera, position),
+                    hit_test.top_node_at(camera, position),
+                )
+            })
+        };
+        assert_eq!(
+            hit_test(Vec2::new(50., 50.)),
+            (vec![panel, bottom], Some(bottom))
+        );
+        assert_eq!(hit_test(Vec2::new(150., 50.)), (vec![panel], None));
+        assert_eq!(hit_test(Vec2::new(250., 50.)), (vec![], None));
+    }
+
+    #[test]
+    fn cursor_positions_have_y_pointing_down_like_the_ui() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(
+            &mut world,
+            Vec2::ZERO,
+            Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        )
+        .insert(RelativeCursorPosition::default())
+        .id();
+
+        let mut relative_cursor_position = |world: &mut World, position: Vec2| {
+            set_cursor_position(world, position);
+            schedule.run(world);
+            world
+                .get::<RelativeCursorPosition>(node)
+                .unwrap()
+                .normalized
+                .unwrap()
+        };
+        assert_eq!(relative_cursor_position(&mut world, Vec2::ZERO), Vec2::ZERO);
+        assert_eq!(
+            relative_cursor_position(&mut world, Vec2::new(250., 75.)),
+            Vec2::new(0.25, 0.75)
+        );
+        assert_eq!(
+            relative_cursor_position(&mut world, Vec2::new(500., 25.)),
+            Vec2::new(0.5, 0.25)
+        );
+    }
+
+    #[test]
+    fn configured_buttons_press_and_release() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(InteractionButtons::new([
+                MouseButton::Right,
+                MouseButton::Middle,
+            ]))
+            .id();
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+
+        press(&mut world, MouseButton::Middle);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Middle);
+        schedule.run(&mut world);
+        // the cursor is still over the node
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+    }
+
+    #[test]
+    fn empty_buttons_never_press() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(InteractionButtons::new([]))
+            .id();
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+    }
+
+    #[test]
+    fn drag_starts_beyond_threshold_and_ends_on_release() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(Draggable { threshold: 10. })
+            .id();
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        set_cursor_position(&mut world, Vec2::new(55., 50.));
+        schedule.run(&mut world);
+        assert!(world.get::<DragState>(node).is_none());
+
+        // keeps dragging outside of the node
+        set_cursor_position(&mut world, Vec2::new(150., 50.));
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<DragState>(node),
+            Some(&DragState {
+                start: Vec2::new(50., 50.),
+                current: Vec2::new(150., 50.),
+                delta: Vec2::new(100., 0.),
+            })
+        );
+
+        set_cursor_position(&mut world, Vec2::new(160., 40.));
+        schedule.run(&mut world);
+        assert_eq!(
+            world
+                .get::<DragState>(node)
+                .map(|drag_state| drag_state.delta),
+            Some(Vec2::new(10., -10.))
+        );
+
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        schedule.run(&mut world);
+        assert!(world.get::<DragState>(node).is_none());
+        let drag_end_events = world.resource::<Events<DragEnd>>();
+        let drag_end = drag_end_events
+            .get_reader()
+            .read(drag_end_events)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(drag_end.len(), 1);
+        assert_eq!(drag_end[0].entity, node);
+        assert_eq!(drag_end[0].state.current, Vec2::new(160., 40.));
+    }
+
+    #[test]
+    fn drag_ends_when_window_loses_focus() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(Draggable::default())
+            .id();
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        set_cursor_position(&mut world, Vec2::new(80., 50.));
+        schedule.run(&mut world);
+        assert!(world.get::<DragState>(node).is_some());
+
+        let mut windows = world.query::<&mut Window>();
+        windows.single_mut(&mut world).focused = false;
+        schedule.run(&mut world);
+        assert!(world.get::<DragState>(node).is_none());
+        assert_eq!(world.resource::<Events<DragEnd>>().len(), 1);
+
+        // the drag doesn't restart until the node is pressed again
+        windows.single_mut(&mut world).focused = true;
+        set_cursor_position(&mut world, Vec2::new(20., 50.));
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert!(world.get::<DragState>(node).is_none());
+    }
+
+    #[test]
+    fn non_interactive_nodes_are_skipped_only_if_configured() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let button = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let decoration = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .remove::<Interaction>()
+            .insert(FocusPolicy::Block)
+            .id();
+
+        // by default the decoration blocks the cursor
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, button), Interaction::None);
+        assert!(world.resource::<HoveredUiEntity>().is_over_ui);
+
+        world
+            .resource_mut::<UiInteractionConfig>()
+            .interactive_nodes_only = true;
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, button), Interaction::Hovered);
+
+        world.entity_mut(decoration).insert(Interactable(true));
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, button), Interaction::None);
+
+        // skipped nodes aren't over the UI
+        world.entity_mut(decoration).remove::<Interactable>();
+        world.despawn(button);
+        schedule.run(&mut world);
+        assert!(!world.resource::<HoveredUiEntity>().is_over_ui);
+    }
+
+    /// Prints the time taken by [`ui_focus_system`] for 2000 visible nodes, 20 of them with an
+    /// [`Interaction`], with and without [`UiInteractionConfig::interactive_nodes_only`].
+    ///
+    /// Run it in release with `cargo test -p bevy_ui --release --lib -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "measures the time taken by ui_focus_system"]
+    fn interactive_nodes_only_speeds_up_large_uis() {
+        const FRAMES: u32 = 1000;
+        let frame_time = |interactive_nodes_only: bool| {
+            let (mut world, mut schedule) = setup_focus_test_world();
+            world
+                .resource_mut::<UiInteractionConfig>()
+                .interactive_nodes_only = interactive_nodes_only;
+            // a grid of 100 by 20 nodes covering the window, with an interactive node on each row
+            for i in 0..2000 {
+                let rect_min = Vec2::new((i % 100) as f32 * 10., (i / 100) as f32 * 5.);
+                let mut node = spawn_node(&mut world, rect_min, Vec2::new(10., 5.));
+                if i % 100 != 0 {
+                    node.remove::<Interaction>();
+                }
+            }
+
+            let start = Instant::now();
+            for frame in 0..FRAMES {
+                set_cursor_position(&mut world, Vec2::new((frame % 1000) as f32, 50.));
+                schedule.run(&mut world);
+            }
+            start.elapsed() / FRAMES
+        };
+
+        let all_nodes = frame_time(false);
+        let interactive_nodes = frame_time(true);
+        println!(
+            "ui_focus_system: {all_nodes:?} per frame with all the nodes, \
+            {interactive_nodes:?} with the interactive nodes only"
+        );
+    }
+
+    #[test]
+    fn unfocused_windows_are_ignored_only_if_configured() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let mut windows = world.query::<&mut Window>();
+        windows.single_mut(&mut world).focused = false;
+
+        // by default the UI reacts to the cursor over an unfocused window
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Interaction>(node), Some(&Interaction::Hovered));
+
+        world
+            .resource_mut::<UiInteractionConfig>()
+            .require_focused_window = true;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Interaction>(node), Some(&Interaction::None));
+        assert_eq!(world.resource::<HoveredUiEntity>().entity, None);
+
+        windows.single_mut(&mut world).focused = true;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Interaction>(node), Some(&Interaction::Hovered));
+    }
+
+    #[test]
+    fn dragged_nodes_capture_the_pointer() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let left = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(Draggable::default())
+            .id();
+        let right = spawn_node(&mut world, Vec2::new(100., 0.), Vec2::splat(100.)).id();
+        let interactions = |world: &World| {
+            (
+                world.get::<Interaction>(left).copied(),
+                world.get::<Interaction>(right).copied(),
+            )
+        };
+
+        let start_drag = |world: &mut World, schedule: &mut Schedule| {
+            set_cursor_position(world, Vec2::new(50., 50.));
+            press(world, MouseButton::Left);
+            schedule.run(world);
+            world.resource_mut::<ButtonInput<MouseButton>>().clear();
+            set_cursor_position(world, Vec2::new(80., 50.));
+            schedule.run(world);
+        };
+        start_drag(&mut world, &mut schedule);
+        assert_eq!(world.resource::<PointerCapture>().0, Some(left));
+
+        // the cursor passes over the other node without hovering it
+        set_cursor_position(&mut world, Vec2::new(150., 50.));
+        schedule.run(&mut world);
+        assert_eq!(
+            interactions(&world),
+            (Some(Interaction::Pressed), Some(Interaction::None))
+        );
+        assert!(world.get::<DragState>(left).is_some());
+
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<PointerCapture>().0, None);
+        assert_eq!(
+            interactions(&world),
+            (Some(Interaction::None), Some(Interaction::Hovered))
+        );
+
+        // despawning the captured node releases the capture
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        start_drag(&mut world, &mut schedule);
+        assert_eq!(world.resource::<PointerCapture>().0, Some(left));
+        world.despawn(left);
+        world
+            .resource_mut::<UiStack>()
+            .uinodes
+            .retain(|entity| *entity != left);
+        set_cursor_position(&mut world, Vec2::new(150., 50.));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<PointerCapture>().0, None);
+        assert_eq!(world.get::<Interaction>(right), Some(&Interaction::Hovered));
+    }
+
+    #[test]
+    fn hover_events_follow_the_cursor_jumping_between_nodes() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let left = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let right = spawn_node(&mut world, Vec2::new(100., 0.), Vec2::splat(100.)).id();
+        let mut run = |world: &mut World| {
+            schedule.run(world);
+            let enters = world
+                .resource_mut::<Events<HoverEnter>>()
+                .drain()
+                .map(|event| event.entity)
+                .collect::<Vec<_>>();
+            let exits = world
+                .resource_mut::<Events<HoverExit>>()
+                .drain()
+                .map(|event| event.entity)
+                .collect::<Vec<_>>();
+            (enters, exits)
+        };
+
+        set_cursor_position(&mut world, Vec2::new(50., 50.));
+        assert_eq!(run(&mut world), (vec![left], vec![]));
+        assert_eq!(run(&mut world), (vec![], vec![]));
+
+        set_cursor_position(&mut world, Vec2::new(150., 50.));
+        assert_eq!(run(&mut world), (vec![right], vec![left]));
+
+        // pressing a node doesn't move the cursor out of it
+        press(&mut world, MouseButton::Left);
+        assert_eq!(run(&mut world), (vec![], vec![]));
+        assert_eq!(world.get::<Interaction>(right), Some(&Interaction::Pressed));
+
+        set_cursor_position(&mut world, Vec2::new(250., 50.));
+        assert_eq!(run(&mut world), (vec![], vec![right]));
+    }
+
+    #[test]
+    fn click_requires_release_over_pressed_node() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let left = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let right = spawn_node(&mut world, Vec2::new(100., 0.), Vec2::splat(100.)).id();
+
+        set_cursor_position(&mut world, Vec2::new(25., 50.));
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let presses = read_events::<UiPress>(&world);
+        assert_eq!(presses.len(), 1);
+        assert_eq!(presses[0].entity, left);
+        assert_eq!(presses[0].position.normalized, Some(Vec2::new(0.25, 0.5)));
+
+        // releasing over another node doesn't click either of them
+        set_cursor_position(&mut world, Vec2::new(150., 50.));
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let releases = read_events::<UiRelease>(&world);
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].entity, left);
+        assert_eq!(releases[0].position.normalized, Some(Vec2::new(1.5, 0.5)));
+        assert!(read_events::<UiClick>(&world).is_empty());
+        assert_eq!(interaction(&world, right), Interaction::Hovered);
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        set_cursor_position(&mut world, Vec2::new(175., 50.));
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let clicks = read_events::<UiClick>(&world);
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].entity, right);
+        assert_eq!(
+            clicks[0].press_position.normalized,
+            Some(Vec2::new(0.5, 0.5))
+        );
+        assert_eq!(
+            clicks[0].release_position.normalized,
+            Some(Vec2::new(0.75, 0.5))
+        );
+    }
+
+    #[test]
+    fn buffered_clicks_stay_until_they_are_taken() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        schedule.add_systems(ui_click_buffer_system.after(ui_focus_system));
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(BufferedClicks::default())
+            .id();
+
+        // pressed and released within a frame
+        let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+        input.press(MouseButton::Left);
+        input.release(MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+        assert_eq!(world.get::<BufferedClicks>(node).unwrap().pending(), 1);
+
+        // the interaction is reset in the next frame, before a fixed update consumer ran
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        schedule.run(&mut world);
+        assert_ne!(interaction(&world, node), Interaction::Pressed);
+        assert!(world.get_mut::<BufferedClicks>(node).unwrap().take_click());
+        assert!(!world.get_mut::<BufferedClicks>(node).unwrap().take_click());
+    }
+
+    #[test]
+    fn keyboard_presses_last_until_the_key_is_released() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        schedule.add_systems(button_keyboard_system.after(ui_focus_system));
+        let button = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(Button)
+            .id();
+        world.resource_mut::<FocusedEntity>().0 = Some(button);
+        world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(&mut world)
+            .set_cursor_position(None);
+        let mut run = |world: &mut World| {
+            schedule.run(world);
+            world.resource_mut::<ButtonInput<KeyCode>>().clear();
+            world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        };
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        run(&mut world);
+        for _ in 0..3 {
+            run(&mut world);
+            assert_eq!(interaction(&world, button), Interaction::Pressed);
+        }
+        // releasing a mouse button doesn't release the key either
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        run(&mut world);
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        run(&mut world);
+        assert_eq!(interaction(&world, button), Interaction::Pressed);
+        assert_eq!(read_events::<UiPress>(&world).len(), 1);
+        assert!(read_events::<UiRelease>(&world).is_empty());
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::Space);
+        run(&mut world);
+        assert_eq!(interaction(&world, button), Interaction::None);
+        assert!(world.get::<KeyboardPressed>(button).is_none());
+        let releases = read_events::<UiRelease>(&world);
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].entity, button);
+        assert_eq!(read_events::<UiClick>(&world).len(), 1);
+    }
+
+    #[test]
+    fn prev_interaction_follows_the_interaction_of_the_previous_frame() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(PrevInteraction::default())
+            .id();
+        let interactions = |world: &World| {
+            (
+                interaction(world, node),
+                *world.get::<PrevInteraction>(node).unwrap(),
+            )
+        };
+
+        schedule.run(&mut world);
+        let (current, previous) = interactions(&world);
+        assert_eq!(previous, PrevInteraction(Interaction::None));
+        assert!(previous.just_hovered(current));
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let (current, previous) = interactions(&world);
+        assert_eq!(previous, PrevInteraction(Interaction::Hovered));
+        assert!(previous.just_pressed(current));
+        assert!(!previous.just_hovered(current));
+
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let (current, previous) = interactions(&world);
+        assert!(previous.just_released(current));
+        assert!(previous.just_clicked(current));
+
+        // hiding a pressed node releases it without a click
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        world.entity_mut(node).insert(ViewVisibility::HIDDEN);
+        schedule.run(&mut world);
+        let (current, previous) = interactions(&world);
+        assert_eq!(current, Interaction::None);
+        assert_eq!(previous, PrevInteraction(Interaction::Pressed));
+        assert!(previous.just_released(current));
+        assert!(previous.just_unhovered(current));
+        assert!(!previous.just_clicked(current));
+
+        schedule.run(&mut world);
+        let (current, previous) = interactions(&world);
+        assert_eq!(previous, PrevInteraction(Interaction::None));
+        assert!(!previous.just_released(current));
+    }
+
+    #[test]
+    fn disabled_nodes_are_never_pressed_or_clicked() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let below = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(Disabled)
+            .id();
+
+        schedule.run(&mut world);
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+        // the disabled node still blocks the node below it
+        assert_eq!(interaction(&world, below), Interaction::None);
+        assert!(read_events::<UiPress>(&world).is_empty());
+        assert!(read_events::<UiClick>(&world).is_empty());
+
+        // a node disabled while pressed is reset without being clicked
+        world.entity_mut(node).remove::<Disabled>();
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+        world.entity_mut(node).insert(Disabled);
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+        assert!(read_events::<UiClick>(&world).is_empty());
+        assert!(read_events::<UiRelease>(&world).is_empty());
+    }
+
+    #[test]
+    fn non_interactable_nodes_are_never_hovered_or_clicked() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let below = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let overlay = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((Interactable(false), FocusPolicy::Block))
+            .id();
+
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, overlay), Interaction::None);
+        // the cursor passes through the overlay
+        assert_eq!(interaction(&world, below), Interaction::Hovered);
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, overlay), Interaction::None);
+        assert!(read_events::<HoverEnter>(&world)
+            .iter()
+            .all(|event| event.entity != overlay));
+        let clicks = read_events::<UiClick>(&world);
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].entity, below);
+    }
+
+    #[test]
+    fn only_the_topmost_modal_is_interactive() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let behind = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        // the modal doesn't need to cover the nodes it disables
+        let modal = spawn_node(&mut world, Vec2::new(200., 0.), Vec2::splat(100.))
+            .insert(Modal)
+            .id();
+        let dialog_button = spawn_node(&mut world, Vec2::new(200., 0.), Vec2::splat(100.))
+            .set_parent(modal)
+            .id();
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, behind), Interaction::None);
+        assert!(read_events::<UiPress>(&world).is_empty());
+        assert!(read_events::<UiClick>(&world).is_empty());
+
+        set_cursor_position(&mut world, Vec2::new(250., 50.));
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, dialog_button), Interaction::Hovered);
+        // the hit test skips the same nodes
+        let camera = world
+            .query_filtered::<Entity, With<Camera>>()
+            .single(&world);
+        let nodes_at = |world: &mut World, position: Vec2| {
+            world.run_system_once(move |hit_test: UiHitTest| hit_test.nodes_at(camera, position))
+        };
+        assert!(nodes_at(&mut world, Vec2::new(50., 50.)).is_empty());
+        assert_eq!(
+            nodes_at(&mut world, Vec2::new(250., 50.)),
+            [dialog_button, modal]
+        );
+
+        // a modal opened over the first one disables it, until it is hidden
+        let top_modal = spawn_node(&mut world, Vec2::new(400., 0.), Vec2::splat(100.))
+            .insert(Modal)
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, dialog_button), Interaction::None);
+        *world.get_mut::<ViewVisibility>(top_modal).unwrap() = ViewVisibility::HIDDEN;
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, dialog_button), Interaction::Hovered);
+
+        // closing the modal gives the rest of the UI back its interactions
+        world.entity_mut(modal).remove::<Modal>();
+        set_cursor_position(&mut world, Vec2::new(50., 50.));
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, behind), Interaction::Pressed);
+    }
+
+    #[test]
+    fn press_and_release_in_one_frame_clicks() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+
+        press(&mut world, MouseButton::Left);
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(read_events::<UiPress>(&world).len(), 1);
+        assert_eq!(read_events::<UiRelease>(&world).len(), 1);
+        let clicks = read_events::<UiClick>(&world);
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].entity, node);
+    }
+
+    #[test]
+    fn triple_click_sends_one_double_click() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<DoubleClicked>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_double_click_system);
+
+        let node = world
+            .spawn((DoubleClick::default(), Interaction::None))
+            .id();
+        let mut click = |world: &mut World, delay_ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(delay_ms));
+            *world.get_mut::<Interaction>(node).unwrap() = Interaction::Pressed;
+            schedule.run(world);
+            *world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+            schedule.run(world);
+        };
+
+        click(&mut world, 0);
+        click(&mut world, 100);
+        click(&mut world, 100);
+        assert_eq!(read_events::<DoubleClicked>(&world).len(), 1);
+
+        // too slow
+        click(&mut world, 1000);
+        click(&mut world, 400);
+        assert_eq!(read_events::<DoubleClicked>(&world).len(), 1);
+
+        click(&mut world, 200);
+        assert_eq!(read_events::<DoubleClicked>(&world).len(), 2);
+    }
+
+    #[test]
+    fn long_press_is_sent_once_per_hold() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<LongPressed>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_long_press_system);
+
+        let node = world
+            .spawn((
+                LongPress::default(),
+                Interaction::Pressed,
+                RelativeCursorPosition {
+                    normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+                    normalized: Some(Vec2::splat(0.5)),
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let mut advance = |world: &mut World, ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(ms));
+            schedule.run(world);
+        };
+
+        advance(&mut world, 0);
+        advance(&mut world, 300);
+        assert!(read_events::<LongPressed>(&world).is_empty());
+        advance(&mut world, 300);
+        advance(&mut world, 300);
+        assert_eq!(read_events::<LongPressed>(&world).len(), 1);
+
+        // released before the end of the hold
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+        advance(&mut world, 0);
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::Pressed;
+        advance(&mut world, 0);
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+        advance(&mut world, 600);
+        assert_eq!(read_events::<LongPressed>(&world).len(), 1);
+
+        // the cursor leaves the node during the hold
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::Pressed;
+        advance(&mut world, 0);
+        world
+            .get_mut::<RelativeCursorPosition>(node)
+            .unwrap()
+            .normalized = Some(Vec2::splat(2.));
+        advance(&mut world, 100);
+        world
+            .get_mut::<RelativeCursorPosition>(node)
+            .unwrap()
+            .normalized = Some(Vec2::splat(0.5));
+        advance(&mut world, 600);
+        assert_eq!(read_events::<LongPressed>(&world).len(), 1);
+    }
+
+    #[test]
+    fn scroll_goes_to_topmost_scrolled_over_node() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let bottom = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((ScrolledOver::default(), FocusPolicy::Pass))
+            .id();
+        let middle = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((ScrolledOver::default(), FocusPolicy::Pass))
+            .id();
+        // doesn't receive scrolling, but lets it through
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).insert(FocusPolicy::Pass);
+        let delta =
+            |world: &World, entity: Entity| world.get::<ScrolledOver>(entity).unwrap().delta;
+
+        world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.,
+            y: 1.,
+            window: Entity::PLACEHOLDER,
+        });
+        world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: 5.,
+            y: 0.,
+            window: Entity::PLACEHOLDER,
+        });
+        schedule.run(&mut world);
+        assert_eq!(
+            delta(&world, middle),
+            Vec2::new(5., ScrolledOver::LINE_HEIGHT)
+        );
+        assert_eq!(delta(&world, bottom), Vec2::ZERO);
+
+        world.resource_mut::<Events<MouseWheel>>().update();
+        schedule.run(&mut world);
+        assert_eq!(delta(&world, middle), Vec2::ZERO);
+
+        // a blocking node above captures the scroll
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.));
+        world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: 0.,
+            y: 3.,
+            window: Entity::PLACEHOLDER,
+        });
+        schedule.run(&mut world);
+        assert_eq!(delta(&world, middle), Vec2::ZERO);
+        assert_eq!(delta(&world, bottom), Vec2::ZERO);
+    }
+
+    #[test]
+    fn hit_area_contains() {
+        let size = Vec2::new(200., 100.);
+        let corner = Vec2::new(0.01, 0.02);
+        let center = Vec2::splat(0.5);
+        let outside = Vec2::new(1.1, 0.5);
+
+        assert!(HitArea::Rect.contains(corner, size));
+        assert!(HitArea::Rect.contains(center, size));
+        assert!(!HitArea::Rect.contains(outside, size));
+
+        let rounded = HitArea::RoundedRect { radius: 20. };
+        assert!(!rounded.contains(corner, size));
+        assert!(rounded.contains(center, size));
+        // along an edge, away from the corners
+        assert!(rounded.contains(Vec2::new(0.5, 0.01), size));
+        assert!(!rounded.contains(outside, size));
+        // the radius is clamped, making a capsule
+        let capsule = HitArea::RoundedRect { radius: 1000. };
+        assert!(capsule.contains(Vec2::new(0.5, 0.01), size));
+        assert!(!capsule.contains(Vec2::new(0.05, 0.05), size));
+
+        assert!(!HitArea::Ellipse.contains(corner, size));
+        assert!(HitArea::Ellipse.contains(center, size));
+        assert!(HitArea::Ellipse.contains(Vec2::new(0.99, 0.5), size));
+        assert!(!HitArea::Ellipse.contains(Vec2::new(0.9, 0.9), size));
+    }
+
+    #[test]
+    fn cursor_outside_hit_area_doesnt_hover() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        // the cursor is in the top-left corner of the node
+        let node = spawn_node(&mut world, Vec2::splat(49.), Vec2::splat(100.))
+            .insert(HitArea::Ellipse)
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+
+        world.entity_mut(node).insert(HitArea::Rect);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+    }
+
+    #[test]
+    fn rounded_corners_dont_hover() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        // the cursor is in the top-left corner of the node
+        let node = spawn_node(&mut world, Vec2::splat(45.), Vec2::splat(100.))
+            .insert(BorderRadius {
+                top_left: Val::Percent(50.),
+                ..BorderRadius::DEFAULT
+            })
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+
+        // the corner under the cursor isn't rounded anymore
+        world.entity_mut(node).insert(BorderRadius {
+            top_right: Val::Px(50.),
+            ..BorderRadius::DEFAULT
+        });
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+
+        // an explicit hit area takes precedence over the border radius
+        world
+            .entity_mut(node)
+            .insert((BorderRadius::all(Val::Px(50.)), HitArea::Rect));
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+    }
+
+    #[test]
+    fn hit_padding_extends_the_node_under_the_cursor() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        // the cursor is 2 pixels above and left of the node
+        let node = spawn_node(&mut world, Vec2::splat(52.), Vec2::splat(20.))
+            .insert(RelativeCursorPosition::default())
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+
+        world
+            .entity_mut(node)
+            .insert(HitPadding::all(Val::Percent(20.)));
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+        let relative_cursor_position = world.get::<RelativeCursorPosition>(node).unwrap();
+        assert_eq!(relative_cursor_position.normalized, Some(Vec2::splat(-0.1)));
+        assert_eq!(
+            relative_cursor_position.normalized_visible_node_rect,
+            Rect::new(-0.2, -0.2, 1.2, 1.2)
+        );
+
+        // the padding is clipped like the node
+        world.entity_mut(node).insert((
+            CalculatedClip {
+                clip: Rect::new(52., 52., 72., 72.),
+            },
+            Interaction::None,
+        ));
+        world.resource_mut::<ButtonInput<MouseButton>>().reset_all();
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+    }
+
+    #[test]
+    fn hover_cursor_follows_topmost_hovered_node() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        schedule.add_systems(ui_hover_cursor_system.after(ui_focus_system));
+        let window_icon = |world: &mut World| {
+            world
+                .query_filtered::<&Window, With<PrimaryWindow>>()
+                .single(world)
+                .cursor
+                .icon
+        };
+
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((HoverCursor(CursorIcon::Text), FocusPolicy::Pass));
+        let top = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((HoverCursor::default(), FocusPolicy::Pass))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(window_icon(&mut world), CursorIcon::Pointer);
+
+        world.entity_mut(top).remove::<HoverCursor>();
+        schedule.run(&mut world);
+        assert_eq!(window_icon(&mut world), CursorIcon::Text);
+
+        // the icon is only written on change
+        world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(&mut world)
+            .cursor
+            .icon = CursorIcon::Wait;
+        schedule.run(&mut world);
+        assert_eq!(window_icon(&mut world), CursorIcon::Wait);
+
+        set_cursor_position(&mut world, Vec2::new(500., 50.));
+        schedule.run(&mut world);
+        assert_eq!(window_icon(&mut world), CursorIcon::Default);
+    }
+
+    #[test]
+    fn hovered_ui_entity_stops_at_blocking_node() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let hovered = |world: &World| *world.resource::<HoveredUiEntity>();
+
+        schedule.run(&mut world);
+        assert_eq!(hovered(&world), HoveredUiEntity::default());
+
+        let bottom = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let middle = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        // a panel without an interaction, letting the cursor through
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .remove::<Interaction>()
+            .insert(FocusPolicy::Pass);
+        schedule.run(&mut world);
+        assert_eq!(
+            hovered(&world),
+            HoveredUiEntity {
+                entity: Some(middle),
+                is_over_ui: true,
             }
+        );
+
+        world.entity_mut(middle).insert(FocusPolicy::Pass);
+        world.entity_mut(middle).remove::<Interaction>();
+        schedule.run(&mut world);
+        assert_eq!(hovered(&world).entity, Some(bottom));
+
+        // a blocking panel without an interaction captures the cursor
+        spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).remove::<Interaction>();
+        schedule.run(&mut world);
+        assert_eq!(
+            hovered(&world),
+            HoveredUiEntity {
+                entity: None,
+                is_over_ui: true,
+            }
+        );
+    }
+
+    #[test]
+    fn focus_policies_block_hover_and_presses_separately() {
+        use Interaction::{Hovered, None, Pressed};
+
+        // the interactions of the top and bottom nodes of a stack, when hovered and then pressed
+        let stack_interactions = |focus_policy: FocusPolicy| {
+            let (mut world, mut schedule) = setup_focus_test_world();
+            let bottom = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+            let top = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+                .insert(focus_policy)
+                .id();
+            let interactions =
+                |world: &World| [interaction(world, top), interaction(world, bottom)];
+
+            schedule.run(&mut world);
+            let hovered = interactions(&world);
+            press(&mut world, MouseButton::Left);
+            schedule.run(&mut world);
+            let pressed = interactions(&world);
+            release(&mut world, MouseButton::Left);
+            schedule.run(&mut world);
+            let clicked = read_events::<UiClick>(&world)
+                .iter()
+                .map(|click| click.entity == top)
+                .collect::<Vec<_>>();
+            (hovered, pressed, clicked)
+        };
+
+        assert_eq!(
+            stack_interactions(FocusPolicy::Block),
+            ([Hovered, None], [Pressed, None], vec![true])
+        );
+        assert_eq!(
+            stack_interactions(FocusPolicy::Pass),
+            ([Hovered, Hovered], [Pressed, Pressed], vec![true, false])
+        );
+        assert_eq!(
+            stack_interactions(FocusPolicy::HoverPassClickBlock),
+            ([Hovered, Hovered], [Pressed, Hovered], vec![true])
+        );
+        assert_eq!(
+            stack_interactions(FocusPolicy::HoverBlockClickPass),
+            ([Hovered, None], [Pressed, Pressed], vec![true, false])
+        );
+    }
+
+    #[test]
+    fn pass_all_nodes_are_hovered_below_blocking_nodes() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let below_block = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(FocusPolicy::Pass)
+            .id();
+        let stacked = [(); 3].map(|_| {
+            spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+                .insert(FocusPolicy::PassAll)
+                .id()
+        });
+        let interactions = |world: &World| stacked.map(|entity| interaction(world, entity));
+
+        schedule.run(&mut world);
+        assert_eq!(interactions(&world), [Interaction::Hovered; 3]);
+        assert_eq!(interaction(&world, below_block), Interaction::Hovered);
+
+        let block = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(FocusPolicy::Block)
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, block), Interaction::Hovered);
+        assert_eq!(interactions(&world), [Interaction::Hovered; 3]);
+        assert_eq!(interaction(&world, below_block), Interaction::None);
+
+        // the blocking node still captures presses
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, block), Interaction::Pressed);
+        assert_eq!(interactions(&world), [Interaction::Hovered; 3]);
+        assert_eq!(read_events::<UiPress>(&world).len(), 1);
+
+        release(&mut world, MouseButton::Left);
+        set_cursor_position(&mut world, Vec2::new(150., 50.));
+        schedule.run(&mut world);
+        assert_eq!(interactions(&world), [Interaction::None; 3]);
+    }
+
+    #[test]
+    fn nodes_only_react_to_the_cursor_of_their_camera_window() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let mut window = Window {
+            resolution: WindowResolution::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+            ..default()
+        };
+        window.set_cursor_position(None);
+        let second_window = world.spawn(window).id();
+        let second_camera = world
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    target: RenderTarget::Window(WindowRef::Entity(second_window)),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+
+        let first_node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.)).id();
+        let second_node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert((TargetCamera(second_camera), FocusPolicy::Pass))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, first_node), Interaction::Hovered);
+        assert_eq!(interaction(&world, second_node), Interaction::None);
+
+        // move the cursor over to the second window
+        let mut windows = world.query::<&mut Window>();
+        for mut window in windows.iter_mut(&mut world) {
+            let cursor_position = window.cursor_position();
+            window.set_cursor_position(match cursor_position {
+                Some(_) => None,
+                None => Some(Vec2::new(50., 50.)),
+            });
+        }
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, first_node), Interaction::None);
+        assert_eq!(interaction(&world, second_node), Interaction::Hovered);
+    }
+
+    #[test]
+    fn split_screen_nodes_only_react_to_the_cursor_in_their_camera_viewport() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ManualTextureViews>();
+
+        let half_viewport = |x| Viewport {
+            physical_position: UVec2::new(x, 0),
+            physical_size: UVec2::new(WINDOW_WIDTH as u32 / 2, WINDOW_HEIGHT as u32),
+            ..default()
+        };
+        let left_camera_entity = world
+            .query_filtered::<Entity, With<Camera>>()
+            .single(&world);
+        world
+            .get_mut::<Camera>(left_camera_entity)
+            .unwrap()
+            .viewport = Some(half_viewport(0));
+        let right_camera_entity = world
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    order: 1,
+                    viewport: Some(half_viewport(WINDOW_WIDTH as u32 / 2)),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        let mut camera_schedule = Schedule::default();
+        camera_schedule.add_systems(camera_system::<OrthographicProjection>);
+        camera_schedule.run(&mut world);
+
+        // both nodes are at the top-left of their camera's viewport
+        let left_node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(TargetCamera(left_camera_entity))
+            .id();
+        let right_node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(TargetCamera(right_camera_entity))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, left_node), Interaction::Hovered);
+        assert_eq!(interaction(&world, right_node), Interaction::None);
+
+        set_cursor_position(&mut world, Vec2::new(WINDOW_WIDTH / 2. + 50., 50.));
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, left_node), Interaction::None);
+        assert_eq!(interaction(&world, right_node), Interaction::Hovered);
+    }
+
+    #[test]
+    fn nodes_rendered_to_an_image_react_to_its_texture_cursor() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let texture_camera = world
+            .spawn((
+                Camera2dBundle {
+                    camera: Camera {
+                        target: RenderTarget::Image(Handle::default()),
+                        ..default()
+                    },
+                    ..default()
+                },
+                UiTextureCursor::default(),
+            ))
+            .id();
+        // the window cursor is over the node, but the node isn't displayed in the window
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(TargetCamera(texture_camera))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+
+        let mut set_texture_cursor = |world: &mut World, position| {
+            world
+                .get_mut::<UiTextureCursor>(texture_camera)
+                .unwrap()
+                .position = position;
+            schedule.run(world);
+            interaction(world, node)
+        };
+        assert_eq!(
+            set_texture_cursor(&mut world, Some(Vec2::new(150., 50.))),
+            Interaction::None
+        );
+        assert_eq!(
+            set_texture_cursor(&mut world, Some(Vec2::new(50., 50.))),
+            Interaction::Hovered
+        );
+
+        // the position is in pixels of the image, so it is scaled like window cursor positions
+        world.resource_mut::<UiScale>().0 = 2.;
+        assert_eq!(
+            set_texture_cursor(&mut world, Some(Vec2::new(150., 50.))),
+            Interaction::Hovered
+        );
+        assert_eq!(set_texture_cursor(&mut world, None), Interaction::None);
+    }
+
+    /// Presses a node, moves the cursor out of it, back in and out again, and releases it,
+    /// returning the interaction and whether the node was [`DraggingOutside`] after each step
+    fn press_outside_transitions(
+        policy: Option<InteractionPolicy>,
+    ) -> ([(Interaction, bool); 5], usize) {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let mut node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.));
+        if let Some(policy) = policy {
+            node.insert(policy);
         }
+        let node = node.id();
+        let state = |world: &World| {
+            (
+                interaction(world, node),
+                world.get::<DraggingOutside>(node).is_some(),
+            )
+        };
+
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let pressed = state(&world);
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+
+        set_cursor_position(&mut world, Vec2::new(500., 50.));
+        schedule.run(&mut world);
+        let outside = state(&world);
+
+        set_cursor_position(&mut world, Vec2::new(50., 50.));
+        schedule.run(&mut world);
+        let back_inside = state(&world);
+
+        set_cursor_position(&mut world, Vec2::new(500., 50.));
+        schedule.run(&mut world);
+        let outside_again = state(&world);
+
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        let released = state(&world);
+
+        (
+            [pressed, outside, back_inside, outside_again, released],
+            read_events::<UiRelease>(&world).len(),
+        )
+    }
+
+    #[test]
+    fn hold_policy_keeps_press_outside() {
+        use Interaction::*;
+        let expected = (
+            [
+                (Pressed, false),
+                (Pressed, false),
+                (Pressed, false),
+                (Pressed, false),
+                (None, false),
+            ],
+            1,
+        );
+        assert_eq!(press_outside_transitions(Option::None), expected);
+        assert_eq!(
+            press_outside_transitions(Some(InteractionPolicy::Hold)),
+            expected
+        );
+    }
+
+    #[test]
+    fn release_policy_cancels_press_outside() {
+        use Interaction::*;
+        assert_eq!(
+            press_outside_transitions(Some(InteractionPolicy::Release)),
+            (
+                [
+                    (Pressed, false),
+                    (None, false),
+                    (Hovered, false),
+                    (None, false),
+                    (None, false),
+                ],
+                0,
+            )
+        );
+    }
+
+    #[test]
+    fn hold_outside_policy_marks_dragging_outside() {
+        use Interaction::*;
+        assert_eq!(
+            press_outside_transitions(Some(InteractionPolicy::HoldOutside)),
+            (
+                [
+                    (Pressed, false),
+                    (Pressed, true),
+                    (Pressed, false),
+                    (Pressed, true),
+                    (None, false),
+                ],
+                1,
+            )
+        );
+    }
+
+    #[test]
+    fn debounce_delays_hover_but_not_presses() {
+        let (mut world, mut schedule) = setup_focus_test_world();
+        let node = spawn_node(&mut world, Vec2::ZERO, Vec2::splat(100.))
+            .insert(InteractionDebounce { frames: 3 })
+            .id();
+        let inside = Vec2::new(50., 50.);
+        let outside = Vec2::new(500., 50.);
+
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+
+        // flickering out and back in doesn't change the hover
+        set_cursor_position(&mut world, outside);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        set_cursor_position(&mut world, inside);
+        schedule.run(&mut world);
+        set_cursor_position(&mut world, outside);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Hovered);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::None);
+
+        // presses are immediate, even before the hover was committed
+        set_cursor_position(&mut world, inside);
+        press(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(interaction(&world, node), Interaction::Pressed);
+        release(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(read_events::<UiClick>(&world).len(), 1);
     }
 }
@@ -1,6 +1,7 @@
 use bevy_math::Vec2;
 use bevy_reflect::std_traits::ReflectDefault;
 use bevy_reflect::Reflect;
+use bevy_utils::tracing::warn;
 use std::ops::Neg;
 use std::ops::{Div, DivAssign, Mul, MulAssign};
 use thiserror::Error;
@@ -201,6 +202,102 @@ impl Val {
             Val::Auto => Err(ValArithmeticError::NonEvaluateable),
         }
     }
+
+    /// Adds `rhs` to this [`Val`], returning a [`Val`] of the same unit.
+    ///
+    /// Returns a [`ValArithmeticError::NonIdenticalVariants`] if the units differ, use
+    /// [`Val::add_resolved`] to add mixed units once the sizes they depend on are known.
+    /// Returns a [`ValArithmeticError::NonEvaluateable`] if either value is [`Val::Auto`].
+    pub fn try_add(self, rhs: Val) -> Result<Val, ValArithmeticError> {
+        self.try_combine(rhs, |lhs, rhs| lhs + rhs)
+    }
+
+    /// Subtracts `rhs` from this [`Val`], returning a [`Val`] of the same unit.
+    ///
+    /// Fails in the same cases as [`Val::try_add`].
+    pub fn try_sub(self, rhs: Val) -> Result<Val, ValArithmeticError> {
+        self.try_combine(rhs, |lhs, rhs| lhs - rhs)
+    }
+
+    /// Linearly interpolates from this [`Val`] to `other` by `t`, for animating layout values.
+    ///
+    /// 0. gives this value and 1. gives `other`, and `t` isn't clamped. Only values of the same unit
+    /// can be interpolated: if the units differ, or either value is [`Val::Auto`], this value is
+    /// returned unchanged. Interpolate the resolved values to animate between units.
+    ///
+    /// ```
+    /// # use bevy_ui::Val;
+    /// #
+    /// assert_eq!(Val::Px(10.).lerp(Val::Px(20.), 0.25), Val::Px(12.5));
+    /// assert_eq!(Val::Px(10.).lerp(Val::Percent(50.), 0.25), Val::Px(10.));
+    /// ```
+    pub fn lerp(self, other: Val, t: f32) -> Val {
+        self.try_combine(other, |from, to| from + (to - from) * t)
+            .unwrap_or(self)
+    }
+
+    /// Restricts this [`Val`] to the range from `min` to `max`.
+    ///
+    /// Each bound only applies if it has the same unit as this value, so a bound of another unit or
+    /// [`Val::Auto`] is ignored, and [`Val::Auto`] is returned unchanged. If `min` is greater than
+    /// `max`, `max` is returned.
+    ///
+    /// ```
+    /// # use bevy_ui::Val;
+    /// #
+    /// assert_eq!(Val::Px(150.).clamp(Val::Px(0.), Val::Px(100.)), Val::Px(100.));
+    /// // the minimum is ignored
+    /// assert_eq!(Val::Px(-5.).clamp(Val::Percent(0.), Val::Px(100.)), Val::Px(-5.));
+    /// ```
+    pub fn clamp(self, min: Val, max: Val) -> Val {
+        let value = self.try_combine(min, f32::max).unwrap_or(self);
+        value.try_combine(max, f32::min).unwrap_or(value)
+    }
+
+    fn try_combine(
+        self,
+        rhs: Val,
+        op: impl Fn(f32, f32) -> f32,
+    ) -> Result<Val, ValArithmeticError> {
+        match (self, rhs) {
+            (Val::Auto, _) | (_, Val::Auto) => Err(ValArithmeticError::NonEvaluateable),
+            (Val::Px(lhs), Val::Px(rhs)) => Ok(Val::Px(op(lhs, rhs))),
+            (Val::Percent(lhs), Val::Percent(rhs)) => Ok(Val::Percent(op(lhs, rhs))),
+            (Val::Vw(lhs), Val::Vw(rhs)) => Ok(Val::Vw(op(lhs, rhs))),
+            (Val::Vh(lhs), Val::Vh(rhs)) => Ok(Val::Vh(op(lhs, rhs))),
+            (Val::VMin(lhs), Val::VMin(rhs)) => Ok(Val::VMin(op(lhs, rhs))),
+            (Val::VMax(lhs), Val::VMax(rhs)) => Ok(Val::VMax(op(lhs, rhs))),
+            _ => Err(ValArithmeticError::NonIdenticalVariants),
+        }
+    }
+
+    /// Resolves this [`Val`] and `rhs` with [`Val::resolve`] and returns their sum in logical pixels.
+    ///
+    /// This allows mixing units, e.g. `Val::Percent(100.).sub_resolved(Val::Px(20.), ..)` is the
+    /// equivalent of CSS's `calc(100% - 20px)`. Both values are resolved against the same `parent_size`,
+    /// so they should refer to the same axis.
+    ///
+    /// [`Val::Auto`] has no numeric value, so it is treated as zero and a warning is logged.
+    pub fn add_resolved(self, rhs: Val, parent_size: f32, viewport_size: Vec2) -> f32 {
+        self.resolve_or_zero(parent_size, viewport_size)
+            + rhs.resolve_or_zero(parent_size, viewport_size)
+    }
+
+    /// Resolves this [`Val`] and `rhs` with [`Val::resolve`] and returns their difference in logical pixels.
+    ///
+    /// See [`Val::add_resolved`] for how units are mixed and how [`Val::Auto`] is handled.
+    pub fn sub_resolved(self, rhs: Val, parent_size: f32, viewport_size: Vec2) -> f32 {
+        self.resolve_or_zero(parent_size, viewport_size)
+            - rhs.resolve_or_zero(parent_size, viewport_size)
+    }
+
+    fn resolve_or_zero(self, parent_size: f32, viewport_size: Vec2) -> f32 {
+        self.resolve(parent_size, viewport_size)
+            .unwrap_or_else(|_| {
+                warn!("`Val::Auto` can't be used in arithmetic, it is treated as zero instead");
+                0.
+            })
+    }
 }
 
 /// A type which is commonly used to define margins, paddings and borders.
@@ -395,11 +492,11 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::ZERO);
     /// assert_eq!(ui_rect.bottom, Val::ZERO);
     /// ```
-    pub fn horizontal(value: Val) -> Self {
+    pub const fn horizontal(value: Val) -> Self {
         UiRect {
             left: value,
             right: value,
-            ..Default::default()
+            ..Self::DEFAULT
         }
     }
 
@@ -418,11 +515,11 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::Px(10.0));
     /// assert_eq!(ui_rect.bottom, Val::Px(10.0));
     /// ```
-    pub fn vertical(value: Val) -> Self {
+    pub const fn vertical(value: Val) -> Self {
         UiRect {
             top: value,
             bottom: value,
-            ..Default::default()
+            ..Self::DEFAULT
         }
     }
 
@@ -440,7 +537,7 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::Percent(15.0));
     /// assert_eq!(ui_rect.bottom, Val::Percent(15.0));
     /// ```
-    pub fn axes(horizontal: Val, vertical: Val) -> Self {
+    pub const fn axes(horizontal: Val, vertical: Val) -> Self {
         UiRect {
             left: horizontal,
             right: horizontal,
@@ -464,10 +561,10 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::ZERO);
     /// assert_eq!(ui_rect.bottom, Val::ZERO);
     /// ```
-    pub fn left(value: Val) -> Self {
+    pub const fn left(value: Val) -> Self {
         UiRect {
             left: value,
-            ..Default::default()
+            ..Self::DEFAULT
         }
     }
 
@@ -486,10 +583,10 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::ZERO);
     /// assert_eq!(ui_rect.bottom, Val::ZERO);
     /// ```
-    pub fn right(value: Val) -> Self {
+    pub const fn right(value: Val) -> Self {
         UiRect {
             right: value,
-            ..Default::default()
+            ..Self::DEFAULT
         }
     }
 
@@ -508,10 +605,10 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::Px(10.0));
     /// assert_eq!(ui_rect.bottom, Val::ZERO);
     /// ```
-    pub fn top(value: Val) -> Self {
+    pub const fn top(value: Val) -> Self {
         UiRect {
             top: value,
-            ..Default::default()
+            ..Self::DEFAULT
         }
     }
 
@@ -530,10 +627,10 @@ impl UiRect {
     /// assert_eq!(ui_rect.top, Val::ZERO);
     /// assert_eq!(ui_rect.bottom, Val::Px(10.0));
     /// ```
-    pub fn bottom(value: Val) -> Self {
+    pub const fn bottom(value: Val) -> Self {
         UiRect {
             bottom: value,
-            ..Default::default()
+            ..Self::DEFAULT
         }
     }
 
@@ -551,7 +648,7 @@ impl UiRect {
     /// assert_eq!(ui_rect.bottom, Val::Px(20.0));
     /// ```
     #[inline]
-    pub fn with_left(mut self, left: Val) -> Self {
+    pub const fn with_left(mut self, left: Val) -> Self {
         self.left = left;
         self
     }
@@ -570,7 +667,7 @@ impl UiRect {
     /// assert_eq!(ui_rect.bottom, Val::Px(20.0));
     /// ```
     #[inline]
-    pub fn with_right(mut self, right: Val) -> Self {
+    pub const fn with_right(mut self, right: Val) -> Self {
         self.right = right;
         self
     }
@@ -589,7 +686,7 @@ impl UiRect {
     /// assert_eq!(ui_rect.bottom, Val::Px(20.0));
     /// ```
     #[inline]
-    pub fn with_top(mut self, top: Val) -> Self {
+    pub const fn with_top(mut self, top: Val) -> Self {
         self.top = top;
         self
     }
@@ -608,7 +705,7 @@ impl UiRect {
     /// assert_eq!(ui_rect.bottom, Val::Px(10.0));
     /// ```
     #[inline]
-    pub fn with_bottom(mut self, bottom: Val) -> Self {
+    pub const fn with_bottom(mut self, bottom: Val) -> Self {
         self.bottom = bottom;
         self
     }
@@ -682,6 +779,67 @@ mod tests {
         assert_eq!(resolve_auto, Err(ValArithmeticError::NonEvaluateable));
     }
 
+    #[test]
+    fn val_try_add_and_sub() {
+        assert_eq!(Val::Px(10.).try_add(Val::Px(5.)), Ok(Val::Px(15.)));
+        assert_eq!(Val::Vw(10.).try_sub(Val::Vw(15.)), Ok(Val::Vw(-5.)));
+        assert_eq!(
+            Val::Percent(100.).try_sub(Val::Px(20.)),
+            Err(ValArithmeticError::NonIdenticalVariants)
+        );
+        assert_eq!(
+            Val::Auto.try_add(Val::Auto),
+            Err(ValArithmeticError::NonEvaluateable)
+        );
+        assert_eq!(
+            Val::Px(10.).try_add(Val::Auto),
+            Err(ValArithmeticError::NonEvaluateable)
+        );
+    }
+
+    #[test]
+    fn val_mixed_unit_arithmetic_resolves_each_side() {
+        let size = 250.;
+        let viewport_size = vec2(1000., 500.);
+
+        // calc(100% - 20px)
+        assert_eq!(
+            Val::Percent(100.).sub_resolved(Val::Px(20.), size, viewport_size),
+            230.
+        );
+        // calc(10vw + 50%)
+        assert_eq!(
+            Val::Vw(10.).add_resolved(Val::Percent(50.), size, viewport_size),
+            225.
+        );
+        // calc(10vmin - 10vmax)
+        assert_eq!(
+            Val::VMin(10.).sub_resolved(Val::VMax(10.), size, viewport_size),
+            -50.
+        );
+        // expressions are evaluated left to right
+        let lhs = Val::Percent(100.).sub_resolved(Val::Px(20.), size, viewport_size);
+        assert_eq!(
+            Val::Px(lhs).add_resolved(Val::Vh(10.), size, viewport_size),
+            280.
+        );
+    }
+
+    #[test]
+    fn val_auto_is_zero_in_mixed_unit_arithmetic() {
+        let size = 250.;
+        let viewport_size = vec2(1000., 500.);
+
+        assert_eq!(
+            Val::Auto.add_resolved(Val::Px(20.), size, viewport_size),
+            20.
+        );
+        assert_eq!(
+            Val::Percent(50.).sub_resolved(Val::Auto, size, viewport_size),
+            125.
+        );
+    }
+
     #[test]
     fn val_arithmetic_error_messages() {
         assert_eq!(
@@ -744,4 +902,56 @@ mod tests {
         assert_eq!(r.top, Val::Percent(20.));
         assert_eq!(r.bottom, Val::Percent(99.));
     }
+
+    #[test]
+    fn uirect_constructors_are_const() {
+        const INSET: UiRect = UiRect::horizontal(Val::Px(4.)).with_top(Val::Px(2.));
+        const SIDE: UiRect = UiRect::left(Val::Percent(10.)).with_bottom(Val::Auto);
+        assert_eq!(
+            INSET,
+            UiRect::new(Val::Px(4.), Val::Px(4.), Val::Px(2.), Val::ZERO)
+        );
+        assert_eq!(
+            SIDE,
+            UiRect::new(Val::Percent(10.), Val::ZERO, Val::ZERO, Val::Auto)
+        );
+    }
+
+    #[test]
+    fn val_lerp_interpolates_values_of_the_same_unit() {
+        assert_eq!(Val::Px(0.).lerp(Val::Px(100.), 0.5), Val::Px(50.));
+        assert_eq!(
+            Val::Percent(20.).lerp(Val::Percent(40.), 0.75),
+            Val::Percent(35.)
+        );
+        assert_eq!(Val::Vw(10.).lerp(Val::Vw(20.), 1.5), Val::Vw(25.));
+        assert_eq!(Val::Px(10.).lerp(Val::Px(20.), 0.), Val::Px(10.));
+        assert_eq!(Val::Px(10.).lerp(Val::Px(20.), 1.), Val::Px(20.));
+    }
+
+    #[test]
+    fn val_lerp_returns_the_start_for_mismatched_units() {
+        assert_eq!(Val::Px(10.).lerp(Val::Percent(50.), 0.5), Val::Px(10.));
+        assert_eq!(Val::Percent(50.).lerp(Val::Px(10.), 1.), Val::Percent(50.));
+        assert_eq!(Val::Px(10.).lerp(Val::Auto, 0.5), Val::Px(10.));
+        assert_eq!(Val::Auto.lerp(Val::Px(10.), 0.5), Val::Auto);
+    }
+
+    #[test]
+    fn val_clamp_applies_the_bounds_of_the_same_unit() {
+        assert_eq!(Val::Px(50.).clamp(Val::Px(0.), Val::Px(100.)), Val::Px(50.));
+        assert_eq!(Val::Px(-5.).clamp(Val::Px(0.), Val::Px(100.)), Val::Px(0.));
+        assert_eq!(
+            Val::Percent(150.).clamp(Val::Percent(0.), Val::Percent(100.)),
+            Val::Percent(100.)
+        );
+        assert_eq!(
+            Val::Percent(150.).clamp(Val::Percent(0.), Val::Px(100.)),
+            Val::Percent(150.)
+        );
+        assert_eq!(Val::Px(5.).clamp(Val::Auto, Val::Px(1.)), Val::Px(1.));
+        assert_eq!(Val::Auto.clamp(Val::Px(0.), Val::Px(1.)), Val::Auto);
+        // inverted bounds give the maximum
+        assert_eq!(Val::Px(5.).clamp(Val::Px(10.), Val::Px(0.)), Val::Px(0.));
+    }
 }
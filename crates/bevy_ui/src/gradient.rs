@@ -0,0 +1,127 @@
+use bevy_asset::{Assets, Handle};
+use bevy_color::ColorToPacked;
+use bevy_ecs::prelude::*;
+use bevy_render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+    texture::Image,
+};
+
+use crate::BackgroundGradient;
+
+/// The number of texels of the textures the colors of a [`BackgroundGradient`] are baked into
+pub(crate) const GRADIENT_TEXTURE_WIDTH: u32 = 256;
+
+/// Component storing the texture the colors of a [`BackgroundGradient`] are baked into
+///
+/// This component is automatically inserted and updated
+#[derive(Debug, Clone, Component)]
+pub struct GradientTexture(pub(crate) Handle<Image>);
+
+/// Bakes the colors of `gradient` into a one pixel high texture, from its start on the left to its end on the right
+fn gradient_image(gradient: &BackgroundGradient) -> Image {
+    let data = (0..GRADIENT_TEXTURE_WIDTH)
+        .flat_map(|x| {
+            let position = x as f32 / (GRADIENT_TEXTURE_WIDTH - 1) as f32;
+            gradient.color_at(position).to_srgba().to_u8_array()
+        })
+        .collect();
+    Image::new(
+        Extent3d {
+            width: GRADIENT_TEXTURE_WIDTH,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// System baking the colors of added or modified [`BackgroundGradient`]s into their [`GradientTexture`]
+pub(crate) fn update_gradient_textures(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    changed_gradients: Query<
+        (Entity, &BackgroundGradient, Option<&GradientTexture>),
+        Changed<BackgroundGradient>,
+    >,
+    mut removed_gradients: RemovedComponents<BackgroundGradient>,
+) {
+    for (entity, gradient, texture) in &changed_gradients {
+        let image = gradient_image(gradient);
+        match texture {
+            // reuse the texture of the gradient
+            Some(texture) => {
+                images.insert(&texture.0, image);
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .try_insert(GradientTexture(images.add(image)));
+            }
+        }
+    }
+
+    for entity in removed_gradients.read() {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.remove::<GradientTexture>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::{Color, ColorToPacked};
+
+    use super::{gradient_image, GRADIENT_TEXTURE_WIDTH};
+    use crate::{BackgroundGradient, ColorStop};
+
+    #[test]
+    fn stops_are_normalized() {
+        let gradient = BackgroundGradient::linear(
+            0.,
+            [
+                ColorStop::new(Color::BLACK, -1.),
+                ColorStop::new(Color::WHITE, 0.75),
+                ColorStop::new(Color::BLACK, 0.5),
+                ColorStop::new(Color::WHITE, 2.),
+            ],
+        );
+        let positions = gradient
+            .normalized_stops()
+            .iter()
+            .map(|stop| stop.position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, [0., 0.75, 0.75, 1.]);
+
+        // the color jumps at the two stops sharing the same position
+        assert_eq!(gradient.color_at(0.), Color::BLACK);
+        let red = |position: f32| gradient.color_at(position).to_srgba().red;
+        assert!((red(0.74) - 0.74 / 0.75).abs() < 1e-5);
+        assert!((red(0.76) - 0.04).abs() < 1e-5);
+        assert_eq!(gradient.color_at(1.), Color::WHITE);
+    }
+
+    #[test]
+    fn image_spans_the_gradient() {
+        let gradient = BackgroundGradient::linear(
+            0.,
+            [
+                ColorStop::new(Color::srgb(1., 0., 0.), 0.),
+                ColorStop::new(Color::srgb(0., 1., 0.), 1. / 3.),
+                ColorStop::new(Color::srgb(0., 0., 1.), 2. / 3.),
+                ColorStop::new(Color::WHITE, 1.),
+            ],
+        );
+        let image = gradient_image(&gradient);
+        let texel = |x: u32| {
+            let start = 4 * x as usize;
+            &image.data[start..start + 4]
+        };
+        assert_eq!(image.data.len(), 4 * GRADIENT_TEXTURE_WIDTH as usize);
+        assert_eq!(texel(0), Color::srgb(1., 0., 0.).to_srgba().to_u8_array());
+        assert_eq!(texel(GRADIENT_TEXTURE_WIDTH - 1), [255; 4]);
+    }
+}
@@ -2,11 +2,171 @@ use std::fmt::Write;
 
 use taffy::{NodeId, TraversePartialTree};
 
-use bevy_ecs::prelude::Entity;
+use bevy_ecs::{
+    prelude::Entity,
+    system::{Res, SystemParam},
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::Reflect;
 use bevy_utils::HashMap;
 
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
 use crate::layout::ui_surface::UiSurface;
 
+/// A read-only view of the layout tree computed by [`ui_layout_system`](crate::ui_layout_system),
+/// to find out why a node is laid out the way it is, from a debug overlay or an inspector.
+///
+/// ```
+/// # use bevy_ui::debug::UiLayoutTree;
+/// fn log_layout(layout_tree: UiLayoutTree) {
+///     for node in layout_tree.snapshot().nodes {
+///         println!("{:?}: {:?}", node.entity, node.rect);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(log_layout);
+/// ```
+#[derive(SystemParam)]
+pub struct UiLayoutTree<'w> {
+    ui_surface: Res<'w, UiSurface>,
+}
+
+impl<'w> UiLayoutTree<'w> {
+    /// Returns a snapshot of the computed layout of every UI node, see [`ui_layout_snapshot`].
+    pub fn snapshot(&self) -> UiLayoutSnapshot {
+        ui_layout_snapshot(&self.ui_surface)
+    }
+
+    /// Prints the layout tree of each camera, see [`print_ui_layout_tree`].
+    pub fn print(&self) {
+        print_ui_layout_tree(&self.ui_surface);
+    }
+}
+
+/// The computed layout of the UI nodes of every camera, returned by [`UiLayoutTree::snapshot`]
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiLayoutSnapshot {
+    /// The laid out nodes, each parent before its children
+    pub nodes: Vec<UiLayoutSnapshotNode>,
+}
+
+impl UiLayoutSnapshot {
+    /// Returns the layout of the given node, if it was laid out.
+    pub fn node(&self, entity: Entity) -> Option<&UiLayoutSnapshotNode> {
+        self.nodes.iter().find(|node| node.entity == entity)
+    }
+}
+
+/// The computed layout of a UI node in a [`UiLayoutSnapshot`]
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiLayoutSnapshotNode {
+    /// The UI node
+    pub entity: Entity,
+    /// The camera the node is laid out for
+    pub camera: Entity,
+    /// The parent of the node, or `None` for root nodes
+    pub parent: Option<Entity>,
+    /// The children of the node, in order
+    pub children: Vec<Entity>,
+    /// The rect of the node from the top-left corner of its camera's viewport, in physical pixels.
+    ///
+    /// Unlike the [`Node`](crate::Node) size and position, the rect isn't rounded, and isn't shifted by
+    /// the [`ScrollPosition`](crate::ScrollPosition) of the node's ancestors.
+    pub rect: Rect,
+    /// The size of the content of the node in physical pixels, which overflows the node when it's larger
+    pub content_size: Vec2,
+    /// Whether the node is sized by a measure of its content, see [`ContentSize`](crate::ContentSize)
+    pub measured: bool,
+}
+
+/// Returns a snapshot of the layout tree of each camera, with the cameras in the order of their entities.
+pub fn ui_layout_snapshot(ui_surface: &UiSurface) -> UiLayoutSnapshot {
+    let taffy_to_entity: HashMap<NodeId, Entity> = ui_surface
+        .entity_to_taffy
+        .iter()
+        .map(|(entity, node)| (*node, *entity))
+        .collect();
+    let mut cameras: Vec<Entity> = ui_surface.camera_roots.keys().copied().collect();
+    cameras.sort();
+
+    let mut snapshot = UiLayoutSnapshot::default();
+    for camera in cameras {
+        for root in &ui_surface.camera_roots[&camera] {
+            let viewport_location = ui_surface
+                .taffy
+                .layout(root.implicit_viewport_node)
+                .map_or(Vec2::ZERO, |layout| {
+                    Vec2::new(layout.location.x, layout.location.y)
+                });
+            snapshot_node(
+                ui_surface,
+                &taffy_to_entity,
+                camera,
+                None,
+                root.user_root_node,
+                viewport_location,
+                &mut snapshot.nodes,
+            );
+        }
+    }
+    snapshot
+}
+
+/// Recursively adds a node and its descendants to the nodes of a snapshot.
+fn snapshot_node(
+    ui_surface: &UiSurface,
+    taffy_to_entity: &HashMap<NodeId, Entity>,
+    camera: Entity,
+    parent: Option<Entity>,
+    node: NodeId,
+    parent_location: Vec2,
+    nodes: &mut Vec<UiLayoutSnapshotNode>,
+) {
+    let tree = &ui_surface.taffy;
+    let (Some(&entity), Ok(layout)) = (taffy_to_entity.get(&node), tree.layout(node)) else {
+        return;
+    };
+    let location = parent_location + Vec2::new(layout.location.x, layout.location.y);
+    let child_nodes = tree.children(node).unwrap_or_default();
+    nodes.push(UiLayoutSnapshotNode {
+        entity,
+        camera,
+        parent,
+        children: child_nodes
+            .iter()
+            .filter_map(|child| taffy_to_entity.get(child).copied())
+            .collect(),
+        rect: Rect::from_corners(
+            location,
+            location + Vec2::new(layout.size.width, layout.size.height),
+        ),
+        content_size: Vec2::new(layout.content_size.width, layout.content_size.height),
+        measured: tree.get_node_context(node).is_some(),
+    });
+    for child in child_nodes {
+        snapshot_node(
+            ui_surface,
+            taffy_to_entity,
+            camera,
+            Some(entity),
+            child,
+            location,
+            nodes,
+        );
+    }
+}
+
 /// Prints a debug representation of the computed layout of the UI layout tree for each window.
 pub fn print_ui_layout_tree(ui_surface: &UiSurface) {
     let taffy_to_entity: HashMap<NodeId, Entity> = ui_surface
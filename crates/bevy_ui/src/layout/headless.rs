@@ -0,0 +1,286 @@
+use bevy_ecs::entity::Entity;
+use bevy_math::{Rect, Vec2};
+
+use super::{ui_surface::UiSurface, LayoutContext, LayoutError, NodeGeometry};
+use crate::{Direction, Style};
+
+/// A tree of UI nodes to lay out with [`compute_layout`], without a [`World`](bevy_ecs::world::World).
+///
+/// Nodes that aren't the child of another node are root nodes, laid out in the viewport like UI
+/// nodes without a [`Parent`](bevy_hierarchy::Parent).
+///
+/// ```
+/// # use bevy_math::{Rect, Vec2};
+/// # use bevy_ui::{compute_layout, LayoutInput, Style, Val};
+/// let mut input = LayoutInput::new(Vec2::new(800., 600.));
+/// let child = input.add_node(
+///     Style {
+///         width: Val::Px(100.),
+///         height: Val::Percent(50.),
+///         ..Default::default()
+///     },
+///     [],
+/// );
+/// let root = input.add_node(
+///     Style {
+///         width: Val::Percent(100.),
+///         height: Val::Percent(100.),
+///         ..Default::default()
+///     },
+///     [child],
+/// );
+///
+/// let output = compute_layout(&input).unwrap();
+/// assert_eq!(output.rects[root], Rect::new(0., 0., 800., 600.));
+/// assert_eq!(output.rects[child], Rect::new(0., 0., 100., 300.));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LayoutInput {
+    /// The nodes of the tree, referred to by their index
+    pub nodes: Vec<LayoutInputNode>,
+    /// The size of the viewport the root nodes are laid out in, in logical pixels
+    pub viewport_size: Vec2,
+    /// The scale factor of the viewport, which is the scale factor of the window multiplied by
+    /// the [`UiScale`](crate::UiScale)
+    pub scale_factor: f32,
+}
+
+/// A node of a [`LayoutInput`]
+#[derive(Clone, Debug, Default)]
+pub struct LayoutInputNode {
+    /// The style of the node
+    pub style: Style,
+    /// The indices of the children of the node, in order
+    pub children: Vec<usize>,
+}
+
+impl LayoutInput {
+    /// Creates an empty tree laid out in a viewport of the given logical size, with a scale
+    /// factor of 1.
+    pub fn new(viewport_size: Vec2) -> Self {
+        Self {
+            nodes: Vec::new(),
+            viewport_size,
+            scale_factor: 1.,
+        }
+    }
+
+    /// Returns this [`LayoutInput`] with the given scale factor.
+    pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Adds a node with the given children, and returns its index.
+    pub fn add_node(&mut self, style: Style, children: impl IntoIterator<Item = usize>) -> usize {
+        self.nodes.push(LayoutInputNode {
+            style,
+            children: children.into_iter().collect(),
+        });
+        self.nodes.len() - 1
+    }
+}
+
+/// The layout of a [`LayoutInput`] computed by [`compute_layout`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayoutOutput {
+    /// The rect of each node, in the order of [`LayoutInput::nodes`].
+    ///
+    /// Rects are in logical pixels from the top-left corner of the viewport, rounded like
+    /// [`Node::size`](crate::Node::size) and the positions of [`NodeLayoutChanged`](crate::NodeLayoutChanged).
+    pub rects: Vec<Rect>,
+    /// The resolved direction of each node, see [`Node::direction`](crate::Node::direction)
+    pub directions: Vec<Direction>,
+}
+
+/// Lays out a tree of UI nodes the way [`ui_layout_system`](crate::ui_layout_system) lays out the
+/// UI nodes of a camera, so layouts can be computed and tested without a [`World`](bevy_ecs::world::World).
+///
+/// The nodes are laid out by the same engine, and their rects are rounded the same way, so they
+/// match the [`Node`](crate::Node) sizes and positions of the same tree in an app. Nodes have no
+/// content, so text and images take no space.
+///
+/// Returns [`LayoutError::InvalidHierarchy`] if a child index is out of bounds, or if a node is the
+/// child of more than one node or its own descendant.
+pub fn compute_layout(input: &LayoutInput) -> Result<LayoutOutput, LayoutError> {
+    let node_count = input.nodes.len();
+    let mut parents = vec![None; node_count];
+    for (index, node) in input.nodes.iter().enumerate() {
+        for &child in &node.children {
+            match parents.get_mut(child) {
+                Some(parent @ None) => *parent = Some(index),
+                _ => return Err(LayoutError::InvalidHierarchy),
+            }
+        }
+    }
+    let roots: Vec<usize> = (0..node_count)
+        .filter(|index| parents[*index].is_none())
+        .collect();
+
+    // the nodes of a cycle aren't reachable from the roots
+    let mut order = Vec::with_capacity(node_count);
+    let mut stack: Vec<usize> = roots.iter().rev().copied().collect();
+    while let Some(index) = stack.pop() {
+        order.push(index);
+        stack.extend(input.nodes[index].children.iter().rev());
+    }
+    if order.len() != node_count {
+        return Err(LayoutError::InvalidHierarchy);
+    }
+
+    let entity = |index: usize| Entity::from_raw(index as u32);
+    let camera = Entity::from_raw(node_count as u32);
+    let physical_size = (input.viewport_size * input.scale_factor).round();
+    let layout_context = LayoutContext::new(input.scale_factor, physical_size);
+
+    let mut ui_surface = UiSurface::default();
+    for (index, node) in input.nodes.iter().enumerate() {
+        ui_surface.upsert_node(&layout_context, entity(index), &node.style, None);
+    }
+    for (index, node) in input.nodes.iter().enumerate() {
+        if !node.children.is_empty() {
+            let children: Vec<Entity> = node.children.iter().copied().map(entity).collect();
+            ui_surface.update_children(entity(index), &children);
+        }
+    }
+    ui_surface.set_camera_children(camera, roots.iter().copied().map(entity));
+    ui_surface.compute_camera_layout(camera, physical_size.as_uvec2());
+
+    let mut output = LayoutOutput {
+        rects: vec![Rect::default(); node_count],
+        directions: vec![Direction::LeftToRight; node_count],
+    };
+    // the parent of each node is before it in `order`
+    let mut absolute_locations = vec![Vec2::ZERO; node_count];
+    for index in order {
+        let layout = ui_surface.get_layout(entity(index))?;
+        let (parent_direction, parent_layout_width, parent_absolute_location) = match parents[index]
+        {
+            Some(parent) => (
+                output.directions[parent],
+                ui_surface.get_layout(entity(parent))?.size.width,
+                absolute_locations[parent],
+            ),
+            None => (Direction::LeftToRight, physical_size.x, Vec2::ZERO),
+        };
+        let geometry = NodeGeometry::new(
+            layout,
+            input.nodes[index].style.direction,
+            parent_direction,
+            parent_layout_width,
+            Vec2::ZERO,
+            parent_absolute_location,
+            input.scale_factor.recip(),
+        );
+        absolute_locations[index] = parent_absolute_location + geometry.layout_location;
+        output.rects[index] = Rect::from_corners(
+            geometry.rounded_position,
+            geometry.rounded_position + geometry.rounded_size,
+        );
+        output.directions[index] = geometry.direction;
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Rect, Vec2};
+
+    use super::{compute_layout, LayoutInput};
+    use crate::{Display, FlexDirection, GridTrack, LayoutError, Style, UiRect, Val};
+
+    fn px_box(width: f32, height: f32) -> Style {
+        Style {
+            width: Val::Px(width),
+            height: Val::Px(height),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flex_row_layout() {
+        let mut input = LayoutInput::new(Vec2::new(1000., 100.));
+        let children: Vec<usize> = [50., 100., 25.]
+            .into_iter()
+            .map(|width| input.add_node(px_box(width, 20.), []))
+            .collect();
+        let grow = input.add_node(
+            Style {
+                flex_grow: 1.,
+                ..Default::default()
+            },
+            [],
+        );
+        let row = input.add_node(
+            Style {
+                flex_direction: FlexDirection::Row,
+                width: Val::Px(300.),
+                padding: UiRect::all(Val::Px(5.)),
+                column_gap: Val::Px(10.),
+                ..Default::default()
+            },
+            children.iter().copied().chain([grow]),
+        );
+
+        let output = compute_layout(&input).unwrap();
+        assert_eq!(output.rects[row], Rect::new(0., 0., 300., 30.));
+        assert_eq!(output.rects[children[0]], Rect::new(5., 5., 55., 25.));
+        assert_eq!(output.rects[children[1]], Rect::new(65., 5., 165., 25.));
+        assert_eq!(output.rects[children[2]], Rect::new(175., 5., 200., 25.));
+        assert_eq!(output.rects[grow], Rect::new(210., 5., 295., 25.));
+    }
+
+    #[test]
+    fn grid_layout() {
+        let mut input = LayoutInput::new(Vec2::new(400., 300.)).with_scale_factor(2.);
+        let cells: Vec<usize> = (0..4)
+            .map(|_| input.add_node(Style::default(), []))
+            .collect();
+        let grid = input.add_node(
+            Style {
+                display: Display::Grid,
+                width: Val::Percent(100.),
+                height: Val::Px(100.),
+                grid_template_columns: vec![GridTrack::px(100.), GridTrack::flex(1.)],
+                grid_template_rows: vec![GridTrack::flex(1.), GridTrack::px(30.)],
+                row_gap: Val::Px(10.),
+                ..Default::default()
+            },
+            cells.clone(),
+        );
+
+        let output = compute_layout(&input).unwrap();
+        assert_eq!(output.rects[grid], Rect::new(0., 0., 400., 100.));
+        assert_eq!(output.rects[cells[0]], Rect::new(0., 0., 100., 60.));
+        assert_eq!(output.rects[cells[1]], Rect::new(100., 0., 400., 60.));
+        assert_eq!(output.rects[cells[2]], Rect::new(0., 70., 100., 100.));
+        assert_eq!(output.rects[cells[3]], Rect::new(100., 70., 400., 100.));
+    }
+
+    #[test]
+    fn invalid_hierarchies_are_rejected() {
+        let mut input = LayoutInput::new(Vec2::splat(100.));
+        let child = input.add_node(Style::default(), []);
+        input.add_node(Style::default(), [child]);
+        input.add_node(Style::default(), [child]);
+        assert!(matches!(
+            compute_layout(&input),
+            Err(LayoutError::InvalidHierarchy)
+        ));
+
+        let mut input = LayoutInput::new(Vec2::splat(100.));
+        input.add_node(Style::default(), [1]);
+        input.add_node(Style::default(), [0]);
+        assert!(matches!(
+            compute_layout(&input),
+            Err(LayoutError::InvalidHierarchy)
+        ));
+
+        let mut input = LayoutInput::new(Vec2::splat(100.));
+        input.add_node(Style::default(), [1]);
+        assert!(matches!(
+            compute_layout(&input),
+            Err(LayoutError::InvalidHierarchy)
+        ));
+    }
+}
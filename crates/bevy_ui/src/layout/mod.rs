@@ -1,18 +1,23 @@
 use thiserror::Error;
 
-use crate::{ContentSize, DefaultUiCamera, Node, Outline, Style, TargetCamera, UiScale};
+use crate::{
+    ContentSize, DefaultUiCamera, Direction, Node, Outline, OverflowAxis, ScrollPosition, Style,
+    TargetCamera, TargetUiScale, WindowUiScale,
+};
 use bevy_ecs::{
     change_detection::{DetectChanges, DetectChangesMut},
-    entity::Entity,
-    event::EventReader,
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventReader, EventWriter},
     query::{With, Without},
     removal_detection::RemovedComponents,
-    system::{Query, Res, ResMut, SystemParam},
+    system::{Local, Query, ResMut, SystemParam},
     world::Ref,
 };
 use bevy_hierarchy::{Children, Parent};
 use bevy_math::{UVec2, Vec2};
+use bevy_reflect::Reflect;
 use bevy_render::camera::{Camera, NormalizedRenderTarget};
+use bevy_sprite::BorderRect;
 use bevy_transform::components::Transform;
 use bevy_utils::tracing::warn;
 use bevy_utils::{HashMap, HashSet};
@@ -21,8 +26,11 @@ use ui_surface::UiSurface;
 
 mod convert;
 pub mod debug;
+mod headless;
 pub(crate) mod ui_surface;
 
+pub use headless::*;
+
 pub struct LayoutContext {
     pub scale_factor: f32,
     pub physical_size: Vec2,
@@ -62,21 +70,56 @@ pub enum LayoutError {
     TaffyError(#[from] taffy::TaffyError),
 }
 
+/// Sent by [`ui_layout_system`] when the computed size or position of a UI node differs from
+/// its previous layout, including the first time the node is laid out.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct NodeLayoutChanged {
+    /// The UI node whose layout changed
+    pub entity: Entity,
+    /// The new size of the node in logical pixels, see [`Node::size`]
+    pub size: Vec2,
+    /// The new position of the node's top-left corner in logical pixels, relative to the
+    /// top-left corner of its camera's viewport
+    pub position: Vec2,
+}
+
+type NodeGeometryQuery = (
+    &'static mut Node,
+    &'static mut Transform,
+    Option<&'static Style>,
+    Option<&'static mut ScrollPosition>,
+);
+
 #[derive(SystemParam)]
 pub struct UiLayoutSystemRemovedComponentParam<'w, 's> {
     removed_cameras: RemovedComponents<'w, 's, Camera>,
     removed_children: RemovedComponents<'w, 's, Children>,
     removed_content_sizes: RemovedComponents<'w, 's, ContentSize>,
     removed_nodes: RemovedComponents<'w, 's, Node>,
+    removed_window_ui_scales: RemovedComponents<'w, 's, WindowUiScale>,
+}
+
+/// The state of [`ui_layout_system`] kept from one layout to the next
+#[derive(Default)]
+pub struct UiLayoutState {
+    /// The rounded position of each node, to send [`NodeLayoutChanged`] events
+    node_positions: EntityHashMap<Vec2>,
+    /// The viewport size, scale factor and root nodes of each camera
+    cameras: EntityHashMap<(UVec2, f32, Vec<Entity>)>,
 }
 
 /// Updates the UI's layout tree, computes the new layout geometry and then updates the sizes and transforms of all the UI nodes.
+///
+/// The layout is only computed again in frames where something it depends on changed: a [`Style`],
+/// a [`ContentSize`] measure, a [`ScrollPosition`], the hierarchy of the nodes, or the viewport
+/// size and scale factor of their camera. In other frames the nodes keep their geometry.
 #[allow(clippy::too_many_arguments)]
 pub fn ui_layout_system(
-    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
     cameras: Query<(Entity, &Camera)>,
     default_ui_camera: DefaultUiCamera,
-    ui_scale: Res<UiScale>,
+    ui_scale: TargetUiScale,
     mut scale_factor_events: EventReader<WindowScaleFactorChanged>,
     mut resize_events: EventReader<bevy_window::WindowResized>,
     mut ui_surface: ResMut<UiSurface>,
@@ -93,7 +136,9 @@ pub fn ui_layout_system(
     children_query: Query<(Entity, Ref<Children>), With<Node>>,
     just_children_query: Query<&Children>,
     mut removed_components: UiLayoutSystemRemovedComponentParam,
-    mut node_transform_query: Query<(&mut Node, &mut Transform)>,
+    mut node_transform_query: Query<NodeGeometryQuery>,
+    mut state: Local<UiLayoutState>,
+    mut layout_changed_events: EventWriter<NodeLayoutChanged>,
 ) {
     struct CameraLayoutInfo {
         size: UVec2,
@@ -109,19 +154,34 @@ pub fn ui_layout_system(
     };
 
     let resized_windows: HashSet<Entity> = resize_events.read().map(|event| event.window).collect();
-    let calculate_camera_layout_info = |camera: &Camera| {
-        let size = camera.physical_viewport_size().unwrap_or(UVec2::ZERO);
-        let scale_factor = camera.target_scaling_factor().unwrap_or(1.0);
-        let camera_target = camera
-            .target
-            .normalize(primary_window.get_single().map(|(e, _)| e).ok());
-        let resized = matches!(camera_target,
-          Some(NormalizedRenderTarget::Window(window_ref)) if resized_windows.contains(&window_ref.entity())
-        );
+    let calculate_camera_layout_info = |camera_entity: Entity, camera: &Camera| {
+        let camera_target = camera.target.normalize(primary_window.get_single().ok());
+        let target_window = match camera_target {
+            Some(NormalizedRenderTarget::Window(window_ref)) => Some(window_ref.entity()),
+            _ => None,
+        };
+        // The target info of the camera may not be updated yet in the frame the window is resized,
+        // so the size of the window is read from the window itself
+        let window = target_window.and_then(|window| windows.get(window).ok());
+        let size = match (window, &camera.viewport) {
+            (Some(window), None) => window.physical_size(),
+            _ => camera.physical_viewport_size().unwrap_or(UVec2::ZERO),
+        };
+        let scale_factor = window
+            .map(Window::scale_factor)
+            .or_else(|| camera.target_scaling_factor())
+            .unwrap_or(1.0);
+        // viewport units are resolved again when the viewport changes size, with or without a
+        // `WindowResized` event
+        let resized = target_window.is_some_and(|window| resized_windows.contains(&window))
+            || state
+                .cameras
+                .get(&camera_entity)
+                .is_some_and(|(previous_size, ..)| *previous_size != size);
         CameraLayoutInfo {
             size,
             resized,
-            scale_factor: scale_factor * ui_scale.0,
+            scale_factor: scale_factor * ui_scale.camera(Some(camera)),
             root_nodes: Vec::new(),
         }
     };
@@ -140,7 +200,7 @@ pub fn ui_layout_system(
                 };
                 let layout_info = camera_layout_info
                     .entry(camera_entity)
-                    .or_insert_with(|| calculate_camera_layout_info(camera));
+                    .or_insert_with(|| calculate_camera_layout_info(camera_entity, camera));
                 layout_info.root_nodes.push(entity);
             }
             None => {
@@ -158,9 +218,23 @@ pub fn ui_layout_system(
         }
     }
 
+    // A camera whose viewport, scale factor or root nodes changed is laid out again
+    let mut dirty = camera_layout_info.len() != state.cameras.len()
+        || camera_layout_info.iter().any(|(camera, info)| {
+            state
+                .cameras
+                .get(camera)
+                .map_or(true, |(size, scale_factor, root_nodes)| {
+                    *size != info.size
+                        || *scale_factor != info.scale_factor
+                        || *root_nodes != info.root_nodes
+                })
+        });
+
     // When a `ContentSize` component is removed from an entity, we need to remove the measure from the corresponding taffy node.
     for entity in removed_components.removed_content_sizes.read() {
         ui_surface.try_remove_node_context(entity);
+        dirty = true;
     }
 
     // Sync Style and ContentSize to Taffy for all nodes
@@ -171,6 +245,7 @@ pub fn ui_layout_system(
             if camera.resized
                 || !scale_factor_events.is_empty()
                 || ui_scale.is_changed()
+                || !removed_components.removed_window_ui_scales.is_empty()
                 || style.is_changed()
                 || content_size
                     .as_ref()
@@ -183,18 +258,27 @@ pub fn ui_layout_system(
                 );
                 let measure = content_size.and_then(|mut c| c.measure.take());
                 ui_surface.upsert_node(&layout_context, entity, &style, measure);
+                dirty = true;
             }
         } else {
             ui_surface.upsert_node(&LayoutContext::DEFAULT, entity, &Style::default(), None);
         }
     }
     scale_factor_events.clear();
+    removed_components.removed_window_ui_scales.clear();
 
     // clean up removed nodes
-    ui_surface.remove_entities(removed_components.removed_nodes.read());
+    let removed_nodes: Vec<Entity> = removed_components.removed_nodes.read().collect();
+    for entity in &removed_nodes {
+        state.node_positions.remove(entity);
+    }
+    dirty |= !removed_nodes.is_empty();
+    ui_surface.remove_entities(removed_nodes);
 
     // clean up removed cameras
-    ui_surface.remove_camera_entities(removed_components.removed_cameras.read());
+    let removed_cameras: Vec<Entity> = removed_components.removed_cameras.read().collect();
+    dirty |= !removed_cameras.is_empty();
+    ui_surface.remove_camera_entities(removed_cameras);
 
     // update camera children
     for (camera_id, _) in cameras.iter() {
@@ -210,13 +294,32 @@ pub fn ui_layout_system(
     // update and remove children
     for entity in removed_components.removed_children.read() {
         ui_surface.try_remove_children(entity);
+        dirty = true;
     }
     for (entity, children) in &children_query {
         if children.is_changed() {
             ui_surface.update_children(entity, &children);
+            dirty = true;
         }
     }
 
+    // the children of scrolled nodes are shifted by the clamped scroll offset
+    dirty |= node_transform_query
+        .iter_mut()
+        .any(|(_, _, _, scroll_position)| scroll_position.is_some_and(|s| s.is_changed()));
+    if !dirty {
+        return;
+    }
+    state.cameras = camera_layout_info
+        .iter()
+        .map(|(camera, info)| {
+            (
+                *camera,
+                (info.size, info.scale_factor, info.root_nodes.clone()),
+            )
+        })
+        .collect();
+
     for (camera_id, camera) in &camera_layout_info {
         let inverse_target_scale_factor = camera.scale_factor.recip();
 
@@ -227,44 +330,110 @@ pub fn ui_layout_system(
                 &ui_surface,
                 &mut node_transform_query,
                 &just_children_query,
+                &mut state.node_positions,
+                &mut layout_changed_events,
                 inverse_target_scale_factor,
+                Direction::LeftToRight,
+                camera.size.x as f32,
+                Vec2::ZERO,
                 Vec2::ZERO,
                 Vec2::ZERO,
             );
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn update_uinode_geometry_recursive(
         entity: Entity,
         ui_surface: &UiSurface,
-        node_transform_query: &mut Query<(&mut Node, &mut Transform)>,
+        node_transform_query: &mut Query<NodeGeometryQuery>,
         children_query: &Query<&Children>,
+        node_positions: &mut EntityHashMap<Vec2>,
+        layout_changed_events: &mut EventWriter<NodeLayoutChanged>,
         inverse_target_scale_factor: f32,
+        parent_direction: Direction,
+        parent_layout_width: f32,
+        parent_scroll_offset: Vec2,
         parent_size: Vec2,
         mut absolute_location: Vec2,
     ) {
-        if let Ok((mut node, mut transform)) = node_transform_query.get_mut(entity) {
+        if let Ok((mut node, mut transform, style, scroll_position)) =
+            node_transform_query.get_mut(entity)
+        {
             let Ok(layout) = ui_surface.get_layout(entity) else {
                 return;
             };
-            let layout_size =
-                inverse_target_scale_factor * Vec2::new(layout.size.width, layout.size.height);
-            let layout_location =
-                inverse_target_scale_factor * Vec2::new(layout.location.x, layout.location.y);
-
+            let NodeGeometry {
+                layout_size,
+                layout_location,
+                rounded_size,
+                rounded_position,
+                direction,
+            } = NodeGeometry::new(
+                layout,
+                style.map_or(Direction::Inherit, |style| style.direction),
+                parent_direction,
+                parent_layout_width,
+                parent_scroll_offset,
+                absolute_location,
+                inverse_target_scale_factor,
+            );
             absolute_location += layout_location;
 
-            let rounded_size = round_layout_coords(absolute_location + layout_size)
-                - round_layout_coords(absolute_location);
-
             let rounded_location =
                 round_layout_coords(layout_location) + 0.5 * (rounded_size - parent_size);
 
+            let moved = node_positions.insert(entity, rounded_position) != Some(rounded_position);
+            if moved || node.calculated_size != rounded_size {
+                layout_changed_events.send(NodeLayoutChanged {
+                    entity,
+                    size: rounded_size,
+                    position: rounded_position,
+                });
+            }
+
             // only trigger change detection when the new values are different
             if node.calculated_size != rounded_size || node.unrounded_size != layout_size {
                 node.calculated_size = rounded_size;
                 node.unrounded_size = layout_size;
             }
+            if node.direction != direction {
+                node.direction = direction;
+            }
+            let content_inset = BorderRect {
+                left: inverse_target_scale_factor * (layout.border.left + layout.padding.left),
+                right: inverse_target_scale_factor * (layout.border.right + layout.padding.right),
+                top: inverse_target_scale_factor * (layout.border.top + layout.padding.top),
+                bottom: inverse_target_scale_factor
+                    * (layout.border.bottom + layout.padding.bottom),
+            };
+            if node.content_inset != content_inset {
+                node.content_inset = content_inset;
+            }
+
+            // clamp the scroll offset along the scrolling axes, the children are shifted by the clamped offset
+            let mut scroll_offset = Vec2::ZERO;
+            if let (Some(style), Some(mut scroll_position)) = (style, scroll_position) {
+                // scrolled to the end, the content ends at the end of the content box
+                let content_end = inverse_target_scale_factor
+                    * Vec2::new(
+                        layout.content_size.width + layout.border.right + layout.padding.right,
+                        layout.content_size.height + layout.border.bottom + layout.padding.bottom,
+                    );
+                let max_offset = (content_end - layout_size).max(Vec2::ZERO);
+                let mut offset = scroll_position.offset;
+                if style.overflow.x == OverflowAxis::Scroll {
+                    offset.x = offset.x.clamp(0., max_offset.x);
+                    scroll_offset.x = offset.x;
+                }
+                if style.overflow.y == OverflowAxis::Scroll {
+                    offset.y = offset.y.clamp(0., max_offset.y);
+                    scroll_offset.y = offset.y;
+                }
+                if scroll_position.offset != offset {
+                    scroll_position.offset = offset;
+                }
+            }
             if transform.translation.truncate() != rounded_location {
                 transform.translation = rounded_location.extend(0.);
             }
@@ -275,7 +444,12 @@ pub fn ui_layout_system(
                         ui_surface,
                         node_transform_query,
                         children_query,
+                        node_positions,
+                        layout_changed_events,
                         inverse_target_scale_factor,
+                        direction,
+                        layout.size.width,
+                        scroll_offset,
                         rounded_size,
                         absolute_location,
                     );
@@ -285,17 +459,75 @@ pub fn ui_layout_system(
     }
 }
 
+/// The geometry of a UI node computed from its taffy layout, in logical pixels
+struct NodeGeometry {
+    /// The size of the node before rounding
+    layout_size: Vec2,
+    /// The position of the node's top-left corner relative to its parent's, before rounding
+    layout_location: Vec2,
+    /// The size of the node, see [`Node::size`]
+    rounded_size: Vec2,
+    /// The position of the node's top-left corner relative to the viewport's, see
+    /// [`NodeLayoutChanged::position`]
+    rounded_position: Vec2,
+    /// The resolved direction of the node, see [`Node::direction`]
+    direction: Direction,
+}
+
+impl NodeGeometry {
+    /// Computes the geometry of a node whose [`Style::direction`] is `style_direction`, from its
+    /// taffy `layout` and its parent's geometry.
+    ///
+    /// `parent_layout_width` is the width of the parent's taffy layout in physical pixels. The other
+    /// positions are in logical pixels, `parent_absolute_location` being the unrounded position of
+    /// the parent's top-left corner relative to the viewport's.
+    fn new(
+        layout: &taffy::Layout,
+        style_direction: Direction,
+        parent_direction: Direction,
+        parent_layout_width: f32,
+        parent_scroll_offset: Vec2,
+        parent_absolute_location: Vec2,
+        inverse_target_scale_factor: f32,
+    ) -> Self {
+        // taffy only lays out left to right, so the children of right to left nodes are mirrored
+        let layout_x = match parent_direction {
+            Direction::RightToLeft => parent_layout_width - layout.location.x - layout.size.width,
+            _ => layout.location.x,
+        };
+        let direction = match style_direction {
+            Direction::Inherit => parent_direction,
+            direction => direction,
+        };
+        let layout_size =
+            inverse_target_scale_factor * Vec2::new(layout.size.width, layout.size.height);
+        let layout_location = inverse_target_scale_factor * Vec2::new(layout_x, layout.location.y)
+            - parent_scroll_offset;
+
+        let absolute_location = parent_absolute_location + layout_location;
+        let rounded_size = round_layout_coords(absolute_location + layout_size)
+            - round_layout_coords(absolute_location);
+
+        Self {
+            layout_size,
+            layout_location,
+            rounded_size,
+            rounded_position: round_layout_coords(absolute_location),
+            direction,
+        }
+    }
+}
+
 /// Resolve and update the widths of Node outlines
 pub fn resolve_outlines_system(
-    primary_window: Query<&Window, With<PrimaryWindow>>,
-    ui_scale: Res<UiScale>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    ui_scale: TargetUiScale,
     mut outlines_query: Query<(&Outline, &mut Node)>,
 ) {
     let viewport_size = primary_window
         .get_single()
-        .map(|window| window.size())
-        .unwrap_or(Vec2::ZERO)
-        / ui_scale.0;
+        .map(|(entity, window)| window.size() / ui_scale.window(entity, window.scale_factor()))
+        .unwrap_or(Vec2::ZERO);
 
     for (outline, mut node) in outlines_query.iter_mut() {
         let node = node.bypass_change_detection();
@@ -361,6 +593,7 @@ mod tests {
     use bevy_math::{vec2, Rect, UVec2, Vec2};
     use bevy_render::camera::ManualTextureViews;
     use bevy_render::camera::OrthographicProjection;
+    use bevy_render::camera::RenderTarget;
     use bevy_render::prelude::Camera;
     use bevy_render::texture::Image;
     use bevy_transform::prelude::GlobalTransform;
@@ -370,16 +603,22 @@ mod tests {
     use bevy_window::PrimaryWindow;
     use bevy_window::Window;
     use bevy_window::WindowCreated;
+    use bevy_window::WindowRef;
     use bevy_window::WindowResized;
     use bevy_window::WindowResolution;
     use bevy_window::WindowScaleFactorChanged;
 
     use crate::layout::round_layout_coords;
     use crate::layout::ui_surface::UiSurface;
+    use crate::layout::NodeLayoutChanged;
     use crate::prelude::*;
     use crate::ui_layout_system;
     use crate::update::update_target_camera_system;
-    use crate::ContentSize;
+    use crate::{
+        compute_layout,
+        debug::{UiLayoutSnapshotNode, UiLayoutTree},
+        AvailableSpace, ContentSize, LayoutInput, Measure, NodeMeasure,
+    };
 
     #[test]
     fn round_layout_coords_must_round_ties_up() {
@@ -393,7 +632,9 @@ mod tests {
     fn setup_ui_test_world() -> (World, Schedule) {
         let mut world = World::new();
         world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
         world.init_resource::<UiSurface>();
+        world.init_resource::<Events<NodeLayoutChanged>>();
         world.init_resource::<Events<WindowScaleFactorChanged>>();
         world.init_resource::<Events<WindowResized>>();
         // Required for the camera system
@@ -468,6 +709,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn percent_roots_follow_the_window_size_in_the_frame_it_is_resized() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        let ui_root = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        let ui_child = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Vw(50.),
+                    height: Val::Vh(50.),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        world.entity_mut(ui_root).add_child(ui_child);
+        ui_schedule.run(&mut world);
+
+        // the camera target info is only updated after the layout
+        let mut lagging_schedule = Schedule::default();
+        lagging_schedule.add_systems(
+            (
+                ui_layout_system,
+                bevy_render::camera::camera_system::<OrthographicProjection>,
+            )
+                .chain(),
+        );
+        let mut resize = |world: &mut World, width: f32, height: f32, send_event: bool| {
+            let (window, mut window_component) = world
+                .query_filtered::<(Entity, &mut Window), With<PrimaryWindow>>()
+                .single_mut(world);
+            window_component.resolution.set(width, height);
+            if send_event {
+                world.send_event(WindowResized {
+                    window,
+                    width,
+                    height,
+                });
+            }
+            lagging_schedule.run(world);
+            [ui_root, ui_child].map(|entity| world.get::<Node>(entity).unwrap().size())
+        };
+
+        assert_eq!(
+            resize(&mut world, 400., 600., true),
+            [Vec2::new(400., 600.), Vec2::new(200., 300.)]
+        );
+        // windows resized without an event are laid out again too
+        assert_eq!(
+            resize(&mut world, 300., 200., false),
+            [Vec2::new(300., 200.), Vec2::new(150., 100.)]
+        );
+    }
+
     #[test]
     fn ui_surface_tracks_ui_entities() {
         let (mut world, mut ui_schedule) = setup_ui_test_world();
@@ -895,6 +1198,681 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ui_scale_mode_controls_the_window_scale_factor() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        world
+            .query::<&mut Window>()
+            .single_mut(&mut world)
+            .resolution
+            .set_scale_factor_override(Some(2.));
+
+        let ui_entity = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(100.),
+                    height: Val::Px(10.),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+
+        // the physical width laid out by taffy, and the width of the node in ui values
+        let mut widths = |world: &mut World, mode: UiScaleMode| {
+            *world.resource_mut::<UiScaleMode>() = mode;
+            ui_schedule.run(world);
+            let layout = world.resource::<UiSurface>().get_layout(ui_entity).unwrap();
+            let logical_width = world.get::<Node>(ui_entity).unwrap().size().x;
+            (layout.size.width, logical_width)
+        };
+
+        assert_eq!(widths(&mut world, UiScaleMode::Window), (200., 100.));
+        assert_eq!(widths(&mut world, UiScaleMode::UiScaleOnly), (100., 100.));
+
+        world.resource_mut::<UiScale>().0 = 2.;
+        assert_eq!(widths(&mut world, UiScaleMode::UiScaleOnly), (200., 100.));
+    }
+
+    #[test]
+    fn window_ui_scale_overrides_ui_scale_for_its_window() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        world.resource_mut::<UiScale>().0 = 1.5;
+
+        let windows = [0, 1].map(|_| {
+            world
+                .spawn(Window {
+                    resolution: WindowResolution::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+                    ..default()
+                })
+                .id()
+        });
+        let ui_entities = windows.map(|window| {
+            let camera = world
+                .spawn(Camera2dBundle {
+                    camera: Camera {
+                        target: RenderTarget::Window(WindowRef::Entity(window)),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .id();
+            world
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(100.),
+                            height: Val::Px(10.),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    TargetCamera(camera),
+                ))
+                .id()
+        });
+
+        let mut widths = |world: &mut World| {
+            ui_schedule.run(world);
+            let ui_surface = world.resource::<UiSurface>();
+            ui_entities.map(|entity| ui_surface.get_layout(entity).unwrap().size.width)
+        };
+
+        world.entity_mut(windows[1]).insert(WindowUiScale(2.));
+        assert_eq!(widths(&mut world), [150., 200.]);
+
+        // without its override, the window falls back to the global `UiScale`
+        world.entity_mut(windows[1]).remove::<WindowUiScale>();
+        assert_eq!(widths(&mut world), [150., 150.]);
+    }
+
+    #[test]
+    fn viewport_units_resolve_against_the_window_size() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let ui_entities = [
+            (Val::Vw(50.), Val::Vh(50.)),
+            (Val::VMin(10.), Val::VMax(10.)),
+        ]
+        .map(|(width, height)| {
+            world
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width,
+                        height,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .id()
+        });
+
+        let mut sizes = |world: &mut World| {
+            ui_schedule.run(world);
+            let ui_surface = world.resource::<UiSurface>();
+            ui_entities.map(|entity| {
+                let layout = ui_surface.get_layout(entity).unwrap();
+                Vec2::new(layout.size.width, layout.size.height)
+            })
+        };
+
+        assert_eq!(
+            sizes(&mut world),
+            [
+                Vec2::new(WINDOW_WIDTH / 2., WINDOW_HEIGHT / 2.),
+                Vec2::new(WINDOW_HEIGHT / 10., WINDOW_WIDTH / 10.),
+            ]
+        );
+
+        // viewport units are recomputed when the window is resized
+        let (window, mut window_component) = world
+            .query_filtered::<(Entity, &mut Window), With<PrimaryWindow>>()
+            .single_mut(&mut world);
+        window_component.resolution.set(400., 600.);
+        world.send_event(WindowResized {
+            window,
+            width: 400.,
+            height: 600.,
+        });
+
+        assert_eq!(
+            sizes(&mut world),
+            [Vec2::new(200., 300.), Vec2::new(40., 60.)]
+        );
+    }
+
+    #[test]
+    fn gaps_space_the_children_of_flex_and_grid_containers() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let spawn_container = |world: &mut World, style: Style| {
+            let children = [0; 3].map(|_| {
+                world
+                    .spawn(NodeBundle {
+                        style: Style {
+                            width: Val::Px(100.),
+                            height: Val::Px(20.),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .id()
+            });
+            world
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(500.),
+                        height: Val::Px(100.),
+                        align_items: AlignItems::Start,
+                        ..style
+                    },
+                    ..default()
+                })
+                .push_children(&children);
+            children
+        };
+
+        let flex_row = spawn_container(
+            &mut world,
+            Style {
+                column_gap: Val::Px(10.),
+                ..default()
+            },
+        );
+        // percentage gaps resolve against the container's size along the same axis
+        let grid_column = spawn_container(
+            &mut world,
+            Style {
+                display: Display::Grid,
+                grid_auto_rows: vec![GridTrack::px(20.)],
+                ..default()
+            }
+            .with_gap(Val::Percent(10.)),
+        );
+
+        ui_schedule.run(&mut world);
+        let ui_surface = world.resource::<UiSurface>();
+        let positions = |children: [Entity; 3]| {
+            children.map(|child| {
+                let location = ui_surface.get_layout(child).unwrap().location;
+                Vec2::new(location.x, location.y)
+            })
+        };
+
+        assert_eq!(
+            positions(flex_row),
+            [Vec2::new(0., 0.), Vec2::new(110., 0.), Vec2::new(220., 0.)]
+        );
+        assert_eq!(
+            positions(grid_column),
+            [Vec2::new(0., 0.), Vec2::new(0., 30.), Vec2::new(0., 60.)]
+        );
+    }
+
+    #[test]
+    fn node_layout_changes_are_sent_once_per_change() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let child = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(20.),
+                    height: Val::Px(10.),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        let parent = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(100.),
+                    height: Val::Px(50.),
+                    left: Val::Px(30.),
+                    justify_content: JustifyContent::End,
+                    ..default()
+                },
+                ..default()
+            })
+            .add_child(child)
+            .id();
+
+        let mut changes = |world: &mut World| {
+            ui_schedule.run(world);
+            let mut events = world.resource_mut::<Events<NodeLayoutChanged>>();
+            let changes: Vec<_> = events
+                .drain()
+                .map(|event| (event.entity, event.size, event.position))
+                .collect();
+            changes
+        };
+
+        // every node is reported the first time it is laid out
+        assert_eq!(
+            changes(&mut world),
+            [
+                (parent, Vec2::new(100., 50.), Vec2::new(30., 0.)),
+                (child, Vec2::new(20., 10.), Vec2::new(110., 0.)),
+            ]
+        );
+        assert_eq!(changes(&mut world), []);
+
+        // resizing the parent moves the child, but doesn't change its size
+        world.get_mut::<Style>(parent).unwrap().width = Val::Px(200.);
+        assert_eq!(
+            changes(&mut world),
+            [
+                (parent, Vec2::new(200., 50.), Vec2::new(30., 0.)),
+                (child, Vec2::new(20., 10.), Vec2::new(210., 0.)),
+            ]
+        );
+
+        // restyling a node without changing its geometry sends no events
+        world.get_mut::<Style>(child).unwrap().width = Val::Px(20.);
+        assert_eq!(changes(&mut world), []);
+    }
+
+    #[test]
+    fn right_to_left_nodes_mirror_their_children() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let spawn_row = |world: &mut World, direction: Direction, top: f32| {
+            let child_style = |grandchild_direction| Style {
+                width: Val::Px(20.),
+                height: Val::Px(10.),
+                direction: grandchild_direction,
+                ..default()
+            };
+            let grandchild = world
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(5.),
+                        height: Val::Px(5.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .id();
+            let children = [
+                world
+                    .spawn(NodeBundle {
+                        style: child_style(Direction::Inherit),
+                        ..default()
+                    })
+                    .add_child(grandchild)
+                    .id(),
+                world
+                    .spawn(NodeBundle {
+                        style: child_style(Direction::LeftToRight),
+                        ..default()
+                    })
+                    .id(),
+            ];
+            world
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(top),
+                        width: Val::Px(100.),
+                        height: Val::Px(10.),
+                        direction,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .push_children(&children);
+            [children[0], children[1], grandchild]
+        };
+        let ltr = spawn_row(&mut world, Direction::LeftToRight, 0.);
+        let rtl = spawn_row(&mut world, Direction::RightToLeft, 50.);
+
+        ui_schedule.run(&mut world);
+        let left_edges = |world: &mut World, entities: [Entity; 3]| {
+            entities.map(|entity| {
+                let node = world.get::<Node>(entity).unwrap();
+                let transform = world.get::<GlobalTransform>(entity).unwrap();
+                (node.logical_rect(transform).min.x, node.direction())
+            })
+        };
+
+        assert_eq!(
+            left_edges(&mut world, ltr),
+            [
+                (0., Direction::LeftToRight),
+                (20., Direction::LeftToRight),
+                (0., Direction::LeftToRight),
+            ]
+        );
+        // the grandchild inherits the right to left direction, so it is at the right of its parent
+        assert_eq!(
+            left_edges(&mut world, rtl),
+            [
+                (80., Direction::RightToLeft),
+                (60., Direction::LeftToRight),
+                (95., Direction::RightToLeft),
+            ]
+        );
+
+        // the end of a right to left row is on its left
+        for row in [ltr[0], rtl[0]] {
+            let parent = world.get::<Parent>(row).unwrap().get();
+            world.get_mut::<Style>(parent).unwrap().justify_content = JustifyContent::End;
+        }
+        ui_schedule.run(&mut world);
+        assert_eq!(left_edges(&mut world, ltr).map(|(x, _)| x), [60., 80., 60.]);
+        assert_eq!(left_edges(&mut world, rtl).map(|(x, _)| x), [20., 0., 35.]);
+    }
+
+    #[test]
+    fn compute_layout_matches_ui_layout_system() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let mut input = LayoutInput::new(Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT));
+        let leaf = |width: Val| Style {
+            width,
+            height: Val::Percent(50.),
+            margin: UiRect::left(Val::Px(3.3)),
+            ..default()
+        };
+        let leaves = [
+            leaf(Val::Percent(33.3)),
+            leaf(Val::Px(10.5)),
+            leaf(Val::Vw(7.7)),
+        ];
+        let inputs: Vec<usize> = leaves
+            .iter()
+            .map(|style| input.add_node(style.clone(), []))
+            .collect();
+        let root_style = Style {
+            width: Val::Percent(40.),
+            height: Val::Px(60.5),
+            left: Val::Px(100.25),
+            padding: UiRect::all(Val::Px(2.5)),
+            direction: Direction::RightToLeft,
+            ..default()
+        };
+        let root_input = input.add_node(root_style.clone(), inputs.iter().copied());
+
+        let entities: Vec<Entity> = leaves
+            .into_iter()
+            .map(|style| world.spawn(NodeBundle { style, ..default() }).id())
+            .collect();
+        let root = world
+            .spawn(NodeBundle {
+                style: root_style,
+                ..default()
+            })
+            .push_children(&entities)
+            .id();
+        ui_schedule.run(&mut world);
+
+        let output = compute_layout(&input).unwrap();
+        for (entity, index) in entities
+            .into_iter()
+            .chain([root])
+            .zip(inputs.into_iter().chain([root_input]))
+        {
+            let node = world.get::<Node>(entity).unwrap();
+            let transform = world.get::<GlobalTransform>(entity).unwrap();
+            assert_eq!(node.logical_rect(transform), output.rects[index]);
+            assert_eq!(node.direction(), output.directions[index]);
+        }
+    }
+
+    #[test]
+    fn scroll_positions_shift_the_children_of_scrolling_nodes() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let children = [0; 3].map(|_| {
+            world
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(100.),
+                        height: Val::Px(40.),
+                        flex_shrink: 0.,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .id()
+        });
+        let scroll_node = world
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        width: Val::Px(100.),
+                        height: Val::Px(50.),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ScrollPosition {
+                    offset: Vec2::new(20., 30.),
+                },
+            ))
+            .push_children(&children)
+            .id();
+
+        let mut tops = |world: &mut World, offset: Option<Vec2>| {
+            if let Some(offset) = offset {
+                world.get_mut::<ScrollPosition>(scroll_node).unwrap().offset = offset;
+            }
+            ui_schedule.run(world);
+            children.map(|child| {
+                let node = world.get::<Node>(child).unwrap();
+                let rect = node.logical_rect(world.get::<GlobalTransform>(child).unwrap());
+                rect.min
+            })
+        };
+
+        // the node only scrolls vertically, so the horizontal offset is ignored
+        assert_eq!(
+            tops(&mut world, None),
+            [vec2(0., -30.), vec2(0., 10.), vec2(0., 50.)]
+        );
+        assert_eq!(
+            world.get::<ScrollPosition>(scroll_node).unwrap().offset,
+            vec2(20., 30.)
+        );
+
+        // the offset is clamped to the overflowing content
+        assert_eq!(
+            tops(&mut world, Some(vec2(0., 1000.))),
+            [vec2(0., -70.), vec2(0., -30.), vec2(0., 10.)]
+        );
+        assert_eq!(
+            world.get::<ScrollPosition>(scroll_node).unwrap().offset,
+            vec2(0., 70.)
+        );
+        assert_eq!(
+            tops(&mut world, Some(vec2(0., -10.))),
+            [vec2(0., 0.), vec2(0., 40.), vec2(0., 80.)]
+        );
+    }
+
+    #[test]
+    fn padded_scroll_nodes_scroll_to_the_end_of_their_content_box() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let child = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(50.),
+                    height: Val::Px(200.),
+                    flex_shrink: 0.,
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        let scroll_node = world
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(100.),
+                        height: Val::Px(100.),
+                        padding: UiRect::all(Val::Px(10.)),
+                        border: UiRect::all(Val::Px(5.)),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ScrollPosition {
+                    offset: vec2(0., 1000.),
+                },
+            ))
+            .add_child(child)
+            .id();
+        ui_schedule.run(&mut world);
+
+        // the 70 pixels high content box shows the last 70 pixels of the child
+        assert_eq!(
+            world.get::<ScrollPosition>(scroll_node).unwrap().offset,
+            vec2(0., 130.)
+        );
+        let node = world.get::<Node>(child).unwrap();
+        let rect = node.logical_rect(world.get::<GlobalTransform>(child).unwrap());
+        assert_eq!(rect.max.y, 85.);
+    }
+
+    #[test]
+    fn idle_frames_skip_the_layout() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let child = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(100.),
+                    height: Val::Percent(50.),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        let root = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                ..default()
+            })
+            .add_child(child)
+            .id();
+
+        // the first frame lays the nodes out
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Node>(child).unwrap().size(),
+            Vec2::new(100., 50.)
+        );
+
+        // a node edited by hand keeps its size while nothing the layout depends on changes,
+        // which shows the layout isn't computed again
+        let tamper = |world: &mut World, ui_schedule: &mut Schedule| {
+            world.get_mut::<Node>(child).unwrap().calculated_size = Vec2::ZERO;
+            ui_schedule.run(world);
+            ui_schedule.run(world);
+            world.get::<Node>(child).unwrap().size()
+        };
+        assert_eq!(tamper(&mut world, &mut ui_schedule), Vec2::ZERO);
+        world.resource_mut::<Events<NodeLayoutChanged>>().clear();
+        ui_schedule.run(&mut world);
+        assert!(world.resource::<Events<NodeLayoutChanged>>().is_empty());
+
+        // a changed style lays the nodes out again
+        world.get_mut::<Style>(root).unwrap().height = Val::Percent(100.);
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Node>(child).unwrap().size(),
+            Vec2::new(100., 50.)
+        );
+
+        // as does a change of the hierarchy
+        assert_eq!(tamper(&mut world, &mut ui_schedule), Vec2::ZERO);
+        let sibling = world.spawn(NodeBundle::default()).id();
+        world.entity_mut(root).add_child(sibling);
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Node>(child).unwrap().size(),
+            Vec2::new(100., 50.)
+        );
+
+        // and a change of the UI scale
+        assert_eq!(tamper(&mut world, &mut ui_schedule), Vec2::ZERO);
+        world.resource_mut::<UiScale>().0 = 2.;
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Node>(child).unwrap().size(),
+            Vec2::new(100., 25.)
+        );
+    }
+
+    #[test]
+    fn layout_snapshots_describe_the_layout_tree() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let child = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(100.),
+                    height: Val::Px(40.),
+                    margin: UiRect::left(Val::Px(10.)),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        let root = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(50.),
+                    height: Val::Percent(100.),
+                    padding: UiRect::top(Val::Px(5.)),
+                    ..default()
+                },
+                ..default()
+            })
+            .add_child(child)
+            .id();
+        ui_schedule.run(&mut world);
+
+        let snapshot = world.run_system_once(|layout_tree: UiLayoutTree| layout_tree.snapshot());
+        let camera = world
+            .query_filtered::<Entity, With<Camera>>()
+            .single(&world);
+        assert_eq!(
+            snapshot.nodes,
+            [
+                UiLayoutSnapshotNode {
+                    entity: root,
+                    camera,
+                    parent: None,
+                    children: vec![child],
+                    rect: Rect::new(0., 0., 500., 100.),
+                    content_size: Vec2::new(110., 45.),
+                    measured: false,
+                },
+                UiLayoutSnapshotNode {
+                    entity: child,
+                    camera,
+                    parent: Some(root),
+                    children: Vec::new(),
+                    rect: Rect::new(10., 5., 110., 45.),
+                    content_size: Vec2::ZERO,
+                    measured: false,
+                },
+            ]
+        );
+        assert_eq!(snapshot.node(child).unwrap().parent, Some(root));
+    }
+
     #[test]
     fn ui_node_should_be_set_to_its_content_size() {
         let (mut world, mut ui_schedule) = setup_ui_test_world();
@@ -924,6 +1902,196 @@ mod tests {
         assert_eq!(layout.size.height, content_size.y);
     }
 
+    #[test]
+    fn image_nodes_take_the_size_of_their_texture_once_loaded() {
+        use bevy_render::render_asset::RenderAssetUsages;
+        use bevy_render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+        use bevy_sprite::TextureAtlasLayout;
+
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        world.init_resource::<Assets<TextureAtlasLayout>>();
+        ui_schedule
+            .add_systems(crate::widget::update_image_content_size_system.before(ui_layout_system));
+
+        let texture = world.resource::<Assets<Image>>().reserve_handle();
+        let ui_entity = world
+            .spawn(ImageBundle {
+                style: Style {
+                    align_self: AlignSelf::Start,
+                    ..default()
+                },
+                image: UiImage::new(texture.clone()),
+                ..default()
+            })
+            .id();
+        let mut size = |world: &mut World| {
+            ui_schedule.run(world);
+            let layout = world.resource::<UiSurface>().get_layout(ui_entity).unwrap();
+            Vec2::new(layout.size.width, layout.size.height)
+        };
+
+        // the image is measured as empty until its texture is loaded
+        assert_eq!(size(&mut world), Vec2::ZERO);
+
+        world.resource_mut::<Assets<Image>>().insert(
+            &texture,
+            Image::new_fill(
+                Extent3d {
+                    width: 64,
+                    height: 32,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                &[255; 4],
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            ),
+        );
+        assert_eq!(size(&mut world), Vec2::new(64., 32.));
+
+        world.resource_mut::<UiScale>().0 = 2.;
+        assert_eq!(size(&mut world), Vec2::new(128., 64.));
+    }
+
+    #[test]
+    fn measure_funcs_size_nodes_from_their_known_dimensions() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        // keeps a 2:1 aspect ratio
+        let measure_func = |width: Option<f32>, height: Option<f32>| match (width, height) {
+            (Some(width), _) => Vec2::new(width, width / 2.),
+            (None, Some(height)) => Vec2::new(2. * height, height),
+            (None, None) => Vec2::new(20., 10.),
+        };
+        let styles = [
+            Style {
+                width: Val::Px(100.),
+                ..default()
+            },
+            Style {
+                height: Val::Px(40.),
+                ..default()
+            },
+            Style::default(),
+        ];
+        let ui_entities = styles.map(|style| {
+            world
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            align_self: AlignSelf::Start,
+                            ..style
+                        },
+                        ..default()
+                    },
+                    ContentSize::from_measure_func(measure_func),
+                ))
+                .id()
+        });
+
+        ui_schedule.run(&mut world);
+
+        let ui_surface = world.resource::<UiSurface>();
+        let sizes = ui_entities.map(|entity| {
+            let layout = ui_surface.get_layout(entity).unwrap();
+            (layout.size.width, layout.size.height)
+        });
+        assert_eq!(sizes, [(100., 50.), (80., 40.), (20., 10.)]);
+    }
+
+    #[test]
+    fn percentage_constraints_resolve_before_measuring_content() {
+        /// Measures like a 1000px long line of text wrapping into 10px high lines
+        struct WrappingMeasure;
+
+        impl Measure for WrappingMeasure {
+            fn measure(
+                &self,
+                width: Option<f32>,
+                _: Option<f32>,
+                available_width: AvailableSpace,
+                _: AvailableSpace,
+                _: &taffy::Style,
+            ) -> Vec2 {
+                let width = width.unwrap_or(match available_width {
+                    AvailableSpace::Definite(width) => width.min(1000.),
+                    AvailableSpace::MinContent => 50.,
+                    AvailableSpace::MaxContent => 1000.,
+                });
+                Vec2::new(width, (1000. / width).ceil() * 10.)
+            }
+        }
+
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let styles = [
+            // percent size
+            Style {
+                width: Val::Percent(50.),
+                ..default()
+            },
+            // percent max
+            Style {
+                max_width: Val::Percent(25.),
+                ..default()
+            },
+            // percent min with px max, the min wins
+            Style {
+                min_width: Val::Percent(30.),
+                max_width: Val::Px(200.),
+                ..default()
+            },
+            // px min with percent max
+            Style {
+                min_width: Val::Px(100.),
+                max_width: Val::Percent(40.),
+                ..default()
+            },
+            // percent min larger than the content
+            Style {
+                min_width: Val::Percent(100.),
+                ..default()
+            },
+        ];
+        let expected_sizes = [
+            Vec2::new(500., 20.),
+            Vec2::new(250., 40.),
+            Vec2::new(300., 40.),
+            Vec2::new(400., 30.),
+            Vec2::new(1000., 10.),
+        ];
+
+        let ui_entities = styles.map(|style| {
+            let mut content_size = ContentSize::default();
+            content_size.set(NodeMeasure::Custom(Box::new(WrappingMeasure)));
+            world
+                .spawn((NodeBundle { style, ..default() }, content_size))
+                .id()
+        });
+        world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Start,
+                    ..default()
+                },
+                ..default()
+            })
+            .push_children(&ui_entities);
+
+        ui_schedule.run(&mut world);
+
+        let ui_surface = world.resource::<UiSurface>();
+        for (ui_entity, expected_size) in ui_entities.into_iter().zip(expected_sizes) {
+            let layout = ui_surface.get_layout(ui_entity).unwrap();
+            assert_eq!(
+                Vec2::new(layout.size.width, layout.size.height),
+                expected_size
+            );
+        }
+    }
+
     #[test]
     fn measure_funcs_should_be_removed_on_content_size_removal() {
         let (mut world, mut ui_schedule) = setup_ui_test_world();
@@ -1025,7 +2193,9 @@ mod tests {
     fn no_camera_ui() {
         let mut world = World::new();
         world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
         world.init_resource::<UiSurface>();
+        world.init_resource::<Events<NodeLayoutChanged>>();
         world.init_resource::<Events<WindowScaleFactorChanged>>();
         world.init_resource::<Events<WindowResized>>();
         // Required for the camera system
@@ -18,32 +18,42 @@ pub mod update;
 pub mod widget;
 
 use bevy_derive::{Deref, DerefMut};
-use bevy_reflect::Reflect;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 #[cfg(feature = "bevy_text")]
 mod accessibility;
 mod focus;
 mod geometry;
+mod gradient;
 mod layout;
+mod navigation;
 mod render;
 mod stack;
 mod texture_slice;
+mod touch;
 mod ui_node;
+mod ui_tree;
 
 pub use focus::*;
 pub use geometry::*;
+pub use gradient::GradientTexture;
 pub use layout::*;
 pub use measurement::*;
+pub use navigation::*;
 pub use render::*;
+pub use touch::*;
 pub use ui_material::*;
 pub use ui_node::*;
+pub use ui_tree::*;
 use widget::UiImageSize;
 
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        geometry::*, node_bundles::*, ui_material::*, ui_node::*, widget::Button, widget::Label,
-        Interaction, UiMaterialPlugin, UiScale,
+        geometry::*, node_bundles::*, ui_material::*, ui_node::*, widget::Button,
+        widget::ButtonColors, widget::Checkbox, widget::Label, widget::ScrollView, widget::Slider,
+        widget::Toggle, Disabled, Interactable, Interaction, Modal, SpawnUiTree, UiMaterialPlugin,
+        UiScale, UiScaleMode, UiTree, WindowUiScale,
     };
     // `bevy_sprite` re-exports for texture slicing
     #[doc(hidden)]
@@ -61,7 +71,11 @@ use bevy_transform::TransformSystem;
 use layout::ui_surface::UiSurface;
 use stack::ui_stack_system;
 pub use stack::UiStack;
-use update::{update_clipping_system, update_target_camera_system};
+use update::{
+    ui_scale_changed_system, ui_scale_tween_system, update_clipping_system,
+    update_grid_area_system, update_target_camera_system, update_world_space_ui_system,
+};
+pub use update::{UiEasing, UiScaleChanged, UiScaleTween, UiScaleTweenFinished};
 
 /// The basic plugin for Bevy UI
 #[derive(Default)]
@@ -74,6 +88,11 @@ pub enum UiSystem {
     Layout,
     /// After this label, input interactions with UI entities have been updated for this frame
     Focus,
+    /// After this label, the widgets have reacted to this frame's interactions.
+    ///
+    /// Runs in [`PreUpdate`] after [`UiSystem::Focus`], so systems reading [`Interaction`] or the
+    /// state of widgets in [`Update`] see the changes of the current frame.
+    Widgets,
     /// After this label, the [`UiStack`] resource has been updated
     Stack,
     /// After this label, node outline widths have been updated
@@ -93,6 +112,46 @@ impl Default for UiScale {
     }
 }
 
+impl UiScale {
+    /// Returns the scale between the logical pixels of a render target with the given scale factor
+    /// and ui values, taking the [`UiScaleMode`] into account.
+    pub fn for_target(&self, mode: UiScaleMode, target_scale_factor: f32) -> f32 {
+        match mode {
+            UiScaleMode::Window => self.0,
+            UiScaleMode::UiScaleOnly => self.0 / target_scale_factor,
+        }
+    }
+}
+
+/// Whether the UI is scaled by the scale factor of the window it is rendered to, on top of [`UiScale`].
+///
+/// Text is rasterized at its physical size, so with [`UiScaleMode::UiScaleOnly`] a font size maps to the
+/// same font atlases on every monitor, while [`UiScaleMode::Window`] adds new atlases for each scale factor
+/// the text is displayed at.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect, Resource)]
+pub enum UiScaleMode {
+    /// A ui value of one pixel is one logical pixel of the window, multiplied by [`UiScale`],
+    /// so the UI grows with the scale factor set by the OS.
+    #[default]
+    Window,
+    /// A ui value of one pixel is one physical pixel, multiplied by [`UiScale`],
+    /// so the UI keeps the same size in pixels across monitors.
+    UiScaleOnly,
+}
+
+/// Overrides the [`UiScale`] of the nodes rendered to the window entity it is added to.
+///
+/// Windows without this component use the [`UiScale`] resource.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect, Deref, DerefMut)]
+#[reflect(Component, Default, PartialEq)]
+pub struct WindowUiScale(pub f32);
+
+impl Default for WindowUiScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 // Marks systems that can be ambiguous with [`widget::text_system`] if the `bevy_text` feature is enabled.
 // See https://github.com/bevyengine/bevy/pull/11391 for more details.
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
@@ -109,41 +168,114 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<UiSurface>()
             .init_resource::<UiScale>()
+            .init_resource::<UiScaleMode>()
             .init_resource::<UiStack>()
+            .init_resource::<FocusedEntity>()
+            .init_resource::<HoveredUiEntity>()
+            .init_resource::<UiInteractionConfig>()
+            .init_resource::<PointerCapture>()
             .register_type::<BackgroundColor>()
+            .register_type::<BufferedClicks>()
             .register_type::<CalculatedClip>()
             .register_type::<ContentSize>()
+            .register_type::<DoubleClick>()
+            .register_type::<DoubleClicked>()
+            .register_type::<Draggable>()
+            .register_type::<DragState>()
+            .register_type::<DragEnd>()
             .register_type::<FocusPolicy>()
+            .register_type::<Focusable>()
+            .register_type::<Focused>()
+            .register_type::<FocusedEntity>()
             .register_type::<Interaction>()
+            .register_type::<PrevInteraction>()
+            .register_type::<Interactable>()
+            .register_type::<Modal>()
+            .register_type::<Disabled>()
+            .register_type::<LongPress>()
+            .register_type::<LongPressed>()
+            .register_type::<InteractionButtons>()
+            .register_type::<InteractionPolicy>()
+            .register_type::<InteractionDebounce>()
+            .register_type::<KeyboardPressed>()
+            .register_type::<UiInteractionConfig>()
+            .register_type::<PointerCapture>()
+            .register_type::<DraggingOutside>()
             .register_type::<Node>()
             .register_type::<RelativeCursorPosition>()
+            .register_type::<HitArea>()
+            .register_type::<HitPadding>()
+            .register_type::<HoverCursor>()
+            .register_type::<HoveredUiEntity>()
+            .register_type::<ScrollPosition>()
+            .register_type::<ScrolledOver>()
             .register_type::<Style>()
             .register_type::<TargetCamera>()
+            .register_type::<WorldSpaceUi>()
+            .register_type::<GridTemplateAreas>()
+            .register_type::<GridArea>()
             .register_type::<UiImage>()
+            .register_type::<ImageFit>()
             .register_type::<UiImageSize>()
             .register_type::<UiRect>()
             .register_type::<UiScale>()
+            .register_type::<UiScaleMode>()
+            .register_type::<UiScaleTween>()
+            .register_type::<UiEasing>()
+            .register_type::<UiScaleTweenFinished>()
+            .register_type::<UiTextureCursor>()
+            .register_type::<WindowUiScale>()
             .register_type::<BorderColor>()
+            .register_type::<BackgroundGradient>()
             .register_type::<BorderRadius>()
+            .register_type::<BoxShadow>()
+            .register_type::<TextOutline>()
+            .register_type::<TextShadow>()
+            .register_type::<TouchInteraction>()
+            .register_type::<TouchPress>()
             .register_type::<widget::Button>()
             .register_type::<widget::Label>()
+            .register_type::<AccessibilityLabel>()
             .register_type::<ZIndex>()
             .register_type::<Outline>()
-            .add_systems(
-                PreUpdate,
-                ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
-            );
+            .register_type::<HoverEnter>()
+            .register_type::<HoverExit>()
+            .register_type::<UiPress>()
+            .register_type::<UiRelease>()
+            .register_type::<UiClick>()
+            .register_type::<UiScaleChanged>()
+            .register_type::<NodeLayoutChanged>()
+            .add_event::<HoverEnter>()
+            .add_event::<HoverExit>()
+            .add_event::<UiPress>()
+            .add_event::<UiRelease>()
+            .add_event::<UiClick>()
+            .add_event::<DragEnd>()
+            .add_event::<DoubleClicked>()
+            .add_event::<LongPressed>()
+            .add_event::<UiScaleChanged>()
+            .add_event::<UiScaleTweenFinished>()
+            .add_event::<NodeLayoutChanged>();
+
+        build_interaction(app);
 
         app.add_systems(
             PostUpdate,
             (
                 check_visibility::<WithNode>.in_set(VisibilitySystems::CheckVisibility),
+                ui_scale_changed_system.before(UiSystem::Layout),
                 update_target_camera_system.before(UiSystem::Layout),
+                update_grid_area_system.before(UiSystem::Layout),
+                update_world_space_ui_system
+                    .before(UiSystem::Layout)
+                    .before(VisibilitySystems::VisibilityPropagate),
                 apply_deferred
                     .after(update_target_camera_system)
                     .before(UiSystem::Layout),
                 ui_layout_system
                     .in_set(UiSystem::Layout)
+                    // the viewport sizes of the cameras are up to date
+                    .after(bevy_render::camera::CameraUpdateSystem)
                     .before(TransformSystem::TransformPropagate),
                 resolve_outlines_system
                     .in_set(UiSystem::Outlines)
@@ -172,9 +304,29 @@ impl Plugin for UiPlugin {
                     texture_slice::compute_slices_on_image_change,
                 )
                     .after(UiSystem::Layout),
+                // Potential conflicts: `Assets<Image>`
+                // The gradient textures are only ever read by the renderer, and the other systems
+                // accessing `Assets<Image>` will never observe them.
+                gradient::update_gradient_textures
+                    .ambiguous_with(widget::update_image_content_size_system)
+                    .ambiguous_with(texture_slice::compute_slices_on_asset_event)
+                    .ambiguous_with(texture_slice::compute_slices_on_image_change)
+                    .in_set(AmbiguousWithTextSystem)
+                    .in_set(AmbiguousWithUpdateText2DLayout),
             ),
         );
 
+        app.add_plugins((
+            widget::AnimatedImagePlugin,
+            widget::ButtonPlugin,
+            widget::CheckboxPlugin,
+            widget::ScrollViewPlugin,
+            widget::ScrollbarPlugin,
+            widget::SliderPlugin,
+            widget::TogglePlugin,
+            widget::UiTransitionPlugin,
+        ));
+
         #[cfg(feature = "bevy_text")]
         build_text_interop(app);
 
@@ -190,6 +342,41 @@ impl Plugin for UiPlugin {
     }
 }
 
+/// Adds the systems updating the interactions of UI nodes in [`PreUpdate`], called from [`UiPlugin::build`].
+///
+/// The widget systems reading the interactions are added to [`UiSystem::Widgets`].
+fn build_interaction(app: &mut App) {
+    app.configure_sets(PreUpdate, UiSystem::Widgets.after(UiSystem::Focus))
+        .add_systems(
+            PreUpdate,
+            (
+                // the cursor is mapped to the nodes with the scale of this frame
+                ui_scale_tween_system.before(UiSystem::Focus),
+                ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
+                (
+                    ui_drag_system,
+                    ui_double_click_system,
+                    ui_long_press_system,
+                    ui_hover_cursor_system,
+                    ui_touch_system,
+                )
+                    .in_set(UiSystem::Focus)
+                    .after(ui_focus_system),
+                (
+                    (tab_navigation_system, gamepad_navigation_system).after(ui_focus_system),
+                    update_focused_system,
+                )
+                    .chain()
+                    .in_set(UiSystem::Focus)
+                    .after(InputSystem),
+                // all of the clicks of the frame are sent
+                ui_click_buffer_system
+                    .after(UiSystem::Focus)
+                    .before(UiSystem::Widgets),
+            ),
+        );
+}
+
 /// A function that should be called from [`UiPlugin::build`] when [`bevy_text`] is enabled.
 #[cfg(feature = "bevy_text")]
 fn build_text_interop(app: &mut App) {
@@ -197,7 +384,18 @@ fn build_text_interop(app: &mut App) {
     use bevy_text::TextLayoutInfo;
 
     app.register_type::<TextLayoutInfo>()
-        .register_type::<TextFlags>();
+        .register_type::<TextFlags>()
+        .register_type::<widget::TextOverflow>()
+        .register_type::<widget::TextLineClamp>()
+        .init_resource::<widget::TextLayoutCache>()
+        .add_plugins((
+            widget::ContextMenuPlugin,
+            widget::DropdownPlugin,
+            widget::RichTextPlugin,
+            widget::TextInputPlugin,
+            widget::TextLinkPlugin,
+            widget::TooltipPlugin,
+        ));
 
     app.add_systems(
         PostUpdate,
@@ -236,3 +434,110 @@ fn build_text_interop(app: &mut App) {
         AmbiguousWithUpdateText2DLayout.ambiguous_with(bevy_text::update_text2d_layout),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, PreUpdate};
+    use bevy_core_pipeline::core_2d::Camera2dBundle;
+    use bevy_ecs::{
+        prelude::{Query, ResMut, Resource},
+        schedule::IntoSystemConfigs,
+    };
+    use bevy_input::{
+        mouse::{MouseButton, MouseButtonInput},
+        ButtonState, InputPlugin,
+    };
+    use bevy_math::Vec2;
+    use bevy_render::view::ViewVisibility;
+    use bevy_time::TimePlugin;
+    use bevy_transform::components::GlobalTransform;
+    use bevy_utils::default;
+    use bevy_window::{PrimaryWindow, Window};
+
+    use super::build_interaction;
+    use crate::{
+        widget::{ButtonColors, ButtonPlugin},
+        BackgroundColor, DoubleClicked, DragEnd, FocusedEntity, HoverEnter, HoverExit,
+        HoveredUiEntity, Interaction, LongPressed, Node, PointerCapture, UiClick,
+        UiInteractionConfig, UiPress, UiRelease, UiScale, UiScaleMode, UiScaleTweenFinished,
+        UiStack, UiSystem,
+    };
+
+    #[derive(Resource, Default)]
+    struct SeenInteractions(Vec<Interaction>);
+
+    #[test]
+    fn widgets_and_handlers_see_the_interactions_of_the_same_frame() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, TimePlugin, ButtonPlugin))
+            .init_resource::<SeenInteractions>();
+        // the resources and events of the interaction systems
+        let world = app.world_mut();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<UiStack>();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<HoveredUiEntity>();
+        world.init_resource::<UiInteractionConfig>();
+        world.init_resource::<PointerCapture>();
+        app.add_event::<HoverEnter>()
+            .add_event::<HoverExit>()
+            .add_event::<UiPress>()
+            .add_event::<UiRelease>()
+            .add_event::<UiClick>()
+            .add_event::<DragEnd>()
+            .add_event::<DoubleClicked>()
+            .add_event::<LongPressed>()
+            .add_event::<UiScaleTweenFinished>();
+        build_interaction(&mut app);
+        app.add_systems(
+            PreUpdate,
+            (|query: Query<&Interaction>, mut seen: ResMut<SeenInteractions>| {
+                seen.0.extend(query.iter().copied());
+            })
+            .after(UiSystem::Widgets),
+        );
+
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::new(50., 50.)));
+        let window = app.world_mut().spawn((window, PrimaryWindow)).id();
+        app.world_mut().spawn(Camera2dBundle::default());
+        let mut view_visibility = ViewVisibility::HIDDEN;
+        view_visibility.set();
+        let button = app
+            .world_mut()
+            .spawn((
+                Node {
+                    calculated_size: Vec2::splat(100.),
+                    unrounded_size: Vec2::splat(100.),
+                    ..default()
+                },
+                GlobalTransform::from_translation(Vec2::splat(50.).extend(0.)),
+                view_visibility,
+                Interaction::None,
+                ButtonColors::default(),
+                BackgroundColor::default(),
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<UiStack>()
+            .uinodes
+            .push(button);
+
+        app.world_mut().send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state: ButtonState::Pressed,
+            window,
+        });
+        app.update();
+        // the press is seen by the handler and the button in the frame of the click
+        assert_eq!(
+            app.world().resource::<SeenInteractions>().0,
+            vec![Interaction::Pressed]
+        );
+        assert_eq!(
+            app.world().get::<BackgroundColor>(button).unwrap().0,
+            ButtonColors::default().pressed
+        );
+    }
+}
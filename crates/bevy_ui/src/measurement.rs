@@ -4,6 +4,7 @@ use bevy_math::Vec2;
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use std::fmt::Formatter;
 pub use taffy::style::AvailableSpace;
+use taffy::MaybeResolve;
 
 use crate::widget::ImageMeasure;
 
@@ -30,6 +31,51 @@ pub trait Measure: Send + Sync + 'static {
     ) -> Vec2;
 }
 
+/// A simpler `Measure` for content whose size only depends on the known dimensions of its node,
+/// like a custom-drawn widget keeping an aspect ratio.
+///
+/// Closures taking the known width and height are `MeasureFunc`s. Use [`ContentSize::from_measure_func`]
+/// to measure a node with one.
+pub trait MeasureFunc: Send + Sync + 'static {
+    /// Calculate the size of the node given its width and height, when they are already known from
+    /// the layout or the node's [`Style`](crate::Style).
+    fn measure(&self, width: Option<f32>, height: Option<f32>) -> Vec2;
+}
+
+impl<F> MeasureFunc for F
+where
+    F: Fn(Option<f32>, Option<f32>) -> Vec2 + Send + Sync + 'static,
+{
+    fn measure(&self, width: Option<f32>, height: Option<f32>) -> Vec2 {
+        self(width, height)
+    }
+}
+
+/// The `Measure` calling a [`MeasureFunc`]
+struct MeasureFuncMeasure<F: MeasureFunc>(F);
+
+impl<F: MeasureFunc> Measure for MeasureFuncMeasure<F> {
+    fn measure(
+        &self,
+        width: Option<f32>,
+        height: Option<f32>,
+        available_width: AvailableSpace,
+        available_height: AvailableSpace,
+        style: &taffy::Style,
+    ) -> Vec2 {
+        // the layout doesn't always pass the sizes set by the style
+        let width = width.or(style
+            .size
+            .width
+            .maybe_resolve(available_width.into_option()));
+        let height = height.or(style
+            .size
+            .height
+            .maybe_resolve(available_height.into_option()));
+        self.0.measure(width, height)
+    }
+}
+
 /// A type to serve as Taffy's node context (which allows the content size of leaf nodes to be computed)
 ///
 /// It has specific variants for common built-in types to avoid making them opaque and needing to box them
@@ -111,4 +157,13 @@ impl ContentSize {
         content_size.set(NodeMeasure::Fixed(FixedMeasure { size }));
         content_size
     }
+
+    /// Creates a `ContentSize` whose size is computed by the given [`MeasureFunc`].
+    pub fn from_measure_func(measure_func: impl MeasureFunc) -> ContentSize {
+        let mut content_size = Self::default();
+        content_size.set(NodeMeasure::Custom(Box::new(MeasureFuncMeasure(
+            measure_func,
+        ))));
+        content_size
+    }
 }
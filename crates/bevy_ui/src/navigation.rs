@@ -0,0 +1,444 @@
+//! This module contains keyboard and gamepad focus navigation between UI nodes
+
+use crate::{CalculatedClip, Interaction, Node, UiStack};
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    entity::Entity,
+    prelude::{Component, With},
+    reflect::{ReflectComponent, ReflectResource},
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+};
+use bevy_input::{
+    gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType},
+    keyboard::KeyCode,
+    Axis, ButtonInput,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::view::ViewVisibility;
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::HashMap;
+
+/// Makes a UI node focusable with the keyboard.
+///
+/// Pressing Tab moves the focus to the next focusable node and Shift+Tab to the previous one,
+/// see [`tab_navigation_system`].
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Focusable {
+    /// The position of the node in the tab order.
+    ///
+    /// Nodes are ordered by ascending `tab_index`, and then by their order in the [`UiStack`].
+    /// Nodes with a negative `tab_index` are skipped by Tab navigation,
+    /// but can still be focused through [`FocusedEntity`].
+    pub tab_index: i32,
+}
+
+impl Focusable {
+    /// Creates a [`Focusable`] with the given `tab_index`.
+    pub const fn new(tab_index: i32) -> Self {
+        Self { tab_index }
+    }
+}
+
+/// The UI node that currently has keyboard focus, if any.
+///
+/// Write to this resource to focus a node programmatically.
+/// The focused node is marked with the [`Focused`] component.
+///
+/// With the `bevy_text` feature, the focused node is also reported to assistive technologies
+/// through [`bevy_a11y::Focus`], if it has an [`AccessibilityNode`](bevy_a11y::AccessibilityNode).
+#[derive(Resource, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct FocusedEntity(pub Option<Entity>);
+
+/// Marker component for the UI node referenced by [`FocusedEntity`].
+///
+/// Use it to render a focus ring, for example.
+///
+/// Updated by [`update_focused_system`].
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Focused;
+
+/// Moves the keyboard focus between [`Focusable`] nodes when Tab or Shift+Tab is pressed.
+///
+/// The focus wraps around at both ends of the tab order. Hidden nodes are skipped.
+pub fn tab_navigation_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    ui_stack: Res<UiStack>,
+    mut focused_entity: ResMut<FocusedEntity>,
+    focusable_query: Query<(&Focusable, Option<&ViewVisibility>)>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    let backwards = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let tab_order = tab_order(&ui_stack, &focusable_query);
+    if tab_order.is_empty() {
+        return;
+    }
+
+    let current = focused_entity
+        .0
+        .and_then(|focused| tab_order.iter().position(|entity| *entity == focused));
+    let next = match (current, backwards) {
+        (Some(index), false) => (index + 1) % tab_order.len(),
+        (Some(index), true) => (index + tab_order.len() - 1) % tab_order.len(),
+        (None, false) => 0,
+        (None, true) => tab_order.len() - 1,
+    };
+    focused_entity.0 = Some(tab_order[next]);
+}
+
+/// The visible [`Focusable`] nodes ordered by their `tab_index`, then by their order in the [`UiStack`].
+fn tab_order(
+    ui_stack: &UiStack,
+    focusable_query: &Query<(&Focusable, Option<&ViewVisibility>)>,
+) -> Vec<Entity> {
+    let mut tab_order = ui_stack
+        .uinodes
+        .iter()
+        .filter_map(|entity| {
+            let (focusable, view_visibility) = focusable_query.get(*entity).ok()?;
+            (focusable.tab_index >= 0 && view_visibility.map_or(true, |v| v.get()))
+                .then_some((focusable.tab_index, *entity))
+        })
+        .collect::<Vec<_>>();
+    // the sort is stable, so nodes with the same tab index keep their stack order
+    tab_order.sort_by_key(|(tab_index, _)| *tab_index);
+    tab_order.into_iter().map(|(_, entity)| entity).collect()
+}
+
+/// How far the left stick has to be tilted to move the focus
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// Scores how good of a target `to` is when moving the focus from `from` in `direction`.
+///
+/// `direction` is in UI coordinates, so `Vec2::NEG_Y` points up.
+/// Returns `None` if `to` isn't in the given direction, otherwise lower scores are better.
+/// The score is the distance between the centers of the rects along `direction`,
+/// plus twice the distance perpendicular to it, preferring nodes that are aligned with `from`.
+pub fn directional_navigation_score(from: Rect, to: Rect, direction: Vec2) -> Option<f32> {
+    let direction = direction.try_normalize()?;
+    let offset = to.center() - from.center();
+    let along = offset.dot(direction);
+    (along > 0.).then(|| along + 2. * offset.perp_dot(direction).abs())
+}
+
+/// Finds the best candidate to move the focus to from the `current` rect in `direction`,
+/// using [`directional_navigation_score`].
+///
+/// This is what [`gamepad_navigation_system`] uses to pick the next focused node.
+/// Ties are broken in favor of the earliest candidate.
+pub fn find_in_direction(
+    current: Rect,
+    direction: Vec2,
+    candidates: impl IntoIterator<Item = (Entity, Rect)>,
+) -> Option<Entity> {
+    candidates
+        .into_iter()
+        .filter_map(|(entity, rect)| {
+            directional_navigation_score(current, rect, direction).map(|score| (score, entity))
+        })
+        .fold(
+            None,
+            |best: Option<(f32, Entity)>, (score, entity)| match best {
+                Some((best_score, _)) if best_score <= score => best,
+                _ => Some((score, entity)),
+            },
+        )
+        .map(|(_, entity)| entity)
+}
+
+/// Moves the focus between [`Focusable`] nodes with the D-pad or the left stick of any gamepad,
+/// to the nearest node in the pressed direction.
+///
+/// If no node is focused, the first node of the tab order is focused instead.
+/// Hidden and fully clipped nodes are skipped.
+///
+/// Pressing [`GamepadButtonType::South`] sets the [`Interaction`] of the focused node to
+/// [`Interaction::Pressed`] and releasing it resets it to [`Interaction::None`],
+/// so handlers written for the mouse also work with a gamepad.
+#[allow(clippy::too_many_arguments)]
+pub fn gamepad_navigation_system(
+    mut stick_directions: Local<HashMap<Gamepad, Vec2>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    ui_stack: Res<UiStack>,
+    mut focused_entity: ResMut<FocusedEntity>,
+    focusable_query: Query<(&Focusable, Option<&ViewVisibility>)>,
+    node_query: Query<(&Node, &GlobalTransform, Option<&CalculatedClip>)>,
+    mut interaction_query: Query<&mut Interaction, With<Focusable>>,
+) {
+    let mut direction = Vec2::ZERO;
+    for button in gamepad_buttons.get_just_pressed() {
+        direction += match button.button_type {
+            GamepadButtonType::DPadUp => Vec2::NEG_Y,
+            GamepadButtonType::DPadDown => Vec2::Y,
+            GamepadButtonType::DPadLeft => Vec2::NEG_X,
+            GamepadButtonType::DPadRight => Vec2::X,
+            _ => Vec2::ZERO,
+        };
+    }
+
+    // only move once each time the stick is tilted in a new direction
+    for axis in gamepad_axes.devices() {
+        if axis.axis_type != GamepadAxisType::LeftStickX {
+            continue;
+        }
+        let stick_y = GamepadAxis::new(axis.gamepad, GamepadAxisType::LeftStickY);
+        let stick = Vec2::new(
+            gamepad_axes.get(*axis).unwrap_or_default(),
+            // gamepad axes point up, UI coordinates point down
+            -gamepad_axes.get(stick_y).unwrap_or_default(),
+        );
+        let stick_direction = if stick.length() < STICK_THRESHOLD {
+            Vec2::ZERO
+        } else if stick.x.abs() > stick.y.abs() {
+            Vec2::new(stick.x.signum(), 0.)
+        } else {
+            Vec2::new(0., stick.y.signum())
+        };
+        let previous = stick_directions.insert(axis.gamepad, stick_direction);
+        if previous != Some(stick_direction) {
+            direction += stick_direction;
+        }
+    }
+
+    if direction != Vec2::ZERO {
+        let visible_rect = |entity: Entity| {
+            let (node, global_transform, calculated_clip) = node_query.get(entity).ok()?;
+            let node_rect = node.logical_rect(global_transform);
+            let visible_rect = calculated_clip
+                .map(|clip| node_rect.intersect(clip.clip))
+                .unwrap_or(node_rect);
+            (!visible_rect.is_empty()).then_some(visible_rect)
+        };
+        let candidates = tab_order(&ui_stack, &focusable_query)
+            .into_iter()
+            .filter_map(|entity| Some((entity, visible_rect(entity)?)))
+            .collect::<Vec<_>>();
+        let current = focused_entity
+            .0
+            .and_then(|focused| Some((focused, visible_rect(focused)?)));
+        let next = match current {
+            Some((focused, current_rect)) => find_in_direction(
+                current_rect,
+                direction,
+                candidates
+                    .into_iter()
+                    .filter(|(entity, _)| *entity != focused),
+            ),
+            None => candidates.first().map(|(entity, _)| *entity),
+        };
+        if next.is_some() {
+            focused_entity.0 = next;
+        }
+    }
+
+    let Some(focused) = focused_entity.0 else {
+        return;
+    };
+    let Ok(mut interaction) = interaction_query.get_mut(focused) else {
+        return;
+    };
+    let south_pressed = gamepad_buttons
+        .get_just_pressed()
+        .any(|button| button.button_type == GamepadButtonType::South);
+    let south_released = gamepad_buttons
+        .get_just_released()
+        .any(|button| button.button_type == GamepadButtonType::South);
+    if south_pressed {
+        interaction.set_if_neq(Interaction::Pressed);
+    } else if south_released {
+        interaction.set_if_neq(Interaction::None);
+    }
+}
+
+/// Moves the [`Focused`] marker to the node referenced by [`FocusedEntity`].
+pub fn update_focused_system(
+    mut commands: Commands,
+    focused_entity: Res<FocusedEntity>,
+    focused_query: Query<Entity, With<Focused>>,
+) {
+    if !focused_entity.is_changed() {
+        return;
+    }
+    for entity in &focused_query {
+        if Some(entity) != focused_entity.0 {
+            commands.entity(entity).remove::<Focused>();
+        }
+    }
+    if let Some(entity) = focused_entity.0 {
+        if !focused_query.contains(entity) {
+            if let Some(mut entity_commands) = commands.get_entity(entity) {
+                entity_commands.try_insert(Focused);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        entity::Entity,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_input::{
+        gamepad::{Gamepad, GamepadAxis, GamepadButton, GamepadButtonType},
+        keyboard::KeyCode,
+        Axis, ButtonInput,
+    };
+    use bevy_math::{Rect, Vec2};
+    use bevy_render::view::ViewVisibility;
+    use bevy_transform::components::GlobalTransform;
+    use bevy_utils::default;
+
+    use crate::{CalculatedClip, Interaction, Node, UiStack};
+
+    use super::{
+        find_in_direction, gamepad_navigation_system, tab_navigation_system, update_focused_system,
+        Focusable, Focused, FocusedEntity,
+    };
+
+    fn setup_navigation_test_world() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<UiStack>();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((tab_navigation_system, update_focused_system).chain());
+
+        (world, schedule)
+    }
+
+    fn spawn_focusable(world: &mut World, tab_index: i32, visible: bool) -> Entity {
+        let mut view_visibility = ViewVisibility::HIDDEN;
+        if visible {
+            view_visibility.set();
+        }
+        let entity = world
+            .spawn((Focusable::new(tab_index), view_visibility))
+            .id();
+        world.resource_mut::<UiStack>().uinodes.push(entity);
+        entity
+    }
+
+    fn press_tab(world: &mut World, schedule: &mut Schedule, shift: bool) -> Option<Entity> {
+        let mut input = world.resource_mut::<ButtonInput<KeyCode>>();
+        input.reset_all();
+        if shift {
+            input.press(KeyCode::ShiftLeft);
+        }
+        input.press(KeyCode::Tab);
+        schedule.run(world);
+        world.resource::<FocusedEntity>().0
+    }
+
+    #[test]
+    fn tab_follows_tab_index_then_stack_order_and_wraps() {
+        let (mut world, mut schedule) = setup_navigation_test_world();
+        let a = spawn_focusable(&mut world, 1, true);
+        let b = spawn_focusable(&mut world, 0, true);
+        let c = spawn_focusable(&mut world, 0, true);
+        let _hidden = spawn_focusable(&mut world, 0, false);
+        let _skipped = spawn_focusable(&mut world, -1, true);
+
+        assert_eq!(press_tab(&mut world, &mut schedule, false), Some(b));
+        assert_eq!(press_tab(&mut world, &mut schedule, false), Some(c));
+        assert_eq!(press_tab(&mut world, &mut schedule, false), Some(a));
+        assert_eq!(press_tab(&mut world, &mut schedule, false), Some(b));
+        assert_eq!(press_tab(&mut world, &mut schedule, true), Some(a));
+        assert_eq!(press_tab(&mut world, &mut schedule, true), Some(c));
+    }
+
+    #[test]
+    fn focused_marker_follows_focused_entity() {
+        let (mut world, mut schedule) = setup_navigation_test_world();
+        let a = spawn_focusable(&mut world, 0, true);
+        let b = spawn_focusable(&mut world, -1, true);
+
+        press_tab(&mut world, &mut schedule, false);
+        assert!(world.get::<Focused>(a).is_some());
+
+        // programmatic focus
+        world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        world.resource_mut::<FocusedEntity>().0 = Some(b);
+        schedule.run(&mut world);
+        assert!(world.get::<Focused>(a).is_none());
+        assert!(world.get::<Focused>(b).is_some());
+
+        // Shift+Tab from a node outside of the tab order starts from the end
+        assert_eq!(press_tab(&mut world, &mut schedule, true), Some(a));
+    }
+
+    #[test]
+    fn find_in_direction_prefers_aligned_nodes() {
+        let current = Rect::new(0., 0., 10., 10.);
+        let candidates = [
+            (Entity::from_raw(0), Rect::new(-20., 0., -10., 10.)),
+            (Entity::from_raw(1), Rect::new(30., 0., 40., 10.)),
+            (Entity::from_raw(2), Rect::new(15., 20., 25., 30.)),
+        ];
+        assert_eq!(
+            find_in_direction(current, Vec2::X, candidates),
+            Some(Entity::from_raw(1))
+        );
+        assert_eq!(
+            find_in_direction(current, Vec2::NEG_X, candidates),
+            Some(Entity::from_raw(0))
+        );
+        assert_eq!(
+            find_in_direction(current, Vec2::Y, candidates),
+            Some(Entity::from_raw(2))
+        );
+        assert_eq!(find_in_direction(current, Vec2::NEG_Y, candidates), None);
+    }
+
+    #[test]
+    fn gamepad_moves_focus_and_presses() {
+        let (mut world, mut schedule) = setup_navigation_test_world();
+        world.init_resource::<ButtonInput<GamepadButton>>();
+        world.init_resource::<Axis<GamepadAxis>>();
+        schedule.add_systems(gamepad_navigation_system);
+
+        let spawn_node = |world: &mut World, center: Vec2, clip: Option<Rect>| {
+            let entity = spawn_focusable(world, 0, true);
+            world.entity_mut(entity).insert((
+                Node {
+                    calculated_size: Vec2::splat(10.),
+                    ..default()
+                },
+                GlobalTransform::from_translation(center.extend(0.)),
+                Interaction::None,
+            ));
+            if let Some(clip) = clip {
+                world.entity_mut(entity).insert(CalculatedClip { clip });
+            }
+            entity
+        };
+        let a = spawn_node(&mut world, Vec2::new(5., 5.), None);
+        let _clipped = spawn_node(&mut world, Vec2::new(25., 5.), Some(Rect::default()));
+        let b = spawn_node(&mut world, Vec2::new(45., 5.), None);
+
+        let gamepad = Gamepad::new(0);
+        let mut press = |world: &mut World, button_type: GamepadButtonType| {
+            let mut input = world.resource_mut::<ButtonInput<GamepadButton>>();
+            input.reset_all();
+            input.press(GamepadButton::new(gamepad, button_type));
+            schedule.run(world);
+        };
+
+        press(&mut world, GamepadButtonType::DPadRight);
+        assert_eq!(world.resource::<FocusedEntity>().0, Some(a));
+        press(&mut world, GamepadButtonType::DPadRight);
+        assert_eq!(world.resource::<FocusedEntity>().0, Some(b));
+        press(&mut world, GamepadButtonType::South);
+        assert_eq!(world.get::<Interaction>(b), Some(&Interaction::Pressed));
+        assert_eq!(world.get::<Interaction>(a), Some(&Interaction::None));
+    }
+}
@@ -3,16 +3,23 @@
 //! This module contains basic node bundles used to build UIs
 
 #[cfg(feature = "bevy_text")]
-use crate::widget::TextFlags;
+use crate::widget::{Dropdown, DropdownStyle, TextFlags, TextInput};
 use crate::{
-    widget::{Button, UiImageSize},
-    BackgroundColor, BorderColor, BorderRadius, ContentSize, FocusPolicy, Interaction, Node, Style,
+    widget::{
+        Button, Checkbox, CheckboxImages, ScrollView, Scrollbar, Slider, Toggle, ToggleColors,
+        UiImageSize,
+    },
+    BackgroundColor, BorderColor, BorderRadius, ContentSize, FlexDirection, FocusPolicy, Focusable,
+    Interaction, Node, Overflow, RelativeCursorPosition, ScrollPosition, ScrolledOver, Style,
     UiImage, UiMaterial, ZIndex,
 };
 use bevy_asset::Handle;
 use bevy_color::Color;
 use bevy_ecs::bundle::Bundle;
-use bevy_render::view::{InheritedVisibility, ViewVisibility, Visibility};
+use bevy_render::{
+    texture::Image,
+    view::{InheritedVisibility, ViewVisibility, Visibility},
+};
 use bevy_sprite::TextureAtlas;
 #[cfg(feature = "bevy_text")]
 use bevy_text::{BreakLineOn, JustifyText, Text, TextLayoutInfo, TextSection, TextStyle};
@@ -78,6 +85,46 @@ impl Default for NodeBundle {
     }
 }
 
+impl NodeBundle {
+    /// Create a [`NodeBundle`] laying out its children from top to bottom,
+    /// with [`FlexDirection::Column`].
+    ///
+    /// [`NodeBundle::with_style`] replaces the whole [`Style`], including the direction.
+    pub fn column() -> Self {
+        Self::from_flex_direction(FlexDirection::Column)
+    }
+
+    /// Create a [`NodeBundle`] laying out its children from left to right,
+    /// with [`FlexDirection::Row`].
+    ///
+    /// [`NodeBundle::with_style`] replaces the whole [`Style`], including the direction.
+    pub fn row() -> Self {
+        Self::from_flex_direction(FlexDirection::Row)
+    }
+
+    fn from_flex_direction(flex_direction: FlexDirection) -> Self {
+        Self {
+            style: Style {
+                flex_direction,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Returns this [`NodeBundle`] with a new [`Style`].
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns this [`NodeBundle`] with a new [`BackgroundColor`].
+    pub const fn with_background_color(mut self, color: Color) -> Self {
+        self.background_color = BackgroundColor(color);
+        self
+    }
+}
+
 /// A UI node that is an image
 ///
 /// # Extra behaviours
@@ -121,6 +168,46 @@ pub struct ImageBundle {
     pub z_index: ZIndex,
 }
 
+impl ImageBundle {
+    /// Create an [`ImageBundle`] displaying the given image.
+    ///
+    /// ```
+    /// # use bevy_asset::AssetServer;
+    /// # use bevy_color::Color;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ui::{node_bundles::ImageBundle, Style, Val};
+    /// fn spawn_icon(mut commands: Commands, asset_server: Res<AssetServer>) {
+    ///     commands.spawn(
+    ///         ImageBundle::from_image(asset_server.load("branding/icon.png"))
+    ///             .with_style(Style {
+    ///                 width: Val::Px(64.),
+    ///                 ..Default::default()
+    ///             })
+    ///             .with_color(Color::srgb(0.8, 0.8, 1.)),
+    ///     );
+    /// }
+    /// # bevy_ecs::system::assert_is_system(spawn_icon);
+    /// ```
+    pub fn from_image(texture: Handle<Image>) -> Self {
+        Self {
+            image: UiImage::new(texture),
+            ..Default::default()
+        }
+    }
+
+    /// Returns this [`ImageBundle`] with a new [`Style`].
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns this [`ImageBundle`] with the image tinted by `color`, see [`UiImage::color`].
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.image.color = color;
+        self
+    }
+}
+
 /// A UI node that is a texture atlas sprite
 ///
 /// # Extra behaviours
@@ -285,6 +372,94 @@ impl TextBundle {
     }
 }
 
+/// A UI node that is an editable text input
+///
+/// See [`TextInput`] for how the [`Text`] is displayed.
+#[cfg(feature = "bevy_text")]
+#[derive(Bundle, Debug)]
+pub struct TextInputBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// The edited text and caret position
+    pub text_input: TextInput,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    pub style: Style,
+    /// Contains the displayed text of the node, its first section giving the style of the text
+    pub text: Text,
+    /// Text layout information
+    pub text_layout_info: TextLayoutInfo,
+    /// Text system flags
+    pub text_flags: TextFlags,
+    /// The calculated size based on the given image
+    pub calculated_size: ContentSize,
+    /// Describes whether and how the input has been interacted with by the input
+    pub interaction: Interaction,
+    /// The position of the cursor over the input, used to place the caret and select text
+    pub relative_cursor_position: RelativeCursorPosition,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// Allows the input to be focused with the keyboard
+    pub focusable: Focusable,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `TextInputBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+    /// The background color that will fill the containing node
+    pub background_color: BackgroundColor,
+}
+
+#[cfg(feature = "bevy_text")]
+impl Default for TextInputBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            text_input: Default::default(),
+            style: Default::default(),
+            text: Default::default(),
+            text_layout_info: Default::default(),
+            text_flags: Default::default(),
+            calculated_size: Default::default(),
+            interaction: Default::default(),
+            relative_cursor_position: Default::default(),
+            focus_policy: FocusPolicy::Block,
+            focusable: Default::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+            background_color: BackgroundColor(Color::NONE),
+        }
+    }
+}
+
+#[cfg(feature = "bevy_text")]
+impl TextInputBundle {
+    /// Create a [`TextInputBundle`] showing `placeholder` until text is typed, with the given text style.
+    pub fn new(placeholder: impl Into<String>, style: TextStyle) -> Self {
+        Self {
+            text_input: TextInput::new(placeholder),
+            text: Text::from_section("", style),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(feature = "bevy_text")]
 impl<I> From<I> for TextBundle
 where
@@ -361,6 +536,409 @@ impl Default for ButtonBundle {
     }
 }
 
+/// A UI node that is a checkbox
+///
+/// The displayed image follows the [`Checkbox`] state, see [`CheckboxImages`] to customize it.
+#[derive(Bundle, Clone, Debug)]
+pub struct CheckboxBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// Whether the checkbox is checked
+    pub checkbox: Checkbox,
+    /// The images displayed when the checkbox is checked and unchecked
+    pub images: CheckboxImages,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    pub style: Style,
+    /// Describes whether and how the checkbox has been interacted with by the input
+    pub interaction: Interaction,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// Allows the checkbox to be focused and toggled with the keyboard
+    pub focusable: Focusable,
+    /// The color of the Node's border
+    pub border_color: BorderColor,
+    /// The border radius of the node
+    pub border_radius: BorderRadius,
+    /// The image of the node, replaced by one of the [`CheckboxImages`] when the checkbox changes
+    pub image: UiImage,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `CheckboxBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+impl Default for CheckboxBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            checkbox: Default::default(),
+            images: Default::default(),
+            style: Default::default(),
+            interaction: Default::default(),
+            focus_policy: FocusPolicy::Block,
+            focusable: Default::default(),
+            border_color: BorderColor(Color::WHITE),
+            border_radius: BorderRadius::default(),
+            image: Default::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
+/// A UI node that is a scroll view, clipping its children and scrolling them with the mouse wheel
+#[derive(Bundle, Clone, Debug)]
+pub struct ScrollViewBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// How far the children are scrolled
+    pub scroll_view: ScrollView,
+    /// The scroll offset applied to the children, driven by the [`ScrollView`]
+    pub scroll_position: ScrollPosition,
+    /// The mouse wheel scrolling over the node
+    pub scrolled_over: ScrolledOver,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    ///
+    /// Its [`Style::overflow`] clips and scrolls the children on both axes by default.
+    pub style: Style,
+    /// The background color, which serves as a "fill" for this node
+    pub background_color: BackgroundColor,
+    /// The color of the Node's border
+    pub border_color: BorderColor,
+    /// The border radius of the node
+    pub border_radius: BorderRadius,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `ScrollViewBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+impl Default for ScrollViewBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            scroll_view: Default::default(),
+            scroll_position: Default::default(),
+            scrolled_over: Default::default(),
+            style: Style {
+                overflow: Overflow::scroll(),
+                ..Default::default()
+            },
+            background_color: Color::NONE.into(),
+            border_color: Color::NONE.into(),
+            border_radius: BorderRadius::default(),
+            focus_policy: Default::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
+/// A UI node that is the track of a scrollbar for a [`ScrollView`]
+///
+/// Spawn a child with a [`ScrollbarThumb`](crate::widget::ScrollbarThumb), an [`Interaction`] and a
+/// [`Draggable`](crate::Draggable) to show and drag the scrolling of the target.
+#[derive(Bundle, Clone, Debug)]
+pub struct ScrollbarBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// The orientation and target of the scrollbar
+    pub scrollbar: Scrollbar,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    pub style: Style,
+    /// Describes whether and how the track has been interacted with by the input
+    pub interaction: Interaction,
+    /// The position of the cursor along the track, used to page through the target
+    pub relative_cursor_position: RelativeCursorPosition,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The background color of the track
+    pub background_color: BackgroundColor,
+    /// The color of the Node's border
+    pub border_color: BorderColor,
+    /// The border radius of the node
+    pub border_radius: BorderRadius,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `ScrollbarBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+impl ScrollbarBundle {
+    /// Create a [`ScrollbarBundle`] for the given scrollbar, with the other fields set to their defaults.
+    pub fn new(scrollbar: Scrollbar) -> Self {
+        Self {
+            node: Default::default(),
+            scrollbar,
+            style: Default::default(),
+            interaction: Default::default(),
+            relative_cursor_position: Default::default(),
+            focus_policy: FocusPolicy::Block,
+            background_color: Default::default(),
+            border_color: BorderColor(Color::NONE),
+            border_radius: BorderRadius::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
+/// A UI node that is a slider track
+///
+/// Spawn a child with a [`SliderHandle`](crate::widget::SliderHandle) to show the value.
+#[derive(Bundle, Clone, Debug)]
+pub struct SliderBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// The value and range of the slider
+    pub slider: Slider,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    pub style: Style,
+    /// Describes whether and how the slider has been interacted with by the input
+    pub interaction: Interaction,
+    /// The position of the cursor along the track, used to set the value
+    pub relative_cursor_position: RelativeCursorPosition,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The background color of the track
+    pub background_color: BackgroundColor,
+    /// The color of the Node's border
+    pub border_color: BorderColor,
+    /// The border radius of the node
+    pub border_radius: BorderRadius,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `SliderBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+impl Default for SliderBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            slider: Default::default(),
+            style: Default::default(),
+            interaction: Default::default(),
+            relative_cursor_position: Default::default(),
+            focus_policy: FocusPolicy::Block,
+            background_color: Default::default(),
+            border_color: BorderColor(Color::NONE),
+            border_radius: BorderRadius::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
+/// A UI node that is a toggle switch
+///
+/// Spawn a child with a [`ToggleKnob`](crate::widget::ToggleKnob) to slide between the ends of the node.
+#[derive(Bundle, Clone, Debug)]
+pub struct ToggleBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// Whether the toggle is on
+    pub toggle: Toggle,
+    /// The background colors of the node when the toggle is on and off
+    pub colors: ToggleColors,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    pub style: Style,
+    /// Describes whether and how the toggle has been interacted with by the input
+    pub interaction: Interaction,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// Allows the toggle to be focused and flipped with the keyboard
+    pub focusable: Focusable,
+    /// The background color, blended between the [`ToggleColors`]
+    pub background_color: BackgroundColor,
+    /// The color of the Node's border
+    pub border_color: BorderColor,
+    /// The border radius of the node
+    pub border_radius: BorderRadius,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `ToggleBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+impl Default for ToggleBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            toggle: Default::default(),
+            colors: Default::default(),
+            style: Default::default(),
+            interaction: Default::default(),
+            focus_policy: FocusPolicy::Block,
+            focusable: Default::default(),
+            background_color: Default::default(),
+            border_color: BorderColor(Color::NONE),
+            border_radius: BorderRadius::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
+/// A UI node that is a dropdown
+///
+/// Add a [`DropdownLabel`](crate::widget::DropdownLabel) text child to display the selected option.
+#[cfg(feature = "bevy_text")]
+#[derive(Bundle, Clone, Debug)]
+pub struct DropdownBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// The options and selection of the dropdown
+    pub dropdown: Dropdown,
+    /// How the list of options is displayed
+    pub dropdown_style: DropdownStyle,
+    /// Styles which control the layout (size and position) of the node and its children
+    /// In some cases these styles also affect how the node drawn/painted.
+    pub style: Style,
+    /// Describes whether and how the dropdown has been interacted with by the input
+    pub interaction: Interaction,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The background color, which serves as a "fill" for this node
+    pub background_color: BackgroundColor,
+    /// The color of the Node's border
+    pub border_color: BorderColor,
+    /// The border radius of the node
+    pub border_radius: BorderRadius,
+    /// The transform of the node
+    ///
+    /// This component is automatically managed by the UI layout system.
+    /// To alter the position of the `DropdownBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This component is automatically updated by the [`TransformPropagate`](`bevy_transform::TransformSystem::TransformPropagate`) systems.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Inherited visibility of an entity.
+    pub inherited_visibility: InheritedVisibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub view_visibility: ViewVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+#[cfg(feature = "bevy_text")]
+impl Default for DropdownBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            dropdown: Default::default(),
+            dropdown_style: Default::default(),
+            style: Default::default(),
+            interaction: Default::default(),
+            focus_policy: FocusPolicy::Block,
+            background_color: Default::default(),
+            border_color: BorderColor(Color::NONE),
+            border_radius: BorderRadius::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            inherited_visibility: Default::default(),
+            view_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
 /// A UI node that is rendered using a [`UiMaterial`]
 ///
 /// Adding a `BackgroundColor` component to an entity with this bundle will ignore the custom
@@ -9,7 +9,7 @@ use bevy_core_pipeline::{core_2d::Camera2d, core_3d::Camera3d};
 use bevy_hierarchy::Parent;
 use bevy_render::render_phase::ViewSortedRenderPhases;
 use bevy_render::{
-    render_phase::{PhaseItem, PhaseItemExtraIndex},
+    render_phase::{PhaseItem, PhaseItemExtraIndex, SortedRenderPhase},
     texture::GpuImage,
     view::ViewVisibility,
     ExtractSchedule, Render,
@@ -21,11 +21,13 @@ pub use ui_material_pipeline::*;
 
 use crate::graph::{NodeUi, SubGraphUi};
 use crate::{
-    texture_slice::ComputedTextureSlices, BackgroundColor, BorderColor, BorderRadius,
-    CalculatedClip, ContentSize, DefaultUiCamera, Node, Outline, Style, TargetCamera, UiImage,
-    UiScale, Val,
+    texture_slice::ComputedTextureSlices, BackgroundColor, BackgroundGradient, BorderColor,
+    BorderRadius, BoxShadow, CalculatedClip, ContentSize, DefaultUiCamera, GradientShape,
+    GradientTexture, ImageFit, Node, Outline, Style, TargetCamera, TargetUiScale, UiImage, Val,
 };
 
+#[cfg(feature = "bevy_text")]
+use crate::{widget::TextInput, TextOutline, TextShadow};
 use bevy_app::prelude::*;
 use bevy_asset::{load_internal_asset, AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::entity::{EntityHashMap, EntityHashSet};
@@ -66,6 +68,7 @@ pub const UI_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(130128470471
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub enum RenderUiSystem {
+    ExtractBoxShadows,
     ExtractBackgrounds,
     ExtractImages,
     ExtractBorders,
@@ -91,6 +94,7 @@ pub fn build_ui_render(app: &mut App) {
         .configure_sets(
             ExtractSchedule,
             (
+                RenderUiSystem::ExtractBoxShadows,
                 RenderUiSystem::ExtractBackgrounds,
                 RenderUiSystem::ExtractImages,
                 RenderUiSystem::ExtractBorders,
@@ -102,6 +106,7 @@ pub fn build_ui_render(app: &mut App) {
             ExtractSchedule,
             (
                 extract_default_ui_camera_view,
+                extract_uinode_box_shadows.in_set(RenderUiSystem::ExtractBoxShadows),
                 extract_uinode_background_colors.in_set(RenderUiSystem::ExtractBackgrounds),
                 extract_uinode_images.in_set(RenderUiSystem::ExtractImages),
                 extract_uinode_borders.in_set(RenderUiSystem::ExtractBorders),
@@ -154,6 +159,42 @@ fn get_ui_graph(render_app: &mut SubApp) -> RenderGraph {
 pub enum NodeType {
     Rect,
     Border,
+    /// A [`BoxShadow`], whose blur radius is stored in [`ExtractedUiNode::border`]
+    Shadow,
+    /// A [`BackgroundGradient`], whose colors are baked into [`ExtractedUiNode::image`]
+    Gradient(GradientShape),
+    /// A glyph of a text node, or one of the copies of it drawn for its effects
+    Text(TextLayer),
+}
+
+/// The layers the glyphs of a text node are drawn in, from bottom to top.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextLayer {
+    /// The highlight behind the selected glyphs of a [`TextInput`]
+    Selection,
+    /// A copy of a glyph drawn for a [`TextShadow`](crate::TextShadow)
+    Shadow,
+    /// A copy of a glyph drawn for a [`TextOutline`](crate::TextOutline)
+    Outline,
+    /// The glyph itself
+    Glyph,
+}
+
+impl NodeType {
+    /// Returns the depth at which a node of the given type is sorted, so that shadows are drawn
+    /// below their node but above the nodes before it in the [`UiStack`](crate::UiStack), and
+    /// glyphs are drawn above the background of their node in the order of their [`TextLayer`].
+    fn sort_depth(self, stack_index: u32) -> f32 {
+        let stack_index = stack_index as f32;
+        match self {
+            NodeType::Shadow => stack_index - 0.5,
+            NodeType::Rect | NodeType::Border | NodeType::Gradient(_) => stack_index,
+            NodeType::Text(TextLayer::Selection) => stack_index + 0.05,
+            NodeType::Text(TextLayer::Shadow) => stack_index + 0.1,
+            NodeType::Text(TextLayer::Outline) => stack_index + 0.2,
+            NodeType::Text(TextLayer::Glyph) => stack_index + 0.3,
+        }
+    }
 }
 
 pub struct ExtractedUiNode {
@@ -188,20 +229,24 @@ pub fn extract_uinode_background_colors(
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
     camera_query: Extract<Query<(Entity, &Camera)>>,
     default_ui_camera: Extract<DefaultUiCamera>,
-    ui_scale: Extract<Res<UiScale>>,
+    ui_scale: Extract<TargetUiScale>,
     uinode_query: Extract<
-        Query<(
-            Entity,
-            &Node,
-            &GlobalTransform,
-            &ViewVisibility,
-            Option<&CalculatedClip>,
-            Option<&TargetCamera>,
-            &BackgroundColor,
-            Option<&BorderRadius>,
-            &Style,
-            Option<&Parent>,
-        )>,
+        Query<
+            (
+                Entity,
+                &Node,
+                &GlobalTransform,
+                &ViewVisibility,
+                Option<&CalculatedClip>,
+                Option<&TargetCamera>,
+                Option<&BackgroundColor>,
+                Option<(&BackgroundGradient, &GradientTexture)>,
+                Option<&BorderRadius>,
+                &Style,
+                Option<&Parent>,
+            ),
+            Or<(With<BackgroundColor>, With<GradientTexture>)>,
+        >,
     >,
     node_query: Extract<Query<&Node>>,
 ) {
@@ -213,6 +258,7 @@ pub fn extract_uinode_background_colors(
         clip,
         camera,
         background_color,
+        gradient,
         border_radius,
         style,
         parent,
@@ -223,19 +269,37 @@ pub fn extract_uinode_background_colors(
             continue;
         };
 
-        // Skip invisible backgrounds
-        if !view_visibility.get() || background_color.0.is_fully_transparent() {
+        if !view_visibility.get() {
             continue;
         }
 
-        let ui_logical_viewport_size = camera_query
+        // A gradient is drawn instead of the background color
+        let (color, image, node_type) = match (gradient, background_color) {
+            (Some((gradient, texture)), _) => (
+                LinearRgba::WHITE,
+                texture.0.id(),
+                NodeType::Gradient(gradient.shape),
+            ),
+            (None, Some(background_color)) if !background_color.0.is_fully_transparent() => (
+                background_color.0.into(),
+                AssetId::default(),
+                NodeType::Rect,
+            ),
+            // Skip invisible backgrounds
+            _ => continue,
+        };
+
+        let camera = camera_query
             .get(camera_entity)
             .ok()
-            .and_then(|(_, c)| c.logical_viewport_size())
+            .map(|(_, camera)| camera);
+        let ui_scale = ui_scale.camera(camera);
+        let ui_logical_viewport_size = camera
+            .and_then(Camera::logical_viewport_size)
             .unwrap_or(Vec2::ZERO)
             // The logical window resolution returned by `Window` only takes into account the window scale factor and not `UiScale`,
             // so we have to divide by `UiScale` to get the size of the UI viewport.
-            / ui_scale.0;
+            / ui_scale;
 
         // Both vertical and horizontal percentage border values are calculated based on the width of the parent node
         // <https://developer.mozilla.org/en-US/docs/Web/CSS/border-width>
@@ -259,7 +323,7 @@ pub fn extract_uinode_background_colors(
                 border_radius,
                 uinode.size(),
                 ui_logical_viewport_size,
-                ui_scale.0,
+                ui_scale,
             )
         } else {
             [0.; 4]
@@ -270,20 +334,114 @@ pub fn extract_uinode_background_colors(
             ExtractedUiNode {
                 stack_index: uinode.stack_index,
                 transform: transform.compute_matrix(),
-                color: background_color.0.into(),
+                color,
                 rect: Rect {
                     min: Vec2::ZERO,
                     max: uinode.calculated_size,
                 },
                 clip: clip.map(|clip| clip.clip),
-                image: AssetId::default(),
+                image,
                 atlas_size: None,
                 flip_x: false,
                 flip_y: false,
                 camera_entity,
                 border,
                 border_radius,
-                node_type: NodeType::Rect,
+                node_type,
+            },
+        );
+    }
+}
+
+pub fn extract_uinode_box_shadows(
+    mut commands: Commands,
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    camera_query: Extract<Query<(Entity, &Camera)>>,
+    default_ui_camera: Extract<DefaultUiCamera>,
+    ui_scale: Extract<TargetUiScale>,
+    uinode_query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            &ViewVisibility,
+            Option<&CalculatedClip>,
+            Option<&TargetCamera>,
+            &BoxShadow,
+            Option<&BorderRadius>,
+        )>,
+    >,
+) {
+    for (uinode, transform, view_visibility, clip, camera, box_shadow, border_radius) in
+        &uinode_query
+    {
+        let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
+        else {
+            continue;
+        };
+
+        // Skip invisible shadows
+        if !view_visibility.get() || box_shadow.color.is_fully_transparent() {
+            continue;
+        }
+
+        let blur = box_shadow.blur.max(0.);
+        let shadow_size = uinode.size() + 2. * box_shadow.spread;
+        if shadow_size.cmple(Vec2::ZERO).any() {
+            continue;
+        }
+
+        let camera = camera_query
+            .get(camera_entity)
+            .ok()
+            .map(|(_, camera)| camera);
+        let ui_scale = ui_scale.camera(camera);
+        let ui_logical_viewport_size = camera
+            .and_then(Camera::logical_viewport_size)
+            .unwrap_or(Vec2::ZERO)
+            // The logical window resolution returned by `Window` only takes into account the window scale factor and not `UiScale`,
+            // so we have to divide by `UiScale` to get the size of the UI viewport.
+            / ui_scale;
+
+        let border_radius = if let Some(border_radius) = border_radius {
+            resolve_border_radius(
+                border_radius,
+                uinode.size(),
+                ui_logical_viewport_size,
+                ui_scale,
+            )
+        } else {
+            [0.; 4]
+        }
+        .map(|radius| {
+            if radius > 0. {
+                (radius + box_shadow.spread).max(0.)
+            } else {
+                0.
+            }
+        });
+
+        // The quad extends past the shadow by the blur radius, so that its edges can fade out
+        let transform =
+            transform.compute_matrix() * Mat4::from_translation(box_shadow.offset.extend(0.));
+        extracted_uinodes.uinodes.insert(
+            commands.spawn_empty().id(),
+            ExtractedUiNode {
+                stack_index: uinode.stack_index,
+                transform,
+                color: box_shadow.color.into(),
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: shadow_size + 2. * blur,
+                },
+                clip: clip.map(|clip| clip.clip),
+                image: AssetId::default(),
+                atlas_size: None,
+                flip_x: false,
+                flip_y: false,
+                camera_entity,
+                border: [blur; 4],
+                border_radius,
+                node_type: NodeType::Shadow,
             },
         );
     }
@@ -295,7 +453,8 @@ pub fn extract_uinode_images(
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
     camera_query: Extract<Query<(Entity, &Camera)>>,
     texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
-    ui_scale: Extract<Res<UiScale>>,
+    textures: Extract<Res<Assets<Image>>>,
+    ui_scale: Extract<TargetUiScale>,
     default_ui_camera: Extract<DefaultUiCamera>,
     uinode_query: Extract<
         Query<(
@@ -347,19 +506,34 @@ pub fn extract_uinode_images(
             continue;
         }
 
-        let (rect, atlas_size) = match atlas {
+        let texture_rect = match atlas {
             Some(atlas) => {
-                let Some(layout) = texture_atlases.get(&atlas.layout) else {
-                    // Atlas not present in assets resource (should this warn the user?)
+                let Some(texture_rect) = texture_atlases
+                    .get(&atlas.layout)
+                    .and_then(|layout| atlas_texture_rect(layout, atlas.index, image.rect))
+                else {
+                    // Atlas not present in assets resource, or index out of bounds (should this warn the user?)
                     continue;
                 };
-                let mut atlas_rect = layout.textures[atlas.index].as_rect();
-                let mut atlas_size = layout.size.as_vec2();
-                let scale = uinode.size() / atlas_rect.size();
-                atlas_rect.min *= scale;
-                atlas_rect.max *= scale;
-                atlas_size *= scale;
-                (atlas_rect, Some(atlas_size))
+                Some(texture_rect)
+            }
+            // the whole texture is stretched over the node without needing its size
+            None if image.rect.is_none() && image.fit == ImageFit::Fill => None,
+            None => textures.get(&image.texture).map(|texture| {
+                let texture_size = texture.size_f32();
+                (
+                    image
+                        .rect
+                        .unwrap_or(Rect::from_corners(Vec2::ZERO, texture_size)),
+                    texture_size,
+                )
+            }),
+        };
+        let (rect, atlas_size) = match texture_rect {
+            Some((texture_rect, texture_size)) => {
+                let (rect, texture_size) =
+                    fitted_image_rect(image.fit, uinode.size(), texture_rect, texture_size);
+                (rect, Some(texture_size))
             }
             None => (
                 Rect {
@@ -370,14 +544,17 @@ pub fn extract_uinode_images(
             ),
         };
 
-        let ui_logical_viewport_size = camera_query
+        let camera = camera_query
             .get(camera_entity)
             .ok()
-            .and_then(|(_, c)| c.logical_viewport_size())
+            .map(|(_, camera)| camera);
+        let ui_scale = ui_scale.camera(camera);
+        let ui_logical_viewport_size = camera
+            .and_then(Camera::logical_viewport_size)
             .unwrap_or(Vec2::ZERO)
             // The logical window resolution returned by `Window` only takes into account the window scale factor and not `UiScale`,
             // so we have to divide by `UiScale` to get the size of the UI viewport.
-            / ui_scale.0;
+            / ui_scale;
 
         // Both vertical and horizontal percentage border values are calculated based on the width of the parent node
         // <https://developer.mozilla.org/en-US/docs/Web/CSS/border-width>
@@ -401,7 +578,7 @@ pub fn extract_uinode_images(
                 border_radius,
                 uinode.size(),
                 ui_logical_viewport_size,
-                ui_scale.0,
+                ui_scale,
             )
         } else {
             [0.; 4]
@@ -428,6 +605,73 @@ pub fn extract_uinode_images(
     }
 }
 
+/// Returns the area of the texture of `layout` drawn by an image node showing the section at `index`,
+/// and the size of the whole texture, or `None` if there is no section at `index`.
+///
+/// `image_rect` is the [`UiImage::rect`] of the node, relative to the section.
+fn atlas_texture_rect(
+    layout: &TextureAtlasLayout,
+    index: usize,
+    image_rect: Option<Rect>,
+) -> Option<(Rect, Vec2)> {
+    let atlas_rect = layout.textures.get(index)?.as_rect();
+    Some((
+        image_rect.map_or(atlas_rect, |rect| {
+            Rect::from_corners(atlas_rect.min + rect.min, atlas_rect.min + rect.max)
+        }),
+        layout.size.as_vec2(),
+    ))
+}
+
+/// Returns the `rect` and `atlas_size` of the [`ExtractedUiNode`] drawing the `texture_rect` area
+/// of a texture of size `texture_size` in a node of size `node_size`, according to `fit`.
+///
+/// The size of the returned rect is the size of the drawn quad, which is centered in the node.
+/// The texture is scaled so that the area of it displayed by the quad is the returned rect.
+fn fitted_image_rect(
+    fit: ImageFit,
+    node_size: Vec2,
+    texture_rect: Rect,
+    texture_size: Vec2,
+) -> (Rect, Vec2) {
+    let source_size = texture_rect.size();
+    let (quad_size, source_rect) = if source_size.cmple(Vec2::ZERO).any() {
+        (node_size, texture_rect)
+    } else {
+        match fit {
+            ImageFit::Fill => (node_size, texture_rect),
+            ImageFit::Contain => {
+                let scale = (node_size / source_size).min_element();
+                (source_size * scale, texture_rect)
+            }
+            ImageFit::Cover => {
+                let scale = (node_size / source_size).max_element();
+                let visible_size = node_size / scale;
+                (
+                    node_size,
+                    Rect::from_center_size(texture_rect.center(), visible_size),
+                )
+            }
+            ImageFit::None => {
+                let visible_size = source_size.min(node_size);
+                (
+                    visible_size,
+                    Rect::from_center_size(texture_rect.center(), visible_size),
+                )
+            }
+        }
+    };
+    // scale the texture so that the displayed area covers the quad
+    let scale = quad_size / source_rect.size();
+    (
+        Rect {
+            min: source_rect.min * scale,
+            max: source_rect.max * scale,
+        },
+        texture_size * scale,
+    )
+}
+
 pub(crate) fn resolve_border_thickness(value: Val, parent_width: f32, viewport_size: Vec2) -> f32 {
     match value {
         Val::Auto => 0.,
@@ -489,12 +733,13 @@ fn clamp_radius(
     ]
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn extract_uinode_borders(
     mut commands: Commands,
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
     camera_query: Extract<Query<(Entity, &Camera)>>,
     default_ui_camera: Extract<DefaultUiCamera>,
-    ui_scale: Extract<Res<UiScale>>,
+    ui_scale: Extract<TargetUiScale>,
     uinode_query: Extract<
         Query<
             (
@@ -541,14 +786,17 @@ pub fn extract_uinode_borders(
             continue;
         }
 
-        let ui_logical_viewport_size = camera_query
+        let camera = camera_query
             .get(camera_entity)
             .ok()
-            .and_then(|(_, c)| c.logical_viewport_size())
+            .map(|(_, camera)| camera);
+        let ui_scale = ui_scale.camera(camera);
+        let ui_logical_viewport_size = camera
+            .and_then(Camera::logical_viewport_size)
             .unwrap_or(Vec2::ZERO)
             // The logical window resolution returned by `Window` only takes into account the window scale factor and not `UiScale`,
             // so we have to divide by `UiScale` to get the size of the UI viewport.
-            / ui_scale.0;
+            / ui_scale;
 
         // Both vertical and horizontal percentage border values are calculated based on the width of the parent node
         // <https://developer.mozilla.org/en-US/docs/Web/CSS/border-width>
@@ -576,7 +824,7 @@ pub fn extract_uinode_borders(
             border_radius,
             node.size(),
             ui_logical_viewport_size,
-            ui_scale.0,
+            ui_scale,
         );
 
         let border_radius = clamp_radius(border_radius, node.size(), border.into());
@@ -720,19 +968,20 @@ pub struct DefaultCameraView(pub Entity);
 pub fn extract_default_ui_camera_view(
     mut commands: Commands,
     mut transparent_render_phases: ResMut<ViewSortedRenderPhases<TransparentUi>>,
-    ui_scale: Extract<Res<UiScale>>,
+    ui_scale: Extract<TargetUiScale>,
     query: Extract<Query<(Entity, &Camera), Or<(With<Camera2d>, With<Camera3d>)>>>,
     mut live_entities: Local<EntityHashSet>,
 ) {
     live_entities.clear();
 
-    let scale = ui_scale.0.recip();
     for (entity, camera) in &query {
         // ignore inactive cameras
         if !camera.is_active {
             continue;
         }
 
+        let scale = ui_scale.camera(Some(camera)).recip();
+
         if let (
             Some(logical_size),
             Some(URect {
@@ -786,13 +1035,14 @@ pub fn extract_default_ui_camera_view(
 }
 
 #[cfg(feature = "bevy_text")]
+#[allow(clippy::too_many_arguments)]
 pub fn extract_uinode_text(
     mut commands: Commands,
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
     camera_query: Extract<Query<(Entity, &Camera)>>,
     default_ui_camera: Extract<DefaultUiCamera>,
     texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
-    ui_scale: Extract<Res<UiScale>>,
+    ui_scale: Extract<TargetUiScale>,
     uinode_query: Extract<
         Query<(
             &Node,
@@ -802,11 +1052,24 @@ pub fn extract_uinode_text(
             Option<&TargetCamera>,
             &Text,
             &TextLayoutInfo,
+            Option<&TextShadow>,
+            Option<&TextOutline>,
+            Option<&TextInput>,
         )>,
     >,
 ) {
-    for (uinode, global_transform, view_visibility, clip, camera, text, text_layout_info) in
-        &uinode_query
+    for (
+        uinode,
+        global_transform,
+        view_visibility,
+        clip,
+        camera,
+        text,
+        text_layout_info,
+        shadow,
+        outline,
+        text_input,
+    ) in &uinode_query
     {
         let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
         else {
@@ -818,12 +1081,8 @@ pub fn extract_uinode_text(
             continue;
         }
 
-        let scale_factor = camera_query
-            .get(camera_entity)
-            .ok()
-            .and_then(|(_, c)| c.target_scaling_factor())
-            .unwrap_or(1.0)
-            * ui_scale.0;
+        let scale_factor =
+            ui_scale.camera_scale_factor(camera_query.get(camera_entity).ok().map(|(_, c)| c));
         let inverse_scale_factor = scale_factor.recip();
 
         // Align the text to the nearest physical pixel:
@@ -842,6 +1101,37 @@ pub fn extract_uinode_text(
         transform.translation = transform.translation.round();
         transform.translation *= inverse_scale_factor;
 
+        let effects = text_effects(shadow, outline);
+
+        if let Some(selection) =
+            text_input.and_then(|text_input| text_input.selection_rect(text, text_layout_info))
+        {
+            extracted_uinodes.uinodes.insert(
+                commands.spawn_empty().id(),
+                ExtractedUiNode {
+                    stack_index: uinode.stack_index,
+                    transform: transform
+                        * Mat4::from_translation(
+                            (selection.center() * inverse_scale_factor).extend(0.),
+                        ),
+                    color: TextInput::SELECTION_COLOR.into(),
+                    rect: Rect {
+                        min: Vec2::ZERO,
+                        max: selection.size() * inverse_scale_factor,
+                    },
+                    image: AssetId::default(),
+                    atlas_size: None,
+                    clip: clip.map(|clip| clip.clip),
+                    flip_x: false,
+                    flip_y: false,
+                    camera_entity,
+                    border: [0.; 4],
+                    border_radius: [0.; 4],
+                    node_type: NodeType::Text(TextLayer::Selection),
+                },
+            );
+        }
+
         let mut color = LinearRgba::WHITE;
         let mut current_section = usize::MAX;
         for PositionedGlyph {
@@ -860,29 +1150,55 @@ pub fn extract_uinode_text(
             let mut rect = atlas.textures[atlas_info.glyph_index].as_rect();
             rect.min *= inverse_scale_factor;
             rect.max *= inverse_scale_factor;
-            extracted_uinodes.uinodes.insert(
-                commands.spawn_empty().id(),
-                ExtractedUiNode {
-                    stack_index: uinode.stack_index,
-                    transform: transform
-                        * Mat4::from_translation(position.extend(0.) * inverse_scale_factor),
-                    color,
-                    rect,
-                    image: atlas_info.texture.id(),
-                    atlas_size: Some(atlas.size.as_vec2() * inverse_scale_factor),
-                    clip: clip.map(|clip| clip.clip),
-                    flip_x: false,
-                    flip_y: false,
-                    camera_entity,
-                    border: [0.; 4],
-                    border_radius: [0.; 4],
-                    node_type: NodeType::Rect,
-                },
-            );
+            let glyph_position = *position * inverse_scale_factor;
+            let glyph = (Vec2::ZERO, color, TextLayer::Glyph);
+            for &(offset, color, layer) in effects.iter().chain([&glyph]) {
+                extracted_uinodes.uinodes.insert(
+                    commands.spawn_empty().id(),
+                    ExtractedUiNode {
+                        stack_index: uinode.stack_index,
+                        transform: transform
+                            * Mat4::from_translation((glyph_position + offset).extend(0.)),
+                        color,
+                        rect,
+                        image: atlas_info.texture.id(),
+                        atlas_size: Some(atlas.size.as_vec2() * inverse_scale_factor),
+                        clip: clip.map(|clip| clip.clip),
+                        flip_x: false,
+                        flip_y: false,
+                        camera_entity,
+                        border: [0.; 4],
+                        border_radius: [0.; 4],
+                        node_type: NodeType::Text(layer),
+                    },
+                );
+            }
         }
     }
 }
 
+/// Returns the offset and color of each copy of the glyphs of a text node drawn for its effects.
+///
+/// Text without a [`TextShadow`] or a [`TextOutline`] gets no copies, and doesn't allocate.
+#[cfg(feature = "bevy_text")]
+fn text_effects(
+    shadow: Option<&TextShadow>,
+    outline: Option<&TextOutline>,
+) -> Vec<(Vec2, LinearRgba, TextLayer)> {
+    let mut effects = Vec::new();
+    if let Some(shadow) = shadow {
+        effects.push((shadow.offset, shadow.color.into(), TextLayer::Shadow));
+    }
+    if let Some(outline) = outline {
+        let color = outline.color.into();
+        effects.extend((0..8).map(|i| {
+            let direction = Vec2::from_angle(i as f32 * std::f32::consts::FRAC_PI_4);
+            (direction * outline.width, color, TextLayer::Outline)
+        }));
+    }
+    effects
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Pod, Zeroable)]
 struct UiVertex {
@@ -942,6 +1258,9 @@ pub mod shader_flags {
     /// Ordering: top left, top right, bottom right, bottom left.
     pub const CORNERS: [u32; 4] = [0, 2, 2 | 4, 4];
     pub const BORDER: u32 = 8;
+    pub const SHADOW: u32 = 16;
+    pub const GRADIENT: u32 = 32;
+    pub const RADIAL: u32 = 64;
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -974,7 +1293,11 @@ pub fn queue_uinodes(
             pipeline,
             entity: *entity,
             sort_key: (
-                FloatOrd(extracted_uinode.stack_index as f32),
+                FloatOrd(
+                    extracted_uinode
+                        .node_type
+                        .sort_depth(extracted_uinode.stack_index),
+                ),
                 entity.index(),
             ),
             // batch_range will be calculated in prepare_uinodes
@@ -989,6 +1312,274 @@ pub struct UiImageBindGroups {
     pub values: HashMap<AssetId<Image>, BindGroup>,
 }
 
+/// Computes the UVs of the corners of a textured node, in the order of [`QUAD_VERTEX_POSITIONS`].
+///
+/// `rect` is the area of the texture covered by the node, scaled so that `atlas_extent` is the size of
+/// the whole texture. `positions_diff` are the offsets of the corners of the node after clipping.
+fn image_uvs(
+    mut rect: Rect,
+    atlas_extent: Vec2,
+    flip_x: bool,
+    flip_y: bool,
+    mut positions_diff: [Vec2; 4],
+) -> [Vec2; 4] {
+    if flip_x {
+        std::mem::swap(&mut rect.max.x, &mut rect.min.x);
+        positions_diff[0].x *= -1.;
+        positions_diff[1].x *= -1.;
+        positions_diff[2].x *= -1.;
+        positions_diff[3].x *= -1.;
+    }
+    if flip_y {
+        std::mem::swap(&mut rect.max.y, &mut rect.min.y);
+        positions_diff[0].y *= -1.;
+        positions_diff[1].y *= -1.;
+        positions_diff[2].y *= -1.;
+        positions_diff[3].y *= -1.;
+    }
+    [
+        Vec2::new(
+            rect.min.x + positions_diff[0].x,
+            rect.min.y + positions_diff[0].y,
+        ),
+        Vec2::new(
+            rect.max.x + positions_diff[1].x,
+            rect.min.y + positions_diff[1].y,
+        ),
+        Vec2::new(
+            rect.max.x + positions_diff[2].x,
+            rect.max.y + positions_diff[2].y,
+        ),
+        Vec2::new(
+            rect.min.x + positions_diff[3].x,
+            rect.max.y + positions_diff[3].y,
+        ),
+    ]
+    .map(|pos| pos / atlas_extent)
+}
+
+/// Computes the UVs of the corners of a gradient node, in the order of [`QUAD_VERTEX_POSITIONS`].
+///
+/// For linear gradients, `u` goes from `0.` to `1.` along the gradient line. For radial gradients,
+/// the UVs are the offset from the center, scaled so that the farthest corner is at a distance of
+/// `1.`, and the shader uses their length.
+fn gradient_uvs(shape: GradientShape, size: Vec2, positions_diff: [Vec2; 4]) -> [Vec2; 4] {
+    let points = [0, 1, 2, 3].map(|i| QUAD_VERTEX_POSITIONS[i].xy() * size + positions_diff[i]);
+    match shape {
+        GradientShape::Linear { angle } => {
+            // UI coordinates point down, so "up" is towards negative y
+            let (sin, cos) = angle.sin_cos();
+            let direction = Vec2::new(sin, -cos);
+            // the length of the gradient line, so that both of its ends reach a corner
+            let length = (size.x * sin).abs() + (size.y * cos).abs();
+            points.map(|point| Vec2::new(point.dot(direction) / length + 0.5, 0.5))
+        }
+        GradientShape::Radial { center } => {
+            let center = (center - 0.5) * size;
+            let radius = (center.abs() + 0.5 * size).length();
+            points.map(|point| (point - center) / radius)
+        }
+    }
+}
+
+/// Returns whether a node drawing `image` for `camera` has to start a new batch, instead of being
+/// added to the current `batch` of consecutive nodes drawing the same image for the same camera.
+///
+/// Untextured nodes use [`AssetId::default`] and can be added to any batch; a batch of untextured
+/// nodes takes the image of the first textured node added to it. A batch image of
+/// [`AssetId::invalid`] means that the previous item wasn't drawn by this pipeline, so the nodes
+/// after it can't be merged with the nodes before it without changing their order.
+fn starts_new_batch(
+    batch: Option<(AssetId<Image>, Entity)>,
+    image: AssetId<Image>,
+    camera: Entity,
+) -> bool {
+    let Some((batch_image, batch_camera)) = batch else {
+        return true;
+    };
+    batch_image == AssetId::invalid()
+        || (batch_image != AssetId::default()
+            && image != AssetId::default()
+            && batch_image != image)
+        || batch_camera != camera
+}
+
+/// Adds the nodes drawn by the items of `ui_phase` to `batches`, in the order of the items, and
+/// appends their vertices and indices to `ui_meta`.
+///
+/// `prepare_image` is called with the image of each node starting a batch or giving its image to a
+/// batch of untextured nodes, and returns whether the image can be drawn. Nodes whose image can't
+/// be drawn yet are skipped.
+fn batch_ui_phase(
+    ui_phase: &mut SortedRenderPhase<TransparentUi>,
+    extracted_uinodes: &ExtractedUiNodes,
+    ui_meta: &mut UiMeta,
+    batches: &mut Vec<(Entity, UiBatch)>,
+    mut prepare_image: impl FnMut(AssetId<Image>) -> bool,
+) {
+    // Buffer indexes, continuing after the items of the previous phases
+    let mut vertices_index = ui_meta.indices.len() as u32;
+    let mut indices_index = ui_meta.vertices.len() as u32;
+
+    let mut batch_item_index = 0;
+    let mut batch_image_handle = AssetId::invalid();
+
+    for item_index in 0..ui_phase.items.len() {
+        let item = &mut ui_phase.items[item_index];
+        if let Some(extracted_uinode) = extracted_uinodes.uinodes.get(&item.entity) {
+            let mut existing_batch = batches.last_mut();
+
+            if starts_new_batch(
+                existing_batch
+                    .as_ref()
+                    .map(|(_, batch)| (batch_image_handle, batch.camera)),
+                extracted_uinode.image,
+                extracted_uinode.camera_entity,
+            ) {
+                if prepare_image(extracted_uinode.image) {
+                    batch_item_index = item_index;
+                    batch_image_handle = extracted_uinode.image;
+
+                    let new_batch = UiBatch {
+                        range: vertices_index..vertices_index,
+                        image: extracted_uinode.image,
+                        camera: extracted_uinode.camera_entity,
+                    };
+
+                    batches.push((item.entity, new_batch));
+
+                    existing_batch = batches.last_mut();
+                } else {
+                    continue;
+                }
+            } else if batch_image_handle == AssetId::default()
+                && extracted_uinode.image != AssetId::default()
+            {
+                if prepare_image(extracted_uinode.image) {
+                    batch_image_handle = extracted_uinode.image;
+                    existing_batch.as_mut().unwrap().1.image = extracted_uinode.image;
+                } else {
+                    continue;
+                }
+            }
+
+            let mut flags = if extracted_uinode.image != AssetId::default() {
+                shader_flags::TEXTURED
+            } else {
+                shader_flags::UNTEXTURED
+            };
+
+            let uinode_rect = extracted_uinode.rect;
+
+            let rect_size = uinode_rect.size().extend(1.0);
+
+            // Specify the corners of the node
+            let positions = QUAD_VERTEX_POSITIONS
+                .map(|pos| (extracted_uinode.transform * (pos * rect_size).extend(1.)).xyz());
+
+            // Calculate the effect of clipping
+            // Note: this won't work with rotation/scaling, but that's much more complex (may need more that 2 quads)
+            let positions_diff = if let Some(clip) = extracted_uinode.clip {
+                [
+                    Vec2::new(
+                        f32::max(clip.min.x - positions[0].x, 0.),
+                        f32::max(clip.min.y - positions[0].y, 0.),
+                    ),
+                    Vec2::new(
+                        f32::min(clip.max.x - positions[1].x, 0.),
+                        f32::max(clip.min.y - positions[1].y, 0.),
+                    ),
+                    Vec2::new(
+                        f32::min(clip.max.x - positions[2].x, 0.),
+                        f32::min(clip.max.y - positions[2].y, 0.),
+                    ),
+                    Vec2::new(
+                        f32::max(clip.min.x - positions[3].x, 0.),
+                        f32::min(clip.max.y - positions[3].y, 0.),
+                    ),
+                ]
+            } else {
+                [Vec2::ZERO; 4]
+            };
+
+            let positions_clipped = [
+                positions[0] + positions_diff[0].extend(0.),
+                positions[1] + positions_diff[1].extend(0.),
+                positions[2] + positions_diff[2].extend(0.),
+                positions[3] + positions_diff[3].extend(0.),
+            ];
+
+            let transformed_rect_size = extracted_uinode.transform.transform_vector3(rect_size);
+
+            // Don't try to cull nodes that have a rotation
+            // In a rotation around the Z-axis, this value is 0.0 for an angle of 0.0 or π
+            // In those two cases, the culling check can proceed normally as corners will be on
+            // horizontal / vertical lines
+            // For all other angles, bypass the culling check
+            // This does not properly handles all rotations on all axis
+            if extracted_uinode.transform.x_axis[1] == 0.0 {
+                // Cull nodes that are completely clipped
+                if positions_diff[0].x - positions_diff[1].x >= transformed_rect_size.x
+                    || positions_diff[1].y - positions_diff[2].y >= transformed_rect_size.y
+                {
+                    continue;
+                }
+            }
+            let uvs = if flags == shader_flags::UNTEXTURED {
+                [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y]
+            } else if let NodeType::Gradient(shape) = extracted_uinode.node_type {
+                gradient_uvs(shape, rect_size.xy(), positions_diff)
+            } else {
+                let atlas_extent = extracted_uinode.atlas_size.unwrap_or(uinode_rect.max);
+                image_uvs(
+                    uinode_rect,
+                    atlas_extent,
+                    extracted_uinode.flip_x,
+                    extracted_uinode.flip_y,
+                    positions_diff,
+                )
+            };
+
+            let color = extracted_uinode.color.to_f32_array();
+            match extracted_uinode.node_type {
+                NodeType::Rect | NodeType::Text(_) => {}
+                NodeType::Border => flags |= shader_flags::BORDER,
+                NodeType::Shadow => flags |= shader_flags::SHADOW,
+                NodeType::Gradient(GradientShape::Linear { .. }) => {
+                    flags |= shader_flags::GRADIENT;
+                }
+                NodeType::Gradient(GradientShape::Radial { .. }) => {
+                    flags |= shader_flags::GRADIENT | shader_flags::RADIAL;
+                }
+            }
+
+            for i in 0..4 {
+                ui_meta.vertices.push(UiVertex {
+                    position: positions_clipped[i].into(),
+                    uv: uvs[i].into(),
+                    color,
+                    flags: flags | shader_flags::CORNERS[i],
+                    radius: extracted_uinode.border_radius,
+                    border: extracted_uinode.border,
+                    size: rect_size.xy().into(),
+                });
+            }
+
+            for &i in &QUAD_INDICES {
+                ui_meta.indices.push(indices_index + i as u32);
+            }
+
+            vertices_index += 6;
+            indices_index += 4;
+
+            existing_batch.unwrap().1.range.end = vertices_index;
+            ui_phase.items[batch_item_index].batch_range_mut().end += 1;
+        } else {
+            batch_image_handle = AssetId::invalid();
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn prepare_uinodes(
     mut commands: Commands,
@@ -1028,215 +1619,27 @@ pub fn prepare_uinodes(
             &BindGroupEntries::single(view_binding),
         ));
 
-        // Buffer indexes
-        let mut vertices_index = 0;
-        let mut indices_index = 0;
-
+        let mut prepare_image = |image: AssetId<Image>| {
+            let Some(gpu_image) = gpu_images.get(image) else {
+                return false;
+            };
+            image_bind_groups.values.entry(image).or_insert_with(|| {
+                render_device.create_bind_group(
+                    "ui_material_bind_group",
+                    &ui_pipeline.image_layout,
+                    &BindGroupEntries::sequential((&gpu_image.texture_view, &gpu_image.sampler)),
+                )
+            });
+            true
+        };
         for ui_phase in phases.values_mut() {
-            let mut batch_item_index = 0;
-            let mut batch_image_handle = AssetId::invalid();
-
-            for item_index in 0..ui_phase.items.len() {
-                let item = &mut ui_phase.items[item_index];
-                if let Some(extracted_uinode) = extracted_uinodes.uinodes.get(&item.entity) {
-                    let mut existing_batch = batches.last_mut();
-
-                    if batch_image_handle == AssetId::invalid()
-                        || existing_batch.is_none()
-                        || (batch_image_handle != AssetId::default()
-                            && extracted_uinode.image != AssetId::default()
-                            && batch_image_handle != extracted_uinode.image)
-                        || existing_batch.as_ref().map(|(_, b)| b.camera)
-                            != Some(extracted_uinode.camera_entity)
-                    {
-                        if let Some(gpu_image) = gpu_images.get(extracted_uinode.image) {
-                            batch_item_index = item_index;
-                            batch_image_handle = extracted_uinode.image;
-
-                            let new_batch = UiBatch {
-                                range: vertices_index..vertices_index,
-                                image: extracted_uinode.image,
-                                camera: extracted_uinode.camera_entity,
-                            };
-
-                            batches.push((item.entity, new_batch));
-
-                            image_bind_groups
-                                .values
-                                .entry(batch_image_handle)
-                                .or_insert_with(|| {
-                                    render_device.create_bind_group(
-                                        "ui_material_bind_group",
-                                        &ui_pipeline.image_layout,
-                                        &BindGroupEntries::sequential((
-                                            &gpu_image.texture_view,
-                                            &gpu_image.sampler,
-                                        )),
-                                    )
-                                });
-
-                            existing_batch = batches.last_mut();
-                        } else {
-                            continue;
-                        }
-                    } else if batch_image_handle == AssetId::default()
-                        && extracted_uinode.image != AssetId::default()
-                    {
-                        if let Some(gpu_image) = gpu_images.get(extracted_uinode.image) {
-                            batch_image_handle = extracted_uinode.image;
-                            existing_batch.as_mut().unwrap().1.image = extracted_uinode.image;
-
-                            image_bind_groups
-                                .values
-                                .entry(batch_image_handle)
-                                .or_insert_with(|| {
-                                    render_device.create_bind_group(
-                                        "ui_material_bind_group",
-                                        &ui_pipeline.image_layout,
-                                        &BindGroupEntries::sequential((
-                                            &gpu_image.texture_view,
-                                            &gpu_image.sampler,
-                                        )),
-                                    )
-                                });
-                        } else {
-                            continue;
-                        }
-                    }
-
-                    let mut flags = if extracted_uinode.image != AssetId::default() {
-                        shader_flags::TEXTURED
-                    } else {
-                        shader_flags::UNTEXTURED
-                    };
-
-                    let mut uinode_rect = extracted_uinode.rect;
-
-                    let rect_size = uinode_rect.size().extend(1.0);
-
-                    // Specify the corners of the node
-                    let positions = QUAD_VERTEX_POSITIONS.map(|pos| {
-                        (extracted_uinode.transform * (pos * rect_size).extend(1.)).xyz()
-                    });
-
-                    // Calculate the effect of clipping
-                    // Note: this won't work with rotation/scaling, but that's much more complex (may need more that 2 quads)
-                    let mut positions_diff = if let Some(clip) = extracted_uinode.clip {
-                        [
-                            Vec2::new(
-                                f32::max(clip.min.x - positions[0].x, 0.),
-                                f32::max(clip.min.y - positions[0].y, 0.),
-                            ),
-                            Vec2::new(
-                                f32::min(clip.max.x - positions[1].x, 0.),
-                                f32::max(clip.min.y - positions[1].y, 0.),
-                            ),
-                            Vec2::new(
-                                f32::min(clip.max.x - positions[2].x, 0.),
-                                f32::min(clip.max.y - positions[2].y, 0.),
-                            ),
-                            Vec2::new(
-                                f32::max(clip.min.x - positions[3].x, 0.),
-                                f32::min(clip.max.y - positions[3].y, 0.),
-                            ),
-                        ]
-                    } else {
-                        [Vec2::ZERO; 4]
-                    };
-
-                    let positions_clipped = [
-                        positions[0] + positions_diff[0].extend(0.),
-                        positions[1] + positions_diff[1].extend(0.),
-                        positions[2] + positions_diff[2].extend(0.),
-                        positions[3] + positions_diff[3].extend(0.),
-                    ];
-
-                    let transformed_rect_size =
-                        extracted_uinode.transform.transform_vector3(rect_size);
-
-                    // Don't try to cull nodes that have a rotation
-                    // In a rotation around the Z-axis, this value is 0.0 for an angle of 0.0 or π
-                    // In those two cases, the culling check can proceed normally as corners will be on
-                    // horizontal / vertical lines
-                    // For all other angles, bypass the culling check
-                    // This does not properly handles all rotations on all axis
-                    if extracted_uinode.transform.x_axis[1] == 0.0 {
-                        // Cull nodes that are completely clipped
-                        if positions_diff[0].x - positions_diff[1].x >= transformed_rect_size.x
-                            || positions_diff[1].y - positions_diff[2].y >= transformed_rect_size.y
-                        {
-                            continue;
-                        }
-                    }
-                    let uvs = if flags == shader_flags::UNTEXTURED {
-                        [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y]
-                    } else {
-                        let atlas_extent = extracted_uinode.atlas_size.unwrap_or(uinode_rect.max);
-                        if extracted_uinode.flip_x {
-                            std::mem::swap(&mut uinode_rect.max.x, &mut uinode_rect.min.x);
-                            positions_diff[0].x *= -1.;
-                            positions_diff[1].x *= -1.;
-                            positions_diff[2].x *= -1.;
-                            positions_diff[3].x *= -1.;
-                        }
-                        if extracted_uinode.flip_y {
-                            std::mem::swap(&mut uinode_rect.max.y, &mut uinode_rect.min.y);
-                            positions_diff[0].y *= -1.;
-                            positions_diff[1].y *= -1.;
-                            positions_diff[2].y *= -1.;
-                            positions_diff[3].y *= -1.;
-                        }
-                        [
-                            Vec2::new(
-                                uinode_rect.min.x + positions_diff[0].x,
-                                uinode_rect.min.y + positions_diff[0].y,
-                            ),
-                            Vec2::new(
-                                uinode_rect.max.x + positions_diff[1].x,
-                                uinode_rect.min.y + positions_diff[1].y,
-                            ),
-                            Vec2::new(
-                                uinode_rect.max.x + positions_diff[2].x,
-                                uinode_rect.max.y + positions_diff[2].y,
-                            ),
-                            Vec2::new(
-                                uinode_rect.min.x + positions_diff[3].x,
-                                uinode_rect.max.y + positions_diff[3].y,
-                            ),
-                        ]
-                        .map(|pos| pos / atlas_extent)
-                    };
-
-                    let color = extracted_uinode.color.to_f32_array();
-                    if extracted_uinode.node_type == NodeType::Border {
-                        flags |= shader_flags::BORDER;
-                    }
-
-                    for i in 0..4 {
-                        ui_meta.vertices.push(UiVertex {
-                            position: positions_clipped[i].into(),
-                            uv: uvs[i].into(),
-                            color,
-                            flags: flags | shader_flags::CORNERS[i],
-                            radius: extracted_uinode.border_radius,
-                            border: extracted_uinode.border,
-                            size: rect_size.xy().into(),
-                        });
-                    }
-
-                    for &i in &QUAD_INDICES {
-                        ui_meta.indices.push(indices_index + i as u32);
-                    }
-
-                    vertices_index += 6;
-                    indices_index += 4;
-
-                    existing_batch.unwrap().1.range.end = vertices_index;
-                    ui_phase.items[batch_item_index].batch_range_mut().end += 1;
-                } else {
-                    batch_image_handle = AssetId::invalid();
-                }
-            }
+            batch_ui_phase(
+                ui_phase,
+                &extracted_uinodes,
+                &mut ui_meta,
+                &mut batches,
+                &mut prepare_image,
+            );
         }
         ui_meta.vertices.write_buffer(&render_device, &render_queue);
         ui_meta.indices.write_buffer(&render_device, &render_queue);
@@ -1245,3 +1648,474 @@ pub fn prepare_uinodes(
     }
     extracted_uinodes.uinodes.clear();
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::LinearRgba;
+    use bevy_math::{FloatOrd, Mat4, Rect, UVec2, Vec2, Vec3};
+    use bevy_render::{
+        render_phase::{
+            Draw, DrawFunctionId, DrawFunctions, PhaseItemExtraIndex, SortedRenderPhase,
+            TrackedRenderPass,
+        },
+        render_resource::CachedRenderPipelineId,
+    };
+    use bevy_sprite::TextureAtlasLayout;
+
+    #[cfg(feature = "bevy_text")]
+    use super::text_effects;
+    use super::{
+        atlas_texture_rect, batch_ui_phase, fitted_image_rect, gradient_uvs, image_uvs,
+        resolve_border_thickness, ExtractedUiNode, ExtractedUiNodes, NodeType, TextLayer,
+        TransparentUi, UiBatch, UiMeta,
+    };
+    use crate::{GradientShape, ImageFit, UiRect, Val};
+    use bevy_asset::{AssetId, Handle};
+    use bevy_ecs::{entity::Entity, world::World};
+    use bevy_render::texture::Image;
+
+    struct NoDraw;
+
+    impl Draw<TransparentUi> for NoDraw {
+        fn draw<'w>(
+            &mut self,
+            _world: &'w World,
+            _pass: &mut TrackedRenderPass<'w>,
+            _view: Entity,
+            _item: &TransparentUi,
+        ) {
+        }
+    }
+
+    /// The width of the nodes, which are placed side by side in the order of their stack index
+    const NODE_WIDTH: f32 = 10.;
+
+    fn phase_item(
+        draw_function: DrawFunctionId,
+        entity: Entity,
+        stack_index: u32,
+    ) -> TransparentUi {
+        TransparentUi {
+            sort_key: (FloatOrd(NodeType::Rect.sort_depth(stack_index)), 0),
+            entity,
+            pipeline: CachedRenderPipelineId::INVALID,
+            draw_function,
+            batch_range: 0..0,
+            extra_index: PhaseItemExtraIndex::NONE,
+        }
+    }
+
+    /// Sorts the phase like the render graph and batches its items with `batch_ui_phase`, returning
+    /// the batches and the stack indices of the nodes drawn by each of them, in drawing order.
+    fn batch(
+        ui_phase: &mut SortedRenderPhase<TransparentUi>,
+        extracted_uinodes: &ExtractedUiNodes,
+    ) -> Vec<(UiBatch, Vec<u32>)> {
+        ui_phase.sort();
+        let mut ui_meta = UiMeta::default();
+        let mut batches = Vec::new();
+        batch_ui_phase(
+            ui_phase,
+            extracted_uinodes,
+            &mut ui_meta,
+            &mut batches,
+            |_| true,
+        );
+
+        let (vertices, indices) = (ui_meta.vertices.values(), ui_meta.indices.values());
+        batches
+            .into_iter()
+            .map(|(_, batch)| {
+                let drawn = indices[batch.range.start as usize..batch.range.end as usize]
+                    .chunks(6)
+                    .map(|quad| {
+                        let left = quad
+                            .iter()
+                            .map(|index| vertices[*index as usize].position[0])
+                            .fold(f32::INFINITY, f32::min);
+                        (left / NODE_WIDTH) as u32
+                    })
+                    .collect();
+                (batch, drawn)
+            })
+            .collect()
+    }
+
+    /// Batches nodes drawing the given images for the given cameras, stacked in the given order but
+    /// added to the phase in reverse
+    fn batch_nodes(
+        nodes: impl IntoIterator<Item = (AssetId<Image>, Entity)>,
+    ) -> Vec<(UiBatch, Vec<u32>)> {
+        let draw_function = DrawFunctions::<TransparentUi>::default()
+            .write()
+            .add(NoDraw);
+        let mut ui_phase = SortedRenderPhase::default();
+        let mut extracted_uinodes = ExtractedUiNodes::default();
+        for (stack_index, (image, camera)) in nodes.into_iter().enumerate() {
+            let stack_index = stack_index as u32;
+            let entity = Entity::from_raw(1000 + stack_index);
+            extracted_uinodes
+                .uinodes
+                .insert(entity, extracted_node(stack_index, image, camera));
+            ui_phase
+                .items
+                .insert(0, phase_item(draw_function, entity, stack_index));
+        }
+        batch(&mut ui_phase, &extracted_uinodes)
+    }
+
+    fn extracted_node(stack_index: u32, image: AssetId<Image>, camera: Entity) -> ExtractedUiNode {
+        ExtractedUiNode {
+            stack_index,
+            transform: Mat4::from_translation(Vec3::new(
+                (stack_index as f32 + 0.5) * NODE_WIDTH,
+                0.,
+                0.,
+            )),
+            color: LinearRgba::WHITE,
+            rect: Rect::new(0., 0., NODE_WIDTH, NODE_WIDTH),
+            image,
+            atlas_size: None,
+            clip: None,
+            flip_x: false,
+            flip_y: false,
+            camera_entity: camera,
+            border_radius: [0.; 4],
+            border: [0.; 4],
+            node_type: NodeType::Rect,
+        }
+    }
+
+    #[test]
+    fn consecutive_nodes_with_the_same_texture_share_a_batch() {
+        let camera = Entity::from_raw(0);
+        let texture = Handle::<Image>::weak_from_u128(1).id();
+        let other_texture = Handle::<Image>::weak_from_u128(2).id();
+        let untextured = AssetId::default();
+
+        // a grid of 500 nodes with the same texture, drawn in the order of the stack
+        let batches = batch_nodes([(texture, camera); 500]);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].0.range, 0..3000);
+        assert_eq!(batches[0].1, (0..500).collect::<Vec<_>>());
+
+        // with untextured backgrounds, the batch takes the texture of the first textured node
+        let batches = batch_nodes((0..500).flat_map(|_| [(untextured, camera), (texture, camera)]));
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].0.image, texture);
+        assert_eq!(batches[0].1, (0..1000).collect::<Vec<_>>());
+
+        // the nodes are drawn in order, so alternating textures can't be merged
+        let batches = batch_nodes(
+            (0..500).map(|i| (if i % 2 == 0 { texture } else { other_texture }, camera)),
+        );
+        assert_eq!(batches.len(), 500);
+        assert!(batches
+            .iter()
+            .enumerate()
+            .all(|(i, (_, drawn))| *drawn == [i as u32]));
+        let batches = batch_nodes([
+            (texture, camera),
+            (texture, Entity::from_raw(1)),
+            (texture, camera),
+        ]);
+        assert_eq!(
+            batches
+                .iter()
+                .map(|(_, drawn)| drawn.clone())
+                .collect::<Vec<_>>(),
+            [[0], [1], [2]]
+        );
+    }
+
+    #[test]
+    fn items_from_other_pipelines_split_batches() {
+        let camera = Entity::from_raw(0);
+        let texture = Handle::<Image>::weak_from_u128(1).id();
+        let draw_function = DrawFunctions::<TransparentUi>::default()
+            .write()
+            .add(NoDraw);
+        let mut ui_phase = SortedRenderPhase::default();
+        let mut extracted_uinodes = ExtractedUiNodes::default();
+        for stack_index in [0, 2] {
+            let entity = Entity::from_raw(1000 + stack_index);
+            extracted_uinodes
+                .uinodes
+                .insert(entity, extracted_node(stack_index, texture, camera));
+            ui_phase.add(phase_item(draw_function, entity, stack_index));
+        }
+        // a node drawn by another pipeline, like a `UiMaterial`, between the two nodes
+        ui_phase.add(phase_item(draw_function, Entity::from_raw(1001), 1));
+
+        let batches = batch(&mut ui_phase, &extracted_uinodes);
+        assert_eq!(
+            batches
+                .iter()
+                .map(|(_, drawn)| drawn.clone())
+                .collect::<Vec<_>>(),
+            [[0], [2]]
+        );
+        // each batch is drawn by its first item
+        assert_eq!(ui_phase.items[0].batch_range, 0..1);
+        assert_eq!(ui_phase.items[2].batch_range, 0..1);
+    }
+
+    #[test]
+    fn border_thickness_per_side() {
+        let viewport_size = Vec2::new(800., 600.);
+        let resolve = |border: UiRect| {
+            [border.left, border.top, border.right, border.bottom]
+                .map(|value| resolve_border_thickness(value, 200., viewport_size))
+        };
+
+        assert_eq!(resolve(UiRect::all(Val::Px(2.))), [2.; 4]);
+        assert_eq!(resolve(UiRect::DEFAULT), [0.; 4]);
+        // percentages resolve against the width of the parent on both axes
+        assert_eq!(
+            resolve(UiRect::new(
+                Val::Percent(1.),
+                Val::Vw(1.),
+                Val::Percent(2.),
+                Val::Px(-3.)
+            )),
+            [2., 4., 8., 0.]
+        );
+    }
+
+    #[test]
+    fn shadows_sort_between_their_node_and_the_previous_one() {
+        let shadow = NodeType::Shadow.sort_depth(3);
+        assert!(NodeType::Rect.sort_depth(2) < shadow);
+        assert!(NodeType::Border.sort_depth(2) < shadow);
+        assert!(shadow < NodeType::Rect.sort_depth(3));
+    }
+
+    #[test]
+    fn glyphs_sort_above_their_effects_and_background() {
+        let layers = [
+            NodeType::Rect,
+            NodeType::Text(TextLayer::Selection),
+            NodeType::Text(TextLayer::Shadow),
+            NodeType::Text(TextLayer::Outline),
+            NodeType::Text(TextLayer::Glyph),
+        ]
+        .map(|node_type| node_type.sort_depth(3));
+        assert!(layers.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(layers[4] < NodeType::Shadow.sort_depth(4));
+    }
+
+    #[cfg(feature = "bevy_text")]
+    #[test]
+    fn text_effects_are_only_drawn_when_set() {
+        use crate::{TextOutline, TextShadow};
+
+        let layers = |shadow: Option<&TextShadow>, outline: Option<&TextOutline>| {
+            text_effects(shadow, outline)
+                .into_iter()
+                .map(|(_, _, layer)| layer)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(layers(None, None).is_empty());
+        assert_eq!(
+            layers(Some(&TextShadow::default()), None),
+            [TextLayer::Shadow]
+        );
+        assert_eq!(
+            layers(None, Some(&TextOutline::default())),
+            [TextLayer::Outline; 8]
+        );
+        assert_eq!(
+            layers(Some(&TextShadow::default()), Some(&TextOutline::default())).len(),
+            9
+        );
+
+        let outline = TextOutline {
+            width: 2.,
+            ..TextOutline::DEFAULT
+        };
+        for (offset, _, _) in text_effects(None, Some(&outline)) {
+            assert!((offset.length() - 2.).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn flipped_image_uvs() {
+        // the right half of a texture
+        let rect = Rect::new(50., 0., 100., 100.);
+        let extent = Vec2::splat(100.);
+        let no_clip = [Vec2::ZERO; 4];
+
+        assert_eq!(
+            image_uvs(rect, extent, false, false, no_clip),
+            [
+                Vec2::new(0.5, 0.),
+                Vec2::new(1., 0.),
+                Vec2::new(1., 1.),
+                Vec2::new(0.5, 1.)
+            ]
+        );
+        assert_eq!(
+            image_uvs(rect, extent, true, false, no_clip),
+            [
+                Vec2::new(1., 0.),
+                Vec2::new(0.5, 0.),
+                Vec2::new(0.5, 1.),
+                Vec2::new(1., 1.)
+            ]
+        );
+        assert_eq!(
+            image_uvs(rect, extent, false, true, no_clip),
+            [
+                Vec2::new(0.5, 1.),
+                Vec2::new(1., 1.),
+                Vec2::new(1., 0.),
+                Vec2::new(0.5, 0.)
+            ]
+        );
+    }
+
+    #[test]
+    fn atlas_section_uvs() {
+        // a 2 by 2 grid of 16 pixels cells
+        let layout = TextureAtlasLayout::from_grid(UVec2::splat(16), 2, 2, None, None);
+        let no_clip = [Vec2::ZERO; 4];
+        let uvs = |index, image_rect| {
+            let (rect, extent) = atlas_texture_rect(&layout, index, image_rect)?;
+            Some(image_uvs(rect, extent, false, false, no_clip))
+        };
+
+        // sections are numbered row by row
+        assert_eq!(
+            uvs(1, None),
+            Some([
+                Vec2::new(0.5, 0.),
+                Vec2::new(1., 0.),
+                Vec2::new(1., 0.5),
+                Vec2::new(0.5, 0.5)
+            ])
+        );
+        // the rect of the image is relative to the section
+        assert_eq!(
+            uvs(3, Some(Rect::new(0., 0., 8., 16.))),
+            Some([
+                Vec2::new(0.5, 0.5),
+                Vec2::new(0.75, 0.5),
+                Vec2::new(0.75, 1.),
+                Vec2::new(0.5, 1.)
+            ])
+        );
+        assert_eq!(uvs(4, None), None);
+    }
+
+    #[test]
+    fn image_fit_uvs() {
+        // a wide texture in a square node
+        let texture_rect = Rect::new(0., 0., 200., 100.);
+        let texture_size = Vec2::new(200., 100.);
+        let node_size = Vec2::splat(50.);
+        let no_clip = [Vec2::ZERO; 4];
+        let fit = |fit| {
+            let (rect, extent) = fitted_image_rect(fit, node_size, texture_rect, texture_size);
+            (rect.size(), image_uvs(rect, extent, false, false, no_clip))
+        };
+
+        // the middle of the texture covers the node
+        assert_eq!(
+            fit(ImageFit::Cover),
+            (
+                node_size,
+                [
+                    Vec2::new(0.25, 0.),
+                    Vec2::new(0.75, 0.),
+                    Vec2::new(0.75, 1.),
+                    Vec2::new(0.25, 1.)
+                ]
+            )
+        );
+        let whole_texture = [
+            Vec2::new(0., 0.),
+            Vec2::new(1., 0.),
+            Vec2::new(1., 1.),
+            Vec2::new(0., 1.),
+        ];
+        assert_eq!(fit(ImageFit::Fill), (node_size, whole_texture));
+        assert_eq!(fit(ImageFit::Contain), (Vec2::new(50., 25.), whole_texture));
+        assert_eq!(
+            fit(ImageFit::None),
+            (
+                node_size,
+                [
+                    Vec2::new(0.375, 0.25),
+                    Vec2::new(0.625, 0.25),
+                    Vec2::new(0.625, 0.75),
+                    Vec2::new(0.375, 0.75)
+                ]
+            )
+        );
+
+        // the cropped area of an atlas section stays inside the section, even when flipped
+        let (rect, extent) = fitted_image_rect(
+            ImageFit::Cover,
+            node_size,
+            Rect::new(100., 0., 200., 50.),
+            texture_size,
+        );
+        assert_eq!(
+            image_uvs(rect, extent, true, false, no_clip),
+            [
+                Vec2::new(0.875, 0.),
+                Vec2::new(0.625, 0.),
+                Vec2::new(0.625, 0.5),
+                Vec2::new(0.875, 0.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn gradient_uvs_reach_the_corners() {
+        let size = Vec2::new(200., 100.);
+        let no_clip = [Vec2::ZERO; 4];
+        let u = |angle: f32| {
+            gradient_uvs(GradientShape::Linear { angle }, size, no_clip)
+                .map(|uv| (uv.x * 100.).round() / 100.)
+        };
+
+        // top to bottom
+        assert_eq!(u(std::f32::consts::PI), [0., 0., 1., 1.]);
+        // left to right
+        assert_eq!(u(std::f32::consts::FRAC_PI_2), [0., 1., 1., 0.]);
+        // from the bottom left corner to the top right one
+        let diagonal = u(size.x.atan2(size.y));
+        assert_eq!(diagonal[3], 0.);
+        assert_eq!(diagonal[1], 1.);
+
+        // clipping the left half of the node moves the start of a horizontal gradient to its middle
+        let clip = [
+            Vec2::new(100., 0.),
+            Vec2::ZERO,
+            Vec2::ZERO,
+            Vec2::new(100., 0.),
+        ];
+        let uvs = gradient_uvs(
+            GradientShape::Linear {
+                angle: std::f32::consts::FRAC_PI_2,
+            },
+            size,
+            clip,
+        );
+        assert!((uvs[0].x - 0.5).abs() < 1e-5);
+
+        let radial = gradient_uvs(
+            GradientShape::Radial {
+                center: Vec2::new(0., 0.5),
+            },
+            size,
+            no_clip,
+        );
+        // the corners on the left are closer to the center than the ones on the right
+        assert!(radial[0].length() < 1.);
+        assert!((radial[1].length() - 1.).abs() < 1e-5);
+        assert!((radial[2].length() - 1.).abs() < 1e-5);
+    }
+}
@@ -4,6 +4,12 @@ const TEXTURED = 1u;
 const RIGHT_VERTEX = 2u;
 const BOTTOM_VERTEX = 4u;
 const BORDER: u32 = 8u;
+const SHADOW: u32 = 16u;
+const GRADIENT: u32 = 32u;
+const RADIAL: u32 = 64u;
+
+// The width of the textures the colors of gradients are baked into.
+const GRADIENT_TEXTURE_WIDTH: f32 = 256.0;
 
 fn enabled(flags: u32, mask: u32) -> bool {
     return (flags & mask) != 0u;
@@ -169,11 +175,34 @@ fn draw_background(in: VertexOutput, texture_color: vec4<f32>) -> vec4<f32> {
     return vec4(color.rgb, saturate(color.a * t));
 }
 
+fn draw_shadow(in: VertexOutput) -> vec4<f32> {
+    // The blur radius is stored in the border, and the quad extends past the shadow by that much 
+    // on each side.
+    let blur = in.border.x;
+    let distance = sd_rounded_box(in.point, in.size - vec2(2.0 * blur), in.radius);
+
+    // Fade out the shadow over twice the blur radius around its edge, approximating a gaussian blur.
+    let t = select(antialias(distance), 1.0 - smoothstep(-blur, blur, distance), 0.0 < blur);
+    return vec4(in.color.rgb, saturate(in.color.a * t));
+}
+
+// Returns the coordinates in the gradient texture of the color at the current fragment.
+fn gradient_uv(in: VertexOutput) -> vec2<f32> {
+    // Radial gradients get the offset from their center, whose length is the position along the gradient.
+    let position = select(in.uv.x, length(in.uv), enabled(in.flags, RADIAL));
+    // Sample between the centers of the first and last texels, which hold the ends of the gradient.
+    let x = (0.5 + saturate(position) * (GRADIENT_TEXTURE_WIDTH - 1.0)) / GRADIENT_TEXTURE_WIDTH;
+    return vec2(x, 0.5);
+}
+
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-    let texture_color = textureSample(sprite_texture, sprite_sampler, in.uv);
+    let uv = select(in.uv, gradient_uv(in), enabled(in.flags, GRADIENT));
+    let texture_color = textureSample(sprite_texture, sprite_sampler, uv);
 
-    if enabled(in.flags, BORDER) {
+    if enabled(in.flags, SHADOW) {
+        return draw_shadow(in);
+    } else if enabled(in.flags, BORDER) {
         return draw(in, texture_color);    
     } else {
         return draw_background(in, texture_color);
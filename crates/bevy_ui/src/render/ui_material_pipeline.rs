@@ -352,6 +352,7 @@ impl<M: UiMaterial> Default for ExtractedUiMaterialNodes<M> {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn extract_ui_material_nodes<M: UiMaterial>(
     mut extracted_uinodes: ResMut<ExtractedUiMaterialNodes<M>>,
     materials: Extract<Res<Assets<M>>>,
@@ -372,16 +373,17 @@ pub fn extract_ui_material_nodes<M: UiMaterial>(
             Without<BackgroundColor>,
         >,
     >,
-    windows: Extract<Query<&Window, With<PrimaryWindow>>>,
-    ui_scale: Extract<Res<UiScale>>,
+    windows: Extract<Query<(Entity, &Window), With<PrimaryWindow>>>,
+    ui_scale: Extract<TargetUiScale>,
 ) {
     let ui_logical_viewport_size = windows
         .get_single()
-        .map(|window| window.size())
-        .unwrap_or(Vec2::ZERO)
-        // The logical window resolution returned by `Window` only takes into account the window scale factor and not `UiScale`,
-        // so we have to divide by `UiScale` to get the size of the UI viewport.
-        / ui_scale.0;
+        .map(|(entity, window)| {
+            // The logical window resolution returned by `Window` only takes into account the window scale factor and not `UiScale`,
+            // so we have to divide by `UiScale` to get the size of the UI viewport.
+            window.size() / ui_scale.window(entity, window.scale_factor())
+        })
+        .unwrap_or(Vec2::ZERO);
 
     // If there is only one camera, we use it as default
     let default_single_camera = default_ui_camera.get();
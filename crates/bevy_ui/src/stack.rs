@@ -1,6 +1,6 @@
 //! This module contains the systems that update the stored UI nodes stack
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
 use bevy_hierarchy::prelude::*;
 
 use crate::{Node, ZIndex};
@@ -9,10 +9,34 @@ use crate::{Node, ZIndex};
 ///
 /// The first entry is the furthest node from the camera and is the first one to get rendered
 /// while the last entry is the first node to receive interactions.
+///
+/// The lookups by entity use an index rebuilt with the stack in [`UiSystem::Stack`](crate::UiSystem::Stack),
+/// so they don't see changes made to `uinodes` directly until the next update.
 #[derive(Debug, Resource, Default)]
 pub struct UiStack {
     /// List of UI nodes ordered from back-to-front
     pub uinodes: Vec<Entity>,
+    /// The index of each node in `uinodes`
+    indices: EntityHashMap<usize>,
+}
+
+impl UiStack {
+    /// Returns the index of the node in [`UiStack::uinodes`], or `None` if it isn't in the stack.
+    pub fn index_of(&self, entity: Entity) -> Option<usize> {
+        self.indices.get(&entity).copied()
+    }
+
+    /// Returns true if `a` is drawn above `b`, and receives interactions before it.
+    ///
+    /// Returns `None` if either node isn't in the stack.
+    pub fn is_above(&self, a: Entity, b: Entity) -> Option<bool> {
+        Some(self.index_of(a)? > self.index_of(b)?)
+    }
+
+    /// Iterates over the nodes from the topmost to the bottommost, in the order they receive interactions.
+    pub fn iter_top_to_bottom(&self) -> impl DoubleEndedIterator<Item = Entity> + '_ {
+        self.uinodes.iter().rev().copied()
+    }
 }
 
 /// Caches stacking context buffers for use in [`ui_stack_system`].
@@ -49,6 +73,14 @@ struct StackingContextEntry {
 ///
 /// First generate a UI node tree (`StackingContext`) based on z-index.
 /// Then flatten that tree into back-to-front ordered `UiStack`.
+///
+/// Nodes with a [`ZIndex::Global`] are moved out of their parent into the stacking context of the
+/// root nodes, where they are ordered against the roots and the other global nodes regardless of
+/// their depth. [`ZIndex::Local`] only orders a node among its siblings, so a node and its
+/// descendants with local z-indices are always drawn together, on top of its parent. A node with
+/// [`ZIndex::Auto`] doesn't have a stacking context of its own: it is sorted in the context of its
+/// parent at a z-index of 0, and its children are sorted in that context too, after it. Nodes with
+/// the same z-index keep their hierarchy order.
 pub(crate) fn ui_stack_system(
     mut cache: Local<StackingContextCache>,
     mut ui_stack: ResMut<UiStack>,
@@ -79,7 +111,10 @@ pub(crate) fn ui_stack_system(
     fill_stack_recursively(&mut cache, &mut ui_stack.uinodes, &mut global_context);
     cache.push(global_context);
 
-    for (i, entity) in ui_stack.uinodes.iter().enumerate() {
+    let UiStack { uinodes, indices } = &mut *ui_stack;
+    indices.clear();
+    for (i, entity) in uinodes.iter().enumerate() {
+        indices.insert(*entity, i);
         if let Ok(mut node) = update_query.get_mut(*entity) {
             node.bypass_change_detection().stack_index = i as u32;
         }
@@ -96,6 +131,34 @@ fn insert_context_hierarchy(
     parent_context: Option<&mut StackingContext>,
     total_entry_count: &mut usize,
 ) {
+    let z_index = zindex_query.get(entity).unwrap_or(&ZIndex::Local(0));
+    if *z_index == ZIndex::Auto {
+        // the node and its children are sorted in the stacking context of its parent
+        *total_entry_count += 1;
+        let mut parent_context = parent_context;
+        parent_context
+            .as_deref_mut()
+            .unwrap_or(global_context)
+            .entries
+            .push(StackingContextEntry {
+                z_index: 0,
+                entity,
+                stack: cache.pop(),
+            });
+        for child in children_query.get(entity).into_iter().flatten() {
+            insert_context_hierarchy(
+                cache,
+                zindex_query,
+                children_query,
+                *child,
+                global_context,
+                parent_context.as_deref_mut(),
+                total_entry_count,
+            );
+        }
+        return;
+    }
+
     let mut new_context = cache.pop();
 
     if let Ok(children) = children_query.get(entity) {
@@ -117,10 +180,10 @@ fn insert_context_hierarchy(
     }
 
     // The node will be added either to global/parent based on its z-index type: global/local.
-    let z_index = zindex_query.get(entity).unwrap_or(&ZIndex::Local(0));
     let (entity_context, z_index) = match z_index {
         ZIndex::Local(value) => (parent_context.unwrap_or(global_context), *value),
         ZIndex::Global(value) => (global_context, *value),
+        ZIndex::Auto => unreachable!("nodes with `ZIndex::Auto` are inserted above"),
     };
 
     *total_entry_count += 1;
@@ -158,7 +221,7 @@ mod tests {
         system::Commands,
         world::{CommandQueue, World},
     };
-    use bevy_hierarchy::BuildChildren;
+    use bevy_hierarchy::{BuildChildren, BuildWorldChildren, DespawnRecursiveExt};
 
     use crate::{Node, UiStack, ZIndex};
 
@@ -264,4 +327,151 @@ mod tests {
         ];
         assert_eq!(actual_result, expected_result);
     }
+
+    #[test]
+    fn global_z_index_escapes_the_hierarchy_and_local_z_index_does_not() {
+        let mut world = World::default();
+        world.init_resource::<UiStack>();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands
+            .spawn(node_without_zindex("root"))
+            .with_children(|parent| {
+                parent
+                    .spawn(node_without_zindex("a"))
+                    .with_children(|parent| {
+                        parent
+                            .spawn(node_without_zindex("a-0"))
+                            .with_children(|parent| {
+                                parent.spawn(node_with_zindex("a-0-global", ZIndex::Global(1)));
+                                parent.spawn(node_with_zindex("a-0-local", ZIndex::Local(100)));
+                            });
+                    });
+                parent.spawn(node_without_zindex("b"));
+            });
+        queue.apply(&mut world);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_stack_system);
+        schedule.run(&mut world);
+
+        let mut query = world.query::<&Label>();
+        let ui_stack = world.resource::<UiStack>();
+        let actual_result = ui_stack
+            .uinodes
+            .iter()
+            .map(|entity| query.get(&world, *entity).unwrap().clone())
+            .collect::<Vec<_>>();
+        // the deeply nested global node is drawn above the shallow sibling `b`, while the local
+        // node is drawn with its parent, below `b`
+        let expected_result = vec![
+            Label("root"),
+            Label("a"),
+            Label("a-0"),
+            Label("a-0-local"),
+            Label("b"),
+            Label("a-0-global"),
+        ];
+        assert_eq!(actual_result, expected_result);
+    }
+
+    #[test]
+    fn auto_z_index_sorts_nodes_in_the_stacking_context_of_their_parent() {
+        let mut world = World::default();
+        world.init_resource::<UiStack>();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands
+            .spawn(node_without_zindex("root"))
+            .with_children(|parent| {
+                parent.spawn(node_with_zindex("a", ZIndex::Local(1)));
+                parent
+                    .spawn(node_with_zindex("b", ZIndex::Auto))
+                    .with_children(|parent| {
+                        parent.spawn(node_without_zindex("b-0"));
+                        parent.spawn(node_with_zindex("b-1", ZIndex::Local(-1)));
+                        parent.spawn(node_with_zindex("b-2", ZIndex::Local(2)));
+                        parent.spawn(node_with_zindex("b-3", ZIndex::Global(1)));
+                    });
+                parent.spawn(node_without_zindex("c"));
+                parent
+                    .spawn(node_with_zindex("d", ZIndex::Local(-1)))
+                    .with_children(|parent| {
+                        parent
+                            .spawn(node_with_zindex("d-0", ZIndex::Auto))
+                            .with_children(|parent| {
+                                parent.spawn(node_with_zindex("d-0-0", ZIndex::Local(-1)));
+                            });
+                    });
+            });
+        queue.apply(&mut world);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_stack_system);
+        schedule.run(&mut world);
+
+        let mut query = world.query::<&Label>();
+        let ui_stack = world.resource::<UiStack>();
+        let actual_result = ui_stack
+            .uinodes
+            .iter()
+            .map(|entity| query.get(&world, *entity).unwrap().clone())
+            .collect::<Vec<_>>();
+        // the children of the auto nodes are sorted with the siblings of their parent, while the
+        // local nodes keep their children with them
+        let expected_result = vec![
+            Label("root"),
+            Label("b-1"), // ZIndex::Local(-1), in the context of `root`
+            Label("d"),   // ZIndex::Local(-1)
+            Label("d-0-0"),
+            Label("d-0"),
+            Label("b"),
+            Label("b-0"),
+            Label("c"),
+            Label("a"),   // ZIndex::Local(1)
+            Label("b-2"), // ZIndex::Local(2), in the context of `root`
+            Label("b-3"), // ZIndex::Global(1)
+        ];
+        assert_eq!(actual_result, expected_result);
+    }
+
+    #[test]
+    fn lookups_follow_the_stack_after_reparenting() {
+        let mut world = World::default();
+        world.init_resource::<UiStack>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_stack_system);
+
+        let root = world.spawn(node_without_zindex("root")).id();
+        let a = world.spawn(node_without_zindex("a")).set_parent(root).id();
+        let b = world.spawn(node_without_zindex("b")).set_parent(root).id();
+        let child = world.spawn(node_without_zindex("child")).set_parent(a).id();
+        schedule.run(&mut world);
+
+        let ui_stack = world.resource::<UiStack>();
+        assert_eq!(ui_stack.index_of(child), Some(2));
+        assert_eq!(ui_stack.is_above(b, child), Some(true));
+        assert_eq!(ui_stack.is_above(child, a), Some(true));
+
+        world.entity_mut(child).set_parent(b);
+        schedule.run(&mut world);
+
+        let ui_stack = world.resource::<UiStack>();
+        assert_eq!(ui_stack.index_of(child), Some(3));
+        assert_eq!(ui_stack.is_above(b, child), Some(false));
+        assert_eq!(
+            ui_stack.iter_top_to_bottom().collect::<Vec<_>>(),
+            vec![child, b, a, root]
+        );
+
+        world.entity_mut(a).despawn_recursive();
+        schedule.run(&mut world);
+
+        let ui_stack = world.resource::<UiStack>();
+        assert_eq!(ui_stack.index_of(a), None);
+        assert_eq!(ui_stack.is_above(a, b), None);
+        assert_eq!(ui_stack.index_of(child), Some(2));
+    }
 }
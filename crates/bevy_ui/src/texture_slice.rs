@@ -99,7 +99,7 @@ fn compute_texture_slices(
     atlas: Option<&TextureAtlas>,
     atlas_layouts: &Assets<TextureAtlasLayout>,
 ) -> Option<ComputedTextureSlices> {
-    let (image_size, texture_rect) = match atlas {
+    let (image_size, mut texture_rect) = match atlas {
         Some(a) => {
             let layout = atlas_layouts.get(&a.layout)?;
             (
@@ -120,6 +120,9 @@ fn compute_texture_slices(
             (size, rect)
         }
     };
+    if let Some(rect) = image_handle.rect {
+        texture_rect = Rect::from_corners(texture_rect.min + rect.min, texture_rect.min + rect.max);
+    }
     let slices = match scale_mode {
         ImageScaleMode::Sliced(slicer) => slicer.compute_slices(texture_rect, Some(draw_area)),
         ImageScaleMode::Tiled {
@@ -217,3 +220,83 @@ pub(crate) fn compute_slices_on_image_change(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_ecs::entity::Entity;
+    use bevy_math::Vec2;
+    use bevy_render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::Image,
+    };
+    use bevy_sprite::{BorderRect, ImageScaleMode, TextureAtlasLayout, TextureSlicer};
+    use bevy_transform::components::GlobalTransform;
+
+    use super::compute_texture_slices;
+    use crate::{Node, UiImage};
+
+    #[test]
+    fn sliced_image_keeps_border_uvs() {
+        let mut images = Assets::<Image>::default();
+        let texture = images.add(Image::new_fill(
+            Extent3d {
+                width: 30,
+                height: 30,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+        let image = UiImage::new(texture);
+        let scale_mode = ImageScaleMode::Sliced(TextureSlicer {
+            border: BorderRect::square(10.),
+            ..Default::default()
+        });
+        let draw_area = Vec2::splat(60.);
+        let node = Node {
+            calculated_size: draw_area,
+            unrounded_size: draw_area,
+            ..Default::default()
+        };
+
+        let slices = compute_texture_slices(
+            draw_area,
+            &scale_mode,
+            &image,
+            &images,
+            None,
+            &Assets::<TextureAtlasLayout>::default(),
+        )
+        .unwrap();
+        let transform = GlobalTransform::default();
+        let uvs = slices
+            .extract_ui_nodes(&transform, &node, &image, None, Entity::PLACEHOLDER)
+            .map(|uinode| {
+                let atlas_size = uinode.atlas_size.unwrap();
+                (
+                    uinode.rect.size(),
+                    uinode.rect.min / atlas_size,
+                    uinode.rect.max / atlas_size,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(uvs.len(), 9);
+
+        // the corners aren't scaled, and the center stretches over the rest of the node
+        let corners = uvs
+            .iter()
+            .filter(|(size, ..)| *size == Vec2::splat(10.))
+            .count();
+        assert_eq!(corners, 4);
+        let (_, min, max) = uvs
+            .iter()
+            .find(|(size, ..)| *size == Vec2::splat(40.))
+            .unwrap();
+        assert!(min.abs_diff_eq(Vec2::splat(1. / 3.), 1e-5));
+        assert!(max.abs_diff_eq(Vec2::splat(2. / 3.), 1e-5));
+    }
+}
@@ -0,0 +1,394 @@
+//! This module tracks the touches pressing UI nodes, each finger independently of the others
+
+use crate::{
+    focus::ui_viewport_size, ui_position, Disabled, HitTestQuery, TargetUiScale, UiHitTest,
+};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Component, With},
+    query::Has,
+    reflect::ReflectComponent,
+    system::{Local, Query, Res},
+};
+use bevy_input::touch::Touches;
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{camera::NormalizedRenderTarget, prelude::Camera};
+use bevy_window::PrimaryWindow;
+
+/// Tracks every touch pressing a UI node, for UI used with several fingers at once, like two
+/// sliders dragged together or on-screen game controls.
+///
+/// [`Interaction`](crate::Interaction) follows a single pointer, the cursor or else the first touch.
+/// Nodes with this component are also pressed by each touch that starts over them, see
+/// [`ui_touch_system`]. A touch presses the node it started on until it is lifted or cancelled,
+/// wherever it moves, and lifting one finger leaves the presses of the others untouched.
+#[derive(Component, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TouchInteraction {
+    /// The touches pressing the node, in the order they started
+    pub presses: Vec<TouchPress>,
+}
+
+impl TouchInteraction {
+    /// Returns true if at least one touch is pressing the node.
+    pub fn is_pressed(&self) -> bool {
+        !self.presses.is_empty()
+    }
+
+    /// Returns the press of the touch with the given [`Touch::id`](bevy_input::touch::Touch::id),
+    /// if it is pressing the node.
+    pub fn press(&self, id: u64) -> Option<&TouchPress> {
+        self.presses.iter().find(|press| press.id == id)
+    }
+}
+
+/// A touch pressing a node with a [`TouchInteraction`]
+#[derive(Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct TouchPress {
+    /// The [`Touch::id`](bevy_input::touch::Touch::id) of the touch
+    pub id: u64,
+    /// The position of the touch relative to the node, like [`RelativeCursorPosition::normalized`](crate::RelativeCursorPosition::normalized).
+    ///
+    /// Keeps its last value while the touch is outside of the viewport of the node's camera.
+    pub position: Option<Vec2>,
+}
+
+/// Contains the nodes pressed by each touch
+#[derive(Default)]
+pub struct TouchState {
+    targets: Vec<TouchTarget>,
+}
+
+/// The node pressed by a touch, and the camera used to find it
+struct TouchTarget {
+    id: u64,
+    node: Entity,
+    camera: Entity,
+}
+
+/// The system that updates the [`TouchInteraction`] of UI nodes from the [`Touches`].
+///
+/// Each new touch presses the topmost node with a [`TouchInteraction`] under it, unless a node
+/// above it blocks the touch with its [`FocusPolicy`](crate::FocusPolicy), or it is [`Disabled`].
+/// Touches are hit-tested against the active cameras rendering to a window with [`UiHitTest`], so
+/// they don't reach the nodes outside of the topmost [`Modal`](crate::Modal) either.
+///
+/// Disabling or despawning a node releases the touches pressing it.
+#[allow(clippy::too_many_arguments)]
+pub fn ui_touch_system(
+    mut state: Local<TouchState>,
+    touches: Res<Touches>,
+    camera_query: Query<(Entity, &Camera)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    ui_scale: TargetUiScale,
+    hit_test: UiHitTest,
+    node_query: Query<HitTestQuery>,
+    mut touch_query: Query<(Entity, &mut TouchInteraction, Has<Disabled>)>,
+) {
+    // Only the touches that were lifted or cancelled release their node. A touch id reused in the
+    // same frame is a new touch.
+    state.targets.retain(|target| {
+        touches.get_pressed(target.id).is_some()
+            && !touches.just_pressed(target.id)
+            && touch_query
+                .get(target.node)
+                .is_ok_and(|(_, _, disabled)| !disabled)
+    });
+
+    let primary_window = primary_window.get_single().ok();
+    for touch in touches.iter_just_pressed() {
+        if touches.get_pressed(touch.id()).is_none() {
+            continue;
+        }
+        let target = camera_query
+            .iter()
+            .filter(|(_, camera)| {
+                camera.is_active
+                    && matches!(
+                        camera.target.normalize(primary_window),
+                        Some(NormalizedRenderTarget::Window(_))
+                    )
+            })
+            .find_map(|(camera, _)| {
+                let node = hit_test
+                    .nodes_at(camera, touch.position())
+                    .into_iter()
+                    .find(|entity| {
+                        touch_query.contains(*entity)
+                            || node_query.get(*entity).map_or(true, |node| node.blocks())
+                    })?;
+                touch_query
+                    .get(node)
+                    .is_ok_and(|(_, _, disabled)| !disabled)
+                    .then_some(TouchTarget {
+                        id: touch.id(),
+                        node,
+                        camera,
+                    })
+            });
+        state.targets.extend(target);
+    }
+
+    for (entity, mut touch_interaction, _) in &mut touch_query {
+        let presses: Vec<TouchPress> = state
+            .targets
+            .iter()
+            .filter(|target| target.node == entity)
+            .map(|target| {
+                let position = touches
+                    .get_pressed(target.id)
+                    .and_then(|touch| {
+                        let (_, camera) = camera_query.get(target.camera).ok()?;
+                        let position = ui_position(camera, touch.position(), &ui_scale)?;
+                        node_query
+                            .get(entity)
+                            .ok()?
+                            .relative_cursor_position(
+                                Some(position),
+                                ui_viewport_size(camera, &ui_scale),
+                            )
+                            .normalized
+                    })
+                    .or_else(|| {
+                        touch_interaction
+                            .press(target.id)
+                            .and_then(|press| press.position)
+                    });
+                TouchPress {
+                    id: target.id,
+                    position,
+                }
+            })
+            .collect();
+        if touch_interaction.presses != presses {
+            touch_interaction.presses = presses;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core_pipeline::core_2d::Camera2dBundle;
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_input::touch::{touch_screen_input_system, TouchInput, TouchPhase, Touches};
+    use bevy_math::Vec2;
+    use bevy_render::view::ViewVisibility;
+    use bevy_transform::components::GlobalTransform;
+    use bevy_utils::default;
+    use bevy_window::{PrimaryWindow, Window, WindowResolution};
+
+    use super::{ui_touch_system, TouchInteraction, TouchPress};
+    use crate::{Modal, Node, UiScale, UiScaleMode, UiStack};
+
+    fn spawn_node(world: &mut World, rect_min: Vec2) -> Entity {
+        let size = Vec2::splat(100.);
+        let mut view_visibility = ViewVisibility::HIDDEN;
+        view_visibility.set();
+        let entity = world
+            .spawn((
+                Node {
+                    calculated_size: size,
+                    unrounded_size: size,
+                    ..default()
+                },
+                GlobalTransform::from_translation((rect_min + 0.5 * size).extend(0.)),
+                view_visibility,
+            ))
+            .id();
+        world.resource_mut::<UiStack>().uinodes.push(entity);
+        entity
+    }
+
+    fn touch(world: &mut World, window: Entity, id: u64, phase: TouchPhase, position: Vec2) {
+        world.send_event(TouchInput {
+            phase,
+            position,
+            window,
+            force: None,
+            id,
+        });
+    }
+
+    fn presses(world: &World, entity: Entity) -> Vec<TouchPress> {
+        world
+            .get::<TouchInteraction>(entity)
+            .unwrap()
+            .presses
+            .clone()
+    }
+
+    #[test]
+    fn touches_only_reach_the_topmost_modal() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<UiStack>();
+        world.init_resource::<Touches>();
+        world.init_resource::<Events<TouchInput>>();
+        let window = world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(1000., 100.),
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        world.spawn(Camera2dBundle::default());
+        let mut schedule = Schedule::default();
+        schedule.add_systems((touch_screen_input_system, ui_touch_system).chain());
+
+        // a slider next to a dialog without a backdrop
+        let slider = spawn_node(&mut world, Vec2::ZERO);
+        world.entity_mut(slider).insert(TouchInteraction::default());
+        let dialog = spawn_node(&mut world, Vec2::new(300., 0.));
+        world.entity_mut(dialog).insert(Modal);
+        let dialog_button = spawn_node(&mut world, Vec2::new(300., 0.));
+        world
+            .entity_mut(dialog_button)
+            .insert(TouchInteraction::default())
+            .set_parent(dialog);
+
+        touch(
+            &mut world,
+            window,
+            0,
+            TouchPhase::Started,
+            Vec2::new(50., 50.),
+        );
+        touch(
+            &mut world,
+            window,
+            1,
+            TouchPhase::Started,
+            Vec2::new(350., 50.),
+        );
+        schedule.run(&mut world);
+        assert!(!world.get::<TouchInteraction>(slider).unwrap().is_pressed());
+        assert_eq!(
+            presses(&world, dialog_button),
+            vec![TouchPress {
+                id: 1,
+                position: Some(Vec2::new(0.5, 0.5)),
+            }]
+        );
+    }
+
+    #[test]
+    fn each_touch_presses_its_own_node() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<UiStack>();
+        world.init_resource::<Touches>();
+        world.init_resource::<Events<TouchInput>>();
+        let window = world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(1000., 100.),
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        world.spawn(Camera2dBundle::default());
+        let mut schedule = Schedule::default();
+        schedule.add_systems((touch_screen_input_system, ui_touch_system).chain());
+
+        let left = spawn_node(&mut world, Vec2::ZERO);
+        world.entity_mut(left).insert(TouchInteraction::default());
+        let right = spawn_node(&mut world, Vec2::new(300., 0.));
+        world.entity_mut(right).insert(TouchInteraction::default());
+        // covered by a node blocking touches
+        let covered = spawn_node(&mut world, Vec2::new(600., 0.));
+        world
+            .entity_mut(covered)
+            .insert(TouchInteraction::default());
+        spawn_node(&mut world, Vec2::new(600., 0.));
+
+        touch(
+            &mut world,
+            window,
+            0,
+            TouchPhase::Started,
+            Vec2::new(50., 50.),
+        );
+        touch(
+            &mut world,
+            window,
+            1,
+            TouchPhase::Started,
+            Vec2::new(350., 25.),
+        );
+        touch(
+            &mut world,
+            window,
+            2,
+            TouchPhase::Started,
+            Vec2::new(650., 50.),
+        );
+        schedule.run(&mut world);
+        assert_eq!(
+            presses(&world, left),
+            vec![TouchPress {
+                id: 0,
+                position: Some(Vec2::new(0.5, 0.5)),
+            }]
+        );
+        assert_eq!(
+            presses(&world, right),
+            vec![TouchPress {
+                id: 1,
+                position: Some(Vec2::new(0.5, 0.25)),
+            }]
+        );
+        assert!(!world.get::<TouchInteraction>(covered).unwrap().is_pressed());
+
+        // the touches keep pressing their node when they leave it
+        touch(
+            &mut world,
+            window,
+            0,
+            TouchPhase::Moved,
+            Vec2::new(150., 50.),
+        );
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<TouchInteraction>(left).unwrap().press(0),
+            Some(&TouchPress {
+                id: 0,
+                position: Some(Vec2::new(1.5, 0.5)),
+            })
+        );
+
+        // lifting a finger only releases its own node
+        touch(
+            &mut world,
+            window,
+            1,
+            TouchPhase::Ended,
+            Vec2::new(350., 25.),
+        );
+        schedule.run(&mut world);
+        assert!(world.get::<TouchInteraction>(left).unwrap().is_pressed());
+        assert!(presses(&world, right).is_empty());
+
+        touch(
+            &mut world,
+            window,
+            0,
+            TouchPhase::Canceled,
+            Vec2::new(150., 50.),
+        );
+        schedule.run(&mut world);
+        assert!(presses(&world, left).is_empty());
+    }
+}
@@ -16,6 +16,10 @@ use bevy_render::render_resource::{AsBindGroup, RenderPipelineDescriptor, Shader
 /// from `bevy_ui::ui_vertex_output` and uses it as the input of your fragment shader like the
 /// example below does.
 ///
+/// Material nodes are clipped by their [`CalculatedClip`](crate::CalculatedClip) like the other UI nodes.
+/// The clipping is done when the vertices are prepared, with the `uv` of the clipped vertices adjusted
+/// to match, so fragment shaders don't need to handle it.
+///
 /// # Example
 ///
 /// Here is a simple [`UiMaterial`] implementation. The [`AsBindGroup`] derive has many features. To see what else is available,
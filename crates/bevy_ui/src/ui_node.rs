@@ -1,13 +1,14 @@
-use crate::{UiRect, Val};
+use crate::{UiRect, UiScale, UiScaleMode, Val, WindowUiScale};
 use bevy_asset::Handle;
-use bevy_color::Color;
+use bevy_color::{Color, Mix, Srgba};
 use bevy_ecs::{prelude::*, system::SystemParam};
-use bevy_math::{Rect, Vec2};
+use bevy_math::{Rect, Vec2, Vec3};
 use bevy_reflect::prelude::*;
 use bevy_render::{
-    camera::{Camera, RenderTarget},
+    camera::{Camera, NormalizedRenderTarget, RenderTarget},
     texture::Image,
 };
+use bevy_sprite::BorderRect;
 use bevy_transform::prelude::GlobalTransform;
 use bevy_utils::warn_once;
 use bevy_window::{PrimaryWindow, WindowRef};
@@ -44,6 +45,14 @@ pub struct Node {
     ///
     /// Automatically calculated by [`super::layout::ui_layout_system`].
     pub(crate) unrounded_size: Vec2,
+    /// The direction of the node, with [`Direction::Inherit`] resolved against its ancestors.
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub(crate) direction: Direction,
+    /// The combined widths of the border and padding of the node on each side, in logical pixels.
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub(crate) content_inset: BorderRect,
 }
 
 impl Node {
@@ -109,6 +118,34 @@ impl Node {
     pub fn outline_width(&self) -> f32 {
         self.outline_width
     }
+
+    /// The direction of the node's contents, either [`Direction::LeftToRight`] or [`Direction::RightToLeft`].
+    ///
+    /// A node with a `direction` of [`Direction::Inherit`] takes the direction of its parent, root nodes
+    /// default to [`Direction::LeftToRight`].
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub const fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// The combined widths of the border and padding of the node on each side, in logical pixels.
+    ///
+    /// The content box of the node, which clips its children when it doesn't have a visible [`Overflow`],
+    /// is its [`logical_rect`](Node::logical_rect) shrunk by this inset.
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub const fn content_inset(&self) -> BorderRect {
+        self.content_inset
+    }
+
+    /// The size of the content box of the node, which is its [`size`](Node::size) without its
+    /// [`content_inset`](Node::content_inset), in logical pixels.
+    pub fn content_box_size(&self) -> Vec2 {
+        let inset = self.content_inset;
+        (self.size() - Vec2::new(inset.left + inset.right, inset.top + inset.bottom))
+            .max(Vec2::ZERO)
+    }
 }
 
 impl Node {
@@ -118,6 +155,13 @@ impl Node {
         outline_width: 0.,
         outline_offset: 0.,
         unrounded_size: Vec2::ZERO,
+        direction: Direction::LeftToRight,
+        content_inset: BorderRect {
+            left: 0.,
+            right: 0.,
+            top: 0.,
+            bottom: 0.,
+        },
     };
 }
 
@@ -175,7 +219,7 @@ pub struct Style {
 
     /// Defines the text direction. For example, English is written LTR (left-to-right) while Arabic is written RTL (right-to-left).
     ///
-    /// Note: the corresponding CSS property also affects box layout order, but this isn't yet implemented in Bevy.
+    /// The children of a right-to-left node are mirrored horizontally, see [`Direction::RightToLeft`].
     ///
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/direction>
     pub direction: Direction,
@@ -468,6 +512,24 @@ impl Style {
         grid_column: GridPlacement::DEFAULT,
         grid_row: GridPlacement::DEFAULT,
     };
+
+    /// Returns the [`Style`] with both its `row_gap` and `column_gap` set to the given value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ui::{Style, Val};
+    /// #
+    /// let style = Style::default().with_gap(Val::Px(10.0));
+    /// assert_eq!(style.row_gap, Val::Px(10.0));
+    /// assert_eq!(style.column_gap, Val::Px(10.0));
+    /// ```
+    #[inline]
+    pub fn with_gap(mut self, gap: Val) -> Self {
+        self.row_gap = gap;
+        self.column_gap = gap;
+        self
+    }
 }
 
 impl Default for Style {
@@ -746,6 +808,11 @@ pub enum Direction {
     /// Text is written left to right.
     LeftToRight,
     /// Text is written right to left.
+    ///
+    /// The children of the node are laid out left to right and then mirrored horizontally within the node,
+    /// so rows flow from right to left and start and end alignments are flipped. Physical offsets like
+    /// `left`, `right` and horizontal margins and paddings of the children are mirrored as well.
+    /// The `JustifyText::Left` and `JustifyText::Right` justifications are swapped for text in the node.
     RightToLeft,
 }
 
@@ -875,6 +942,30 @@ impl Overflow {
         }
     }
 
+    /// Clip overflowing items on both axes and allow them to be scrolled with a [`ScrollPosition`]
+    pub const fn scroll() -> Self {
+        Self {
+            x: OverflowAxis::Scroll,
+            y: OverflowAxis::Scroll,
+        }
+    }
+
+    /// Clip overflowing items on the x axis and allow them to be scrolled horizontally with a [`ScrollPosition`]
+    pub const fn scroll_x() -> Self {
+        Self {
+            x: OverflowAxis::Scroll,
+            y: OverflowAxis::Visible,
+        }
+    }
+
+    /// Clip overflowing items on the y axis and allow them to be scrolled vertically with a [`ScrollPosition`]
+    pub const fn scroll_y() -> Self {
+        Self {
+            x: OverflowAxis::Visible,
+            y: OverflowAxis::Scroll,
+        }
+    }
+
     /// Overflow is visible on both axes
     pub const fn is_visible(&self) -> bool {
         self.x.is_visible() && self.y.is_visible()
@@ -902,6 +993,9 @@ pub enum OverflowAxis {
     Clip,
     /// Hide overflowing items by influencing layout and then clipping.
     Hidden,
+    /// Hide overflowing items by influencing layout and then clipping, and shift them by the
+    /// node's [`ScrollPosition`] along this axis.
+    Scroll,
 }
 
 impl OverflowAxis {
@@ -1680,6 +1774,121 @@ pub enum GridPlacementError {
     InvalidZeroSpan,
 }
 
+/// Names the areas of a grid container, like the CSS `grid-template-areas` property.
+///
+/// Each row is a string of whitespace-separated area names, one for each column, with `.` for the
+/// cells that aren't part of any area. The cells of an area must form a rectangle.
+///
+/// Children of the container with a [`GridArea`] are placed over the cells of their area by
+/// [`update_grid_area_system`](crate::update::update_grid_area_system), which sets their
+/// [`Style::grid_row`] and [`Style::grid_column`].
+///
+/// ```
+/// # use bevy_ui::{GridPlacement, GridTemplateAreas};
+/// let areas = GridTemplateAreas::new([
+///     "header header",
+///     "sidebar main",
+/// ]);
+/// assert_eq!(
+///     areas.area("header"),
+///     Ok((GridPlacement::start_span(1, 1), GridPlacement::start_span(1, 2)))
+/// );
+/// ```
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-areas>
+#[derive(Component, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct GridTemplateAreas {
+    /// The area name of each cell, row by row
+    pub rows: Vec<Vec<String>>,
+}
+
+impl GridTemplateAreas {
+    /// The name of the cells that aren't part of any area
+    pub const EMPTY_CELL: &'static str = ".";
+
+    /// Creates a template from one string of whitespace-separated area names per row.
+    pub fn new(rows: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.as_ref().split_whitespace().map(String::from).collect())
+                .collect(),
+        }
+    }
+
+    /// Returns the row and column placements covering the cells of the area called `name`.
+    pub fn area(&self, name: &str) -> Result<(GridPlacement, GridPlacement), GridAreaError> {
+        let mut cells = self.rows.iter().enumerate().flat_map(|(row, names)| {
+            names
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| *cell == name && name != Self::EMPTY_CELL)
+                .map(move |(column, _)| (row, column))
+        });
+        let Some((first_row, first_column)) = cells.next() else {
+            return Err(GridAreaError::UnknownArea(name.to_string()));
+        };
+        let (mut min, mut max, mut count) =
+            ((first_row, first_column), (first_row, first_column), 1);
+        for (row, column) in cells {
+            min = (min.0.min(row), min.1.min(column));
+            max = (max.0.max(row), max.1.max(column));
+            count += 1;
+        }
+        let (rows, columns) = (max.0 - min.0 + 1, max.1 - min.1 + 1);
+        // every cell of the area is inside its bounding box, so it is a rectangle if they fill it
+        if count != rows * columns {
+            return Err(GridAreaError::NotRectangular(name.to_string()));
+        }
+        Ok((
+            GridPlacement::start_span(min.0 as i16 + 1, rows as u16),
+            GridPlacement::start_span(min.1 as i16 + 1, columns as u16),
+        ))
+    }
+
+    /// Checks that all the rows have the same number of columns, and that every area is a rectangle.
+    pub fn validate(&self) -> Result<(), GridAreaError> {
+        if let Some(first_row) = self.rows.first() {
+            if self.rows.iter().any(|row| row.len() != first_row.len()) {
+                return Err(GridAreaError::UnevenRows);
+            }
+        }
+        for name in self.rows.iter().flatten() {
+            if name != Self::EMPTY_CELL {
+                self.area(name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Places a grid item over the cells of the area with this name in the [`GridTemplateAreas`] of
+/// its parent.
+///
+/// The placement replaces the node's [`Style::grid_row`] and [`Style::grid_column`].
+#[derive(Component, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct GridArea(pub String);
+
+impl GridArea {
+    /// Creates a [`GridArea`] for the area called `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// Errors that occur when resolving the areas of a [`GridTemplateAreas`]
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum GridAreaError {
+    #[error("The grid template has no area named `{0}`")]
+    UnknownArea(String),
+    #[error("The cells of the grid area `{0}` don't form a rectangle")]
+    NotRectangular(String),
+    #[error("The rows of the grid template don't all have the same number of columns")]
+    UnevenRows,
+}
+
 /// The background color of the node
 ///
 /// This serves as the "fill" color.
@@ -1708,6 +1917,150 @@ impl<T: Into<Color>> From<T> for BackgroundColor {
     }
 }
 
+/// A color at a given position along a [`BackgroundGradient`]
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorStop {
+    /// The color at the stop
+    pub color: Color,
+    /// The position of the stop along the gradient, from `0.` at its start to `1.` at its end
+    pub position: f32,
+}
+
+impl ColorStop {
+    pub const DEFAULT: Self = Self {
+        color: Color::WHITE,
+        position: 0.,
+    };
+
+    /// Create a new color stop at the given position
+    pub const fn new(color: Color, position: f32) -> Self {
+        Self { color, position }
+    }
+}
+
+impl Default for ColorStop {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How the colors of a [`BackgroundGradient`] are laid out over the node
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GradientShape {
+    /// The colors change along a line through the center of the node.
+    Linear {
+        /// The direction of the line in radians, clockwise with `0.` pointing up.
+        ///
+        /// The line is long enough for its ends to reach the corners of the node, as in CSS.
+        angle: f32,
+    },
+    /// The colors change with the distance from a point, reaching the end of the gradient at the
+    /// farthest corner of the node.
+    Radial {
+        /// The center of the gradient, from `(0., 0.)` at the top left corner of the node to `(1., 1.)`
+        /// at its bottom right corner
+        center: Vec2,
+    },
+}
+
+impl GradientShape {
+    /// A linear gradient from the top of the node to its bottom
+    pub const DEFAULT: Self = Self::Linear {
+        angle: std::f32::consts::PI,
+    };
+}
+
+impl Default for GradientShape {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A gradient filling the background of the node, drawn instead of its [`BackgroundColor`].
+///
+/// Stop positions are clamped between `0.` and `1.`, and a stop placed before a previous one is
+/// moved to the position of the previous one, as in CSS.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient>
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BackgroundGradient {
+    /// How the colors are laid out over the node
+    pub shape: GradientShape,
+    /// The colors of the gradient, in order
+    pub stops: Vec<ColorStop>,
+}
+
+impl BackgroundGradient {
+    /// Create a linear gradient with colors changing in the direction given by `angle`, in radians
+    /// clockwise with `0.` pointing up
+    pub fn linear(angle: f32, stops: impl Into<Vec<ColorStop>>) -> Self {
+        Self {
+            shape: GradientShape::Linear { angle },
+            stops: stops.into(),
+        }
+    }
+
+    /// Create a radial gradient centered on `center`, relative to the size of the node
+    pub fn radial(center: Vec2, stops: impl Into<Vec<ColorStop>>) -> Self {
+        Self {
+            shape: GradientShape::Radial { center },
+            stops: stops.into(),
+        }
+    }
+
+    /// Returns the stops with their positions clamped between `0.` and `1.` and in increasing order.
+    pub fn normalized_stops(&self) -> Vec<ColorStop> {
+        let mut previous = 0f32;
+        self.stops
+            .iter()
+            .map(|stop| {
+                previous = stop.position.clamp(0., 1.).max(previous);
+                ColorStop::new(stop.color, previous)
+            })
+            .collect()
+    }
+
+    /// Returns the color of the gradient at `position`, from `0.` at its start to `1.` at its end.
+    ///
+    /// Colors are interpolated in the sRGB color space, as in CSS.
+    pub fn color_at(&self, position: f32) -> Color {
+        let stops = self.normalized_stops();
+        let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+            return Color::NONE;
+        };
+        if position <= first.position {
+            return first.color;
+        }
+        stops
+            .windows(2)
+            .find(|pair| position < pair[1].position)
+            .map_or(last.color, |pair| {
+                let t = (position - pair[0].position) / (pair[1].position - pair[0].position);
+                Srgba::from(pair[0].color)
+                    .mix(&Srgba::from(pair[1].color), t)
+                    .into()
+            })
+    }
+}
+
 /// The border color of the UI node.
 #[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
 #[reflect(Component, Default)]
@@ -1818,6 +2171,106 @@ impl Outline {
     }
 }
 
+/// A soft shadow drawn behind a UI node.
+///
+/// The shadow has the shape of the node, including the corners rounded by its [`BorderRadius`].
+/// It is drawn above the nodes before this one in the [`UiStack`](crate::UiStack), but below the node itself.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/box-shadow>
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BoxShadow {
+    /// The color of the shadow
+    pub color: Color,
+    /// How far the shadow is moved from the node, in logical pixels. Positive values move it right and down.
+    pub offset: Vec2,
+    /// How far the edges of the shadow fade out, in logical pixels
+    pub blur: f32,
+    /// How much larger than the node the shadow is on each side, in logical pixels. Negative values shrink it.
+    pub spread: f32,
+}
+
+impl BoxShadow {
+    pub const DEFAULT: Self = Self {
+        color: Color::srgba(0., 0., 0., 0.5),
+        offset: Vec2::new(0., 4.),
+        blur: 8.,
+        spread: 0.,
+    };
+}
+
+impl Default for BoxShadow {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Draws a copy of the glyphs of a text node below them.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/text-shadow>
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextShadow {
+    /// The color of the shadow
+    pub color: Color,
+    /// How far the shadow is moved from the text, in logical pixels. Positive values move it right and down.
+    pub offset: Vec2,
+}
+
+impl TextShadow {
+    pub const DEFAULT: Self = Self {
+        color: Color::srgba(0., 0., 0., 0.75),
+        offset: Vec2::new(2., 2.),
+    };
+}
+
+impl Default for TextShadow {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Draws an outline around the glyphs of a text node.
+///
+/// The outline is made of copies of the glyphs offset in eight directions, so gaps can appear
+/// in the corners of glyphs for widths larger than a few pixels.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextOutline {
+    /// The color of the outline
+    pub color: Color,
+    /// The width of the outline, in logical pixels
+    pub width: f32,
+}
+
+impl TextOutline {
+    pub const DEFAULT: Self = Self {
+        color: Color::BLACK,
+        width: 1.,
+    };
+}
+
+impl Default for TextOutline {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// The 2D texture displayed for this UI node
 #[derive(Component, Clone, Debug, Reflect, Default)]
 #[reflect(Component, Default)]
@@ -1830,6 +2283,13 @@ pub struct UiImage {
     pub flip_x: bool,
     /// Whether the image should be flipped along its y-axis
     pub flip_y: bool,
+    /// An optional rectangle of the texture to display, in pixels, instead of the whole texture.
+    ///
+    /// When the node also has a [`TextureAtlas`](bevy_sprite::TextureAtlas), the rectangle is
+    /// relative to the top left corner of the atlas section.
+    pub rect: Option<Rect>,
+    /// How the image is resized to fit the node when their aspect ratios differ
+    pub fit: ImageFit,
 }
 
 impl UiImage {
@@ -1860,6 +2320,57 @@ impl UiImage {
         self.flip_y = true;
         self
     }
+
+    /// Only display the given rectangle of the texture, in pixels
+    #[must_use]
+    pub const fn with_rect(mut self, rect: Rect) -> Self {
+        self.rect = Some(rect);
+        self
+    }
+
+    /// Set how the image is resized to fit the node
+    #[must_use]
+    pub const fn with_fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+}
+
+/// How the texture of a [`UiImage`] is resized to fit its node, like the CSS `object-fit` property.
+///
+/// The image is centered in the node, and is drawn according to the [`UiImage::rect`] and
+/// [`TextureAtlas`](bevy_sprite::TextureAtlas) of the node when it has them. Images sliced by an
+/// [`ImageScaleMode`](bevy_sprite::ImageScaleMode) are always stretched to fill their node, as
+/// their slices are scaled on their own.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ImageFit {
+    /// The image is stretched to fill the node, ignoring its aspect ratio.
+    Fill,
+    /// The image is scaled to fit inside the node, keeping its aspect ratio, so the node is
+    /// letterboxed along one axis.
+    Contain,
+    /// The image is scaled to cover the node, keeping its aspect ratio, so it is cropped along one
+    /// axis.
+    Cover,
+    /// The image keeps the size of its texture in logical pixels, and is cropped if the node is
+    /// smaller.
+    None,
+}
+
+impl ImageFit {
+    pub const DEFAULT: Self = Self::Fill;
+}
+
+impl Default for ImageFit {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
 impl From<Handle<Image>> for UiImage {
@@ -1868,6 +2379,34 @@ impl From<Handle<Image>> for UiImage {
     }
 }
 
+/// How far the children of a node are scrolled, along the axes on which the node's [`Overflow`]
+/// is [`OverflowAxis::Scroll`].
+///
+/// [`ui_layout_system`](crate::layout::ui_layout_system) shifts the children by the offset and clamps
+/// it so the content can't be scrolled past its ends.
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ScrollPosition {
+    /// How far the children are scrolled right and down, in logical pixels
+    pub offset: Vec2,
+}
+
+/// The name announced by assistive technologies for a UI node.
+///
+/// Buttons, images, checkboxes, toggles and sliders are otherwise named after the text of their
+/// children, and labels after their own text. Use this for nodes whose text doesn't describe them,
+/// such as icon buttons.
+#[derive(Component, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct AccessibilityLabel(pub String);
+
+impl AccessibilityLabel {
+    /// Creates a label with the given text.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self(label.into())
+    }
+}
+
 /// The calculated clip of the node
 #[derive(Component, Default, Copy, Clone, Debug, Reflect)]
 #[reflect(Component, Default)]
@@ -1888,6 +2427,12 @@ pub struct CalculatedClip {
 /// (nodes that have no parent). Because of this, there is no difference between using
 /// `ZIndex::Local(n)` and `ZIndex::Global(n)` for root nodes.
 ///
+/// A node with a `ZIndex::Local` or `ZIndex::Global` z-index is a stacking context: its descendants
+/// with local z-indices are ordered among themselves and drawn together with it. A node with
+/// `ZIndex::Auto` isn't, so it and its children are ordered in the stacking context of its parent,
+/// like in the painter's algorithm. A child of an auto node with `ZIndex::Local(-1)` is drawn below
+/// it, and a child with `ZIndex::Local(1)` above the siblings of the auto node with lower z-indices.
+///
 /// Nodes without this component will be treated as if they had a value of `ZIndex::Local(0)`.
 #[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
 #[reflect(Component, Default)]
@@ -1897,6 +2442,9 @@ pub enum ZIndex {
     /// Indicates the order in which this node should be rendered relative to root nodes and
     /// all other nodes that have a global z-index.
     Global(i32),
+    /// The node is rendered in the stacking context of its parent at a z-index of 0, at its place
+    /// in the hierarchy, and its children are ordered in that context too.
+    Auto,
 }
 
 impl Default for ZIndex {
@@ -2169,7 +2717,73 @@ impl BorderRadius {
 
 #[cfg(test)]
 mod tests {
-    use crate::GridPlacement;
+    use crate::{GridAreaError, GridPlacement, GridTemplateAreas};
+
+    #[test]
+    fn grid_template_areas_resolve_to_the_placement_of_their_cells() {
+        let areas = GridTemplateAreas::new([
+            "header header header",
+            "sidebar main main",
+            "sidebar main main",
+            ". footer .",
+        ]);
+        assert_eq!(areas.validate(), Ok(()));
+        assert_eq!(
+            areas.area("main"),
+            Ok((
+                GridPlacement::start_span(2, 2),
+                GridPlacement::start_span(2, 2)
+            ))
+        );
+        assert_eq!(
+            areas.area("sidebar"),
+            Ok((
+                GridPlacement::start_span(2, 2),
+                GridPlacement::start_span(1, 1)
+            ))
+        );
+        assert_eq!(
+            areas.area("footer"),
+            Ok((
+                GridPlacement::start_span(4, 1),
+                GridPlacement::start_span(2, 1)
+            ))
+        );
+        assert_eq!(
+            areas.area("menu"),
+            Err(GridAreaError::UnknownArea("menu".to_string()))
+        );
+        assert_eq!(
+            areas.area("."),
+            Err(GridAreaError::UnknownArea(".".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_grid_template_areas() {
+        let areas = GridTemplateAreas::new(["a a", "a b"]);
+        assert_eq!(
+            areas.area("a"),
+            Err(GridAreaError::NotRectangular("a".to_string()))
+        );
+        assert_eq!(
+            areas.validate(),
+            Err(GridAreaError::NotRectangular("a".to_string()))
+        );
+        assert_eq!(
+            areas.area("b").map(|(row, _)| row),
+            Ok(GridPlacement::start_span(2, 1))
+        );
+
+        let areas = GridTemplateAreas::new(["a . a"]);
+        assert_eq!(
+            areas.area("a"),
+            Err(GridAreaError::NotRectangular("a".to_string()))
+        );
+
+        let areas = GridTemplateAreas::new(["a b", "c"]);
+        assert_eq!(areas.validate(), Err(GridAreaError::UnevenRows));
+    }
 
     #[test]
     fn invalid_grid_placement_values() {
@@ -2215,6 +2829,46 @@ impl TargetCamera {
     }
 }
 
+/// Pins a root [`Node`] to a point in the world, such as a nameplate above a character.
+///
+/// Each frame, [`update_world_space_ui_system`](crate::update::update_world_space_ui_system)
+/// projects the point through the node's [`TargetCamera`], or the default UI camera, and
+/// centers the node on it by setting its [`Style::left`] and [`Style::top`] with
+/// [`PositionType::Absolute`]. The node is hidden through its
+/// [`Visibility`](bevy_render::view::Visibility) while the point is outside of the camera's depth
+/// range, such as behind it.
+///
+/// The projection uses the camera's [`GlobalTransform`] from the previous frame, so the node lags
+/// one frame behind a moving camera.
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct WorldSpaceUi {
+    /// The point in the world the node is centered on
+    pub world_position: Vec3,
+    /// If set, the node's [`Transform`](bevy_transform::prelude::Transform) is scaled by
+    /// `reference_distance / distance`, so it shrinks as the camera moves away from the point
+    /// and has its layout size at `reference_distance`.
+    ///
+    /// The scale is only visual, interactions still use the layout size.
+    pub reference_distance: Option<f32>,
+}
+
+impl WorldSpaceUi {
+    /// Creates a [`WorldSpaceUi`] pinned to `world_position`, that isn't scaled with distance.
+    pub const fn new(world_position: Vec3) -> Self {
+        Self {
+            world_position,
+            reference_distance: None,
+        }
+    }
+
+    /// Scales the node with its distance to the camera, see [`WorldSpaceUi::reference_distance`].
+    pub const fn with_reference_distance(mut self, reference_distance: f32) -> Self {
+        self.reference_distance = Some(reference_distance);
+        self
+    }
+}
+
 #[derive(Component)]
 /// Marker used to identify default cameras, they will have priority over the [`PrimaryWindow`] camera.
 ///
@@ -2281,3 +2935,56 @@ impl<'w, 's> DefaultUiCamera<'w, 's> {
         })
     }
 }
+
+/// Resolves the [`UiScale`] of the nodes rendered to each window and camera, from the
+/// [`WindowUiScale`] of their window or the [`UiScale`] resource, and the [`UiScaleMode`].
+#[derive(SystemParam)]
+pub struct TargetUiScale<'w, 's> {
+    ui_scale: Res<'w, UiScale>,
+    ui_scale_mode: Res<'w, UiScaleMode>,
+    window_ui_scales: Query<'w, 's, Ref<'static, WindowUiScale>>,
+    primary_window: Query<'w, 's, Entity, With<PrimaryWindow>>,
+}
+
+impl<'w, 's> TargetUiScale<'w, 's> {
+    /// Returns the scale between the logical pixels of `window` and the ui values of its nodes
+    pub fn window(&self, window: Entity, window_scale_factor: f32) -> f32 {
+        let ui_scale = self
+            .window_ui_scales
+            .get(window)
+            .map_or(self.ui_scale.0, |window_ui_scale| window_ui_scale.0);
+        UiScale(ui_scale).for_target(*self.ui_scale_mode, window_scale_factor)
+    }
+
+    /// Returns the scale between the logical pixels of the target of `camera` and the ui values of its nodes
+    pub fn camera(&self, camera: Option<&Camera>) -> f32 {
+        let Some(camera) = camera else {
+            return self.ui_scale.0;
+        };
+        let scale_factor = camera.target_scaling_factor().unwrap_or(1.);
+        match camera
+            .target
+            .normalize(self.primary_window.get_single().ok())
+        {
+            Some(NormalizedRenderTarget::Window(window_ref)) => {
+                self.window(window_ref.entity(), scale_factor)
+            }
+            _ => self.ui_scale.for_target(*self.ui_scale_mode, scale_factor),
+        }
+    }
+
+    /// Returns the number of physical pixels of the target of `camera` per ui value of its nodes
+    pub fn camera_scale_factor(&self, camera: Option<&Camera>) -> f32 {
+        camera.and_then(Camera::target_scaling_factor).unwrap_or(1.) * self.camera(camera)
+    }
+
+    /// Returns whether any of the scales changed since the last run of the system
+    pub fn is_changed(&self) -> bool {
+        self.ui_scale.is_changed()
+            || self.ui_scale_mode.is_changed()
+            || self
+                .window_ui_scales
+                .iter()
+                .any(|window_ui_scale| window_ui_scale.is_changed())
+    }
+}
@@ -0,0 +1,208 @@
+use bevy_ecs::{
+    bundle::Bundle,
+    entity::Entity,
+    system::{Commands, EntityCommands},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuilder};
+
+/// A tree of UI nodes to spawn at once, as an alternative to nesting
+/// [`with_children`](BuildChildren::with_children) closures.
+///
+/// Each node of the tree is spawned with its bundles, and its children are added in order, so the
+/// spawned hierarchy is the same as the one built by hand. Trees are spawned with
+/// [`SpawnUiTree::spawn_ui_tree`], from [`Commands`] or from a [`ChildBuilder`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{node_bundles::{ButtonBundle, ImageBundle, NodeBundle}, SpawnUiTree, UiTree};
+/// #[derive(Component)]
+/// enum MenuAction {
+///     Play,
+///     Quit,
+/// }
+///
+/// fn spawn_menu(mut commands: Commands) {
+///     let button = |action: MenuAction| {
+///         UiTree::new(ButtonBundle::default())
+///             .insert(action)
+///             .with_child(UiTree::new(ImageBundle::default()))
+///     };
+///     commands.spawn_ui_tree(
+///         UiTree::new(NodeBundle::default())
+///             .with_children([button(MenuAction::Play), button(MenuAction::Quit)]),
+///     );
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_menu);
+/// ```
+pub struct UiTree {
+    insert: Box<dyn FnOnce(&mut EntityCommands) + Send + Sync>,
+    children: Vec<UiTree>,
+}
+
+impl UiTree {
+    /// Creates a node without children spawned with `bundle`.
+    pub fn new(bundle: impl Bundle) -> Self {
+        Self {
+            insert: Box::new(move |entity| {
+                entity.insert(bundle);
+            }),
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns this node with `bundle` added to the bundles it is spawned with, like markers or
+    /// other components of the node.
+    pub fn insert(mut self, bundle: impl Bundle) -> Self {
+        let insert = self.insert;
+        self.insert = Box::new(move |entity| {
+            insert(entity);
+            entity.insert(bundle);
+        });
+        self
+    }
+
+    /// Returns this node with `child` added after its other children.
+    pub fn with_child(mut self, child: UiTree) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Returns this node with `children` added after its other children, in order.
+    pub fn with_children(mut self, children: impl IntoIterator<Item = UiTree>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Spawns the nodes of the tree, with `entity` as its root node, and returns the root entity.
+    pub fn spawn(self, mut entity: EntityCommands) -> Entity {
+        let Self { insert, children } = self;
+        insert(&mut entity);
+        if !children.is_empty() {
+            entity.with_children(|parent| {
+                for child in children {
+                    parent.spawn_ui_tree(child);
+                }
+            });
+        }
+        entity.id()
+    }
+}
+
+/// Spawns a [`UiTree`] from [`Commands`] or a [`ChildBuilder`].
+pub trait SpawnUiTree {
+    /// Spawns the nodes of `tree`, and returns the entity of its root node.
+    ///
+    /// From a [`ChildBuilder`], the root node is added to the children of its parent.
+    fn spawn_ui_tree(&mut self, tree: UiTree) -> Entity;
+}
+
+impl SpawnUiTree for Commands<'_, '_> {
+    fn spawn_ui_tree(&mut self, tree: UiTree) -> Entity {
+        tree.spawn(self.spawn_empty())
+    }
+}
+
+impl SpawnUiTree for ChildBuilder<'_> {
+    fn spawn_ui_tree(&mut self, tree: UiTree) -> Entity {
+        tree.spawn(self.spawn_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::{Component, ComponentId},
+        entity::Entity,
+        system::Commands,
+        world::{CommandQueue, World},
+    };
+    use bevy_hierarchy::{BuildChildren, Children};
+
+    use super::{SpawnUiTree, UiTree};
+    use crate::{node_bundles::NodeBundle, Style, Val};
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Label(&'static str);
+
+    fn node(width: f32) -> NodeBundle {
+        NodeBundle {
+            style: Style {
+                width: Val::Px(width),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Returns the component ids, width, label and children of each node of the hierarchy of
+    /// `root`, depth first
+    fn hierarchy(
+        world: &World,
+        root: Entity,
+    ) -> Vec<(Vec<ComponentId>, Val, Option<&Label>, usize)> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            let mut components: Vec<ComponentId> =
+                world.inspect_entity(entity).map(|info| info.id()).collect();
+            components.sort();
+            let children = world.get::<Children>(entity).map_or(&[][..], |c| &**c);
+            nodes.push((
+                components,
+                world.get::<Style>(entity).unwrap().width,
+                world.get::<Label>(entity),
+                children.len(),
+            ));
+            stack.extend(children.iter().rev());
+        }
+        nodes
+    }
+
+    #[test]
+    fn trees_spawn_the_same_hierarchy_as_nested_spawns() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let by_hand = commands
+            .spawn(node(100.))
+            .with_children(|parent| {
+                parent.spawn((node(10.), Label("a")));
+                parent.spawn(node(20.)).with_children(|parent| {
+                    parent.spawn(node(1.));
+                    parent.spawn((node(2.), Label("b")));
+                });
+            })
+            .id();
+
+        let tree = || {
+            UiTree::new(node(100.)).with_children([
+                UiTree::new(node(10.)).insert(Label("a")),
+                UiTree::new(node(20.))
+                    .with_child(UiTree::new(node(1.)))
+                    .with_child(UiTree::new(node(2.)).insert(Label("b"))),
+            ])
+        };
+        let from_commands = commands.spawn_ui_tree(tree());
+        let mut from_child_builder = None;
+        let parent = commands
+            .spawn(node(0.))
+            .with_children(|parent| from_child_builder = Some(parent.spawn_ui_tree(tree())))
+            .id();
+        queue.apply(&mut world);
+
+        let expected = hierarchy(&world, by_hand);
+        assert_eq!(expected.len(), 5);
+        assert_eq!(hierarchy(&world, from_commands), expected);
+        let from_child_builder = from_child_builder.unwrap();
+        assert_eq!(
+            world.get::<Children>(parent).unwrap().as_ref(),
+            [from_child_builder]
+        );
+        // the root node spawned from a `ChildBuilder` also has a `Parent`
+        let mut nested = hierarchy(&world, from_child_builder);
+        assert_eq!(nested.len(), expected.len());
+        nested[0].0.clone_from(&expected[0].0);
+        assert_eq!(nested, expected);
+    }
+}
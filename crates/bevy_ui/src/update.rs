@@ -1,17 +1,30 @@
 //! This module contains systems that update the UI when something changes
 
-use crate::{CalculatedClip, Display, OverflowAxis, Style, TargetCamera};
+use crate::{
+    CalculatedClip, DefaultUiCamera, Display, GridArea, GridTemplateAreas, OverflowAxis,
+    PositionType, Style, TargetCamera, TargetUiScale, UiScale, Val, WindowUiScale, WorldSpaceUi,
+};
 
 use super::Node;
 use bevy_ecs::{
-    entity::Entity,
+    change_detection::{DetectChanges, DetectChangesMut},
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventWriter},
     query::{Changed, With, Without},
-    system::{Commands, Query},
+    reflect::ReflectResource,
+    removal_detection::RemovedComponents,
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+    world::Ref,
 };
 use bevy_hierarchy::{Children, Parent};
-use bevy_math::Rect;
-use bevy_transform::components::GlobalTransform;
-use bevy_utils::HashSet;
+use bevy_math::{Rect, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{camera::Camera, view::Visibility};
+use bevy_time::Time;
+use bevy_transform::components::{GlobalTransform, Transform};
+use bevy_utils::{tracing::warn, HashSet};
+use bevy_window::Window;
+use std::time::Duration;
 
 /// Updates clipping for all nodes
 pub fn update_clipping_system(
@@ -81,6 +94,10 @@ fn update_clipping(
         // of nested `Overflow::Hidden` nodes. If parent `clip` is not
         // defined, use the current node's clip.
         let mut node_rect = node.logical_rect(global_transform);
+        // children are clipped to the content box
+        let inset = node.content_inset();
+        node_rect.min += Vec2::new(inset.left, inset.top);
+        node_rect.max -= Vec2::new(inset.right, inset.bottom);
         if style.overflow.x == OverflowAxis::Visible {
             node_rect.min.x = -f32::INFINITY;
             node_rect.max.x = f32::INFINITY;
@@ -181,3 +198,591 @@ fn update_children_target_camera(
         );
     }
 }
+
+/// Places the children of [`GridTemplateAreas`] containers with a [`GridArea`] over the cells of their area.
+///
+/// Invalid templates, and areas that are missing from their parent's template or don't form a
+/// rectangle, are reported with a warning when they change. The placement of such nodes is left as is.
+pub fn update_grid_area_system(
+    template_query: Query<(Entity, Ref<GridTemplateAreas>)>,
+    mut area_query: Query<(Entity, Ref<GridArea>, Ref<Parent>, &mut Style)>,
+) {
+    for (entity, template) in &template_query {
+        if template.is_changed() {
+            if let Err(error) = template.validate() {
+                warn!("Invalid GridTemplateAreas on {entity:?}: {error}");
+            }
+        }
+    }
+
+    for (entity, area, parent, mut style) in &mut area_query {
+        let template = template_query.get(parent.get()).ok();
+        let template_changed = template
+            .as_ref()
+            .map_or(false, |(_, template)| template.is_changed());
+        if !area.is_changed() && !parent.is_changed() && !template_changed {
+            continue;
+        }
+        let Some((_, template)) = template else {
+            warn!(
+                "{entity:?} has the GridArea `{}`, but its parent has no GridTemplateAreas",
+                area.0
+            );
+            continue;
+        };
+        match template.area(&area.0) {
+            Ok((row, column)) => {
+                if style.grid_row != row || style.grid_column != column {
+                    style.grid_row = row;
+                    style.grid_column = column;
+                }
+            }
+            Err(error) => warn!("Couldn't place {entity:?} in its GridArea: {error}"),
+        }
+    }
+}
+
+/// Sent by [`ui_scale_changed_system`] when the number of physical pixels per ui value of a window
+/// changes, either because of the [`UiScale`](crate::UiScale) of its nodes or its own scale factor.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct UiScaleChanged {
+    /// The window whose nodes changed scale
+    pub window: Entity,
+    /// The previous number of physical pixels per ui value
+    pub old: f64,
+    /// The new number of physical pixels per ui value
+    pub new: f64,
+}
+
+/// Sends a [`UiScaleChanged`] event for each window whose combined scale factor changed since the
+/// last run of the system.
+///
+/// Windows are only compared once they have been seen by the system, so no event is sent for new windows.
+pub fn ui_scale_changed_system(
+    mut last_scale_factors: Local<EntityHashMap<f64>>,
+    windows: Query<(Entity, Ref<Window>)>,
+    ui_scale: TargetUiScale,
+    mut removed_window_ui_scales: RemovedComponents<WindowUiScale>,
+    mut ui_scale_changed_events: EventWriter<UiScaleChanged>,
+) {
+    let ui_scale_changed = ui_scale.is_changed() || removed_window_ui_scales.read().count() > 0;
+
+    for (entity, window) in &windows {
+        if !ui_scale_changed && !window.is_changed() {
+            continue;
+        }
+        let window_scale_factor = window.scale_factor();
+        let new = f64::from(window_scale_factor * ui_scale.window(entity, window_scale_factor));
+        if let Some(old) = last_scale_factors.insert(entity, new) {
+            if old != new {
+                ui_scale_changed_events.send(UiScaleChanged {
+                    window: entity,
+                    old,
+                    new,
+                });
+            }
+        }
+    }
+
+    last_scale_factors.retain(|entity, _| windows.contains(*entity));
+}
+
+/// Animates the [`UiScale`] from one value to another, like for a zoom transition.
+///
+/// While this resource exists, [`ui_scale_tween_system`] advances it and sets the [`UiScale`]
+/// between `from` and `to` along its [`UiEasing`]. Once `elapsed` reaches `duration`, the
+/// scale is set to `to`, a [`UiScaleTweenFinished`] event is sent and the resource is removed.
+///
+/// The scale changes like any other change of [`UiScale`], sending [`UiScaleChanged`] events and
+/// laying the UI out again.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, PartialEq)]
+pub struct UiScaleTween {
+    /// The scale at the start of the tween
+    pub from: f32,
+    /// The scale at the end of the tween
+    pub to: f32,
+    /// How long the tween takes
+    pub duration: Duration,
+    /// How much of the tween has passed
+    pub elapsed: Duration,
+    /// How the scale moves from `from` to `to` over time
+    pub easing: UiEasing,
+}
+
+impl UiScaleTween {
+    /// Creates a tween from `from` to `to` over `duration`, with the default [`UiEasing`].
+    pub fn new(from: f32, to: f32, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+            easing: UiEasing::default(),
+        }
+    }
+
+    /// Returns this tween with a new [`UiEasing`].
+    pub const fn with_easing(mut self, easing: UiEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns whether `elapsed` has reached `duration`.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// The scale after `elapsed`.
+    pub fn scale(&self) -> f32 {
+        let t = if self.duration.is_zero() {
+            1.
+        } else {
+            (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.)
+        };
+        self.from + (self.to - self.from) * self.easing.ease(t)
+    }
+}
+
+/// The curves a [`UiScaleTween`] or a [`UiTransition`](crate::widget::UiTransition) can follow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum UiEasing {
+    /// Moves at a constant speed
+    Linear,
+    /// Starts slowly and speeds up
+    EaseIn,
+    /// Starts quickly and slows down
+    EaseOut,
+    /// Starts and ends slowly
+    #[default]
+    EaseInOut,
+}
+
+impl UiEasing {
+    /// Maps the fraction `t` of a tween that has passed, between `0` and `1`, to the fraction of
+    /// the distance covered.
+    pub fn ease(self, t: f32) -> f32 {
+        match self {
+            UiEasing::Linear => t,
+            UiEasing::EaseIn => t * t * t,
+            UiEasing::EaseOut => 1. - (1. - t).powi(3),
+            UiEasing::EaseInOut => t * t * (3. - 2. * t),
+        }
+    }
+}
+
+/// Sent by [`ui_scale_tween_system`] when a [`UiScaleTween`] finishes.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct UiScaleTweenFinished {
+    /// The [`UiScale`] the tween ended at
+    pub scale: f32,
+}
+
+/// Advances the [`UiScaleTween`], if there is one, and sets the [`UiScale`] it reached.
+pub fn ui_scale_tween_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    tween: Option<ResMut<UiScaleTween>>,
+    mut ui_scale: ResMut<UiScale>,
+    mut tween_finished_events: EventWriter<UiScaleTweenFinished>,
+) {
+    let Some(mut tween) = tween else {
+        return;
+    };
+    tween.elapsed = (tween.elapsed + time.delta()).min(tween.duration);
+    let scale = tween.scale();
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+
+    if tween.is_finished() {
+        tween_finished_events.send(UiScaleTweenFinished { scale: tween.to });
+        commands.remove_resource::<UiScaleTween>();
+    }
+}
+
+/// Centers [`WorldSpaceUi`] nodes on the viewport position of their world point.
+///
+/// Nodes whose point is behind their camera are hidden, and shown again once it's back in front.
+pub fn update_world_space_ui_system(
+    default_ui_camera: DefaultUiCamera,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    ui_scale: TargetUiScale,
+    mut node_query: Query<(
+        &WorldSpaceUi,
+        &Node,
+        &mut Style,
+        &mut Transform,
+        &mut Visibility,
+        Option<&TargetCamera>,
+    )>,
+) {
+    for (world_space_ui, node, mut style, mut transform, mut visibility, target_camera) in
+        &mut node_query
+    {
+        let Some((camera, camera_transform)) = target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+        else {
+            continue;
+        };
+        let Some(viewport_position) =
+            camera.world_to_viewport(camera_transform, world_space_ui.world_position)
+        else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+
+        let position = viewport_position / ui_scale.camera(Some(camera)) - 0.5 * node.size();
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if style.position_type != PositionType::Absolute || style.left != left || style.top != top {
+            style.position_type = PositionType::Absolute;
+            style.left = left;
+            style.top = top;
+        }
+
+        if let Some(reference_distance) = world_space_ui.reference_distance {
+            let distance = camera_transform
+                .translation()
+                .distance(world_space_ui.world_position);
+            let scale = Vec3::splat(reference_distance / distance.max(f32::EPSILON));
+            if transform.scale != scale {
+                transform.scale = scale;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_core_pipeline::core_2d::Camera2dBundle;
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::{Rect, Vec2, Vec3};
+    use bevy_render::{
+        camera::{camera_system, ManualTextureViews, OrthographicProjection},
+        texture::Image,
+        view::Visibility,
+    };
+    use bevy_sprite::BorderRect;
+    use bevy_time::Time;
+    use bevy_transform::components::{GlobalTransform, Transform};
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowResized, WindowResolution,
+        WindowScaleFactorChanged,
+    };
+
+    use super::{
+        ui_scale_changed_system, ui_scale_tween_system, update_clipping_system,
+        update_grid_area_system, update_world_space_ui_system, UiEasing, UiScaleChanged,
+        UiScaleTween, UiScaleTweenFinished,
+    };
+    use crate::{
+        CalculatedClip, GridArea, GridPlacement, GridTemplateAreas, Node, Overflow, PositionType,
+        Style, UiScale, UiScaleMode, Val, WindowUiScale, WorldSpaceUi,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn ui_scale_changes_are_sent_once_per_change() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<Events<UiScaleChanged>>();
+        let window = world.spawn(Window::default()).id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_scale_changed_system);
+
+        let mut run = |world: &mut World| {
+            schedule.run(world);
+            world
+                .resource_mut::<Events<UiScaleChanged>>()
+                .drain()
+                .map(|event| (event.old, event.new))
+                .collect::<Vec<_>>()
+        };
+
+        assert!(run(&mut world).is_empty());
+        assert!(run(&mut world).is_empty());
+
+        world.resource_mut::<UiScale>().0 = 2.;
+        assert_eq!(run(&mut world), [(1., 2.)]);
+        assert!(run(&mut world).is_empty());
+
+        world
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set_scale_factor_override(Some(1.5));
+        assert_eq!(run(&mut world), [(2., 3.)]);
+
+        world.entity_mut(window).insert(WindowUiScale(1.));
+        assert_eq!(run(&mut world), [(3., 1.5)]);
+        world.entity_mut(window).remove::<WindowUiScale>();
+        assert_eq!(run(&mut world), [(1.5, 3.)]);
+    }
+
+    #[test]
+    fn ui_scale_tweens_drive_the_ui_scale_until_they_finish() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<UiScaleTweenFinished>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_scale_tween_system);
+
+        let mut run = |world: &mut World, ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(ms));
+            schedule.run(world);
+            let finished = world
+                .resource_mut::<Events<UiScaleTweenFinished>>()
+                .drain()
+                .map(|event| event.scale)
+                .collect::<Vec<_>>();
+            (world.resource::<UiScale>().0, finished)
+        };
+
+        // without a tween, the scale is left alone
+        assert_eq!(run(&mut world, 50), (1., vec![]));
+
+        world.insert_resource(
+            UiScaleTween::new(1., 2., Duration::from_millis(100)).with_easing(UiEasing::Linear),
+        );
+        assert_eq!(run(&mut world, 50), (1.5, vec![]));
+        assert_eq!(run(&mut world, 80), (2., vec![2.]));
+        assert!(!world.contains_resource::<UiScaleTween>());
+        assert_eq!(run(&mut world, 50), (2., vec![]));
+
+        // eased tweens start and end at the same scales
+        let tween = UiScaleTween::new(2., 1., Duration::from_millis(100));
+        for easing in [UiEasing::EaseIn, UiEasing::EaseOut, UiEasing::EaseInOut] {
+            let mut tween = tween.with_easing(easing);
+            assert_eq!(tween.scale(), 2.);
+            tween.elapsed = tween.duration;
+            assert_eq!(tween.scale(), 1.);
+        }
+        let quarter = |easing| UiScaleTween {
+            elapsed: Duration::from_millis(25),
+            ..tween.with_easing(easing)
+        };
+        assert!(quarter(UiEasing::EaseIn).scale() > quarter(UiEasing::Linear).scale());
+        assert!(quarter(UiEasing::EaseOut).scale() < quarter(UiEasing::Linear).scale());
+    }
+
+    #[test]
+    fn nested_overflow_nodes_intersect_their_content_boxes() {
+        let mut world = World::new();
+        let mut spawn_node = |rect: Rect, inset: f32, overflow: Overflow| {
+            world
+                .spawn((
+                    Node {
+                        calculated_size: rect.size(),
+                        unrounded_size: rect.size(),
+                        content_inset: BorderRect::square(inset),
+                        ..Default::default()
+                    },
+                    GlobalTransform::from_translation(rect.center().extend(0.)),
+                    Style {
+                        overflow,
+                        ..Default::default()
+                    },
+                ))
+                .id()
+        };
+        let outer = spawn_node(Rect::new(0., 0., 100., 100.), 10., Overflow::clip());
+        let inner = spawn_node(Rect::new(50., 20., 150., 120.), 5., Overflow::scroll_y());
+        let outer_leaf = spawn_node(Rect::new(0., 0., 10., 10.), 0., Overflow::visible());
+        let inner_leaf = spawn_node(Rect::new(0., 0., 10., 10.), 0., Overflow::visible());
+        world.entity_mut(outer).push_children(&[inner, outer_leaf]);
+        world.entity_mut(inner).add_child(inner_leaf);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_clipping_system);
+        schedule.run(&mut world);
+
+        let clip = |world: &World, entity: Entity| {
+            world.get::<CalculatedClip>(entity).map(|clip| clip.clip)
+        };
+        assert_eq!(clip(&world, outer), None);
+        // children are clipped to the content box of their parent
+        assert_eq!(clip(&world, inner), Some(Rect::new(10., 10., 90., 90.)));
+        assert_eq!(
+            clip(&world, outer_leaf),
+            Some(Rect::new(10., 10., 90., 90.))
+        );
+        // the inner node only clips vertically, within the clip of the outer node
+        assert_eq!(
+            clip(&world, inner_leaf),
+            Some(Rect::new(10., 25., 90., 90.))
+        );
+
+        world.get_mut::<Style>(outer).unwrap().overflow = Overflow::visible();
+        schedule.run(&mut world);
+        assert_eq!(clip(&world, outer_leaf), None);
+        assert_eq!(
+            clip(&world, inner_leaf),
+            Some(Rect::new(f32::NEG_INFINITY, 25., f32::INFINITY, 115.))
+        );
+    }
+
+    #[test]
+    fn world_space_nodes_are_centered_on_the_projection_of_their_point() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        // Required for the camera system
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ManualTextureViews>();
+        world.spawn((
+            Window {
+                resolution: WindowResolution::new(1000., 100.),
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ));
+        // the 2d camera has the world origin at the center of the window, with y pointing up
+        let camera_z = 500.;
+        let camera_transform = Transform::from_xyz(0., 0., camera_z);
+        world.spawn(Camera2dBundle {
+            transform: camera_transform,
+            global_transform: camera_transform.into(),
+            ..Default::default()
+        });
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                camera_system::<OrthographicProjection>,
+                update_world_space_ui_system,
+            )
+                .chain(),
+        );
+
+        let node = world
+            .spawn((
+                Node {
+                    calculated_size: Vec2::new(40., 20.),
+                    ..Default::default()
+                },
+                Style::default(),
+                Transform::default(),
+                Visibility::default(),
+                WorldSpaceUi::new(Vec3::new(100., 20., 0.)),
+            ))
+            .id();
+        let mut run = |world: &mut World| {
+            schedule.run(world);
+            let style = world.get::<Style>(node).unwrap();
+            (
+                style.position_type,
+                style.left,
+                style.top,
+                *world.get::<Visibility>(node).unwrap(),
+            )
+        };
+        assert_eq!(
+            run(&mut world),
+            (
+                PositionType::Absolute,
+                Val::Px(580.),
+                Val::Px(20.),
+                Visibility::Inherited
+            )
+        );
+
+        // positions are in UI values, so they're divided by the UI scale
+        world.resource_mut::<UiScale>().0 = 2.;
+        assert_eq!(run(&mut world).1, Val::Px(280.));
+        world.resource_mut::<UiScale>().0 = 1.;
+
+        // points outside of the camera's depth range hide the node until they're back in it
+        world
+            .get_mut::<WorldSpaceUi>(node)
+            .unwrap()
+            .world_position
+            .z = camera_z + 2000.;
+        assert_eq!(run(&mut world).3, Visibility::Hidden);
+        world
+            .get_mut::<WorldSpaceUi>(node)
+            .unwrap()
+            .world_position
+            .z = 0.;
+        assert_eq!(run(&mut world).3, Visibility::Inherited);
+        assert_eq!(world.get::<Transform>(node).unwrap().scale, Vec3::ONE);
+
+        *world.get_mut::<WorldSpaceUi>(node).unwrap() =
+            WorldSpaceUi::new(Vec3::ZERO).with_reference_distance(camera_z / 2.);
+        run(&mut world);
+        assert_eq!(
+            world.get::<Transform>(node).unwrap().scale,
+            Vec3::splat(0.5)
+        );
+    }
+
+    #[test]
+    fn grid_areas_place_nodes_over_the_cells_of_their_parent_template() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_grid_area_system);
+
+        let mut main = Entity::PLACEHOLDER;
+        let mut unknown = Entity::PLACEHOLDER;
+        let container = world
+            .spawn(GridTemplateAreas::new(["header header", "sidebar main"]))
+            .with_children(|parent| {
+                main = parent.spawn((Style::default(), GridArea::new("main"))).id();
+                unknown = parent.spawn((Style::default(), GridArea::new("menu"))).id();
+            })
+            .id();
+        let placement = |world: &World, entity| {
+            let style = world.get::<Style>(entity).unwrap();
+            (style.grid_row, style.grid_column)
+        };
+
+        schedule.run(&mut world);
+        assert_eq!(
+            placement(&world, main),
+            (
+                GridPlacement::start_span(2, 1),
+                GridPlacement::start_span(2, 1)
+            )
+        );
+        // unknown areas leave the placement as is
+        assert_eq!(
+            placement(&world, unknown),
+            (GridPlacement::default(), GridPlacement::default())
+        );
+
+        *world.get_mut::<GridTemplateAreas>(container).unwrap() =
+            GridTemplateAreas::new(["menu main", "menu main"]);
+        schedule.run(&mut world);
+        assert_eq!(
+            placement(&world, main),
+            (
+                GridPlacement::start_span(1, 2),
+                GridPlacement::start_span(2, 1)
+            )
+        );
+        assert_eq!(
+            placement(&world, unknown),
+            (
+                GridPlacement::start_span(1, 2),
+                GridPlacement::start_span(1, 1)
+            )
+        );
+    }
+}
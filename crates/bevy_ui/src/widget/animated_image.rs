@@ -0,0 +1,183 @@
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventWriter},
+    prelude::Component,
+    reflect::ReflectComponent,
+    system::{Local, Query, Res},
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_sprite::TextureAtlas;
+use bevy_time::Time;
+
+/// Adds the systems driving [`AnimatedImage`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct AnimatedImagePlugin;
+
+impl Plugin for AnimatedImagePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AnimatedImage>()
+            .register_type::<AnimationFinished>()
+            .add_event::<AnimationFinished>()
+            .add_systems(PreUpdate, animated_image_system);
+    }
+}
+
+/// An image node playing an animation from the sections of its [`TextureAtlas`].
+///
+/// [`animated_image_system`] sets [`TextureAtlas::index`] to the frame to display, so the node
+/// needs a [`TextureAtlas`] along with the [`UiImage`](crate::UiImage) of the sprite sheet.
+#[derive(Component, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct AnimatedImage {
+    /// The indices of the atlas sections to display, in order
+    pub frames: Vec<usize>,
+    /// How many frames are displayed per second
+    pub fps: f32,
+    /// Whether the animation starts over after its last frame, or stops on it
+    pub looping: bool,
+    /// Whether the animation is paused on its current frame
+    pub paused: bool,
+}
+
+impl AnimatedImage {
+    const DEFAULT: Self = Self {
+        frames: Vec::new(),
+        fps: 10.,
+        looping: true,
+        paused: false,
+    };
+
+    /// Creates a looping animation displaying `frames` at the given rate.
+    pub fn new(frames: impl IntoIterator<Item = usize>, fps: f32) -> Self {
+        Self {
+            frames: frames.into_iter().collect(),
+            fps,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Returns the position in `frames` of the frame displayed after `elapsed` seconds of playing,
+    /// or `None` once a non-looping animation is over.
+    fn frame_at(&self, elapsed: f32) -> Option<usize> {
+        let frame = (elapsed * self.fps) as usize;
+        if self.looping {
+            Some(frame % self.frames.len())
+        } else {
+            (frame < self.frames.len()).then_some(frame)
+        }
+    }
+}
+
+impl Default for AnimatedImage {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Sent by [`animated_image_system`] when a non-looping [`AnimatedImage`] has displayed its last frame.
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct AnimationFinished {
+    /// The image whose animation is over
+    pub entity: Entity,
+}
+
+/// How far an [`AnimatedImage`] has played
+pub struct AnimationProgress {
+    /// How long the animation has been playing for, in seconds, not counting pauses
+    elapsed: f32,
+    /// Set once [`AnimationFinished`] has been sent
+    finished: bool,
+}
+
+/// The system that steps [`AnimatedImage`] nodes through their frames.
+pub fn animated_image_system(
+    mut progress: Local<EntityHashMap<AnimationProgress>>,
+    time: Res<Time>,
+    mut animated_image_query: Query<(Entity, &AnimatedImage, &mut TextureAtlas)>,
+    mut animation_finished_events: EventWriter<AnimationFinished>,
+) {
+    for (entity, animated_image, mut atlas) in &mut animated_image_query {
+        let progress = progress.entry(entity).or_insert(AnimationProgress {
+            elapsed: 0.,
+            finished: false,
+        });
+        if animated_image.frames.is_empty() || progress.finished {
+            continue;
+        }
+        if !animated_image.paused {
+            progress.elapsed += time.delta_seconds();
+        }
+
+        let frame = match animated_image.frame_at(progress.elapsed) {
+            Some(frame) => frame,
+            None => {
+                // the last frame stays displayed
+                progress.finished = true;
+                animation_finished_events.send(AnimationFinished { entity });
+                animated_image.frames.len() - 1
+            }
+        };
+        let index = animated_image.frames[frame];
+        if atlas.index != index {
+            atlas.index = index;
+        }
+    }
+
+    // forget about despawned images
+    progress.retain(|entity, _| animated_image_query.contains(*entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_ecs::{event::Events, schedule::Schedule, world::World};
+    use bevy_sprite::TextureAtlas;
+    use bevy_time::Time;
+    use std::time::Duration;
+
+    use super::{animated_image_system, AnimatedImage, AnimationFinished};
+
+    #[test]
+    fn non_looping_animation_stops_on_last_frame() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<AnimationFinished>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(animated_image_system);
+
+        let image = world
+            .spawn((
+                AnimatedImage {
+                    looping: false,
+                    ..AnimatedImage::new([4, 5, 6], 10.)
+                },
+                TextureAtlas {
+                    layout: Handle::default(),
+                    index: 0,
+                },
+            ))
+            .id();
+        let mut advance = |world: &mut World, ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(ms));
+            schedule.run(world);
+            world.get::<TextureAtlas>(image).unwrap().index
+        };
+
+        assert_eq!(advance(&mut world, 0), 4);
+        assert_eq!(advance(&mut world, 150), 5);
+
+        world.get_mut::<AnimatedImage>(image).unwrap().paused = true;
+        assert_eq!(advance(&mut world, 1000), 5);
+        world.get_mut::<AnimatedImage>(image).unwrap().paused = false;
+
+        assert_eq!(advance(&mut world, 100), 6);
+        assert_eq!(advance(&mut world, 100), 6);
+        assert_eq!(advance(&mut world, 100), 6);
+        assert_eq!(world.resource::<Events<AnimationFinished>>().len(), 1);
+    }
+}
@@ -1,9 +1,302 @@
-use bevy_ecs::prelude::Component;
-use bevy_ecs::reflect::ReflectComponent;
+use crate::{
+    ui_focus_system, widget::Checkbox, BackgroundColor, Disabled, FocusedEntity, Interaction,
+    KeyboardPressed, RelativeCursorPosition, UiClick, UiImage, UiPress, UiRelease, UiSystem,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    entity::Entity,
+    event::EventWriter,
+    prelude::Component,
+    query::{Has, With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Local, Query, Res},
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
 use bevy_reflect::std_traits::ReflectDefault;
 use bevy_reflect::Reflect;
 
+/// Adds the systems driving [`ButtonColors`], and activating the focused [`Button`] with the keyboard.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct ButtonPlugin;
+
+impl Plugin for ButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ButtonColors>().add_systems(
+            PreUpdate,
+            (
+                // widgets see the keyboard presses like the other presses
+                button_keyboard_system
+                    .in_set(UiSystem::Focus)
+                    .after(ui_focus_system),
+                button_color_system.in_set(UiSystem::Widgets),
+            ),
+        );
+    }
+}
+
+/// The keys activating the focused [`Button`]
+const ACTIVATION_KEYS: [KeyCode; 3] = [KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space];
+
 /// Marker struct for buttons
+///
+/// The button referenced by [`FocusedEntity`] is pressed with Enter or Space, see [`button_keyboard_system`].
 #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
 #[reflect(Component, Default)]
 pub struct Button;
+
+/// The colors of a button in each of its [`Interaction`] states, and while it is [`Disabled`].
+///
+/// [`button_color_system`] tints the node's [`UiImage`] with the color of its current state, or sets
+/// its [`BackgroundColor`] if it doesn't have an image. Buttons without this component keep their colors.
+#[derive(Component, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ButtonColors {
+    /// The color when the button isn't hovered or pressed
+    pub normal: Color,
+    /// The color while the cursor is over the button
+    pub hovered: Color,
+    /// The color while the button is pressed
+    pub pressed: Color,
+    /// The color while the button is [`Disabled`], whatever its interaction
+    pub disabled: Color,
+}
+
+impl ButtonColors {
+    const DEFAULT: Self = Self {
+        normal: Color::srgb(0.15, 0.15, 0.15),
+        hovered: Color::srgb(0.25, 0.25, 0.25),
+        pressed: Color::srgb(0.35, 0.75, 0.35),
+        disabled: Color::srgb(0.1, 0.1, 0.1),
+    };
+
+    /// Returns the color to display for the given state.
+    pub fn color(&self, interaction: Interaction, disabled: bool) -> Color {
+        if disabled {
+            return self.disabled;
+        }
+        match interaction {
+            Interaction::Pressed => self.pressed,
+            Interaction::Hovered => self.hovered,
+            Interaction::None => self.normal,
+        }
+    }
+}
+
+impl Default for ButtonColors {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The system that activates the [`Button`] referenced by [`FocusedEntity`] when Enter or Space is
+/// pressed, so buttons can be used without a mouse.
+///
+/// The button is [`Interaction::Pressed`] and marked [`KeyboardPressed`] while the key is held down,
+/// so [`ui_focus_system`] doesn't release it when the cursor isn't over it. It is reset to
+/// [`Interaction::None`] when the key is released. [`UiPress`] and [`UiRelease`] events are sent
+/// when the key is pressed and released, and a [`UiClick`] is sent when it is pressed, all with
+/// default cursor positions. Keys repeating while they are held down don't send more clicks.
+/// [`Disabled`] buttons and buttons already pressed with the mouse are ignored, and [`Checkbox`]es
+/// are toggled by their own system.
+#[allow(clippy::too_many_arguments)]
+pub fn button_keyboard_system(
+    mut commands: Commands,
+    mut pressed_button: Local<Option<Entity>>,
+    focused_entity: Res<FocusedEntity>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut button_query: Query<&mut Interaction, (With<Button>, Without<Disabled>, Without<Checkbox>)>,
+    mut press_events: EventWriter<UiPress>,
+    mut release_events: EventWriter<UiRelease>,
+    mut click_events: EventWriter<UiClick>,
+) {
+    if let Some(entity) = *pressed_button {
+        if keyboard_input.any_pressed(ACTIVATION_KEYS) {
+            return;
+        }
+        if let Ok(mut interaction) = button_query.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+            release_events.send(UiRelease {
+                entity,
+                position: RelativeCursorPosition::default(),
+            });
+        }
+        // the button may have been despawned while the key was held down
+        if let Some(mut button) = commands.get_entity(entity) {
+            button.remove::<KeyboardPressed>();
+        }
+        *pressed_button = None;
+    }
+
+    if !keyboard_input.any_just_pressed(ACTIVATION_KEYS) {
+        return;
+    }
+    let Some(entity) = focused_entity.0 else {
+        return;
+    };
+    let Ok(mut interaction) = button_query.get_mut(entity) else {
+        return;
+    };
+    if *interaction == Interaction::Pressed {
+        return;
+    }
+    *interaction = Interaction::Pressed;
+    commands.entity(entity).insert(KeyboardPressed);
+    *pressed_button = Some(entity);
+    press_events.send(UiPress {
+        entity,
+        position: RelativeCursorPosition::default(),
+    });
+    click_events.send(UiClick {
+        entity,
+        press_position: RelativeCursorPosition::default(),
+        release_position: RelativeCursorPosition::default(),
+    });
+}
+
+/// The system that updates the color of [`ButtonColors`] nodes to match their [`Interaction`].
+pub fn button_color_system(
+    mut button_query: Query<(
+        &ButtonColors,
+        &Interaction,
+        Has<Disabled>,
+        Option<&mut UiImage>,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    for (colors, interaction, disabled, image, background_color) in &mut button_query {
+        let color = colors.color(*interaction, disabled);
+        if let Some(mut image) = image {
+            if image.color != color {
+                image.color = color;
+            }
+        } else if let Some(mut background_color) = background_color {
+            if background_color.0 != color {
+                background_color.0 = color;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::Color;
+    use bevy_ecs::{event::Events, schedule::Schedule, world::World};
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+
+    use super::{button_color_system, button_keyboard_system, Button, ButtonColors};
+    use crate::{
+        BackgroundColor, Disabled, FocusedEntity, Interaction, UiClick, UiImage, UiPress, UiRelease,
+    };
+
+    #[test]
+    fn buttons_show_the_color_of_their_state() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(button_color_system);
+
+        let colors = ButtonColors::default();
+        let image_button = world
+            .spawn((colors, Interaction::None, UiImage::default()))
+            .id();
+        let background_button = world
+            .spawn((colors, Interaction::None, BackgroundColor::default()))
+            .id();
+        let plain_button = world.spawn((Interaction::Hovered, UiImage::default())).id();
+
+        let image_color = |world: &World| world.get::<UiImage>(image_button).unwrap().color;
+        let background_color =
+            |world: &World| world.get::<BackgroundColor>(background_button).unwrap().0;
+
+        schedule.run(&mut world);
+        assert_eq!(image_color(&world), colors.normal);
+        assert_eq!(background_color(&world), colors.normal);
+
+        *world.get_mut::<Interaction>(image_button).unwrap() = Interaction::Hovered;
+        *world.get_mut::<Interaction>(background_button).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert_eq!(image_color(&world), colors.hovered);
+        assert_eq!(background_color(&world), colors.pressed);
+
+        world.entity_mut(background_button).insert(Disabled);
+        schedule.run(&mut world);
+        assert_eq!(background_color(&world), colors.disabled);
+
+        // Buttons without `ButtonColors` keep their own colors.
+        assert_eq!(
+            world.get::<UiImage>(plain_button).unwrap().color,
+            Color::WHITE
+        );
+    }
+
+    #[test]
+    fn space_activates_the_focused_button_once_per_press() {
+        let mut world = World::new();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<UiPress>>();
+        world.init_resource::<Events<UiRelease>>();
+        world.init_resource::<Events<UiClick>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(button_keyboard_system);
+
+        let button = world.spawn((Button, Interaction::None)).id();
+        let other = world.spawn((Button, Interaction::None)).id();
+        world.resource_mut::<FocusedEntity>().0 = Some(button);
+        let clicks = |world: &World| {
+            let events = world.resource::<Events<UiClick>>();
+            events
+                .get_reader()
+                .read(events)
+                .map(|click| click.entity)
+                .collect::<Vec<_>>()
+        };
+        let mut run = |world: &mut World| {
+            world.resource_mut::<Events<UiClick>>().update();
+            schedule.run(world);
+            world.resource_mut::<ButtonInput<KeyCode>>().clear();
+        };
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        run(&mut world);
+        assert_eq!(clicks(&world), [button]);
+        assert_eq!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Pressed)
+        );
+        assert_eq!(world.get::<Interaction>(other), Some(&Interaction::None));
+
+        // the repeated key is still held down, without a new click
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        run(&mut world);
+        run(&mut world);
+        assert!(clicks(&world).is_empty());
+        assert_eq!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Pressed)
+        );
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::Space);
+        run(&mut world);
+        assert_eq!(world.get::<Interaction>(button), Some(&Interaction::None));
+
+        // disabled buttons ignore the keyboard
+        world.entity_mut(button).insert(Disabled);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Enter);
+        run(&mut world);
+        assert!(clicks(&world).is_empty());
+        assert_eq!(world.get::<Interaction>(button), Some(&Interaction::None));
+    }
+}
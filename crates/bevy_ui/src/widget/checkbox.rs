@@ -0,0 +1,202 @@
+use crate::{Disabled, FocusedEntity, Interaction, UiImage, UiSystem};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    event::{Event, EventWriter},
+    prelude::Component,
+    query::{Changed, Or, Without},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res},
+    world::Ref,
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Adds the systems driving [`Checkbox`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct CheckboxPlugin;
+
+impl Plugin for CheckboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Checkbox>()
+            .register_type::<CheckboxImages>()
+            .register_type::<CheckboxChanged>()
+            .add_event::<CheckboxChanged>()
+            .add_systems(
+                PreUpdate,
+                (checkbox_system, update_checkbox_image_system)
+                    .chain()
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// A UI node that can be checked and unchecked.
+///
+/// The checkbox is toggled by [`checkbox_system`] when it's pressed, or when Space is pressed while it is
+/// the [`FocusedEntity`].
+#[derive(Component, Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Checkbox {
+    /// Whether the checkbox is checked
+    pub checked: bool,
+}
+
+/// The images displayed by a [`Checkbox`] in each of its states.
+///
+/// The node's [`UiImage`] is replaced with one of them by [`update_checkbox_image_system`] whenever the
+/// checkbox changes. The default draws a filled square when checked, and nothing when unchecked.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct CheckboxImages {
+    /// The image displayed while the checkbox is checked
+    pub checked: UiImage,
+    /// The image displayed while the checkbox is unchecked
+    pub unchecked: UiImage,
+}
+
+impl Default for CheckboxImages {
+    fn default() -> Self {
+        Self {
+            checked: UiImage::default(),
+            unchecked: UiImage::default().with_color(Color::NONE),
+        }
+    }
+}
+
+/// Sent by [`checkbox_system`] when a [`Checkbox`] is toggled.
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct CheckboxChanged {
+    /// The checkbox that was toggled
+    pub entity: Entity,
+    /// Whether the checkbox is now checked
+    pub checked: bool,
+}
+
+/// The system that toggles [`Checkbox`] nodes when they are pressed, unless they are [`Disabled`].
+pub fn checkbox_system(
+    mut checkbox_query: Query<(Entity, &mut Checkbox, Ref<Interaction>), Without<Disabled>>,
+    focused_entity: Res<FocusedEntity>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut checkbox_changed_events: EventWriter<CheckboxChanged>,
+) {
+    for (entity, mut checkbox, interaction) in &mut checkbox_query {
+        let pressed = interaction.is_changed() && *interaction == Interaction::Pressed;
+        let activated =
+            focused_entity.0 == Some(entity) && keyboard_input.just_pressed(KeyCode::Space);
+        if pressed || activated {
+            checkbox.checked = !checkbox.checked;
+            checkbox_changed_events.send(CheckboxChanged {
+                entity,
+                checked: checkbox.checked,
+            });
+        }
+    }
+}
+
+/// The system that displays the [`CheckboxImages`] matching the state of each [`Checkbox`].
+pub fn update_checkbox_image_system(
+    mut checkbox_query: Query<
+        (&Checkbox, &CheckboxImages, &mut UiImage),
+        Or<(Changed<Checkbox>, Changed<CheckboxImages>)>,
+    >,
+) {
+    for (checkbox, images, mut image) in &mut checkbox_query {
+        *image = if checkbox.checked {
+            images.checked.clone()
+        } else {
+            images.unchecked.clone()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+
+    use super::{
+        checkbox_system, update_checkbox_image_system, Checkbox, CheckboxChanged, CheckboxImages,
+    };
+    use crate::{Disabled, FocusedEntity, Interaction, UiImage};
+    use bevy_color::Color;
+
+    #[test]
+    fn checkbox_toggles_on_press_and_space() {
+        let mut world = World::new();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<CheckboxChanged>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((checkbox_system, update_checkbox_image_system).chain());
+
+        let checkbox = world
+            .spawn((
+                Checkbox::default(),
+                CheckboxImages::default(),
+                Interaction::None,
+                UiImage::default(),
+            ))
+            .id();
+        let state = |world: &World| {
+            (
+                world.get::<Checkbox>(checkbox).unwrap().checked,
+                world.get::<UiImage>(checkbox).unwrap().color,
+            )
+        };
+        schedule.run(&mut world);
+        assert_eq!(state(&world), (false, Color::NONE));
+
+        *world.get_mut::<Interaction>(checkbox).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        // staying pressed doesn't toggle it again
+        schedule.run(&mut world);
+        assert_eq!(state(&world), (true, Color::WHITE));
+
+        *world.get_mut::<Interaction>(checkbox).unwrap() = Interaction::None;
+        world.resource_mut::<FocusedEntity>().0 = Some(checkbox);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        schedule.run(&mut world);
+        assert_eq!(state(&world), (false, Color::NONE));
+
+        let events = world.resource::<Events<CheckboxChanged>>();
+        let checked = events
+            .get_reader()
+            .read(events)
+            .map(|event| (event.entity, event.checked))
+            .collect::<Vec<_>>();
+        assert_eq!(checked, vec![(checkbox, true), (checkbox, false)]);
+    }
+
+    #[test]
+    fn disabled_checkboxes_ignore_space() {
+        let mut world = World::new();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<CheckboxChanged>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(checkbox_system);
+
+        let checkbox = world
+            .spawn((Checkbox::default(), Interaction::None, Disabled))
+            .id();
+        world.resource_mut::<FocusedEntity>().0 = Some(checkbox);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        schedule.run(&mut world);
+        assert!(!world.get::<Checkbox>(checkbox).unwrap().checked);
+        assert!(world.resource::<Events<CheckboxChanged>>().is_empty());
+    }
+}
@@ -0,0 +1,675 @@
+use crate::{
+    node_bundles::{NodeBundle, TextBundle},
+    widget::ButtonColors,
+    BackgroundColor, FlexDirection, FocusPolicy, HoveredUiEntity, Interaction, Node, PositionType,
+    Style, TargetCamera, TargetUiScale, UiRect, UiSystem, Val, ZIndex,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventWriter},
+    prelude::Component,
+    query::With,
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Local, Query, Res},
+    world::{Mut, Ref},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuilder, DespawnRecursiveExt, HierarchyQueryExt, Parent};
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton, touch::Touches, ButtonInput};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_text::TextStyle;
+use bevy_window::{PrimaryWindow, Window};
+
+/// Adds the systems driving [`ContextMenu`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin) when the `bevy_text` feature is enabled.
+pub struct ContextMenuPlugin;
+
+impl Plugin for ContextMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ContextMenu>()
+            .register_type::<ContextMenuItem>()
+            .register_type::<ContextMenuStyle>()
+            .register_type::<ContextMenuList>()
+            .register_type::<ContextMenuEntry>()
+            .register_type::<ContextMenuSelected>()
+            .add_event::<ContextMenuSelected>()
+            .add_systems(
+                PreUpdate,
+                (context_menu_system, update_context_menu_system)
+                    .chain()
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// A menu of items opened at the cursor when right-clicking a UI node with an [`Interaction`].
+///
+/// The menu is opened by right-clicking the node or any of its descendants, unless a descendant has
+/// a [`ContextMenu`] of its own. [`update_context_menu_system`] spawns it as a root node drawn over the
+/// rest of the UI, kept inside the primary window and laid out according to the node's
+/// [`ContextMenuStyle`]. Pressing an item sends a [`ContextMenuSelected`] event and closes the menu,
+/// which is also closed when pressing anywhere outside of it, or Escape.
+///
+/// Items with a submenu open it next to them when they are hovered or pressed.
+#[derive(Component, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ContextMenu {
+    /// The items of the menu
+    pub items: Vec<ContextMenuItem>,
+    /// The position of the top left corner of the open menu, in logical pixels of the primary
+    /// window divided by [`UiScale`](crate::UiScale), or `None` if the menu is closed
+    pub position: Option<Vec2>,
+    /// The path of the item whose submenu is open, as in [`ContextMenuEntry::path`].
+    ///
+    /// The submenus of the items along the path are open too.
+    pub open_submenu: Vec<usize>,
+}
+
+impl ContextMenu {
+    /// Creates a closed menu of the given items.
+    pub fn new(items: impl IntoIterator<Item = ContextMenuItem>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the menu is open.
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// Closes the menu, along with its submenus.
+    pub fn close(&mut self) {
+        self.position = None;
+        self.open_submenu.clear();
+    }
+
+    /// Returns the item at `path`, as in [`ContextMenuEntry::path`].
+    pub fn item(&self, path: &[usize]) -> Option<&ContextMenuItem> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.items.get(*first)?, |item, index| {
+                item.submenu.get(*index)
+            })
+    }
+}
+
+/// An item of a [`ContextMenu`]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Default, PartialEq, no_field_bounds)]
+pub struct ContextMenuItem {
+    /// The id sent in [`ContextMenuSelected`] when the item is picked
+    pub id: u32,
+    /// The text of the item
+    pub label: String,
+    /// The items of the submenu opened by this item. Items with a submenu can't be picked.
+    pub submenu: Vec<ContextMenuItem>,
+}
+
+impl ContextMenuItem {
+    /// Creates an item without a submenu.
+    pub fn new(id: u32, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            submenu: Vec::new(),
+        }
+    }
+
+    /// Returns this [`ContextMenuItem`] opening a submenu of the given items.
+    pub fn with_submenu(mut self, items: impl IntoIterator<Item = ContextMenuItem>) -> Self {
+        self.submenu = items.into_iter().collect();
+        self
+    }
+}
+
+/// How the menu of a [`ContextMenu`] is displayed.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct ContextMenuStyle {
+    /// The style of the node containing the items. Its position is set at the cursor.
+    pub list: Style,
+    /// The style of the node containing the items of a submenu, positioned relative to its item
+    pub submenu: Style,
+    /// The background color of the menu and its submenus
+    pub background: Color,
+    /// The style of each item row
+    pub item: Style,
+    /// The background colors of the item rows
+    pub item_colors: ButtonColors,
+    /// The style of the text of the item rows
+    pub text: TextStyle,
+    /// Appended to the label of the items with a submenu
+    pub submenu_indicator: String,
+}
+
+impl Default for ContextMenuStyle {
+    fn default() -> Self {
+        Self {
+            list: Style {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(120.),
+                padding: UiRect::all(Val::Px(2.)),
+                ..Default::default()
+            },
+            submenu: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(100.),
+                top: Val::Px(0.),
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(120.),
+                padding: UiRect::all(Val::Px(2.)),
+                ..Default::default()
+            },
+            background: Color::srgb(0.1, 0.1, 0.1),
+            item: Style {
+                padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                ..Default::default()
+            },
+            item_colors: ButtonColors {
+                normal: Color::NONE,
+                hovered: Color::srgb(0.25, 0.25, 0.25),
+                disabled: Color::NONE,
+                ..Default::default()
+            },
+            text: TextStyle::default(),
+            submenu_indicator: " >".to_string(),
+        }
+    }
+}
+
+/// A list of items of an open [`ContextMenu`], or of one of its submenus, spawned by
+/// [`update_context_menu_system`].
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ContextMenuList {
+    /// The node owning the menu
+    pub menu: Entity,
+}
+
+/// An item row of an open [`ContextMenu`], spawned by [`update_context_menu_system`].
+#[derive(Component, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ContextMenuEntry {
+    /// The node owning the menu
+    pub menu: Entity,
+    /// The index of the item in [`ContextMenu::items`], followed by the indices of the items in the
+    /// submenus leading to it
+    pub path: Vec<usize>,
+}
+
+/// Sent by [`context_menu_system`] when an item of a [`ContextMenu`] is picked.
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct ContextMenuSelected {
+    /// The node owning the menu
+    pub entity: Entity,
+    /// The [`ContextMenuItem::id`] of the picked item
+    pub id: u32,
+}
+
+/// Returns the cursor position of the primary window and the size of the window, in UI coordinates.
+fn primary_window_cursor(
+    ui_scale: &TargetUiScale,
+    windows: &Query<(Entity, &Window), With<PrimaryWindow>>,
+) -> (Option<Vec2>, Vec2) {
+    let Ok((entity, window)) = windows.get_single() else {
+        return (None, Vec2::ZERO);
+    };
+    let ui_scale = ui_scale.window(entity, window.scale_factor());
+    (
+        window.cursor_position().map(|position| position / ui_scale),
+        Vec2::new(window.width(), window.height()) / ui_scale,
+    )
+}
+
+/// Returns the position of the top left corner of a menu of the given size opened at
+/// `cursor_position`, keeping it inside the window.
+fn menu_position(cursor_position: Vec2, menu_size: Vec2, window_size: Vec2) -> Vec2 {
+    cursor_position.min(window_size - menu_size).max(Vec2::ZERO)
+}
+
+/// The system that opens and closes [`ContextMenu`] nodes, and picks their items.
+#[allow(clippy::too_many_arguments)]
+pub fn context_menu_system(
+    mut menu_query: Query<(Entity, &mut ContextMenu)>,
+    entry_query: Query<(&ContextMenuEntry, Ref<Interaction>)>,
+    list_query: Query<&ContextMenuList>,
+    parent_query: Query<&Parent>,
+    hovered_ui_entity: Res<HoveredUiEntity>,
+    ui_scale: TargetUiScale,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    touches_input: Res<Touches>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut context_menu_selected_events: EventWriter<ContextMenuSelected>,
+) {
+    for (entry, interaction) in &entry_query {
+        if !interaction.is_changed() || *interaction == Interaction::None {
+            continue;
+        }
+        let Ok((entity, mut menu)) = menu_query.get_mut(entry.menu) else {
+            continue;
+        };
+        if !menu.is_open() {
+            continue;
+        }
+        let Some(item) = menu.item(&entry.path) else {
+            continue;
+        };
+        if item.submenu.is_empty() {
+            if *interaction == Interaction::Pressed {
+                let id = item.id;
+                menu.close();
+                context_menu_selected_events.send(ContextMenuSelected { entity, id });
+                continue;
+            }
+            // hovering an item closes the submenus of its siblings
+            let parent_path = &entry.path[..entry.path.len() - 1];
+            set_open_submenu(&mut menu, parent_path);
+        } else {
+            set_open_submenu(&mut menu, &entry.path);
+        }
+    }
+
+    let hovered = hovered_ui_entity.entity;
+    let pressed =
+        mouse_button_input.get_just_pressed().next().is_some() || touches_input.any_just_pressed();
+    let escape = keyboard_input.just_pressed(KeyCode::Escape);
+    // right-clicking a node opens the closest menu among it and its ancestors
+    let opened = hovered
+        .filter(|_| mouse_button_input.just_pressed(MouseButton::Right))
+        .and_then(|hovered| {
+            std::iter::once(hovered)
+                .chain(parent_query.iter_ancestors(hovered))
+                .find(|entity| menu_query.contains(*entity))
+        });
+    let (cursor_position, window_size) = primary_window_cursor(&ui_scale, &windows);
+
+    for (entity, mut menu) in &mut menu_query {
+        // presses on the menu or its submenus keep it open
+        let over_menu = hovered.is_some_and(|hovered| {
+            std::iter::once(hovered)
+                .chain(parent_query.iter_ancestors(hovered))
+                .any(|e| list_query.get(e).is_ok_and(|list| list.menu == entity))
+        });
+        if over_menu {
+            continue;
+        }
+        if opened == Some(entity) {
+            if let Some(cursor_position) = cursor_position {
+                menu.position = Some(menu_position(cursor_position, Vec2::ZERO, window_size));
+                menu.open_submenu.clear();
+                continue;
+            }
+        }
+        if menu.is_open() && (escape || pressed) {
+            menu.close();
+        }
+    }
+}
+
+fn set_open_submenu(menu: &mut Mut<ContextMenu>, path: &[usize]) {
+    if menu.open_submenu != path {
+        menu.open_submenu = path.to_vec();
+    }
+}
+
+/// The system that spawns the menus of open [`ContextMenu`] nodes, keeps them inside the primary
+/// window, and despawns them once they are closed.
+pub fn update_context_menu_system(
+    mut commands: Commands,
+    mut lists: Local<EntityHashMap<Entity>>,
+    ui_scale: TargetUiScale,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    menu_query: Query<(
+        Entity,
+        Ref<ContextMenu>,
+        Option<Ref<ContextMenuStyle>>,
+        Option<&TargetCamera>,
+    )>,
+    mut list_query: Query<(&Node, &mut Style), With<ContextMenuList>>,
+) {
+    let (_, window_size) = primary_window_cursor(&ui_scale, &windows);
+
+    for (entity, menu, style, target_camera) in &menu_query {
+        let Some(position) = menu.position else {
+            // the menu may have been despawned along with the rest of the UI
+            if let Some(list) = lists
+                .remove(&entity)
+                .and_then(|list| commands.get_entity(list))
+            {
+                list.despawn_recursive();
+            }
+            continue;
+        };
+
+        // the size of the menu is only known once it has been laid out
+        if !menu.is_changed() && !style.as_ref().is_some_and(DetectChanges::is_changed) {
+            if let Some(Ok((node, mut style))) =
+                lists.get(&entity).map(|list| list_query.get_mut(*list))
+            {
+                let position = menu_position(position, node.size(), window_size);
+                let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+                if style.left != left || style.top != top {
+                    style.left = left;
+                    style.top = top;
+                }
+                continue;
+            }
+        }
+
+        if let Some(list) = lists
+            .remove(&entity)
+            .and_then(|list| commands.get_entity(list))
+        {
+            list.despawn_recursive();
+        }
+        let style = style.map(|style| style.clone()).unwrap_or_default();
+        let mut list = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    left: Val::Px(position.x),
+                    top: Val::Px(position.y),
+                    ..style.list.clone()
+                },
+                background_color: style.background.into(),
+                focus_policy: FocusPolicy::Block,
+                // draw the menu over the rest of the UI
+                z_index: ZIndex::Global(i32::MAX),
+                ..Default::default()
+            },
+            ContextMenuList { menu: entity },
+        ));
+        if let Some(target_camera) = target_camera {
+            list.insert(target_camera.clone());
+        }
+        list.with_children(|parent| {
+            spawn_items(
+                parent,
+                entity,
+                &menu.items,
+                &mut Vec::new(),
+                &menu.open_submenu,
+                &style,
+            );
+        });
+        lists.insert(entity, list.id());
+    }
+
+    // despawn the menus of despawned nodes, which aren't their descendants
+    lists.retain(|entity, list| {
+        let retain = menu_query.contains(*entity);
+        if let (false, Some(list)) = (retain, commands.get_entity(*list)) {
+            list.despawn_recursive();
+        }
+        retain
+    });
+}
+
+/// Spawns the rows of `items`, and the submenus open along `open_submenu`.
+fn spawn_items(
+    parent: &mut ChildBuilder,
+    menu: Entity,
+    items: &[ContextMenuItem],
+    path: &mut Vec<usize>,
+    open_submenu: &[usize],
+    style: &ContextMenuStyle,
+) {
+    for (index, item) in items.iter().enumerate() {
+        path.push(index);
+        let label = if item.submenu.is_empty() {
+            item.label.clone()
+        } else {
+            format!("{}{}", item.label, style.submenu_indicator)
+        };
+        parent
+            .spawn((
+                NodeBundle {
+                    style: style.item.clone(),
+                    background_color: BackgroundColor(style.item_colors.normal),
+                    focus_policy: FocusPolicy::Block,
+                    ..Default::default()
+                },
+                Interaction::default(),
+                style.item_colors,
+                ContextMenuEntry {
+                    menu,
+                    path: path.clone(),
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(label, style.text.clone()));
+                if !item.submenu.is_empty() && open_submenu.starts_with(path) {
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: style.submenu.clone(),
+                                background_color: style.background.into(),
+                                focus_policy: FocusPolicy::Block,
+                                ..Default::default()
+                            },
+                            ContextMenuList { menu },
+                        ))
+                        .with_children(|parent| {
+                            spawn_items(parent, menu, &item.submenu, path, open_submenu, style);
+                        });
+                }
+            });
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        query::With,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::{BuildWorldChildren, DespawnRecursiveExt};
+    use bevy_input::{keyboard::KeyCode, mouse::MouseButton, touch::Touches, ButtonInput};
+    use bevy_math::Vec2;
+    use bevy_window::{PrimaryWindow, Window, WindowResolution};
+
+    use super::{
+        context_menu_system, menu_position, update_context_menu_system, ContextMenu,
+        ContextMenuEntry, ContextMenuItem, ContextMenuList, ContextMenuSelected,
+    };
+    use crate::{HoveredUiEntity, Interaction, UiScale, UiScaleMode};
+
+    #[test]
+    fn menu_stays_inside_window() {
+        let window_size = Vec2::new(800., 600.);
+        let menu_size = Vec2::new(120., 60.);
+        assert_eq!(
+            menu_position(Vec2::new(10., 10.), menu_size, window_size),
+            Vec2::new(10., 10.)
+        );
+        assert_eq!(
+            menu_position(Vec2::new(790., 590.), menu_size, window_size),
+            Vec2::new(680., 540.)
+        );
+    }
+
+    #[test]
+    fn despawned_menus_are_forgotten() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.spawn((Window::default(), PrimaryWindow));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_context_menu_system);
+
+        let open_menu = |world: &mut World, schedule: &mut Schedule| {
+            let node = world
+                .spawn(ContextMenu {
+                    position: Some(Vec2::ZERO),
+                    ..ContextMenu::new([ContextMenuItem::new(0, "Copy")])
+                })
+                .id();
+            schedule.run(world);
+            let list = world
+                .query_filtered::<Entity, With<ContextMenuList>>()
+                .single(world);
+            (node, list)
+        };
+
+        // like a cleanup despawning every UI root
+        let (node, list) = open_menu(&mut world, &mut schedule);
+        world.entity_mut(node).despawn_recursive();
+        world.entity_mut(list).despawn_recursive();
+        schedule.run(&mut world);
+
+        // the menu is respawned or closed once its list is already gone
+        let (node, list) = open_menu(&mut world, &mut schedule);
+        world.entity_mut(list).despawn_recursive();
+        world.get_mut::<ContextMenu>(node).unwrap().open_submenu = vec![0];
+        schedule.run(&mut world);
+        let list = world
+            .query_filtered::<Entity, With<ContextMenuList>>()
+            .single(&world);
+        world.entity_mut(list).despawn_recursive();
+        world.get_mut::<ContextMenu>(node).unwrap().close();
+        schedule.run(&mut world);
+        assert_eq!(
+            world
+                .query_filtered::<(), With<ContextMenuList>>()
+                .iter(&world)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn menu_opens_on_right_click_and_picks_items() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<HoveredUiEntity>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<ContextMenuSelected>>();
+        let mut window = Window {
+            resolution: WindowResolution::new(800., 600.),
+            ..Default::default()
+        };
+        window.set_cursor_position(Some(Vec2::new(50., 40.)));
+        world.spawn((window, PrimaryWindow));
+        let mut schedule = Schedule::default();
+        schedule.add_systems((context_menu_system, update_context_menu_system).chain());
+
+        let node = world
+            .spawn((
+                ContextMenu::new([
+                    ContextMenuItem::new(1, "Copy"),
+                    ContextMenuItem::new(0, "More")
+                        .with_submenu([ContextMenuItem::new(2, "Paste")]),
+                ]),
+                Interaction::None,
+            ))
+            .id();
+        let entries = |world: &mut World| {
+            world
+                .query::<(Entity, &ContextMenuEntry)>()
+                .iter(world)
+                .map(|(entity, entry)| (entry.path.clone(), entity))
+                .collect::<Vec<_>>()
+        };
+        let entry = |world: &mut World, path: &[usize]| {
+            entries(world)
+                .into_iter()
+                .find(|(entry_path, _)| entry_path == path)
+                .unwrap()
+                .1
+        };
+        let click = |world: &mut World, button: MouseButton| {
+            let mut input = world.resource_mut::<ButtonInput<MouseButton>>();
+            input.release_all();
+            input.clear();
+            input.press(button);
+        };
+
+        // right-clicking a child of the node opens the menu at the cursor
+        let child = world.spawn(Interaction::Hovered).set_parent(node).id();
+        world.resource_mut::<HoveredUiEntity>().entity = Some(child);
+        click(&mut world, MouseButton::Right);
+        schedule.run(&mut world);
+        let menu = world.get::<ContextMenu>(node).unwrap();
+        assert_eq!(menu.position, Some(Vec2::new(50., 40.)));
+        assert_eq!(entries(&mut world).len(), 2);
+
+        // hovering an item with a submenu opens it
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        let more = entry(&mut world, &[1]);
+        world.resource_mut::<HoveredUiEntity>().entity = Some(more);
+        *world.get_mut::<Interaction>(more).unwrap() = Interaction::Hovered;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<ContextMenu>(node).unwrap().open_submenu, [1]);
+        assert_eq!(entries(&mut world).len(), 3);
+
+        let paste = entry(&mut world, &[1, 0]);
+        world.resource_mut::<HoveredUiEntity>().entity = Some(paste);
+        click(&mut world, MouseButton::Left);
+        *world.get_mut::<Interaction>(paste).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert!(!world.get::<ContextMenu>(node).unwrap().is_open());
+        assert!(entries(&mut world).is_empty());
+        let events = world.resource::<Events<ContextMenuSelected>>();
+        let selected = events
+            .get_reader()
+            .read(events)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            selected,
+            [ContextMenuSelected {
+                entity: node,
+                id: 2
+            }]
+        );
+
+        // pressing outside of the menu closes it
+        world.resource_mut::<HoveredUiEntity>().entity = Some(node);
+        click(&mut world, MouseButton::Right);
+        schedule.run(&mut world);
+        assert!(world.get::<ContextMenu>(node).unwrap().is_open());
+        world.resource_mut::<HoveredUiEntity>().entity = None;
+        click(&mut world, MouseButton::Left);
+        schedule.run(&mut world);
+        assert!(!world.get::<ContextMenu>(node).unwrap().is_open());
+
+        // as does Escape, and despawning the node despawns its menu
+        world.resource_mut::<HoveredUiEntity>().entity = Some(node);
+        click(&mut world, MouseButton::Right);
+        schedule.run(&mut world);
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        schedule.run(&mut world);
+        assert!(!world.get::<ContextMenu>(node).unwrap().is_open());
+        world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        click(&mut world, MouseButton::Right);
+        schedule.run(&mut world);
+        world.entity_mut(node).despawn();
+        schedule.run(&mut world);
+        assert_eq!(
+            world
+                .query_filtered::<(), With<ContextMenuList>>()
+                .iter(&world)
+                .count(),
+            0
+        );
+    }
+}
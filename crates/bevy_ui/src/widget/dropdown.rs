@@ -0,0 +1,365 @@
+use crate::{
+    node_bundles::{NodeBundle, TextBundle},
+    BackgroundColor, FlexDirection, FocusPolicy, HoveredUiEntity, Interaction, PositionType, Style,
+    UiRect, UiSystem, Val, ZIndex,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventWriter},
+    prelude::Component,
+    query::{Changed, Or, With},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Local, Query, Res},
+    world::Ref,
+};
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt, HierarchyQueryExt, Parent};
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton, touch::Touches, ButtonInput};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_text::{Text, TextStyle};
+
+/// Adds the systems driving [`Dropdown`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin) when the `bevy_text` feature is enabled.
+pub struct DropdownPlugin;
+
+impl Plugin for DropdownPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Dropdown>()
+            .register_type::<DropdownStyle>()
+            .register_type::<DropdownOption>()
+            .register_type::<DropdownLabel>()
+            .register_type::<DropdownSelected>()
+            .add_event::<DropdownSelected>()
+            .add_systems(
+                PreUpdate,
+                (dropdown_system, update_dropdown_list_system)
+                    .chain()
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// A UI node opening a list of options to pick one from when it's pressed.
+///
+/// While the dropdown is open, [`update_dropdown_list_system`] spawns a list of option rows below it,
+/// laid out according to its [`DropdownStyle`]. Pressing an option selects it and closes the dropdown.
+/// The dropdown is also closed when pressing anywhere outside of it, or Escape.
+///
+/// A [`DropdownLabel`] child of the dropdown displays the selected option.
+#[derive(Component, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Dropdown {
+    /// The options to choose from
+    pub options: Vec<String>,
+    /// The index of the selected option, if any
+    pub selected: Option<usize>,
+    /// Whether the list of options is shown
+    pub open: bool,
+}
+
+impl Dropdown {
+    /// Creates a closed dropdown with no selected option.
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// How the list of options of a [`Dropdown`] is displayed.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct DropdownStyle {
+    /// The style of the node containing the options, positioned relative to the dropdown
+    pub list: Style,
+    /// The background color of the node containing the options
+    pub list_background: Color,
+    /// The style of each option row
+    pub option: Style,
+    /// The background color of the option rows
+    pub option_background: Color,
+    /// The background color of the selected option row
+    pub selected_background: Color,
+    /// The style of the text of the option rows
+    pub text: TextStyle,
+}
+
+impl Default for DropdownStyle {
+    fn default() -> Self {
+        Self {
+            list: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.),
+                left: Val::Px(0.),
+                min_width: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            list_background: Color::srgb(0.1, 0.1, 0.1),
+            option: Style {
+                padding: UiRect::all(Val::Px(5.)),
+                ..Default::default()
+            },
+            option_background: Color::NONE,
+            selected_background: Color::srgb(0.25, 0.25, 0.25),
+            text: TextStyle::default(),
+        }
+    }
+}
+
+/// An option row of an open [`Dropdown`], spawned by [`update_dropdown_list_system`].
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct DropdownOption {
+    /// The dropdown the option belongs to
+    pub dropdown: Entity,
+    /// The index of the option in [`Dropdown::options`]
+    pub index: usize,
+}
+
+/// Marks a child [`Text`] of a [`Dropdown`] that displays the selected option.
+///
+/// The value of its first section is set by [`update_dropdown_list_system`].
+#[derive(Component, Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct DropdownLabel;
+
+/// Sent by [`dropdown_system`] when an option of a [`Dropdown`] is picked.
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct DropdownSelected {
+    /// The dropdown the option was picked from
+    pub entity: Entity,
+    /// The index of the picked option in [`Dropdown::options`]
+    pub index: usize,
+}
+
+/// The system that opens, closes and selects the options of [`Dropdown`] nodes.
+#[allow(clippy::too_many_arguments)]
+pub fn dropdown_system(
+    mut dropdown_query: Query<(Entity, &mut Dropdown, Ref<Interaction>)>,
+    option_query: Query<(&DropdownOption, Ref<Interaction>)>,
+    parent_query: Query<&Parent>,
+    hovered_ui_entity: Res<HoveredUiEntity>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    touches_input: Res<Touches>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut dropdown_selected_events: EventWriter<DropdownSelected>,
+) {
+    for (option, interaction) in &option_query {
+        if !interaction.is_changed() || *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok((entity, mut dropdown, _)) = dropdown_query.get_mut(option.dropdown) {
+            dropdown.selected = Some(option.index);
+            dropdown.open = false;
+            dropdown_selected_events.send(DropdownSelected {
+                entity,
+                index: option.index,
+            });
+        }
+    }
+
+    let pressed =
+        mouse_button_input.get_just_pressed().next().is_some() || touches_input.any_just_pressed();
+    let escape = keyboard_input.just_pressed(KeyCode::Escape);
+    for (entity, mut dropdown, interaction) in &mut dropdown_query {
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            dropdown.open = !dropdown.open;
+            continue;
+        }
+        if !dropdown.open {
+            continue;
+        }
+        // presses on the list, or on anything else inside the dropdown, keep it open
+        let pressed_outside = pressed
+            && !hovered_ui_entity.entity.is_some_and(|hovered| {
+                hovered == entity || parent_query.iter_ancestors(hovered).any(|e| e == entity)
+            });
+        if escape || pressed_outside {
+            dropdown.open = false;
+        }
+    }
+}
+
+/// The system that spawns the option list of open [`Dropdown`] nodes, despawns it once they are closed,
+/// and updates their [`DropdownLabel`].
+pub fn update_dropdown_list_system(
+    mut commands: Commands,
+    mut lists: Local<EntityHashMap<Entity>>,
+    dropdown_query: Query<
+        (Entity, &Dropdown, Option<&DropdownStyle>, Option<&Children>),
+        Or<(Changed<Dropdown>, Changed<DropdownStyle>)>,
+    >,
+    all_dropdowns_query: Query<(), With<Dropdown>>,
+    mut label_query: Query<&mut Text, With<DropdownLabel>>,
+) {
+    for (entity, dropdown, style, children) in &dropdown_query {
+        if let Some(children) = children {
+            let mut labels = label_query.iter_many_mut(children);
+            while let Some(mut label) = labels.fetch_next() {
+                let selected = dropdown
+                    .selected
+                    .and_then(|index| dropdown.options.get(index))
+                    .cloned()
+                    .unwrap_or_default();
+                if let Some(section) = label.sections.first_mut() {
+                    section.value = selected;
+                }
+            }
+        }
+
+        if let Some(list) = lists.remove(&entity) {
+            if let Some(list) = commands.get_entity(list) {
+                list.despawn_recursive();
+            }
+        }
+        if !dropdown.open {
+            continue;
+        }
+
+        let default_style;
+        let style = match style {
+            Some(style) => style,
+            None => {
+                default_style = DropdownStyle::default();
+                &default_style
+            }
+        };
+        let list = commands
+            .spawn(NodeBundle {
+                style: style.list.clone(),
+                background_color: style.list_background.into(),
+                focus_policy: FocusPolicy::Block,
+                // draw the list over the rest of the UI
+                z_index: ZIndex::Global(i32::MAX),
+                ..Default::default()
+            })
+            .with_children(|parent| {
+                for (index, option) in dropdown.options.iter().enumerate() {
+                    let background_color = if dropdown.selected == Some(index) {
+                        style.selected_background
+                    } else {
+                        style.option_background
+                    };
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: style.option.clone(),
+                                background_color: BackgroundColor(background_color),
+                                focus_policy: FocusPolicy::Block,
+                                ..Default::default()
+                            },
+                            Interaction::default(),
+                            DropdownOption {
+                                dropdown: entity,
+                                index,
+                            },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                option.clone(),
+                                style.text.clone(),
+                            ));
+                        });
+                }
+            })
+            .set_parent(entity)
+            .id();
+        lists.insert(entity, list);
+    }
+
+    // forget about the lists of despawned dropdowns, which are despawned along with them
+    lists.retain(|entity, _| all_dropdowns_query.contains(*entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        query::With,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_input::{keyboard::KeyCode, mouse::MouseButton, touch::Touches, ButtonInput};
+
+    use super::{
+        dropdown_system, update_dropdown_list_system, Dropdown, DropdownOption, DropdownSelected,
+    };
+    use crate::{HoveredUiEntity, Interaction};
+
+    #[test]
+    fn dropdown_opens_selects_and_closes() {
+        let mut world = World::new();
+        world.init_resource::<HoveredUiEntity>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<DropdownSelected>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((dropdown_system, update_dropdown_list_system).chain());
+
+        let dropdown = world
+            .spawn((Dropdown::new(["a", "b", "c"]), Interaction::None))
+            .id();
+        let options = |world: &mut World| world.query::<&DropdownOption>().iter(world).count();
+        schedule.run(&mut world);
+        assert_eq!(options(&mut world), 0);
+
+        *world.get_mut::<Interaction>(dropdown).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert!(world.get::<Dropdown>(dropdown).unwrap().open);
+        assert_eq!(options(&mut world), 3);
+
+        let second = world
+            .query::<(Entity, &DropdownOption)>()
+            .iter(&world)
+            .find(|(_, option)| option.index == 1)
+            .unwrap()
+            .0;
+        world.entity_mut(second).insert(Interaction::Pressed);
+        schedule.run(&mut world);
+        let state = world.get::<Dropdown>(dropdown).unwrap();
+        assert_eq!((state.open, state.selected), (false, Some(1)));
+        assert_eq!(options(&mut world), 0);
+        assert_eq!(world.resource::<Events<DropdownSelected>>().len(), 1);
+
+        // escape closes it
+        *world.get_mut::<Interaction>(dropdown).unwrap() = Interaction::None;
+        schedule.run(&mut world);
+        *world.get_mut::<Interaction>(dropdown).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert_eq!(options(&mut world), 3);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        schedule.run(&mut world);
+        assert!(!world.get::<Dropdown>(dropdown).unwrap().open);
+
+        // as does pressing outside of it
+        world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        *world.get_mut::<Dropdown>(dropdown).unwrap() = Dropdown {
+            open: true,
+            ..Dropdown::new(["a"])
+        };
+        schedule.run(&mut world);
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        schedule.run(&mut world);
+        assert!(!world.get::<Dropdown>(dropdown).unwrap().open);
+        assert_eq!(
+            world
+                .query_filtered::<(), With<DropdownOption>>()
+                .iter(&world)
+                .count(),
+            0
+        );
+    }
+}
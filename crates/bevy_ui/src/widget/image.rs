@@ -1,5 +1,5 @@
 use crate::{
-    measurement::AvailableSpace, ContentSize, Measure, Node, NodeMeasure, UiImage, UiScale,
+    measurement::AvailableSpace, ContentSize, Measure, Node, NodeMeasure, TargetUiScale, UiImage,
 };
 use bevy_asset::Assets;
 use bevy_ecs::prelude::*;
@@ -96,8 +96,8 @@ type UpdateImageFilter = With<Node>;
 /// Updates content size of the node based on the image provided
 pub fn update_image_content_size_system(
     mut previous_combined_scale_factor: Local<f32>,
-    windows: Query<&Window, With<PrimaryWindow>>,
-    ui_scale: Res<UiScale>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    ui_scale: TargetUiScale,
     textures: Res<Assets<Image>>,
 
     atlases: Res<Assets<TextureAtlasLayout>>,
@@ -111,16 +111,19 @@ pub fn update_image_content_size_system(
         UpdateImageFilter,
     >,
 ) {
-    let combined_scale_factor = windows
-        .get_single()
-        .map(|window| window.resolution.scale_factor())
-        .unwrap_or(1.)
-        * ui_scale.0;
+    let combined_scale_factor =
+        windows
+            .get_single()
+            .map_or(ui_scale.camera(None), |(entity, window)| {
+                let window_scale_factor = window.resolution.scale_factor();
+                window_scale_factor * ui_scale.window(entity, window_scale_factor)
+            });
 
     for (mut content_size, image, mut image_size, atlas_image) in &mut query {
-        if let Some(size) = match atlas_image {
-            Some(atlas) => atlas.texture_rect(&atlases).map(|t| t.size()),
-            None => textures.get(&image.texture).map(|t| t.size()),
+        if let Some(size) = match (image.rect, atlas_image) {
+            (Some(rect), _) => Some(rect.size().round().as_uvec2()),
+            (None, Some(atlas)) => atlas.texture_rect(&atlases).map(|t| t.size()),
+            (None, None) => textures.get(&image.texture).map(|t| t.size()),
         } {
             // Update only if size or scale factor has changed to avoid needless layout calculations
             if size != image_size.size
@@ -1,13 +1,51 @@
 //! This module contains the basic building blocks of Bevy's UI
 
+mod animated_image;
 mod button;
+mod checkbox;
+#[cfg(feature = "bevy_text")]
+mod context_menu;
+#[cfg(feature = "bevy_text")]
+mod dropdown;
 mod image;
 mod label;
 #[cfg(feature = "bevy_text")]
+mod rich_text;
+mod scroll_view;
+mod scrollbar;
+mod slider;
+#[cfg(feature = "bevy_text")]
 mod text;
+#[cfg(feature = "bevy_text")]
+mod text_input;
+#[cfg(feature = "bevy_text")]
+mod text_link;
+mod toggle;
+#[cfg(feature = "bevy_text")]
+mod tooltip;
+mod transition;
 
+pub use animated_image::*;
 pub use button::*;
+pub use checkbox::*;
+#[cfg(feature = "bevy_text")]
+pub use context_menu::*;
+#[cfg(feature = "bevy_text")]
+pub use dropdown::*;
 pub use image::*;
 pub use label::*;
 #[cfg(feature = "bevy_text")]
+pub use rich_text::*;
+pub use scroll_view::*;
+pub use scrollbar::*;
+pub use slider::*;
+#[cfg(feature = "bevy_text")]
 pub use text::*;
+#[cfg(feature = "bevy_text")]
+pub use text_input::*;
+#[cfg(feature = "bevy_text")]
+pub use text_link::*;
+pub use toggle::*;
+#[cfg(feature = "bevy_text")]
+pub use tooltip::*;
+pub use transition::*;
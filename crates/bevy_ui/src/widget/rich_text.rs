@@ -0,0 +1,405 @@
+use crate::{widget::measure_text_system, UiSystem};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::Handle;
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    prelude::Component, query::Changed, reflect::ReflectComponent, schedule::IntoSystemConfigs,
+    system::Query,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_text::{Font, Text, TextSection, TextStyle};
+use bevy_utils::tracing::warn;
+use thiserror::Error;
+
+/// Adds the system parsing [`RichText`] markup.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin) when the `bevy_text` feature is enabled.
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RichText>()
+            .register_type::<RichTextFonts>()
+            .add_systems(
+                PostUpdate,
+                rich_text_system
+                    .before(UiSystem::Layout)
+                    .before(measure_text_system),
+            );
+    }
+}
+
+/// Styles the [`Text`] of a UI node from markup, instead of splitting it into sections by hand.
+///
+/// Each time the component changes, [`rich_text_system`] parses the markup with [`parse_rich_text`]
+/// and replaces the sections of the node's [`Text`]. The markup supports these tags, which can be nested:
+///
+/// - `[b]bold[/b]` and `[i]italic[/i]`, which swap the font for one of the [`RichTextFonts`]
+/// - `[color=#ff8800]orange[/color]`, with a color in hexadecimal notation
+///
+/// A backslash escapes the next character, so `\[b]` is displayed as `[b]` and `\\` as `\`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{node_bundles::TextBundle, widget::RichText};
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     TextBundle::default(),
+///     RichText::new("[b]Warning:[/b] [color=#ff4040]low health[/color]", Default::default()),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct RichText {
+    /// The markup to display
+    pub markup: String,
+    /// The style of the text outside of any tag
+    pub style: TextStyle,
+    /// The fonts of the bold and italic text
+    pub fonts: RichTextFonts,
+}
+
+impl RichText {
+    /// Creates rich text displaying `markup`, with the fonts of the `style` for bold and italic text.
+    pub fn new(markup: impl Into<String>, style: TextStyle) -> Self {
+        Self {
+            markup: markup.into(),
+            style,
+            fonts: RichTextFonts::default(),
+        }
+    }
+
+    /// Returns this [`RichText`] with the given bold and italic fonts.
+    pub fn with_fonts(mut self, fonts: RichTextFonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+/// The fonts used by [`RichText`] for bold and italic text.
+///
+/// Text in a style without a font, left as the default handle, uses the closest matching font,
+/// falling back to the font of the [`RichText::style`].
+#[derive(Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct RichTextFonts {
+    /// The font of `[b]` text
+    pub bold: Handle<Font>,
+    /// The font of `[i]` text
+    pub italic: Handle<Font>,
+    /// The font of text that is both bold and italic
+    pub bold_italic: Handle<Font>,
+}
+
+impl RichTextFonts {
+    /// Returns the font for text in the given style, or `default` if there is none.
+    fn font(&self, bold: bool, italic: bool, default: &Handle<Font>) -> Handle<Font> {
+        let candidates: &[&Handle<Font>] = match (bold, italic) {
+            (true, true) => &[&self.bold_italic, &self.bold, &self.italic],
+            (true, false) => &[&self.bold],
+            (false, true) => &[&self.italic],
+            (false, false) => &[],
+        };
+        candidates
+            .iter()
+            .find(|font| ***font != Handle::default())
+            .map_or(default, |font| *font)
+            .clone()
+    }
+}
+
+/// An error parsing the markup of a [`RichText`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RichTextError {
+    #[error("The tag `[{0}]` is unknown")]
+    UnknownTag(String),
+    #[error("`{0}` is not a valid hexadecimal color")]
+    InvalidColor(String),
+    #[error("A `[` at byte {0} is never closed by a `]`")]
+    UnclosedBracket(usize),
+    #[error("The tag `[{0}]` is never closed")]
+    UnclosedTag(String),
+    #[error("`[/{found}]` doesn't close the innermost open tag, which is `{expected}`")]
+    MismatchedClosingTag { expected: String, found: String },
+}
+
+/// A tag that is open while parsing [`RichText`] markup.
+enum Tag {
+    Bold,
+    Italic,
+    Color(Color),
+}
+
+impl Tag {
+    fn name(&self) -> &'static str {
+        match self {
+            Tag::Bold => "b",
+            Tag::Italic => "i",
+            Tag::Color(_) => "color",
+        }
+    }
+}
+
+/// Parses [`RichText`] markup into the sections of a [`Text`], in the given base style and fonts.
+///
+/// Consecutive runs of text in the same style are merged into a single section.
+pub fn parse_rich_text(
+    markup: &str,
+    style: &TextStyle,
+    fonts: &RichTextFonts,
+) -> Result<Vec<TextSection>, RichTextError> {
+    let mut sections = Vec::<TextSection>::new();
+    let mut tags = Vec::<Tag>::new();
+    let mut value = String::new();
+
+    let current_style = |tags: &[Tag]| TextStyle {
+        font: fonts.font(
+            tags.iter().any(|tag| matches!(tag, Tag::Bold)),
+            tags.iter().any(|tag| matches!(tag, Tag::Italic)),
+            &style.font,
+        ),
+        color: tags
+            .iter()
+            .rev()
+            .find_map(|tag| match tag {
+                Tag::Color(color) => Some(*color),
+                _ => None,
+            })
+            .unwrap_or(style.color),
+        ..style.clone()
+    };
+    let flush = |sections: &mut Vec<TextSection>, value: &mut String, tags: &[Tag]| {
+        if value.is_empty() {
+            return;
+        }
+        let style = current_style(tags);
+        match sections.last_mut() {
+            Some(last) if last.style.font == style.font && last.style.color == style.color => {
+                last.value.push_str(value);
+            }
+            _ => sections.push(TextSection::new(value.clone(), style)),
+        }
+        value.clear();
+    };
+
+    let mut chars = markup.char_indices();
+    while let Some((start, character)) = chars.next() {
+        match character {
+            '\\' => value.push(chars.next().map_or('\\', |(_, escaped)| escaped)),
+            '[' => {
+                let content = &markup[start + 1..];
+                let end = content
+                    .find(']')
+                    .ok_or(RichTextError::UnclosedBracket(start))?;
+                let tag = &content[..end];
+                // skip the tag and its closing bracket
+                for _ in 0..=tag.chars().count() {
+                    chars.next();
+                }
+
+                flush(&mut sections, &mut value, &tags);
+                if let Some(name) = tag.strip_prefix('/') {
+                    match tags.last() {
+                        Some(open) if open.name() == name => {
+                            tags.pop();
+                        }
+                        open => {
+                            return Err(RichTextError::MismatchedClosingTag {
+                                expected: open.map_or("no tag", Tag::name).to_string(),
+                                found: name.to_string(),
+                            })
+                        }
+                    }
+                } else if tag == "b" {
+                    tags.push(Tag::Bold);
+                } else if tag == "i" {
+                    tags.push(Tag::Italic);
+                } else if let Some(hex) = tag.strip_prefix("color=") {
+                    let color = Srgba::hex(hex)
+                        .map_err(|_| RichTextError::InvalidColor(hex.to_string()))?;
+                    tags.push(Tag::Color(color.into()));
+                } else {
+                    return Err(RichTextError::UnknownTag(tag.to_string()));
+                }
+            }
+            _ => value.push(character),
+        }
+    }
+
+    if let Some(tag) = tags.last() {
+        return Err(RichTextError::UnclosedTag(tag.name().to_string()));
+    }
+    flush(&mut sections, &mut value, &tags);
+    Ok(sections)
+}
+
+/// The system that replaces the sections of the [`Text`] of [`RichText`] nodes with their parsed
+/// markup when it changes.
+///
+/// The text is left unchanged if the markup is invalid.
+pub fn rich_text_system(mut text_query: Query<(&RichText, &mut Text), Changed<RichText>>) {
+    for (rich_text, mut text) in &mut text_query {
+        match parse_rich_text(&rich_text.markup, &rich_text.style, &rich_text.fonts) {
+            Ok(sections) => text.sections = sections,
+            Err(error) => warn!("Invalid rich text markup {:?}: {error}", rich_text.markup),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_color::{
+        palettes::basic::{RED, WHITE},
+        Color,
+    };
+    use bevy_ecs::{schedule::Schedule, world::World};
+    use bevy_text::{Font, Text, TextStyle};
+
+    use super::{parse_rich_text, rich_text_system, RichText, RichTextError, RichTextFonts};
+
+    fn font(value: u128) -> Handle<Font> {
+        Handle::weak_from_u128(value)
+    }
+
+    fn fonts() -> RichTextFonts {
+        RichTextFonts {
+            bold: font(1),
+            italic: font(2),
+            bold_italic: font(3),
+        }
+    }
+
+    /// Returns the value, font and color of each section parsed from `markup`.
+    fn parse(markup: &str) -> Result<Vec<(String, Handle<Font>, Color)>, RichTextError> {
+        let style = TextStyle {
+            font: font(10),
+            color: WHITE.into(),
+            ..Default::default()
+        };
+        Ok(parse_rich_text(markup, &style, &fonts())?
+            .into_iter()
+            .map(|section| (section.value, section.style.font, section.style.color))
+            .collect())
+    }
+
+    #[test]
+    fn nested_tags_combine_their_styles() {
+        let white = Color::from(WHITE);
+        let red = Color::from(RED);
+        assert_eq!(
+            parse("a [b]b [i]bi[/i][/b] [color=#f00]r[i]ri[/i][/color]").unwrap(),
+            [
+                ("a ".to_string(), font(10), white),
+                ("b ".to_string(), font(1), white),
+                ("bi".to_string(), font(3), white),
+                (" ".to_string(), font(10), white),
+                ("r".to_string(), font(10), red),
+                ("ri".to_string(), font(2), red),
+            ]
+        );
+        // the innermost color wins, and runs in the same style are merged
+        assert_eq!(
+            parse("[color=#f00]a[color=#fff]b[/color][/color][b][/b]c").unwrap(),
+            [
+                ("a".to_string(), font(10), red),
+                ("bc".to_string(), font(10), white),
+            ]
+        );
+        assert!(parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn missing_fonts_fall_back_to_the_closest_style() {
+        let fonts = RichTextFonts {
+            bold: font(1),
+            ..Default::default()
+        };
+        assert_eq!(fonts.font(true, true, &font(10)), font(1));
+        assert_eq!(fonts.font(false, true, &font(10)), font(10));
+        assert_eq!(fonts.font(false, false, &font(10)), font(10));
+    }
+
+    #[test]
+    fn backslashes_escape_the_markup_delimiters() {
+        let parsed = parse(r"\[b]not bold\[/b] \\[b]bold[/b] a\]b\").unwrap();
+        let values = parsed
+            .into_iter()
+            .map(|(value, font, _)| (value, font))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                (r"[b]not bold[/b] \".to_string(), font(10)),
+                ("bold".to_string(), font(1)),
+                (r" a]b\".to_string(), font(10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_markup_is_rejected() {
+        assert_eq!(
+            parse("[u]x[/u]"),
+            Err(RichTextError::UnknownTag("u".to_string()))
+        );
+        assert_eq!(
+            parse("[color=red]x[/color]"),
+            Err(RichTextError::InvalidColor("red".to_string()))
+        );
+        assert_eq!(parse("a [b"), Err(RichTextError::UnclosedBracket(2)));
+        assert_eq!(
+            parse("[b][i]x[/i]"),
+            Err(RichTextError::UnclosedTag("b".to_string()))
+        );
+        assert_eq!(
+            parse("[b][i]x[/b][/i]"),
+            Err(RichTextError::MismatchedClosingTag {
+                expected: "i".to_string(),
+                found: "b".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("x[/b]"),
+            Err(RichTextError::MismatchedClosingTag {
+                expected: "no tag".to_string(),
+                found: "b".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn changed_markup_replaces_the_text() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(rich_text_system);
+
+        let entity = world
+            .spawn((
+                RichText::new("[b]a[/b]b", TextStyle::default()).with_fonts(fonts()),
+                Text::default(),
+            ))
+            .id();
+        let values = |world: &World| {
+            world
+                .get::<Text>(entity)
+                .unwrap()
+                .sections
+                .iter()
+                .map(|section| section.value.clone())
+                .collect::<Vec<_>>()
+        };
+        schedule.run(&mut world);
+        assert_eq!(values(&world), ["a", "b"]);
+
+        // invalid markup keeps the previous text
+        world.get_mut::<RichText>(entity).unwrap().markup = "[b]c".to_string();
+        schedule.run(&mut world);
+        assert_eq!(values(&world), ["a", "b"]);
+
+        world.get_mut::<RichText>(entity).unwrap().markup = "c".to_string();
+        schedule.run(&mut world);
+        assert_eq!(values(&world), ["c"]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{Node, OverflowAxis, ScrollPosition, ScrolledOver, Style, UiSystem};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    prelude::Component, reflect::ReflectComponent, schedule::IntoSystemConfigs, system::Query,
+};
+use bevy_hierarchy::Children;
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::GlobalTransform;
+
+/// Adds the systems driving [`ScrollView`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct ScrollViewPlugin;
+
+impl Plugin for ScrollViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ScrollView>()
+            .add_systems(PreUpdate, scroll_view_system.in_set(UiSystem::Widgets));
+    }
+}
+
+/// A UI node whose children can be scrolled when they don't fit in its content box.
+///
+/// [`scroll_view_system`] scrolls the node with the mouse wheel when it has a [`ScrolledOver`], and
+/// applies [`ScrollView::offset`] to its [`ScrollPosition`], which shifts the children along the axes
+/// on which its [`Overflow`](crate::Overflow) is [`OverflowAxis::Scroll`]. The node needs all of
+/// them, which are part of [`ScrollViewBundle`](crate::node_bundles::ScrollViewBundle).
+#[derive(Component, Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ScrollView {
+    /// How far the children are scrolled, in logical pixels. Set it to scroll the view programmatically.
+    pub offset: Vec2,
+    /// The size of the area covered by the children from the top left corner of the content box of
+    /// the node, computed by [`scroll_view_system`]
+    pub content_size: Vec2,
+}
+
+impl ScrollView {
+    /// Returns how far the children can be scrolled in a view whose content box has the given size,
+    /// see [`Node::content_box_size`].
+    pub fn max_offset(&self, view_size: Vec2) -> Vec2 {
+        (self.content_size - view_size).max(Vec2::ZERO)
+    }
+}
+
+/// The system that scrolls [`ScrollView`] nodes with the mouse wheel and updates their [`ScrollPosition`].
+///
+/// The offset is clamped so the content can't be scrolled past its ends, and stays at zero along
+/// the axes that don't scroll.
+pub fn scroll_view_system(
+    mut scroll_view_query: Query<(
+        &mut ScrollView,
+        &mut ScrollPosition,
+        &Node,
+        &Style,
+        &GlobalTransform,
+        Option<&ScrolledOver>,
+        Option<&Children>,
+    )>,
+    child_query: Query<(&Node, &GlobalTransform)>,
+) {
+    for (
+        mut scroll_view,
+        mut scroll_position,
+        node,
+        style,
+        global_transform,
+        scrolled_over,
+        children,
+    ) in &mut scroll_view_query
+    {
+        let inset = node.content_inset();
+        let content_min =
+            node.logical_rect(global_transform).min + Vec2::new(inset.left, inset.top);
+
+        // the children were laid out shifted by the scroll position
+        let content_size = child_query
+            .iter_many(children.into_iter().flatten())
+            .map(|(child_node, child_transform)| {
+                child_node.logical_rect(child_transform).max - content_min + scroll_position.offset
+            })
+            .fold(Vec2::ZERO, Vec2::max);
+        if scroll_view.content_size != content_size {
+            scroll_view.content_size = content_size;
+        }
+
+        let mut offset = scroll_view.offset;
+        if let Some(scrolled_over) = scrolled_over {
+            // scrolling the wheel up moves the content down, towards its start
+            offset -= scrolled_over.delta;
+        }
+        let mut max_offset = scroll_view.max_offset(node.content_box_size());
+        if style.overflow.x != OverflowAxis::Scroll {
+            max_offset.x = 0.;
+        }
+        if style.overflow.y != OverflowAxis::Scroll {
+            max_offset.y = 0.;
+        }
+        let offset = offset.clamp(Vec2::ZERO, max_offset);
+        if scroll_view.offset != offset {
+            scroll_view.offset = offset;
+        }
+        if scroll_position.offset != offset {
+            scroll_position.offset = offset;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{entity::Entity, schedule::Schedule, world::World};
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::Vec2;
+    use bevy_sprite::BorderRect;
+    use bevy_transform::components::GlobalTransform;
+
+    use super::{scroll_view_system, ScrollView};
+    use crate::{Node, Overflow, ScrollPosition, ScrolledOver, Style};
+
+    fn node(rect_min: Vec2, size: Vec2) -> (Node, GlobalTransform) {
+        (
+            Node {
+                calculated_size: size,
+                unrounded_size: size,
+                ..Default::default()
+            },
+            GlobalTransform::from_translation((rect_min + 0.5 * size).extend(0.)),
+        )
+    }
+
+    /// Spawns a scroll view with the given view node and children
+    fn spawn_scroll_view(
+        world: &mut World,
+        view: (Node, GlobalTransform),
+        children: impl IntoIterator<Item = (Node, GlobalTransform)>,
+    ) -> Entity {
+        world
+            .spawn((
+                ScrollView::default(),
+                ScrollPosition::default(),
+                ScrolledOver::default(),
+                Style {
+                    overflow: Overflow::scroll_y(),
+                    ..Default::default()
+                },
+                view,
+            ))
+            .with_children(|parent| {
+                for child in children {
+                    parent.spawn(child);
+                }
+            })
+            .id()
+    }
+
+    #[test]
+    fn scroll_is_clamped_to_content() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(scroll_view_system);
+
+        let scroll_view = spawn_scroll_view(
+            &mut world,
+            node(Vec2::ZERO, Vec2::new(100., 100.)),
+            [
+                node(Vec2::ZERO, Vec2::new(100., 150.)),
+                node(Vec2::new(0., 150.), Vec2::new(100., 150.)),
+            ],
+        );
+        let mut scroll = |world: &mut World, delta: Vec2| {
+            world.get_mut::<ScrolledOver>(scroll_view).unwrap().delta = delta;
+            schedule.run(world);
+            *world.get::<ScrollView>(scroll_view).unwrap()
+        };
+
+        assert_eq!(
+            scroll(&mut world, Vec2::ZERO),
+            ScrollView {
+                offset: Vec2::ZERO,
+                content_size: Vec2::new(100., 300.),
+            }
+        );
+
+        // the content can't be scrolled sideways, nor past its end
+        assert_eq!(
+            scroll(&mut world, Vec2::new(-20., -500.)).offset,
+            Vec2::new(0., 200.)
+        );
+        assert_eq!(
+            world.get::<ScrollPosition>(scroll_view).unwrap().offset,
+            Vec2::new(0., 200.)
+        );
+        assert_eq!(scroll(&mut world, Vec2::new(0., 500.)).offset, Vec2::ZERO);
+        assert_eq!(
+            world.get::<ScrollPosition>(scroll_view).unwrap().offset,
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn padded_views_scroll_to_the_end_of_their_content() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(scroll_view_system);
+
+        // a content box of 70 by 70 pixels at (15, 15), and a child laid out at its top
+        let (mut view_node, view_transform) = node(Vec2::ZERO, Vec2::new(100., 100.));
+        view_node.content_inset = BorderRect::square(15.);
+        let scroll_view = spawn_scroll_view(
+            &mut world,
+            (view_node, view_transform),
+            [node(Vec2::splat(15.), Vec2::new(70., 200.))],
+        );
+        world.get_mut::<ScrollView>(scroll_view).unwrap().offset = Vec2::new(0., 1000.);
+        schedule.run(&mut world);
+
+        let scroll_view = world.get::<ScrollView>(scroll_view).unwrap();
+        assert_eq!(scroll_view.content_size, Vec2::new(70., 200.));
+        assert_eq!(
+            scroll_view.max_offset(view_node.content_box_size()),
+            Vec2::new(0., 130.)
+        );
+        assert_eq!(scroll_view.offset, Vec2::new(0., 130.));
+    }
+}
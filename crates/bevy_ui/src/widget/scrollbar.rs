@@ -0,0 +1,320 @@
+use crate::{
+    widget::{scroll_view_system, ScrollView},
+    DragState, Interaction, Node, RelativeCursorPosition, Style, UiSystem, Val,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    prelude::Component,
+    query::{With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::Query,
+    world::Ref,
+};
+use bevy_hierarchy::Children;
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::view::Visibility;
+
+/// Adds the systems driving [`Scrollbar`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct ScrollbarPlugin;
+
+impl Plugin for ScrollbarPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Scrollbar>()
+            .register_type::<ScrollbarOrientation>()
+            .register_type::<ScrollbarThumb>()
+            .add_systems(
+                PreUpdate,
+                (
+                    scrollbar_system.before(scroll_view_system),
+                    update_scrollbar_thumb_system.after(scroll_view_system),
+                )
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// The axis along which a [`Scrollbar`] scrolls its target
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum ScrollbarOrientation {
+    /// Scrolls the target sideways, the track starting on the left
+    Horizontal,
+    /// Scrolls the target up and down, the track starting at the top
+    #[default]
+    Vertical,
+}
+
+/// A UI node used as the track of a scrollbar for a [`ScrollView`].
+///
+/// Its [`ScrollbarThumb`] child is sized to show how much of the content is visible, and moved to
+/// show the offset of the scroll view. Dragging the thumb scrolls the target, and pressing the track
+/// on either side of the thumb scrolls the target by a page.
+///
+/// The track needs an [`Interaction`] and a [`RelativeCursorPosition`] to be pressed.
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct Scrollbar {
+    /// The axis the scrollbar scrolls
+    pub orientation: ScrollbarOrientation,
+    /// The [`ScrollView`] scrolled by the scrollbar
+    pub target: Entity,
+}
+
+impl Scrollbar {
+    /// Creates a vertical scrollbar for `target`.
+    pub const fn vertical(target: Entity) -> Self {
+        Self {
+            orientation: ScrollbarOrientation::Vertical,
+            target,
+        }
+    }
+
+    /// Creates a horizontal scrollbar for `target`.
+    pub const fn horizontal(target: Entity) -> Self {
+        Self {
+            orientation: ScrollbarOrientation::Horizontal,
+            target,
+        }
+    }
+
+    /// Returns the component of `vector` along the scrollbar.
+    fn along(&self, vector: Vec2) -> f32 {
+        match self.orientation {
+            ScrollbarOrientation::Horizontal => vector.x,
+            ScrollbarOrientation::Vertical => vector.y,
+        }
+    }
+
+    /// Returns a vector that is `length` along the scrollbar.
+    fn vector(&self, length: f32) -> Vec2 {
+        match self.orientation {
+            ScrollbarOrientation::Horizontal => Vec2::new(length, 0.),
+            ScrollbarOrientation::Vertical => Vec2::new(0., length),
+        }
+    }
+}
+
+/// Marks the child of a [`Scrollbar`] that is sized and moved along the track to show the target's scrolling.
+///
+/// The thumb is positioned with [`Style::left`] or [`Style::top`], so its [`Style::position_type`] should be
+/// [`PositionType::Absolute`](crate::PositionType::Absolute). It needs an [`Interaction`] and a
+/// [`Draggable`](crate::Draggable) to be dragged. It is hidden while all the content of the target is visible.
+#[derive(Component, Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ScrollbarThumb;
+
+/// The geometry of a scrollbar thumb along a track of length `track_length`, for a view of length
+/// `view_length` showing content of length `content_length`.
+///
+/// Returns the length of the thumb, and how far it can move along the track.
+fn thumb_geometry(track_length: f32, view_length: f32, content_length: f32) -> (f32, f32) {
+    let thumb_length = track_length * (view_length / content_length).min(1.);
+    (thumb_length, (track_length - thumb_length).max(0.))
+}
+
+/// The system that scrolls the targets of [`Scrollbar`] nodes when their thumb is dragged or their track pressed.
+pub fn scrollbar_system(
+    scrollbar_query: Query<(
+        &Scrollbar,
+        &Node,
+        Ref<Interaction>,
+        Option<&RelativeCursorPosition>,
+        Option<&Children>,
+    )>,
+    thumb_query: Query<Ref<DragState>, With<ScrollbarThumb>>,
+    mut scroll_view_query: Query<(&mut ScrollView, &Node), Without<Scrollbar>>,
+) {
+    for (scrollbar, track_node, interaction, relative_cursor_position, children) in &scrollbar_query
+    {
+        let Ok((mut scroll_view, view_node)) = scroll_view_query.get_mut(scrollbar.target) else {
+            continue;
+        };
+        let view_length = scrollbar.along(view_node.content_box_size());
+        let content_length = scrollbar.along(scroll_view.content_size);
+        let max_offset = scrollbar.along(scroll_view.max_offset(view_node.content_box_size()));
+        if max_offset <= 0. {
+            continue;
+        }
+        let track_length = scrollbar.along(track_node.size());
+        let (thumb_length, thumb_travel) =
+            thumb_geometry(track_length, view_length, content_length);
+        let offset = scrollbar.along(scroll_view.offset);
+
+        let mut scroll = 0.;
+        // the delta of a drag is only updated when the cursor moves
+        for drag_state in thumb_query.iter_many(children.into_iter().flatten()) {
+            if drag_state.is_changed() && thumb_travel > 0. {
+                scroll += scrollbar.along(drag_state.delta) * max_offset / thumb_travel;
+            }
+        }
+
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            if let Some(cursor_position) =
+                relative_cursor_position.and_then(|position| position.normalized)
+            {
+                let pressed_at = scrollbar.along(cursor_position) * track_length;
+                let thumb_start = offset / max_offset * thumb_travel;
+                if pressed_at < thumb_start {
+                    scroll -= view_length;
+                } else if pressed_at > thumb_start + thumb_length {
+                    scroll += view_length;
+                }
+            }
+        }
+
+        if scroll != 0. {
+            scroll_view.offset += scrollbar.vector(scroll);
+        }
+    }
+}
+
+/// The system that sizes and moves the [`ScrollbarThumb`] of each [`Scrollbar`] to show the scrolling of its target.
+pub fn update_scrollbar_thumb_system(
+    scrollbar_query: Query<(&Scrollbar, &Node, &Children)>,
+    scroll_view_query: Query<(&ScrollView, &Node)>,
+    mut thumb_query: Query<(&mut Style, &mut Visibility), With<ScrollbarThumb>>,
+) {
+    for (scrollbar, track_node, children) in &scrollbar_query {
+        let Ok((scroll_view, view_node)) = scroll_view_query.get(scrollbar.target) else {
+            continue;
+        };
+        let max_offset = scrollbar.along(scroll_view.max_offset(view_node.content_box_size()));
+        let (thumb_length, thumb_travel) = thumb_geometry(
+            scrollbar.along(track_node.size()),
+            scrollbar.along(view_node.content_box_size()),
+            scrollbar.along(scroll_view.content_size),
+        );
+        let thumb_start = if max_offset > 0. {
+            scrollbar.along(scroll_view.offset) / max_offset * thumb_travel
+        } else {
+            0.
+        };
+
+        let mut thumbs = thumb_query.iter_many_mut(children);
+        while let Some((mut style, mut visibility)) = thumbs.fetch_next() {
+            // there is nothing to scroll when all the content is visible
+            let target_visibility = if max_offset > 0. {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            if *visibility != target_visibility {
+                *visibility = target_visibility;
+            }
+
+            let (start, length) = (Val::Px(thumb_start), Val::Px(thumb_length));
+            let (style_start, style_length) = match scrollbar.orientation {
+                ScrollbarOrientation::Horizontal => (style.left, style.width),
+                ScrollbarOrientation::Vertical => (style.top, style.height),
+            };
+            if style_start != start || style_length != length {
+                match scrollbar.orientation {
+                    ScrollbarOrientation::Horizontal => {
+                        style.left = start;
+                        style.width = length;
+                    }
+                    ScrollbarOrientation::Vertical => {
+                        style.top = start;
+                        style.height = length;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::{Rect, Vec2};
+    use bevy_render::view::Visibility;
+
+    use super::{scrollbar_system, update_scrollbar_thumb_system, Scrollbar, ScrollbarThumb};
+    use crate::{
+        widget::ScrollView, DragState, Interaction, Node, RelativeCursorPosition, Style, Val,
+    };
+
+    fn node(size: Vec2) -> Node {
+        Node {
+            calculated_size: size,
+            unrounded_size: size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn thumb_follows_and_scrolls_target() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((scrollbar_system, update_scrollbar_thumb_system).chain());
+
+        let view = world
+            .spawn((
+                ScrollView {
+                    offset: Vec2::ZERO,
+                    content_size: Vec2::new(100., 400.),
+                },
+                node(Vec2::new(100., 100.)),
+            ))
+            .id();
+        let mut thumb = None;
+        let track = world
+            .spawn((
+                Scrollbar::vertical(view),
+                node(Vec2::new(10., 200.)),
+                Interaction::None,
+                RelativeCursorPosition {
+                    normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+                    normalized: Some(Vec2::new(0.5, 0.9)),
+                    ..Default::default()
+                },
+            ))
+            .with_children(|parent| {
+                thumb = Some(
+                    parent
+                        .spawn((ScrollbarThumb, Style::default(), Visibility::default()))
+                        .id(),
+                );
+            })
+            .id();
+        let thumb = thumb.unwrap();
+        let thumb_geometry = |world: &World| {
+            let style = world.get::<Style>(thumb).unwrap();
+            (style.top, style.height)
+        };
+        let offset = |world: &World| world.get::<ScrollView>(view).unwrap().offset.y;
+
+        schedule.run(&mut world);
+        assert_eq!(thumb_geometry(&world), (Val::Px(0.), Val::Px(50.)));
+
+        // page down by pressing the track below the thumb
+        *world.get_mut::<Interaction>(track).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert_eq!(offset(&world), 100.);
+        assert_eq!(thumb_geometry(&world), (Val::Px(50.), Val::Px(50.)));
+
+        // moving the thumb by 15 pixels out of its 150 pixels of travel scrolls by 30 pixels
+        world.entity_mut(thumb).insert(DragState {
+            delta: Vec2::new(0., 15.),
+            ..Default::default()
+        });
+        schedule.run(&mut world);
+        assert_eq!(offset(&world), 130.);
+
+        // nothing to scroll
+        world.entity_mut(thumb).remove::<DragState>();
+        world.get_mut::<ScrollView>(view).unwrap().content_size = Vec2::new(100., 50.);
+        schedule.run(&mut world);
+        assert_eq!(*world.get::<Visibility>(thumb).unwrap(), Visibility::Hidden);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{Interaction, RelativeCursorPosition, Style, TouchInteraction, UiSystem, Val};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    entity::Entity,
+    event::{Event, EventWriter},
+    prelude::Component,
+    query::{Changed, With},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::Query,
+};
+use bevy_hierarchy::Children;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Adds the systems driving [`Slider`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct SliderPlugin;
+
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Slider>()
+            .register_type::<SliderOrientation>()
+            .register_type::<SliderHandle>()
+            .register_type::<SliderChanged>()
+            .add_event::<SliderChanged>()
+            .add_systems(
+                PreUpdate,
+                (slider_system, update_slider_handle_system)
+                    .chain()
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// The direction in which the value of a [`Slider`] increases
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum SliderOrientation {
+    /// The minimum value is on the left, and the maximum value on the right
+    #[default]
+    Horizontal,
+    /// The minimum value is at the bottom, and the maximum value at the top
+    Vertical,
+}
+
+/// A UI node used as a track to pick a value in a range by pressing or dragging along it.
+///
+/// While the track is [`Interaction::Pressed`], [`slider_system`] sets the value from the track's
+/// [`RelativeCursorPosition`]. The press keeps going when the cursor leaves the track, so dragging
+/// past its ends sets the minimum or maximum value.
+///
+/// A slider with a [`TouchInteraction`] follows the first touch pressing it instead, so several
+/// sliders can be dragged at once with different fingers.
+///
+/// Children marked with [`SliderHandle`] are moved along the track to show the value.
+#[derive(Component, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Slider {
+    /// The current value, between `min` and `max`
+    pub value: f32,
+    /// The value at the start of the track
+    pub min: f32,
+    /// The value at the end of the track
+    pub max: f32,
+    /// If set, values picked with the cursor are snapped to `min` plus a multiple of `step`
+    pub step: Option<f32>,
+    /// The direction of the track
+    pub orientation: SliderOrientation,
+}
+
+impl Slider {
+    const DEFAULT: Self = Self {
+        value: 0.,
+        min: 0.,
+        max: 1.,
+        step: None,
+        orientation: SliderOrientation::Horizontal,
+    };
+
+    /// Returns the value at `position` along the track, with 0. at the start and 1. at the end.
+    ///
+    /// The position is clamped to the track, and the value snapped to [`Slider::step`].
+    pub fn value_at(&self, position: f32) -> f32 {
+        let value = self.min + position.clamp(0., 1.) * (self.max - self.min);
+        match self.step {
+            Some(step) if step > 0. => {
+                let steps = ((value - self.min) / step).round();
+                (self.min + steps * step).clamp(self.min.min(self.max), self.min.max(self.max))
+            }
+            _ => value,
+        }
+    }
+
+    /// Returns the position of the value along the track, with 0. at the start and 1. at the end.
+    pub fn position(&self) -> f32 {
+        if self.max == self.min {
+            return 0.;
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0., 1.)
+    }
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Marks the child of a [`Slider`] moved along the track to show its value.
+///
+/// The handle is positioned with [`Style::left`], or [`Style::bottom`] for vertical sliders, as a
+/// percentage of the track's size. Its [`Style::position_type`] should be
+/// [`PositionType::Absolute`](crate::PositionType::Absolute), with a negative margin of half its size to center it on the value.
+#[derive(Component, Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct SliderHandle;
+
+/// Sent by [`slider_system`] when the value of a [`Slider`] is changed with the cursor.
+#[derive(Event, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct SliderChanged {
+    /// The slider that changed
+    pub entity: Entity,
+    /// The new value of the slider
+    pub value: f32,
+}
+
+/// The system that sets the value of pressed [`Slider`] nodes from the cursor or touch position.
+pub fn slider_system(
+    mut slider_query: Query<(
+        Entity,
+        &mut Slider,
+        &Interaction,
+        &RelativeCursorPosition,
+        Option<&TouchInteraction>,
+    )>,
+    mut slider_changed_events: EventWriter<SliderChanged>,
+) {
+    for (entity, mut slider, interaction, relative_cursor_position, touch_interaction) in
+        &mut slider_query
+    {
+        let cursor_position = match touch_interaction.and_then(|touch| touch.presses.first()) {
+            Some(press) => press.position,
+            None if *interaction == Interaction::Pressed => relative_cursor_position.normalized,
+            None => continue,
+        };
+        let Some(cursor_position) = cursor_position else {
+            continue;
+        };
+        let position = match slider.orientation {
+            SliderOrientation::Horizontal => cursor_position.x,
+            SliderOrientation::Vertical => 1. - cursor_position.y,
+        };
+        let value = slider.value_at(position);
+        if slider.value != value {
+            slider.value = value;
+            slider_changed_events.send(SliderChanged { entity, value });
+        }
+    }
+}
+
+/// The system that moves the [`SliderHandle`] children of each [`Slider`] to show its value.
+pub fn update_slider_handle_system(
+    slider_query: Query<(&Slider, &Children), Changed<Slider>>,
+    mut handle_query: Query<&mut Style, With<SliderHandle>>,
+) {
+    for (slider, children) in &slider_query {
+        let offset = Val::Percent(100. * slider.position());
+        let mut handles = handle_query.iter_many_mut(children);
+        while let Some(mut style) = handles.fetch_next() {
+            match slider.orientation {
+                SliderOrientation::Horizontal => style.left = offset,
+                SliderOrientation::Vertical => style.bottom = offset,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::{Rect, Vec2};
+
+    use super::{
+        slider_system, update_slider_handle_system, Slider, SliderChanged, SliderHandle,
+        SliderOrientation,
+    };
+    use crate::{Interaction, RelativeCursorPosition, Style, TouchInteraction, TouchPress, Val};
+
+    #[test]
+    fn value_at_snaps_to_step() {
+        let slider = Slider {
+            min: 10.,
+            max: 20.,
+            step: Some(3.),
+            ..Default::default()
+        };
+        assert_eq!(slider.value_at(-1.), 10.);
+        assert_eq!(slider.value_at(0.4), 13.);
+        assert_eq!(slider.value_at(0.5), 16.);
+        // the maximum isn't a multiple of the step
+        assert_eq!(slider.value_at(1.), 19.);
+        assert_eq!(Slider::default().value_at(0.25), 0.25);
+    }
+
+    #[test]
+    fn pressed_slider_follows_cursor() {
+        let mut world = World::new();
+        world.init_resource::<Events<SliderChanged>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((slider_system, update_slider_handle_system).chain());
+
+        let mut handle = None;
+        let slider = world
+            .spawn((
+                Slider {
+                    max: 100.,
+                    orientation: SliderOrientation::Vertical,
+                    ..Default::default()
+                },
+                Interaction::Hovered,
+                RelativeCursorPosition {
+                    normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+                    normalized: Some(Vec2::new(0.5, 0.25)),
+                    ..Default::default()
+                },
+            ))
+            .with_children(|parent| {
+                handle = Some(parent.spawn((Style::default(), SliderHandle)).id());
+            })
+            .id();
+        let handle = handle.unwrap();
+
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Slider>(slider).unwrap().value, 0.);
+
+        *world.get_mut::<Interaction>(slider).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Slider>(slider).unwrap().value, 75.);
+        assert_eq!(
+            world.get::<Style>(handle).unwrap().bottom,
+            Val::Percent(75.)
+        );
+
+        // dragging below the track
+        world
+            .get_mut::<RelativeCursorPosition>(slider)
+            .unwrap()
+            .normalized = Some(Vec2::new(0.5, 3.));
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Slider>(slider).unwrap().value, 0.);
+
+        let events = world.resource::<Events<SliderChanged>>();
+        let values = events
+            .get_reader()
+            .read(events)
+            .map(|event| event.value)
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![75., 0.]);
+    }
+
+    #[test]
+    fn touched_sliders_follow_their_own_touch() {
+        let mut world = World::new();
+        world.init_resource::<Events<SliderChanged>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(slider_system);
+
+        let mut spawn_slider = |presses: Vec<TouchPress>| {
+            world
+                .spawn((
+                    Slider::default(),
+                    Interaction::None,
+                    RelativeCursorPosition::default(),
+                    TouchInteraction { presses },
+                ))
+                .id()
+        };
+        let first = spawn_slider(vec![
+            TouchPress {
+                id: 0,
+                position: Some(Vec2::new(0.25, 0.5)),
+            },
+            TouchPress {
+                id: 2,
+                position: Some(Vec2::new(0.9, 0.5)),
+            },
+        ]);
+        let second = spawn_slider(vec![TouchPress {
+            id: 1,
+            position: Some(Vec2::new(0.75, 0.5)),
+        }]);
+        let untouched = spawn_slider(Vec::new());
+
+        schedule.run(&mut world);
+        // the first touch drives the slider
+        assert_eq!(world.get::<Slider>(first).unwrap().value, 0.25);
+        assert_eq!(world.get::<Slider>(second).unwrap().value, 0.75);
+        assert_eq!(world.get::<Slider>(untouched).unwrap().value, 0.);
+    }
+}
@@ -1,24 +1,28 @@
 use crate::{
-    ContentSize, DefaultUiCamera, FixedMeasure, Measure, Node, NodeMeasure, TargetCamera, UiScale,
+    ContentSize, DefaultUiCamera, Direction, FixedMeasure, Measure, Node, NodeMeasure,
+    TargetCamera, TargetUiScale,
 };
-use bevy_asset::Assets;
+use bevy_asset::{AssetEvent, AssetId, Assets};
 use bevy_ecs::{
     entity::{Entity, EntityHashMap},
+    event::EventReader,
     prelude::{Component, DetectChanges},
     query::With,
     reflect::ReflectComponent,
-    system::{Local, Query, Res, ResMut},
+    system::{Local, Query, Res, ResMut, Resource},
     world::{Mut, Ref},
 };
-use bevy_math::Vec2;
+use bevy_math::{FloatOrd, Vec2};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{camera::Camera, texture::Image};
 use bevy_sprite::TextureAtlasLayout;
 use bevy_text::{
-    scale_value, BreakLineOn, Font, FontAtlasSets, Text, TextError, TextLayoutInfo,
-    TextMeasureInfo, TextPipeline, TextSettings, YAxisOrientation,
+    glyph_line_at, scale_value, BreakLineOn, Font, FontAtlasSets, JustifyText, PositionedGlyph,
+    Text, TextError, TextLayoutInfo, TextMeasureInfo, TextPipeline, TextSection, TextSettings,
+    YAxisOrientation,
 };
-use bevy_utils::Entry;
+use bevy_utils::{Entry, HashMap};
+use std::collections::BTreeMap;
 use taffy::style::AvailableSpace;
 
 /// Text system flags
@@ -42,9 +46,58 @@ impl Default for TextFlags {
     }
 }
 
+/// Controls what happens to the text of a node that doesn't fit inside it
+///
+/// The size of the node has to be constrained by its [`Style`](crate::Style) for its text to
+/// overflow, as text nodes are otherwise sized to fit their text.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub enum TextOverflow {
+    /// The text is laid out as is, and drawn past the edges of the node
+    #[default]
+    Clip,
+    /// The glyphs that don't fit are replaced with an ellipsis at the end of the last visible line
+    Ellipsis,
+}
+
+/// The text appended to truncated text by [`TextOverflow::Ellipsis`]
+pub const ELLIPSIS: &str = "\u{2026}";
+
+/// Limits the text of a node to its first lines, hiding the rest.
+///
+/// The node is measured to fit only the retained lines. With [`TextOverflow::Ellipsis`], the last
+/// retained line ends with an ellipsis when lines are left out.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TextLineClamp {
+    /// The number of lines to keep, or `None` to keep all of them
+    pub max_lines: Option<usize>,
+}
+
+impl TextLineClamp {
+    /// Keeps the first `max_lines` lines of the text.
+    pub const fn new(max_lines: usize) -> Self {
+        Self {
+            max_lines: Some(max_lines),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TextMeasure {
     pub info: TextMeasureInfo,
+    /// The number of lines the text is limited to by its [`TextLineClamp`]
+    pub max_lines: Option<usize>,
+}
+
+impl TextMeasure {
+    /// Computes the size of the text within `bounds`, keeping only its first `max_lines` lines
+    fn compute_size(&self, bounds: Vec2) -> Vec2 {
+        match self.max_lines {
+            Some(max_lines) => self.info.compute_size_with_max_lines(bounds, max_lines).0,
+            None => self.info.compute_size(bounds),
+        }
+    }
 }
 
 impl Measure for TextMeasure {
@@ -71,7 +124,7 @@ impl Measure for TextMeasure {
         height
             .map_or_else(
                 || match available_width {
-                    AvailableSpace::Definite(_) => self.info.compute_size(Vec2::new(x, f32::MAX)),
+                    AvailableSpace::Definite(_) => self.compute_size(Vec2::new(x, f32::MAX)),
                     AvailableSpace::MinContent => Vec2::new(x, self.info.min.y),
                     AvailableSpace::MaxContent => Vec2::new(x, self.info.max.y),
                 },
@@ -86,15 +139,25 @@ fn create_text_measure(
     fonts: &Assets<Font>,
     scale_factor: f32,
     text: Ref<Text>,
+    max_lines: Option<usize>,
     mut content_size: Mut<ContentSize>,
     mut text_flags: Mut<TextFlags>,
 ) {
     match TextMeasureInfo::from_text(&text, fonts, scale_factor) {
-        Ok(measure) => {
+        Ok(mut info) => {
+            if let Some(max_lines) = max_lines {
+                info.min.y = info
+                    .compute_size_with_max_lines(Vec2::new(0.0, f32::INFINITY), max_lines)
+                    .0
+                    .y;
+                info.max = info
+                    .compute_size_with_max_lines(Vec2::INFINITY, max_lines)
+                    .0;
+            }
             if text.linebreak_behavior == BreakLineOn::NoWrap {
-                content_size.set(NodeMeasure::Fixed(FixedMeasure { size: measure.max }));
+                content_size.set(NodeMeasure::Fixed(FixedMeasure { size: info.max }));
             } else {
-                content_size.set(NodeMeasure::Text(TextMeasure { info: measure }));
+                content_size.set(NodeMeasure::Text(TextMeasure { info, max_lines }));
             }
 
             // Text measure func created successfully, so set `TextFlags` to schedule a recompute
@@ -115,7 +178,8 @@ fn create_text_measure(
 /// A `Measure` is used by the UI's layout algorithm to determine the appropriate amount of space
 /// to provide for the text given the fonts, the text itself and the constraints of the layout.
 ///
-/// * Measures are regenerated if the target camera's scale factor (or primary window if no specific target) or [`UiScale`] is changed.
+/// * Measures are regenerated if the target camera's scale factor (or primary window if no specific target) or its [`UiScale`](crate::UiScale) is changed.
+/// * Measures are also regenerated when the [`TextLineClamp`] of the node changes.
 /// * Changes that only modify the colors of a `Text` do not require a new `Measure`. This system
 ///     is only able to detect that a `Text` component has changed and will regenerate the `Measure` on
 ///     color changes. This can be expensive, particularly for large blocks of text, and the [`bypass_change_detection`](bevy_ecs::change_detection::DetectChangesMut::bypass_change_detection)
@@ -125,20 +189,21 @@ pub fn measure_text_system(
     fonts: Res<Assets<Font>>,
     camera_query: Query<(Entity, &Camera)>,
     default_ui_camera: DefaultUiCamera,
-    ui_scale: Res<UiScale>,
+    ui_scale: TargetUiScale,
     mut text_query: Query<
         (
             Ref<Text>,
             &mut ContentSize,
             &mut TextFlags,
             Option<&TargetCamera>,
+            Option<Ref<TextLineClamp>>,
         ),
         With<Node>,
     >,
 ) {
     let mut scale_factors: EntityHashMap<f32> = EntityHashMap::default();
 
-    for (text, content_size, text_flags, camera) in &mut text_query {
+    for (text, content_size, text_flags, camera, line_clamp) in &mut text_query {
         let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
         else {
             continue;
@@ -146,20 +211,24 @@ pub fn measure_text_system(
         let scale_factor = match scale_factors.entry(camera_entity) {
             Entry::Occupied(entry) => *entry.get(),
             Entry::Vacant(entry) => *entry.insert(
-                camera_query
-                    .get(camera_entity)
-                    .ok()
-                    .and_then(|(_, c)| c.target_scaling_factor())
-                    .unwrap_or(1.0)
-                    * ui_scale.0,
+                ui_scale.camera_scale_factor(camera_query.get(camera_entity).ok().map(|(_, c)| c)),
             ),
         };
         if last_scale_factors.get(&camera_entity) != Some(&scale_factor)
             || text.is_changed()
             || text_flags.needs_new_measure_func
             || content_size.is_added()
+            || line_clamp.as_ref().is_some_and(DetectChanges::is_changed)
         {
-            create_text_measure(&fonts, scale_factor, text, content_size, text_flags);
+            let max_lines = line_clamp.and_then(|line_clamp| line_clamp.max_lines);
+            create_text_measure(
+                &fonts,
+                scale_factor,
+                text,
+                max_lines,
+                content_size,
+                text_flags,
+            );
         }
     }
     *last_scale_factors = scale_factors;
@@ -174,39 +243,107 @@ fn queue_text(
     texture_atlases: &mut Assets<TextureAtlasLayout>,
     textures: &mut Assets<Image>,
     text_settings: &TextSettings,
+    layout_cache: &mut TextLayoutCache,
     scale_factor: f32,
     inverse_scale_factor: f32,
     text: &Text,
+    overflow: TextOverflow,
+    max_lines: Option<usize>,
     node: Ref<Node>,
     mut text_flags: Mut<TextFlags>,
     mut text_layout_info: Mut<TextLayoutInfo>,
 ) {
     // Skip the text node if it is waiting for a new measure func
     if !text_flags.needs_new_measure_func {
-        let physical_node_size = if text.linebreak_behavior == BreakLineOn::NoWrap {
+        let layout_key = TextLayoutKey::new(
+            text,
+            node.direction(),
+            overflow,
+            max_lines,
+            node.unrounded_size,
+            scale_factor,
+        );
+        if let Some(info) = layout_cache.get(&layout_key) {
+            *text_layout_info = info.clone();
+            text_flags.needs_recompute = false;
+            return;
+        }
+
+        // `scale_factor` is already multiplied by `UiScale`
+        let mut physical_node_size = node.unrounded_size * scale_factor;
+        let mut bounds = if text.linebreak_behavior == BreakLineOn::NoWrap {
             // With `NoWrap` set, no constraints are placed on the width of the text.
             Vec2::splat(f32::INFINITY)
         } else {
-            // `scale_factor` is already multiplied by `UiScale`
-            Vec2::new(
-                node.unrounded_size.x * scale_factor,
-                node.unrounded_size.y * scale_factor,
+            physical_node_size
+        };
+
+        // The height of the retained lines, if the text has more than `max_lines` lines
+        let clamped_height = max_lines.and_then(|max_lines| {
+            let info = TextMeasureInfo::from_text(text, fonts, scale_factor).ok()?;
+            let (size, truncated) =
+                info.compute_size_with_max_lines(Vec2::new(bounds.x, f32::INFINITY), max_lines);
+            truncated.then_some((size.y, info.line_height.map(|h| h * max_lines as f32)))
+        });
+        if let Some((clamped_height, lines_height)) = clamped_height {
+            // The layout stops before the first line starting below the bounds. Lines spaced by a
+            // `LineHeight` start every `line_height`, which may be above the retained lines' bottom.
+            physical_node_size.y = physical_node_size.y.min(clamped_height);
+            bounds.y = lines_height
+                .unwrap_or(clamped_height)
+                .min(physical_node_size.y);
+        }
+
+        let justify = layout_key.justify;
+
+        let mut queue_sections = |sections: &[TextSection], bounds: Vec2| {
+            text_pipeline.queue_text(
+                fonts,
+                sections,
+                scale_factor,
+                justify,
+                text.linebreak_behavior,
+                text.line_height,
+                bounds,
+                font_atlas_sets,
+                texture_atlases,
+                textures,
+                text_settings,
+                YAxisOrientation::TopToBottom,
             )
         };
 
-        match text_pipeline.queue_text(
-            fonts,
-            &text.sections,
-            scale_factor,
-            text.justify,
-            text.linebreak_behavior,
-            physical_node_size,
-            font_atlas_sets,
-            texture_atlases,
-            textures,
-            text_settings,
-            YAxisOrientation::TopToBottom,
-        ) {
+        let mut result = queue_sections(&text.sections, bounds);
+        if overflow == TextOverflow::Ellipsis {
+            if let Ok(info) = &result {
+                if clamped_height.is_some() || !fits(info.logical_size, physical_node_size) {
+                    let glyphs = info
+                        .glyphs
+                        .iter()
+                        .map(|glyph| (glyph.section_index, glyph.byte_index))
+                        .collect::<Vec<_>>();
+                    // Find how many glyphs can be kept in front of the ellipsis, which may be none
+                    // Lines past the clamp are left out of the layout instead of overflowing, so
+                    // the truncated text is laid out without a height limit to see if it fits
+                    let fitting_bounds = match clamped_height {
+                        Some(_) => Vec2::new(bounds.x, f32::INFINITY),
+                        None => bounds,
+                    };
+                    let fitting = |kept: usize| {
+                        let sections = truncated_sections(&text.sections, &glyphs[..kept]);
+                        match queue_sections(&sections, fitting_bounds) {
+                            Ok(info) if fits(info.logical_size, physical_node_size) => Some(info),
+                            _ => None,
+                        }
+                    };
+                    if let Some(info) = last_fitting(glyphs.len(), fitting) {
+                        result = Ok(info);
+                    }
+                }
+            }
+        }
+
+        match result {
             Err(TextError::NoSuchFont) => {
                 // There was an error processing the text layout, try again next frame
                 text_flags.needs_recompute = true;
@@ -217,6 +354,7 @@ fn queue_text(
             Ok(mut info) => {
                 info.logical_size.x = scale_value(info.logical_size.x, inverse_scale_factor);
                 info.logical_size.y = scale_value(info.logical_size.y, inverse_scale_factor);
+                layout_cache.insert(layout_key, info.clone());
                 *text_layout_info = info;
                 text_flags.needs_recompute = false;
             }
@@ -224,9 +362,213 @@ fn queue_text(
     }
 }
 
+/// Returns whether text laid out to the given physical size fits inside a node of the given physical size
+fn fits(text_size: Vec2, node_size: Vec2) -> bool {
+    // allow for the rounding of the node size
+    text_size.x <= node_size.x + 0.5 && text_size.y <= node_size.y + 0.5
+}
+
+/// Returns the sections of the text cut after the last of the `kept` glyphs, given as their section
+/// and byte indices, and followed by an [`ELLIPSIS`] in the style of the last kept glyph.
+fn truncated_sections(sections: &[TextSection], kept: &[(usize, usize)]) -> Vec<TextSection> {
+    let (section_index, end) = match kept.last() {
+        Some(&(section_index, byte_index)) => {
+            let value = &sections[section_index].value;
+            let glyph_len = value[byte_index..].chars().next().map_or(0, char::len_utf8);
+            (section_index, byte_index + glyph_len)
+        }
+        None => (0, 0),
+    };
+    let mut truncated = sections[..=section_index].to_vec();
+    let last = truncated.last_mut().unwrap();
+    last.value.truncate(end);
+    last.value.push_str(ELLIPSIS);
+    truncated
+}
+
+/// Calls `fitting` with decreasing candidates for the number of glyphs to keep in front of an
+/// ellipsis, returning the result for the largest one that fits.
+///
+/// This assumes that keeping fewer glyphs never makes the text larger, so only `O(log(glyph_count))`
+/// candidates are laid out.
+fn last_fitting<T>(glyph_count: usize, mut fitting: impl FnMut(usize) -> Option<T>) -> Option<T> {
+    // `low` glyphs are known to fit, if `best` is set; `high` glyphs are known to overflow
+    let (mut low, mut high) = (0, glyph_count);
+    let mut best = fitting(0);
+    best.as_ref()?;
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+        match fitting(middle) {
+            Some(result) => {
+                low = middle;
+                best = Some(result);
+            }
+            None => high = middle,
+        }
+    }
+    best
+}
+
+/// The contents and constraints that determine the layout of a text node
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct TextLayoutKey {
+    /// The value, font and font size of each section
+    sections: Vec<(String, AssetId<Font>, FloatOrd)>,
+    justify: JustifyText,
+    linebreak_behavior: BreakLineOn,
+    line_height: Option<FloatOrd>,
+    overflow: TextOverflow,
+    max_lines: Option<usize>,
+    node_size: (FloatOrd, FloatOrd),
+    scale_factor: FloatOrd,
+}
+
+impl TextLayoutKey {
+    fn new(
+        text: &Text,
+        direction: Direction,
+        overflow: TextOverflow,
+        max_lines: Option<usize>,
+        node_size: Vec2,
+        scale_factor: f32,
+    ) -> Self {
+        Self {
+            sections: text
+                .sections
+                .iter()
+                .map(|section| {
+                    (
+                        section.value.clone(),
+                        section.style.font.id(),
+                        FloatOrd(section.style.font_size),
+                    )
+                })
+                .collect(),
+            // `Left` and `Right` are relative to the direction of the node
+            justify: match (direction, text.justify) {
+                (Direction::RightToLeft, JustifyText::Left) => JustifyText::Right,
+                (Direction::RightToLeft, JustifyText::Right) => JustifyText::Left,
+                (_, justify) => justify,
+            },
+            linebreak_behavior: text.linebreak_behavior,
+            line_height: text
+                .line_height
+                .resolve_physical(&text.sections, scale_factor)
+                .map(FloatOrd),
+            overflow,
+            max_lines,
+            node_size: (FloatOrd(node_size.x), FloatOrd(node_size.y)),
+            scale_factor: FloatOrd(scale_factor),
+        }
+    }
+}
+
+/// Keeps the layouts of recently laid out UI text, so that [`text_system`] can reuse the layout of
+/// text that is laid out again with the same contents and constraints.
+///
+/// This happens when a [`Text`] is changed without changing the laid out text, like when it is set
+/// to the same value every frame, or only its colors are changed.
+///
+/// At most [`capacity`](Self::capacity) layouts are kept, evicting the least recently used ones.
+/// The capacity should exceed the number of text nodes laid out again each frame, or their layouts
+/// are evicted before they are reused.
+#[derive(Resource, Debug)]
+pub struct TextLayoutCache {
+    capacity: usize,
+    layouts: HashMap<TextLayoutKey, (TextLayoutInfo, u64)>,
+    /// The keys of the layouts by the tick of their last use, oldest first
+    recency: BTreeMap<u64, TextLayoutKey>,
+    /// Counts the uses of the cache, to find the least recently used layouts
+    tick: u64,
+}
+
+impl Default for TextLayoutCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl TextLayoutCache {
+    /// The number of layouts kept by default
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates an empty cache keeping up to `capacity` layouts.
+    /// A capacity of zero disables the cache.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            layouts: HashMap::default(),
+            recency: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// The maximum number of layouts kept by the cache
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of layouts kept by the cache, evicting the least recently used
+    /// layouts past it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.layouts.len() > capacity {
+            self.evict_least_recently_used();
+        }
+    }
+
+    /// The number of layouts in the cache
+    pub fn len(&self) -> usize {
+        self.layouts.len()
+    }
+
+    /// Returns `true` if the cache holds no layouts.
+    pub fn is_empty(&self) -> bool {
+        self.layouts.is_empty()
+    }
+
+    /// Removes all layouts from the cache.
+    pub fn clear(&mut self) {
+        self.layouts.clear();
+        self.recency.clear();
+    }
+
+    fn get(&mut self, key: &TextLayoutKey) -> Option<&TextLayoutInfo> {
+        self.tick += 1;
+        let (info, last_used) = self.layouts.get_mut(key)?;
+        if let Some(key) = self.recency.remove(last_used) {
+            self.recency.insert(self.tick, key);
+        }
+        *last_used = self.tick;
+        Some(info)
+    }
+
+    fn insert(&mut self, key: TextLayoutKey, info: TextLayoutInfo) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        self.recency.insert(self.tick, key.clone());
+        match self.layouts.insert(key, (info, self.tick)) {
+            Some((_, last_used)) => {
+                self.recency.remove(&last_used);
+            }
+            None if self.layouts.len() > self.capacity => self.evict_least_recently_used(),
+            None => {}
+        }
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, key)) = self.recency.pop_first() {
+            self.layouts.remove(&key);
+        }
+    }
+}
+
 /// Updates the layout and size information for a UI text node on changes to the size value of its [`Node`] component,
 /// or when the `needs_recompute` field of [`TextFlags`] is set to true.
 /// This information is computed by the [`TextPipeline`] and then stored in [`TextLayoutInfo`].
+/// Text laid out again with unchanged contents and constraints reuses its layout from the [`TextLayoutCache`].
 ///
 /// ## World Resources
 ///
@@ -240,21 +582,36 @@ pub fn text_system(
     camera_query: Query<(Entity, &Camera)>,
     default_ui_camera: DefaultUiCamera,
     text_settings: Res<TextSettings>,
-    ui_scale: Res<UiScale>,
+    ui_scale: TargetUiScale,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
     mut font_atlas_sets: ResMut<FontAtlasSets>,
     mut text_pipeline: ResMut<TextPipeline>,
+    mut layout_cache: ResMut<TextLayoutCache>,
+    mut font_events: EventReader<AssetEvent<Font>>,
     mut text_query: Query<(
         Ref<Node>,
         &Text,
         &mut TextLayoutInfo,
         &mut TextFlags,
         Option<&TargetCamera>,
+        Option<Ref<TextOverflow>>,
+        Option<Ref<TextLineClamp>>,
     )>,
 ) {
+    // The cached glyphs of a font are no longer in its atlases once it is changed or removed
+    if font_events.read().any(|event| {
+        !matches!(
+            event,
+            AssetEvent::Added { .. } | AssetEvent::LoadedWithDependencies { .. }
+        )
+    }) {
+        layout_cache.clear();
+    }
+
     let mut scale_factors: EntityHashMap<f32> = EntityHashMap::default();
 
-    for (node, text, text_layout_info, text_flags, camera) in &mut text_query {
+    for (node, text, text_layout_info, text_flags, camera, overflow, line_clamp) in &mut text_query
+    {
         let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
         else {
             continue;
@@ -262,12 +619,7 @@ pub fn text_system(
         let scale_factor = match scale_factors.entry(camera_entity) {
             Entry::Occupied(entry) => *entry.get(),
             Entry::Vacant(entry) => *entry.insert(
-                camera_query
-                    .get(camera_entity)
-                    .ok()
-                    .and_then(|(_, c)| c.target_scaling_factor())
-                    .unwrap_or(1.0)
-                    * ui_scale.0,
+                ui_scale.camera_scale_factor(camera_query.get(camera_entity).ok().map(|(_, c)| c)),
             ),
         };
         let inverse_scale_factor = scale_factor.recip();
@@ -275,6 +627,8 @@ pub fn text_system(
         if last_scale_factors.get(&camera_entity) != Some(&scale_factor)
             || node.is_changed()
             || text_flags.needs_recompute
+            || overflow.as_ref().is_some_and(DetectChanges::is_changed)
+            || line_clamp.as_ref().is_some_and(DetectChanges::is_changed)
         {
             queue_text(
                 &fonts,
@@ -283,9 +637,12 @@ pub fn text_system(
                 &mut texture_atlases,
                 &mut textures,
                 &text_settings,
+                &mut layout_cache,
                 scale_factor,
                 inverse_scale_factor,
                 text,
+                overflow.as_deref().copied().unwrap_or_default(),
+                line_clamp.and_then(|line_clamp| line_clamp.max_lines),
                 node,
                 text_flags,
                 text_layout_info,
@@ -294,3 +651,369 @@ pub fn text_system(
     }
     *last_scale_factors = scale_factors;
 }
+
+/// Returns the index of the character of each section of `text` in the concatenated sections.
+fn section_starts(text: &Text) -> Vec<usize> {
+    text.sections
+        .iter()
+        .scan(0, |start, section| {
+            let section_start = *start;
+            *start += section.value.chars().count();
+            Some(section_start)
+        })
+        .collect()
+}
+
+/// Returns the index of the character of `glyph` in the concatenated sections of `text`, given
+/// their [`section_starts`].
+fn glyph_char_index(
+    text: &Text,
+    section_starts: &[usize],
+    glyph: &PositionedGlyph,
+) -> Option<usize> {
+    let value = &text.sections.get(glyph.section_index)?.value;
+    let index = value.get(..glyph.byte_index)?.chars().count();
+    Some(section_starts[glyph.section_index] + index)
+}
+
+/// Returns the glyphs of `text_layout_info` along with the index of their character in the
+/// concatenated sections of `text`.
+pub(crate) fn glyph_char_indices<'a>(
+    text: &'a Text,
+    text_layout_info: &'a TextLayoutInfo,
+) -> impl Iterator<Item = (usize, &'a PositionedGlyph)> {
+    let section_starts = section_starts(text);
+    text_layout_info
+        .glyphs
+        .iter()
+        .filter_map(move |glyph| Some((glyph_char_index(text, &section_starts, glyph)?, glyph)))
+}
+
+/// Returns the index of the character boundary closest to `position` in laid out text, counting
+/// characters across all the sections of `text`.
+///
+/// `position` is measured from the top left of the text node in physical pixels, like the
+/// positions of the glyphs in `text_layout_info`. The boundary is found on the closest line with
+/// [`glyph_line_at`]. This can be used to place a caret or a selection under the cursor.
+///
+/// Characters without a glyph, like spaces, share the gap between the glyphs around them. At the
+/// start and end of a line, where there is no such gap, they are as wide as the closest glyph.
+pub fn char_index_at(text: &Text, text_layout_info: &TextLayoutInfo, position: Vec2) -> usize {
+    let section_starts = section_starts(text);
+    let char_index =
+        |glyph: &PositionedGlyph| glyph_char_index(text, &section_starts, glyph).unwrap_or(0);
+    let chars: Vec<char> = text
+        .sections
+        .iter()
+        .flat_map(|section| section.value.chars())
+        .collect();
+    let glyphs = &text_layout_info.glyphs;
+    let line = glyph_line_at(text_layout_info, position);
+    let index = line
+        .clone()
+        .find(|index| glyphs[*index].position.x > position.x)
+        .unwrap_or(line.end);
+
+    // the number of `count` characters of the given width from `left` that are left of `position`
+    let chars_left_of_position = |left: f32, width: f32, count: usize| {
+        (0..count)
+            .filter(|char| left + (*char as f32 + 0.5) * width < position.x)
+            .count()
+    };
+    let previous = (index > line.start).then(|| &glyphs[index - 1]);
+    let next = glyphs.get(index).filter(|_| index < line.end);
+    match (previous, next) {
+        (Some(previous), Some(next)) => {
+            let start = char_index(previous) + 1;
+            let count = char_index(next).saturating_sub(start);
+            let left = previous.position.x + previous.size.x / 2.;
+            let right = next.position.x - next.size.x / 2.;
+            let width = (right - left) / count.max(1) as f32;
+            start + chars_left_of_position(left, width, count)
+        }
+        (Some(previous), None) => {
+            // the characters after the line, up to a line break
+            let start = char_index(previous) + 1;
+            let end = glyphs.get(line.end).map_or(chars.len(), char_index);
+            let count = chars.get(start..end).map_or(0, |chars| {
+                chars.iter().take_while(|char| **char != '\n').count()
+            });
+            let left = previous.position.x + previous.size.x / 2.;
+            start + chars_left_of_position(left, previous.size.x, count)
+        }
+        (None, Some(next)) => {
+            // the characters before the line, after a line break or the start of the text, as
+            // the characters wrapped at the end of the previous line belong to it
+            let start = line
+                .start
+                .checked_sub(1)
+                .map_or(0, |previous| char_index(&glyphs[previous]) + 1);
+            let end = char_index(next);
+            let between = chars.get(start..end).unwrap_or_default();
+            let count = match between.iter().rposition(|char| *char == '\n') {
+                Some(line_break) => between.len() - line_break - 1,
+                None if line.start == 0 => between.len(),
+                None => 0,
+            };
+            let left = next.position.x - next.size.x / 2. - count as f32 * next.size.x;
+            end - count + chars_left_of_position(left, next.size.x, count)
+        }
+        (None, None) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets, Handle};
+    use bevy_ecs::{
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_render::{camera::Camera, texture::Image};
+    use bevy_sprite::TextureAtlasLayout;
+    use bevy_text::{
+        Font, FontAtlasSets, GlyphAtlasInfo, PositionedGlyph, Text, TextLayoutInfo,
+        TextMeasureInfo, TextPipeline, TextSection, TextSettings, TextStyle,
+    };
+    use taffy::style::AvailableSpace;
+
+    use bevy_math::Vec2;
+
+    use super::{
+        char_index_at, last_fitting, measure_text_system, text_system, truncated_sections,
+        TextFlags, TextLayoutCache, TextLayoutKey, TextMeasure, TextOverflow,
+    };
+    use crate::{ContentSize, Direction, Measure, Node, TargetCamera, UiScale, UiScaleMode};
+
+    #[test]
+    fn char_boundaries_around_spaces_are_reachable() {
+        // "a b " above "c", in glyphs of 10 by 20 pixels, which spaces and line breaks don't have
+        let text = Text::from_section("a b \nc", TextStyle::default());
+        let glyph = |x: f32, y: f32, byte_index| PositionedGlyph {
+            position: Vec2::new(x, y),
+            size: Vec2::new(10., 20.),
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Handle::default(),
+                texture: Handle::default(),
+                glyph_index: 0,
+            },
+            section_index: 0,
+            byte_index,
+        };
+        let text_layout_info = TextLayoutInfo {
+            glyphs: vec![glyph(5., 10., 0), glyph(25., 10., 2), glyph(5., 30., 5)],
+            logical_size: Vec2::new(30., 40.),
+        };
+        let index_at = |x: f32, y: f32| char_index_at(&text, &text_layout_info, Vec2::new(x, y));
+
+        assert_eq!(index_at(4., 10.), 0);
+        assert_eq!(index_at(6., 10.), 1);
+        // the space between the glyphs
+        assert_eq!(index_at(16., 10.), 2);
+        assert_eq!(index_at(24., 10.), 2);
+        assert_eq!(index_at(26., 10.), 3);
+        // the trailing space is as wide as the last glyph, and the line break isn't reachable
+        assert_eq!(index_at(34., 10.), 3);
+        assert_eq!(index_at(36., 10.), 4);
+        assert_eq!(index_at(100., 10.), 4);
+        assert_eq!(index_at(-5., 30.), 5);
+        assert_eq!(index_at(100., 30.), 6);
+    }
+
+    #[test]
+    fn truncated_sections_end_with_an_ellipsis() {
+        let sections = [
+            TextSection::new("Hello ", TextStyle::default()),
+            TextSection::new(
+                "wörld",
+                TextStyle {
+                    font_size: 12.,
+                    ..TextStyle::default()
+                },
+            ),
+        ];
+        let values = |kept: &[(usize, usize)]| {
+            truncated_sections(&sections, kept)
+                .into_iter()
+                .map(|section| section.value)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(&[]), ["\u{2026}"]);
+        assert_eq!(values(&[(0, 0), (0, 1)]), ["He\u{2026}"]);
+        // the ellipsis takes the style of the last kept glyph
+        let truncated = truncated_sections(&sections, &[(0, 0), (1, 0), (1, 1)]);
+        assert_eq!(truncated[0].value, "Hello ");
+        assert_eq!(truncated[1].value, "wö\u{2026}");
+        assert_eq!(truncated[1].style.font_size, 12.);
+    }
+
+    #[test]
+    fn last_fitting_keeps_as_many_glyphs_as_possible() {
+        let mut candidates = Vec::new();
+        let kept = last_fitting(100, |kept| {
+            candidates.push(kept);
+            (kept <= 42).then_some(kept)
+        });
+        assert_eq!(kept, Some(42));
+        assert!(candidates.len() <= 8);
+
+        assert_eq!(last_fitting(100, |_| None::<usize>), None);
+        assert_eq!(last_fitting(1, Some), Some(0));
+    }
+
+    #[test]
+    fn line_clamp_limits_the_measured_height() {
+        let mut fonts = Assets::<Font>::default();
+        let font = Font::try_from_bytes(
+            include_bytes!("../../../bevy_text/src/FiraMono-subset.ttf").to_vec(),
+        )
+        .unwrap();
+        let text = Text::from_section(
+            "a paragraph that wraps over many lines",
+            TextStyle {
+                font: fonts.add(font),
+                font_size: 20.,
+                ..Default::default()
+            },
+        );
+        let measure = |max_lines: Option<usize>, width: Option<f32>, available_width: f32| {
+            let info = TextMeasureInfo::from_text(&text, &fonts, 1.).unwrap();
+            TextMeasure { info, max_lines }.measure(
+                width,
+                None,
+                AvailableSpace::Definite(available_width),
+                AvailableSpace::MaxContent,
+                &Default::default(),
+            )
+        };
+
+        let unclamped = measure(None, None, 100.);
+        let clamped = measure(Some(2), None, 100.);
+        // only the retained lines are measured
+        assert!(clamped.x <= unclamped.x);
+        assert!(clamped.y < unclamped.y);
+        // a width resolved from a percentage of the parent wraps the text the same way
+        assert_eq!(measure(Some(2), Some(100.), 400.), clamped);
+        assert!(measure(Some(2), None, 60.).y <= clamped.y);
+        assert_eq!(measure(Some(10), None, 100.), unclamped);
+    }
+
+    #[test]
+    fn layout_cache_evicts_the_least_recently_used_layouts() {
+        let key = |value: &str, width: f32| {
+            TextLayoutKey::new(
+                &Text::from_section(value, TextStyle::default()),
+                Direction::Inherit,
+                TextOverflow::Clip,
+                None,
+                Vec2::new(width, 20.),
+                1.,
+            )
+        };
+        let mut cache = TextLayoutCache::new(2);
+        cache.insert(key("a", 100.), Default::default());
+        cache.insert(key("b", 100.), Default::default());
+        assert!(cache.get(&key("a", 100.)).is_some());
+        // the same text in a node of another size has another layout
+        assert!(cache.get(&key("a", 50.)).is_none());
+
+        cache.insert(key("c", 100.), Default::default());
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key("b", 100.)).is_none());
+        assert!(cache.get(&key("a", 100.)).is_some());
+        assert!(cache.get(&key("c", 100.)).is_some());
+
+        // replacing a layout makes it the most recently used one
+        cache.insert(key("a", 100.), Default::default());
+        assert_eq!(cache.len(), 2);
+
+        cache.set_capacity(1);
+        assert!(cache.get(&key("c", 100.)).is_none());
+        assert!(cache.get(&key("a", 100.)).is_some());
+
+        cache.set_capacity(2);
+        cache.insert(key("c", 100.), Default::default());
+        cache.set_capacity(1);
+        assert!(cache.get(&key("a", 100.)).is_none());
+        assert!(cache.get(&key("c", 100.)).is_some());
+
+        cache.set_capacity(0);
+        cache.insert(key("a", 100.), Default::default());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn text_system_reuses_cached_layouts_until_a_font_changes() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<TextSettings>();
+        world.init_resource::<TextPipeline>();
+        world.init_resource::<FontAtlasSets>();
+        world.init_resource::<TextLayoutCache>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<Assets<TextureAtlasLayout>>();
+        world.init_resource::<Events<AssetEvent<Font>>>();
+        let mut fonts = Assets::<Font>::default();
+        let font = fonts.add(
+            Font::try_from_bytes(
+                include_bytes!("../../../bevy_text/src/FiraMono-subset.ttf").to_vec(),
+            )
+            .unwrap(),
+        );
+        world.insert_resource(fonts);
+        let camera = world.spawn(Camera::default()).id();
+        let size = Vec2::new(200., 50.);
+        let text = world
+            .spawn((
+                Text::from_section(
+                    "some text",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.,
+                        ..Default::default()
+                    },
+                ),
+                Node {
+                    calculated_size: size,
+                    unrounded_size: size,
+                    ..Default::default()
+                },
+                ContentSize::default(),
+                TextFlags::default(),
+                TextLayoutInfo::default(),
+                TargetCamera(camera),
+            ))
+            .id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((measure_text_system, text_system).chain());
+        let reset_text = |world: &mut World| {
+            world.get_mut::<Text>(text).unwrap().sections[0].value = "some text".to_string();
+        };
+        let logical_size = |world: &World| world.get::<TextLayoutInfo>(text).unwrap().logical_size;
+
+        schedule.run(&mut world);
+        let laid_out = logical_size(&world);
+        assert!(laid_out.x > 0.);
+        let mut cache = world.resource_mut::<TextLayoutCache>();
+        assert_eq!(cache.len(), 1);
+        // mark the cached layout, to tell it from a new layout
+        let marked = Vec2::new(-1., -1.);
+        cache.layouts.values_mut().next().unwrap().0.logical_size = marked;
+
+        reset_text(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(logical_size(&world), marked);
+
+        world.send_event(AssetEvent::Modified { id: font.id() });
+        schedule.run(&mut world);
+        assert!(world.resource::<TextLayoutCache>().is_empty());
+
+        reset_text(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(logical_size(&world), laid_out);
+        assert_eq!(world.resource::<TextLayoutCache>().len(), 1);
+    }
+}
@@ -0,0 +1,763 @@
+use crate::{
+    widget::{char_index_at, glyph_char_indices},
+    DefaultUiCamera, Disabled, FocusedEntity, Interaction, Node, RelativeCursorPosition,
+    TargetCamera, TargetUiScale, UiSystem,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::{Alpha, Color};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    event::{Event, EventReader, EventWriter},
+    prelude::Component,
+    query::Without,
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut},
+    world::Ref,
+};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::camera::Camera;
+use bevy_text::{Text, TextLayoutInfo, TextSection, TextStyle};
+use bevy_time::Time;
+use std::ops::Range;
+
+/// Adds the systems driving [`TextInput`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin) when the `bevy_text` feature is enabled.
+pub struct TextInputPlugin;
+
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TextInput>()
+            .register_type::<TextChanged>()
+            .register_type::<TextSubmitted>()
+            .register_type::<TextCopied>()
+            .add_event::<TextChanged>()
+            .add_event::<TextSubmitted>()
+            .add_event::<TextCopied>()
+            .add_systems(
+                PreUpdate,
+                (
+                    text_input_selection_system,
+                    text_input_system,
+                    update_text_input_text_system,
+                )
+                    .chain()
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// A UI node displaying text that can be edited with the keyboard.
+///
+/// The text is edited by [`text_input_system`] while the node is the [`FocusedEntity`].
+/// Pressing the node focuses it.
+///
+/// The node's [`Text`] is rewritten by [`update_text_input_text_system`] to show the value,
+/// or the placeholder when the value is empty, along with a blinking caret. The style of its first
+/// section is used for the whole text.
+///
+/// Pressing the node places the caret under the cursor, and dragging selects the text between the
+/// press and the cursor with [`text_input_selection_system`]. The selection is highlighted with
+/// [`TextInput::SELECTION_COLOR`], and copied with Ctrl+C.
+#[derive(Component, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TextInput {
+    /// The text typed in the input
+    pub value: String,
+    /// The position of the caret in `value`, in characters
+    pub cursor: usize,
+    /// The selected characters of `value`, one end of which is the caret
+    pub selection: Option<Range<usize>>,
+    /// The text displayed while `value` is empty
+    pub placeholder: String,
+}
+
+impl TextInput {
+    /// How long the caret stays visible, then hidden, in seconds
+    pub const CARET_BLINK_INTERVAL: f32 = 0.5;
+
+    /// The color of the quad drawn behind the selected glyphs
+    pub const SELECTION_COLOR: Color = Color::srgba(0.25, 0.45, 0.9, 0.6);
+
+    /// Creates an empty input showing `placeholder`.
+    pub fn new(placeholder: impl Into<String>) -> Self {
+        Self {
+            placeholder: placeholder.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the byte index of the character at `cursor`, or the length of `value` past its end.
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+
+    /// Returns the selected part of `value`, if any characters are selected.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selected_bytes().map(|bytes| &self.value[bytes])
+    }
+
+    /// Returns the byte range of the selected characters in `value`, if any.
+    fn selected_bytes(&self) -> Option<Range<usize>> {
+        let selection = self
+            .selection
+            .as_ref()
+            .filter(|selection| !selection.is_empty())?;
+        Some(self.byte_index(selection.start)..self.byte_index(selection.end))
+    }
+
+    /// Moves the caret to `cursor`, selecting the characters between it and the other end of the
+    /// selection, or the previous caret position if nothing is selected.
+    fn select_to(&mut self, cursor: usize) {
+        let anchor = match &self.selection {
+            Some(selection) if selection.start == self.cursor => selection.end,
+            Some(selection) => selection.start,
+            None => self.cursor,
+        };
+        self.cursor = cursor;
+        self.selection = (anchor != cursor).then(|| anchor.min(cursor)..anchor.max(cursor));
+    }
+
+    /// Removes the selected characters and moves the caret where they were, returning true if
+    /// anything was selected.
+    fn delete_selection(&mut self) -> bool {
+        let Some(bytes) = self.selected_bytes() else {
+            self.selection = None;
+            return false;
+        };
+        self.cursor = self.value[..bytes.start].chars().count();
+        self.value.replace_range(bytes, "");
+        self.selection = None;
+        true
+    }
+
+    /// Returns the index in `value` of the character boundary under `position`, in the coordinates
+    /// of [`char_index_at`], given the [`Text`] displaying the input and its layout.
+    pub fn char_index_at(
+        &self,
+        text: &Text,
+        text_layout_info: &TextLayoutInfo,
+        position: Vec2,
+    ) -> usize {
+        self.value_index(text, char_index_at(text, text_layout_info, position))
+    }
+
+    /// Returns the rect covering the selected glyphs, in the physical coordinates of the glyphs of
+    /// `text_layout_info`, or `None` if no glyph is selected.
+    pub fn selection_rect(&self, text: &Text, text_layout_info: &TextLayoutInfo) -> Option<Rect> {
+        let selection = self
+            .selection
+            .as_ref()
+            .filter(|selection| !selection.is_empty())?;
+        let glyph_rect =
+            |glyph: &bevy_text::PositionedGlyph| Rect::from_center_size(glyph.position, glyph.size);
+        // the highlight covers the height of the whole line
+        let line = glyph_char_indices(text, text_layout_info)
+            .map(|(_, glyph)| glyph_rect(glyph))
+            .reduce(|line, rect| line.union(rect))?;
+        let caret = text
+            .sections
+            .first()
+            .map_or(0, |section| section.value.chars().count());
+        let selected = glyph_char_indices(text, text_layout_info)
+            .filter(|(index, _)| {
+                *index != caret && selection.contains(&self.value_index(text, *index))
+            })
+            .map(|(_, glyph)| glyph_rect(glyph))
+            .reduce(|selected, rect| selected.union(rect))?;
+        Some(Rect::new(
+            selected.min.x,
+            line.min.y,
+            selected.max.x,
+            line.max.y,
+        ))
+    }
+
+    /// Converts an index in the characters displayed by `text` to an index in `value`, skipping the caret.
+    fn value_index(&self, text: &Text, displayed_index: usize) -> usize {
+        let caret = text
+            .sections
+            .first()
+            .map_or(0, |section| section.value.chars().count());
+        let index = if displayed_index > caret {
+            displayed_index - 1
+        } else {
+            displayed_index
+        };
+        index.min(self.value.chars().count())
+    }
+
+    /// Applies a key press, returning true if `value` was edited.
+    ///
+    /// Typing, Backspace and Delete replace the selected characters, and moving the caret clears the selection.
+    fn edit(&mut self, key: &Key) -> bool {
+        self.cursor = self.cursor.min(self.value.chars().count());
+        if matches!(key, Key::Backspace | Key::Delete) && self.delete_selection() {
+            return true;
+        }
+        if matches!(key, Key::ArrowLeft | Key::ArrowRight | Key::Home | Key::End) {
+            self.selection = None;
+        }
+        match key {
+            Key::Character(characters) => {
+                let characters = characters
+                    .chars()
+                    .filter(|character| !character.is_control())
+                    .collect::<String>();
+                let deleted = !characters.is_empty() && self.delete_selection();
+                self.value
+                    .insert_str(self.byte_index(self.cursor), &characters);
+                self.cursor += characters.chars().count();
+                deleted || !characters.is_empty()
+            }
+            Key::Space => {
+                self.delete_selection();
+                self.value.insert(self.byte_index(self.cursor), ' ');
+                self.cursor += 1;
+                true
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.value.remove(self.byte_index(self.cursor));
+                true
+            }
+            Key::Delete if self.cursor < self.value.chars().count() => {
+                self.value.remove(self.byte_index(self.cursor));
+                true
+            }
+            Key::ArrowLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+                false
+            }
+            Key::ArrowRight => {
+                self.cursor = (self.cursor + 1).min(self.value.chars().count());
+                false
+            }
+            Key::Home => {
+                self.cursor = 0;
+                false
+            }
+            Key::End => {
+                self.cursor = self.value.chars().count();
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Sent by [`text_input_system`] each time the value of a [`TextInput`] is edited.
+#[derive(Event, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct TextChanged {
+    /// The edited input
+    pub entity: Entity,
+    /// The new value of the input
+    pub value: String,
+}
+
+/// Sent by [`text_input_system`] when Enter is pressed in a [`TextInput`].
+#[derive(Event, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct TextSubmitted {
+    /// The submitted input
+    pub entity: Entity,
+    /// The value of the input
+    pub value: String,
+}
+
+/// Sent by [`text_input_system`] when Ctrl+C is pressed in a [`TextInput`] with selected text.
+///
+/// Bevy doesn't access the system clipboard, so this event should be forwarded to it by the app,
+/// for example with the `arboard` crate.
+#[derive(Event, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct TextCopied {
+    /// The input the text was copied from
+    pub entity: Entity,
+    /// The selected text
+    pub text: String,
+}
+
+/// The system that places the caret of a [`TextInput`] under the cursor when it is pressed, and
+/// selects text while the cursor is dragged.
+///
+/// Only inputs with a [`RelativeCursorPosition`] are updated, and the cursor is mapped to a
+/// character with [`TextInput::char_index_at`].
+pub fn text_input_selection_system(
+    camera_query: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: TargetUiScale,
+    mut text_input_query: Query<
+        (
+            &mut TextInput,
+            Ref<Interaction>,
+            &RelativeCursorPosition,
+            &Node,
+            &Text,
+            &TextLayoutInfo,
+            Option<&TargetCamera>,
+        ),
+        Without<Disabled>,
+    >,
+) {
+    for (
+        mut text_input,
+        interaction,
+        relative_cursor_position,
+        node,
+        text,
+        text_layout_info,
+        camera,
+    ) in &mut text_input_query
+    {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(normalized) = relative_cursor_position.normalized else {
+            continue;
+        };
+        let camera = camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera| camera_query.get(camera).ok());
+        // glyphs are laid out in physical pixels
+        let position = normalized * node.size() * ui_scale.camera_scale_factor(camera);
+        let index = text_input.char_index_at(text, text_layout_info, position);
+        if interaction.is_changed() {
+            if text_input.cursor != index || text_input.selection.is_some() {
+                text_input.cursor = index;
+                text_input.selection = None;
+            }
+        } else if text_input.cursor != index {
+            text_input.select_to(index);
+        }
+    }
+}
+
+/// The system that edits the focused [`TextInput`] with the keyboard.
+///
+/// Typed characters are inserted at the caret, Backspace and Delete remove the character before and
+/// after it, and the arrow keys, Home and End move it. Ctrl+C sends a [`TextCopied`] event with the
+/// selected text, and other characters typed while Ctrl or Super is held are ignored, so shortcuts
+/// don't replace the selection. [`Disabled`] inputs ignore the keyboard.
+#[allow(clippy::too_many_arguments)]
+pub fn text_input_system(
+    mut text_input_query: Query<(Entity, &mut TextInput, Ref<Interaction>), Without<Disabled>>,
+    mut focused_entity: ResMut<FocusedEntity>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut keyboard_input_events: EventReader<KeyboardInput>,
+    mut text_changed_events: EventWriter<TextChanged>,
+    mut text_submitted_events: EventWriter<TextSubmitted>,
+    mut text_copied_events: EventWriter<TextCopied>,
+) {
+    for (entity, _, interaction) in &text_input_query {
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            focused_entity.0 = Some(entity);
+        }
+    }
+
+    let Some(Ok((entity, mut text_input, _))) = focused_entity
+        .0
+        .map(|entity| text_input_query.get_mut(entity))
+    else {
+        keyboard_input_events.clear();
+        return;
+    };
+
+    let control = keyboard_input.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    for event in keyboard_input_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if control && event.key_code == KeyCode::KeyC {
+            if let Some(text) = text_input.selected_text() {
+                text_copied_events.send(TextCopied {
+                    entity,
+                    text: text.to_string(),
+                });
+            }
+        } else if control && matches!(event.logical_key, Key::Character(_) | Key::Space) {
+            continue;
+        } else if event.logical_key == Key::Enter {
+            text_submitted_events.send(TextSubmitted {
+                entity,
+                value: text_input.value.clone(),
+            });
+        } else if text_input.edit(&event.logical_key) {
+            text_changed_events.send(TextChanged {
+                entity,
+                value: text_input.value.clone(),
+            });
+        }
+    }
+}
+
+/// The system that displays the value or placeholder of each [`TextInput`], and its caret while it is focused.
+pub fn update_text_input_text_system(
+    time: Res<Time>,
+    focused_entity: Res<FocusedEntity>,
+    mut text_input_query: Query<(Entity, &TextInput, &mut Text)>,
+) {
+    let blink_on = (time.elapsed_seconds() / TextInput::CARET_BLINK_INTERVAL) as u32 % 2 == 0;
+
+    for (entity, text_input, mut text) in &mut text_input_query {
+        let style = text
+            .sections
+            .first()
+            .map(|section| section.style.clone())
+            .unwrap_or_default();
+        let caret_color = if focused_entity.0 == Some(entity) && blink_on {
+            style.color
+        } else {
+            Color::NONE
+        };
+        let section = |value: &str, color: Color| TextSection {
+            value: value.to_string(),
+            style: TextStyle {
+                color,
+                ..style.clone()
+            },
+        };
+
+        let sections = if text_input.value.is_empty() {
+            let placeholder_color = style.color.with_alpha(0.5 * style.color.alpha());
+            [
+                section("", style.color),
+                section("|", caret_color),
+                section(&text_input.placeholder, placeholder_color),
+            ]
+        } else {
+            let (before, after) = text_input
+                .value
+                .split_at(text_input.byte_index(text_input.cursor));
+            [
+                section(before, style.color),
+                section("|", caret_color),
+                section(after, style.color),
+            ]
+        };
+
+        let unchanged = text.sections.len() == sections.len()
+            && text.sections.iter().zip(&sections).all(|(old, new)| {
+                old.value == new.value
+                    && old.style.color == new.style.color
+                    && old.style.font_size == new.style.font_size
+                    && old.style.font == new.style.font
+            });
+        if !unchanged {
+            text.sections = sections.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_input::{
+        keyboard::{Key, KeyCode, KeyboardInput},
+        ButtonInput, ButtonState,
+    };
+    use bevy_math::{Rect, Vec2};
+    use bevy_text::{GlyphAtlasInfo, PositionedGlyph, Text, TextLayoutInfo, TextSection};
+    use bevy_time::Time;
+
+    use super::{
+        text_input_selection_system, text_input_system, update_text_input_text_system, TextChanged,
+        TextCopied, TextInput, TextSubmitted,
+    };
+    use crate::{FocusedEntity, Interaction, Node, RelativeCursorPosition, UiScale, UiScaleMode};
+
+    fn type_key(world: &mut World, logical_key: Key) {
+        world.send_event(KeyboardInput {
+            key_code: KeyCode::KeyA,
+            logical_key,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    /// Returns the text displayed by an input with the caret after its first character, and its
+    /// layout with glyphs 10 pixels apart.
+    fn displayed_text(value: &str) -> (Text, TextLayoutInfo) {
+        let (before, after) = value.split_at(1);
+        let text = Text::from_sections([before, "|", after].map(|value| TextSection {
+            value: value.to_string(),
+            style: Default::default(),
+        }));
+        let glyphs = [(0, 0), (1, 0)]
+            .into_iter()
+            .chain(after.char_indices().map(|(index, _)| (2, index)))
+            .enumerate()
+            .map(|(i, (section_index, byte_index))| PositionedGlyph {
+                position: Vec2::new(5. + 10. * i as f32, 10.),
+                size: Vec2::new(8., 16.),
+                atlas_info: GlyphAtlasInfo {
+                    texture_atlas: Handle::default(),
+                    texture: Handle::default(),
+                    glyph_index: 0,
+                },
+                section_index,
+                byte_index,
+            })
+            .collect();
+        let text_layout_info = TextLayoutInfo {
+            glyphs,
+            logical_size: Vec2::new(40., 20.),
+        };
+        (text, text_layout_info)
+    }
+
+    #[test]
+    fn edit_moves_cursor_by_characters() {
+        let mut input = TextInput::new("placeholder");
+        assert!(input.edit(&Key::Character("hé".into())));
+        assert!(input.edit(&Key::Space));
+        assert!(input.edit(&Key::Character("o".into())));
+        assert_eq!((input.value.as_str(), input.cursor), ("hé o", 4));
+
+        assert!(!input.edit(&Key::ArrowLeft));
+        assert!(!input.edit(&Key::ArrowLeft));
+        assert!(input.edit(&Key::Backspace));
+        assert_eq!((input.value.as_str(), input.cursor), ("h o", 1));
+        assert!(input.edit(&Key::Delete));
+        assert_eq!((input.value.as_str(), input.cursor), ("ho", 1));
+
+        assert!(!input.edit(&Key::Home));
+        assert!(!input.edit(&Key::Backspace));
+        assert!(!input.edit(&Key::End));
+        assert!(!input.edit(&Key::Delete));
+        assert!(!input.edit(&Key::ArrowRight));
+        assert_eq!((input.value.as_str(), input.cursor), ("ho", 2));
+        assert!(!input.edit(&Key::Character("\u{8}".into())));
+    }
+
+    #[test]
+    fn pressed_input_receives_keyboard_input() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<Events<KeyboardInput>>();
+        world.init_resource::<Events<TextChanged>>();
+        world.init_resource::<Events<TextSubmitted>>();
+        world.init_resource::<Events<TextCopied>>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((text_input_system, update_text_input_text_system).chain());
+
+        let input = world
+            .spawn((
+                TextInput::new("Name"),
+                Interaction::None,
+                Text::from_section("", Default::default()),
+            ))
+            .id();
+        let displayed = |world: &World| {
+            world
+                .get::<Text>(input)
+                .unwrap()
+                .sections
+                .iter()
+                .map(|section| section.value.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // not focused yet
+        type_key(&mut world, Key::Character("a".into()));
+        schedule.run(&mut world);
+        assert_eq!(displayed(&world), ["", "|", "Name"]);
+
+        *world.get_mut::<Interaction>(input).unwrap() = Interaction::Pressed;
+        type_key(&mut world, Key::Character("a".into()));
+        type_key(&mut world, Key::Character("b".into()));
+        type_key(&mut world, Key::ArrowLeft);
+        type_key(&mut world, Key::Enter);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<FocusedEntity>().0, Some(input));
+        assert_eq!(displayed(&world), ["a", "|", "b"]);
+
+        let changed = world.resource::<Events<TextChanged>>();
+        let changed = changed
+            .get_reader()
+            .read(changed)
+            .map(|event| event.value.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(changed, ["a", "ab"]);
+        let submitted = world.resource::<Events<TextSubmitted>>();
+        let submitted = submitted
+            .get_reader()
+            .read(submitted)
+            .map(|event| event.value.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(submitted, ["ab"]);
+    }
+
+    #[test]
+    fn cursor_positions_map_to_characters_around_the_caret() {
+        let input = TextInput {
+            value: "abc".to_string(),
+            cursor: 1,
+            ..TextInput::new("")
+        };
+        // a | b c, centered at 5, 15, 25 and 35
+        let (text, text_layout_info) = displayed_text("abc");
+        let index_at = |x: f32| input.char_index_at(&text, &text_layout_info, Vec2::new(x, 10.));
+        assert_eq!(index_at(-10.), 0);
+        assert_eq!(index_at(8.), 1);
+        assert_eq!(index_at(20.), 1);
+        assert_eq!(index_at(30.), 2);
+        assert_eq!(index_at(100.), 3);
+    }
+
+    #[test]
+    fn dragging_selects_and_control_c_copies() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<KeyboardInput>>();
+        world.init_resource::<Events<TextChanged>>();
+        world.init_resource::<Events<TextSubmitted>>();
+        world.init_resource::<Events<TextCopied>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((text_input_selection_system, text_input_system).chain());
+
+        let (text, text_layout_info) = displayed_text("abcd");
+        let size = Vec2::new(50., 20.);
+        let cursor_at = |x: f32| RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+            normalized: Some(Vec2::new(x / 50., 0.5)),
+            ..Default::default()
+        };
+        let input = world
+            .spawn((
+                TextInput {
+                    value: "abcd".to_string(),
+                    cursor: 1,
+                    ..TextInput::new("")
+                },
+                Interaction::Pressed,
+                cursor_at(8.),
+                Node {
+                    calculated_size: size,
+                    unrounded_size: size,
+                    ..Default::default()
+                },
+                text.clone(),
+                text_layout_info.clone(),
+            ))
+            .id();
+        let selection = |world: &World| {
+            let input = world.get::<TextInput>(input).unwrap();
+            (input.cursor, input.selection.clone())
+        };
+
+        // pressing places the caret, and dragging selects from there
+        schedule.run(&mut world);
+        assert_eq!(selection(&world), (1, None));
+        *world.get_mut::<RelativeCursorPosition>(input).unwrap() = cursor_at(40.);
+        schedule.run(&mut world);
+        assert_eq!(selection(&world), (3, Some(1..3)));
+        *world.get_mut::<RelativeCursorPosition>(input).unwrap() = cursor_at(-5.);
+        schedule.run(&mut world);
+        assert_eq!(selection(&world), (0, Some(0..1)));
+        *world.get_mut::<RelativeCursorPosition>(input).unwrap() = cursor_at(100.);
+        schedule.run(&mut world);
+        assert_eq!(selection(&world), (4, Some(1..4)));
+
+        let text_input = world.get::<TextInput>(input).unwrap();
+        assert_eq!(text_input.selected_text(), Some("bcd"));
+        // the highlight spans the selected glyphs, skipping the caret, and the height of the line
+        assert_eq!(
+            text_input.selection_rect(&text, &text_layout_info),
+            Some(Rect::new(21., 2., 49., 18.))
+        );
+
+        *world.get_mut::<Interaction>(input).unwrap() = Interaction::None;
+        world.resource_mut::<FocusedEntity>().0 = Some(input);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ControlLeft);
+        world.send_event(KeyboardInput {
+            key_code: KeyCode::KeyC,
+            logical_key: Key::Character("c".into()),
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        schedule.run(&mut world);
+        let copied = world.resource::<Events<TextCopied>>();
+        let copied = copied
+            .get_reader()
+            .read(copied)
+            .map(|event| event.text.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(copied, ["bcd"]);
+        assert_eq!(world.get::<TextInput>(input).unwrap().value, "abcd");
+
+        // other shortcuts don't type their character over the selection
+        for (key_code, character) in [(KeyCode::KeyA, "a"), (KeyCode::KeyV, "v")] {
+            world.send_event(KeyboardInput {
+                key_code,
+                logical_key: Key::Character(character.into()),
+                state: ButtonState::Pressed,
+                window: Entity::PLACEHOLDER,
+            });
+        }
+        schedule.run(&mut world);
+        assert_eq!(world.get::<TextInput>(input).unwrap().value, "abcd");
+        assert_eq!(selection(&world), (4, Some(1..4)));
+        assert!(world.resource::<Events<TextChanged>>().is_empty());
+
+        // typing replaces the selection
+        world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        type_key(&mut world, Key::Character("x".into()));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<TextInput>(input).unwrap().value, "ax");
+        assert_eq!(selection(&world), (2, None));
+    }
+
+    #[test]
+    fn edits_replace_the_selection() {
+        let mut input = TextInput {
+            value: "hello".to_string(),
+            cursor: 4,
+            selection: Some(1..4),
+            ..TextInput::new("")
+        };
+        assert!(input.edit(&Key::Backspace));
+        assert_eq!((input.value.as_str(), input.cursor), ("ho", 1));
+        assert_eq!(input.selection, None);
+
+        input.selection = Some(0..1);
+        assert!(!input.edit(&Key::ArrowRight));
+        assert_eq!(input.selection, None);
+        assert!(!input.edit(&Key::Shift));
+        input.selection = Some(0..2);
+        assert!(!input.edit(&Key::Shift));
+        assert_eq!(input.selection, Some(0..2));
+        assert!(input.edit(&Key::Space));
+        assert_eq!((input.value.as_str(), input.cursor), (" ", 1));
+    }
+}
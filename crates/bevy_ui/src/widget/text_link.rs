@@ -0,0 +1,303 @@
+use crate::{
+    DefaultUiCamera, HoverCursor, Interaction, RelativeCursorPosition, TargetCamera, TargetUiScale,
+    UiSystem,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    event::{Event, EventWriter},
+    prelude::Component,
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::camera::Camera;
+use bevy_text::TextLayoutInfo;
+use bevy_window::CursorIcon;
+
+/// Adds the system driving [`TextLinks`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin) when the `bevy_text` feature is enabled.
+pub struct TextLinkPlugin;
+
+impl Plugin for TextLinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TextLinks>()
+            .register_type::<TextLink>()
+            .register_type::<LinkClicked>()
+            .add_event::<LinkClicked>()
+            .add_systems(PreUpdate, text_link_system.in_set(UiSystem::Widgets));
+    }
+}
+
+/// Makes sections of the [`Text`](bevy_text::Text) of a UI node clickable, like links in a web page.
+///
+/// [`text_link_system`] finds the link under the cursor from the glyphs of the node's
+/// [`TextLayoutInfo`], so only the linked text reacts to the cursor, even when it wraps across lines.
+/// Pressing a link sends a [`LinkClicked`] event, and hovering it shows [`CursorIcon::Pointer`].
+///
+/// The node also needs an [`Interaction`] and a [`RelativeCursorPosition`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_text::TextSection;
+/// # use bevy_ui::{node_bundles::TextBundle, widget::{TextLink, TextLinks}, Interaction, RelativeCursorPosition};
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     TextBundle::from_sections([
+///         TextSection::new("Read the docs ", Default::default()),
+///         TextSection::new("here", Default::default()),
+///     ]),
+///     TextLinks::new([TextLink::new([1], "https://docs.rs/bevy")]),
+///     Interaction::default(),
+///     RelativeCursorPosition::default(),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TextLinks {
+    /// The links of the text
+    pub links: Vec<TextLink>,
+    /// The index of the link under the cursor, if any
+    pub hovered: Option<usize>,
+}
+
+impl TextLinks {
+    /// Creates links in the text of a node, none of them hovered.
+    pub fn new(links: impl IntoIterator<Item = TextLink>) -> Self {
+        Self {
+            links: links.into_iter().collect(),
+            hovered: None,
+        }
+    }
+
+    /// Returns the index of the link at `position`, in the coordinates of the glyphs of
+    /// `text_layout_info`, if any.
+    pub fn link_at(&self, text_layout_info: &TextLayoutInfo, position: Vec2) -> Option<usize> {
+        self.links.iter().position(|link| {
+            link.rects(text_layout_info)
+                .iter()
+                .any(|rect| rect.contains(position))
+        })
+    }
+}
+
+/// A link in the text of a node with [`TextLinks`]
+#[derive(Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct TextLink {
+    /// The indices of the [`TextSection`](bevy_text::TextSection)s of the link
+    pub sections: Vec<usize>,
+    /// The url of the link, sent with the [`LinkClicked`] event
+    pub url: String,
+}
+
+impl TextLink {
+    /// Creates a link with the given sections to `url`.
+    pub fn new(sections: impl IntoIterator<Item = usize>, url: impl Into<String>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+            url: url.into(),
+        }
+    }
+
+    /// Returns the area covered by the link in `text_layout_info`, in the coordinates of its glyphs,
+    /// with a rect for each run of linked glyphs on a line.
+    ///
+    /// The rect of a run spans the gaps between its glyphs, like the spaces between words.
+    pub fn rects(&self, text_layout_info: &TextLayoutInfo) -> Vec<Rect> {
+        let mut rects: Vec<Rect> = Vec::new();
+        let mut previous: Option<Rect> = None;
+        for glyph in &text_layout_info.glyphs {
+            if !self.sections.contains(&glyph.section_index) {
+                previous = None;
+                continue;
+            }
+            let rect = Rect::from_center_size(glyph.position, glyph.size);
+            match (previous, rects.last_mut()) {
+                // glyphs are laid out left to right, so the next line starts left of the previous glyph
+                (Some(previous), Some(run)) if previous.min.x < rect.min.x => {
+                    *run = run.union(rect);
+                }
+                _ => rects.push(rect),
+            }
+            previous = Some(rect);
+        }
+        rects
+    }
+}
+
+/// Sent when a [`TextLink`] is pressed.
+#[derive(Event, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct LinkClicked {
+    /// The node containing the link
+    pub entity: Entity,
+    /// The [`TextLink::url`] of the link
+    pub url: String,
+}
+
+/// The system that updates the hovered link of [`TextLinks`] nodes, and sends a [`LinkClicked`]
+/// event when the node is pressed over a link.
+///
+/// While a link is hovered, the node has a [`HoverCursor`] with [`CursorIcon::Pointer`], which is
+/// removed once no link is hovered.
+pub fn text_link_system(
+    mut commands: Commands,
+    camera_query: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: TargetUiScale,
+    mut text_links_query: Query<(
+        Entity,
+        &mut TextLinks,
+        Ref<Interaction>,
+        &RelativeCursorPosition,
+        &TextLayoutInfo,
+        Option<&TargetCamera>,
+    )>,
+    mut link_clicked_events: EventWriter<LinkClicked>,
+) {
+    for (entity, mut text_links, interaction, relative_cursor_position, text_layout_info, camera) in
+        &mut text_links_query
+    {
+        let hovered = relative_cursor_position
+            .pixels
+            .filter(|_| *interaction != Interaction::None)
+            .and_then(|pixels| {
+                let camera = camera
+                    .map(TargetCamera::entity)
+                    .or(default_ui_camera.get())
+                    .and_then(|camera| camera_query.get(camera).ok());
+                // glyphs are laid out in physical pixels
+                let position = pixels * ui_scale.camera_scale_factor(camera);
+                text_links.link_at(text_layout_info, position)
+            });
+
+        if hovered != text_links.hovered {
+            if hovered.is_some() {
+                commands
+                    .entity(entity)
+                    .insert(HoverCursor(CursorIcon::Pointer));
+            } else if text_links.hovered.is_some() {
+                commands.entity(entity).remove::<HoverCursor>();
+            }
+            text_links.hovered = hovered;
+        }
+
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            if let Some(link) = hovered.and_then(|index| text_links.links.get(index)) {
+                link_clicked_events.send(LinkClicked {
+                    entity,
+                    url: link.url.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_ecs::{event::Events, schedule::Schedule, world::World};
+    use bevy_math::{Rect, Vec2};
+    use bevy_text::{GlyphAtlasInfo, PositionedGlyph, TextLayoutInfo};
+
+    use super::{text_link_system, LinkClicked, TextLink, TextLinks};
+    use crate::{HoverCursor, Interaction, RelativeCursorPosition, UiScale, UiScaleMode};
+
+    /// The layout of "ab cd" above "ef", in glyphs of 10 by 20 pixels, with "b cd e" in section 1
+    fn wrapped_layout() -> TextLayoutInfo {
+        let glyph = |x: f32, y: f32, section_index| PositionedGlyph {
+            position: Vec2::new(x, y),
+            size: Vec2::new(10., 20.),
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Handle::default(),
+                texture: Handle::default(),
+                glyph_index: 0,
+            },
+            section_index,
+            byte_index: 0,
+        };
+        TextLayoutInfo {
+            glyphs: vec![
+                glyph(5., 10., 0),
+                glyph(15., 10., 1),
+                glyph(35., 10., 1),
+                glyph(45., 10., 1),
+                glyph(5., 30., 1),
+                glyph(15., 30., 2),
+            ],
+            logical_size: Vec2::new(50., 40.),
+        }
+    }
+
+    #[test]
+    fn wrapped_links_cover_a_rect_per_line() {
+        let link = TextLink::new([1], "url");
+        assert_eq!(
+            link.rects(&wrapped_layout()),
+            [Rect::new(10., 0., 50., 20.), Rect::new(0., 20., 10., 40.)]
+        );
+    }
+
+    #[test]
+    fn pressing_a_link_sends_its_url() {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        world.init_resource::<Events<LinkClicked>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(text_link_system);
+
+        let cursor_at = |x: f32, y: f32| RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+            normalized: Some(Vec2::new(x / 50., y / 40.)),
+            pixels: Some(Vec2::new(x, y)),
+        };
+        let text = world
+            .spawn((
+                TextLinks::new([TextLink::new([1], "https://bevyengine.org")]),
+                Interaction::Hovered,
+                cursor_at(5., 10.),
+                wrapped_layout(),
+            ))
+            .id();
+        let hovered = |world: &World| world.get::<TextLinks>(text).unwrap().hovered;
+
+        schedule.run(&mut world);
+        assert_eq!(hovered(&world), None);
+        assert!(world.get::<HoverCursor>(text).is_none());
+
+        // the space between words and the next line are part of the link
+        world.entity_mut(text).insert(cursor_at(25., 10.));
+        schedule.run(&mut world);
+        assert_eq!(hovered(&world), Some(0));
+        assert!(world.get::<HoverCursor>(text).is_some());
+
+        world.entity_mut(text).insert(cursor_at(5., 35.));
+        *world.get_mut::<Interaction>(text).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        let clicked = world.resource::<Events<LinkClicked>>();
+        let clicked = clicked
+            .get_reader()
+            .read(clicked)
+            .map(|event| (event.entity, event.url.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(clicked, [(text, "https://bevyengine.org".to_string())]);
+
+        // pressing outside of the link sends nothing
+        *world.get_mut::<Interaction>(text).unwrap() = Interaction::Hovered;
+        world.entity_mut(text).insert(cursor_at(15., 35.));
+        schedule.run(&mut world);
+        assert_eq!(hovered(&world), None);
+        assert!(world.get::<HoverCursor>(text).is_none());
+        *world.get_mut::<Interaction>(text).unwrap() = Interaction::Pressed;
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Events<LinkClicked>>().len(), 1);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{BackgroundColor, Disabled, FocusedEntity, Interaction, Node, Style, UiSystem, Val};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::{Color, Mix};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventWriter},
+    prelude::Component,
+    query::{With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Local, Query, Res},
+    world::Ref,
+};
+use bevy_hierarchy::Children;
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::Time;
+use std::time::Duration;
+
+/// Adds the systems driving [`Toggle`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct TogglePlugin;
+
+impl Plugin for TogglePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Toggle>()
+            .register_type::<ToggleColors>()
+            .register_type::<ToggleKnob>()
+            .register_type::<ToggleChanged>()
+            .add_event::<ToggleChanged>()
+            .add_systems(
+                PreUpdate,
+                (toggle_system, animate_toggle_system)
+                    .chain()
+                    .in_set(UiSystem::Widgets),
+            );
+    }
+}
+
+/// A switch that can be turned on and off, sliding its [`ToggleKnob`] child between the two ends of the node.
+///
+/// The toggle is flipped by [`toggle_system`] when it's pressed, or when Space is pressed while it is
+/// the [`FocusedEntity`].
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Toggle {
+    /// Whether the toggle is on
+    pub on: bool,
+    /// How long the knob takes to slide from one end to the other
+    pub duration: Duration,
+}
+
+impl Toggle {
+    const DEFAULT: Self = Self {
+        on: false,
+        duration: Duration::from_millis(150),
+    };
+}
+
+impl Default for Toggle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The [`BackgroundColor`] of a [`Toggle`] node when it's on and off.
+///
+/// The color is blended between the two while the knob slides.
+#[derive(Component, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ToggleColors {
+    /// The background color when the toggle is on
+    pub on: Color,
+    /// The background color when the toggle is off
+    pub off: Color,
+}
+
+impl ToggleColors {
+    const DEFAULT: Self = Self {
+        on: Color::srgb(0.2, 0.6, 0.3),
+        off: Color::srgb(0.3, 0.3, 0.3),
+    };
+}
+
+impl Default for ToggleColors {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Marks the child of a [`Toggle`] sliding between its left end when off and its right end when on.
+///
+/// The knob is positioned with [`Style::left`], so its [`Style::position_type`] should be
+/// [`PositionType::Absolute`](crate::PositionType::Absolute).
+#[derive(Component, Copy, Clone, Default, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ToggleKnob;
+
+/// Sent by [`toggle_system`] when a [`Toggle`] is flipped.
+#[derive(Event, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct ToggleChanged {
+    /// The toggle that was flipped
+    pub entity: Entity,
+    /// Whether the toggle is now on
+    pub on: bool,
+}
+
+/// The system that flips [`Toggle`] nodes when they are pressed, unless they are [`Disabled`].
+pub fn toggle_system(
+    mut toggle_query: Query<(Entity, &mut Toggle, Ref<Interaction>), Without<Disabled>>,
+    focused_entity: Res<FocusedEntity>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut toggle_changed_events: EventWriter<ToggleChanged>,
+) {
+    for (entity, mut toggle, interaction) in &mut toggle_query {
+        let pressed = interaction.is_changed() && *interaction == Interaction::Pressed;
+        let activated =
+            focused_entity.0 == Some(entity) && keyboard_input.just_pressed(KeyCode::Space);
+        if pressed || activated {
+            toggle.on = !toggle.on;
+            toggle_changed_events.send(ToggleChanged {
+                entity,
+                on: toggle.on,
+            });
+        }
+    }
+}
+
+/// The system that slides the [`ToggleKnob`] of each [`Toggle`] towards the end matching its state,
+/// and blends its [`ToggleColors`].
+///
+/// A toggle that is spawned starts with its knob at the end matching its state.
+pub fn animate_toggle_system(
+    mut progress: Local<EntityHashMap<f32>>,
+    time: Res<Time>,
+    mut toggle_query: Query<(
+        Entity,
+        &Toggle,
+        &Node,
+        Option<&Children>,
+        Option<&ToggleColors>,
+        Option<&mut BackgroundColor>,
+    )>,
+    mut knob_query: Query<(&Node, &mut Style), With<ToggleKnob>>,
+) {
+    for (entity, toggle, node, children, colors, background_color) in &mut toggle_query {
+        let target = if toggle.on { 1. } else { 0. };
+        let progress = progress.entry(entity).or_insert(target);
+        let step = if toggle.duration.is_zero() {
+            1.
+        } else {
+            time.delta_seconds() / toggle.duration.as_secs_f32()
+        };
+        *progress = if *progress < target {
+            (*progress + step).min(target)
+        } else {
+            (*progress - step).max(target)
+        };
+
+        if let (Some(colors), Some(mut background_color)) = (colors, background_color) {
+            let color = BackgroundColor(colors.off.mix(&colors.on, *progress));
+            if *background_color != color {
+                *background_color = color;
+            }
+        }
+
+        let Some(children) = children else {
+            continue;
+        };
+        let mut knobs = knob_query.iter_many_mut(children);
+        while let Some((knob_node, mut style)) = knobs.fetch_next() {
+            let travel = (node.size().x - knob_node.size().x).max(0.);
+            let left = Val::Px(*progress * travel);
+            if style.left != left {
+                style.left = left;
+            }
+        }
+    }
+
+    // forget about despawned toggles
+    progress.retain(|entity, _| toggle_query.contains(*entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+    use bevy_math::Vec2;
+    use bevy_time::Time;
+    use std::time::Duration;
+
+    use super::{animate_toggle_system, toggle_system, Toggle, ToggleChanged, ToggleKnob};
+    use crate::{FocusedEntity, Interaction, Node, Style, Val};
+
+    #[test]
+    fn knob_slides_when_toggled() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FocusedEntity>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<ToggleChanged>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((toggle_system, animate_toggle_system).chain());
+
+        let node = |size: Vec2| Node {
+            calculated_size: size,
+            unrounded_size: size,
+            ..Default::default()
+        };
+        let mut knob = None;
+        let toggle = world
+            .spawn((
+                Toggle {
+                    on: false,
+                    duration: Duration::from_millis(100),
+                },
+                node(Vec2::new(60., 30.)),
+                Interaction::None,
+            ))
+            .with_children(|parent| {
+                knob = Some(
+                    parent
+                        .spawn((node(Vec2::splat(30.)), Style::default(), ToggleKnob))
+                        .id(),
+                );
+            })
+            .id();
+        let knob = knob.unwrap();
+        let mut advance = |world: &mut World, ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(ms));
+            schedule.run(world);
+            world.get::<Style>(knob).unwrap().left
+        };
+
+        assert_eq!(advance(&mut world, 0), Val::Px(0.));
+
+        *world.get_mut::<Interaction>(toggle).unwrap() = Interaction::Pressed;
+        assert_eq!(advance(&mut world, 50), Val::Px(15.));
+        assert!(world.get::<Toggle>(toggle).unwrap().on);
+        assert_eq!(advance(&mut world, 100), Val::Px(30.));
+
+        let events = world.resource::<Events<ToggleChanged>>();
+        assert_eq!(events.len(), 1);
+    }
+}
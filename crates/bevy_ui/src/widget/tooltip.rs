@@ -0,0 +1,338 @@
+use crate::{
+    node_bundles::{NodeBundle, TextBundle},
+    BackgroundColor, Interaction, Node, PositionType, Style, TargetUiScale, UiRect, UiSystem, Val,
+    ZIndex,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    prelude::Component,
+    query::With,
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Local, Query, Res},
+};
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_text::TextStyle;
+use bevy_time::Time;
+use bevy_window::{PrimaryWindow, Window};
+use std::time::Duration;
+
+/// Adds the systems driving [`Tooltip`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin) when the `bevy_text` feature is enabled.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Tooltip>()
+            .register_type::<TooltipStyle>()
+            .register_type::<TooltipPopup>()
+            .add_systems(PreUpdate, tooltip_system.in_set(UiSystem::Widgets));
+    }
+}
+
+/// Shows a text next to the cursor once a node with an [`Interaction`] has been hovered for a while.
+///
+/// The popup is spawned by [`tooltip_system`] as a root node drawn over the rest of the UI, laid out
+/// according to the node's [`TooltipStyle`]. It follows the cursor of the primary window until the
+/// node isn't [`Interaction::Hovered`] anymore.
+#[derive(Component, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Tooltip {
+    /// The text shown in the popup
+    pub text: String,
+    /// How long the node must be hovered before the popup is shown
+    pub delay: Duration,
+}
+
+impl Tooltip {
+    const DEFAULT: Self = Self {
+        text: String::new(),
+        delay: Duration::from_millis(500),
+    };
+
+    /// The distance between the cursor and the top left corner of the popup, in logical pixels
+    pub const CURSOR_OFFSET: Vec2 = Vec2::new(12., 16.);
+
+    /// Creates a tooltip showing `text` after the default delay.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How the popup of a [`Tooltip`] is displayed.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct TooltipStyle {
+    /// The space between the border of the popup and its text
+    pub padding: UiRect,
+    /// The background color of the popup
+    pub background: Color,
+    /// The style of the text of the popup
+    pub text: TextStyle,
+}
+
+impl Default for TooltipStyle {
+    fn default() -> Self {
+        Self {
+            padding: UiRect::axes(Val::Px(6.), Val::Px(3.)),
+            background: Color::srgba(0.1, 0.1, 0.1, 0.9),
+            text: TextStyle {
+                font_size: 16.,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// The popup of a [`Tooltip`], spawned by [`tooltip_system`].
+///
+/// The popup doesn't have an [`Interaction`] and its [`FocusPolicy`](crate::FocusPolicy) is
+/// [`Pass`](crate::FocusPolicy::Pass), so it never takes the hover away from its node.
+#[derive(Component, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct TooltipPopup {
+    /// The node showing the tooltip
+    pub owner: Entity,
+}
+
+/// An ongoing hover of a [`Tooltip`] node
+pub struct TooltipHover {
+    /// When the node started being hovered
+    start: Duration,
+    /// The popup shown once the node has been hovered for the delay of the tooltip
+    popup: Option<Entity>,
+}
+
+/// Returns the position of the top left corner of a popup of the given size next to the cursor,
+/// keeping it inside the window.
+fn popup_position(cursor_position: Vec2, popup_size: Vec2, window_size: Vec2) -> Vec2 {
+    (cursor_position + Tooltip::CURSOR_OFFSET)
+        .min(window_size - popup_size)
+        .max(Vec2::ZERO)
+}
+
+/// The system that shows the popup of each [`Tooltip`] once its node has been hovered for its delay,
+/// and despawns it when the hover ends.
+#[allow(clippy::too_many_arguments)]
+pub fn tooltip_system(
+    mut commands: Commands,
+    mut hovers: Local<EntityHashMap<TooltipHover>>,
+    time: Res<Time>,
+    ui_scale: TargetUiScale,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    tooltip_query: Query<(Entity, &Tooltip, &Interaction, Option<&TooltipStyle>)>,
+    mut popup_query: Query<(&Node, &mut Style), With<TooltipPopup>>,
+) {
+    let window = windows.get_single().ok();
+    let ui_scale = window.map_or(ui_scale.camera(None), |(entity, window)| {
+        ui_scale.window(entity, window.scale_factor())
+    });
+    let window = window.map(|(_, window)| window);
+    let cursor_position = window
+        .and_then(Window::cursor_position)
+        .map(|position| position / ui_scale);
+    let window_size = window.map_or(Vec2::ZERO, |window| {
+        Vec2::new(window.width(), window.height()) / ui_scale
+    });
+
+    for (entity, tooltip, interaction, tooltip_style) in &tooltip_query {
+        if *interaction != Interaction::Hovered {
+            if let Some(popup) = hovers.remove(&entity).and_then(|hover| hover.popup) {
+                // the popup may have been despawned along with the rest of the UI
+                if let Some(popup) = commands.get_entity(popup) {
+                    popup.despawn_recursive();
+                }
+            }
+            continue;
+        }
+
+        let hover = hovers.entry(entity).or_insert_with(|| TooltipHover {
+            start: time.elapsed(),
+            popup: None,
+        });
+        let Some(cursor_position) = cursor_position else {
+            continue;
+        };
+
+        match hover.popup {
+            Some(popup) => {
+                if let Ok((node, mut style)) = popup_query.get_mut(popup) {
+                    let position = popup_position(cursor_position, node.size(), window_size);
+                    let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+                    if style.left != left || style.top != top {
+                        style.left = left;
+                        style.top = top;
+                    }
+                }
+            }
+            None if time.elapsed() - hover.start >= tooltip.delay => {
+                let tooltip_style = tooltip_style.cloned().unwrap_or_default();
+                // the size of the popup is only known once it has been laid out
+                let position = popup_position(cursor_position, Vec2::ZERO, window_size);
+                let popup = commands
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px(position.x),
+                                top: Val::Px(position.y),
+                                padding: tooltip_style.padding,
+                                ..Default::default()
+                            },
+                            background_color: BackgroundColor(tooltip_style.background),
+                            z_index: ZIndex::Global(i32::MAX),
+                            ..Default::default()
+                        },
+                        TooltipPopup { owner: entity },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            tooltip.text.clone(),
+                            tooltip_style.text,
+                        ));
+                    })
+                    .id();
+                hover.popup = Some(popup);
+            }
+            None => {}
+        }
+    }
+
+    // forget about despawned tooltips, along with their popups
+    hovers.retain(|entity, hover| {
+        let retain = tooltip_query.contains(*entity);
+        if let (false, Some(popup)) = (retain, hover.popup) {
+            if let Some(popup) = commands.get_entity(popup) {
+                popup.despawn_recursive();
+            }
+        }
+        retain
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{entity::Entity, schedule::Schedule, world::World};
+    use bevy_math::Vec2;
+    use bevy_time::Time;
+    use bevy_window::{PrimaryWindow, Window};
+    use std::time::Duration;
+
+    use super::{popup_position, tooltip_system, Tooltip, TooltipPopup};
+    use crate::{Interaction, UiScale, UiScaleMode};
+
+    #[test]
+    fn popup_stays_inside_window() {
+        let window_size = Vec2::new(800., 600.);
+        let popup_size = Vec2::new(100., 30.);
+        assert_eq!(
+            popup_position(Vec2::new(10., 10.), popup_size, window_size),
+            Vec2::new(10., 10.) + Tooltip::CURSOR_OFFSET
+        );
+        assert_eq!(
+            popup_position(Vec2::new(790., 590.), popup_size, window_size),
+            Vec2::new(700., 570.)
+        );
+    }
+
+    #[test]
+    fn popup_is_shown_after_delay_until_hover_ends() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::new(50., 50.)));
+        world.spawn((window, PrimaryWindow));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(tooltip_system);
+
+        let node = world
+            .spawn((
+                Tooltip {
+                    text: "Tip".to_string(),
+                    delay: Duration::from_millis(100),
+                },
+                Interaction::Hovered,
+            ))
+            .id();
+        let mut advance = |world: &mut World, ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(ms));
+            schedule.run(world);
+            world
+                .query::<&TooltipPopup>()
+                .iter(world)
+                .map(|popup| popup.owner)
+                .collect::<Vec<Entity>>()
+        };
+
+        assert!(advance(&mut world, 0).is_empty());
+        assert!(advance(&mut world, 60).is_empty());
+        assert_eq!(advance(&mut world, 60), [node]);
+        // the popup isn't spawned again while the hover goes on
+        assert_eq!(advance(&mut world, 60), [node]);
+
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+        assert!(advance(&mut world, 0).is_empty());
+    }
+
+    #[test]
+    fn despawning_the_node_and_its_popup_together() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<UiScale>();
+        world.init_resource::<UiScaleMode>();
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::new(50., 50.)));
+        world.spawn((window, PrimaryWindow));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(tooltip_system);
+
+        let spawn_shown_tooltip = |world: &mut World, schedule: &mut Schedule| {
+            let node = world
+                .spawn((Tooltip::new("Tip"), Interaction::Hovered))
+                .id();
+            schedule.run(world);
+            world
+                .resource_mut::<Time>()
+                .advance_by(Tooltip::DEFAULT.delay);
+            schedule.run(world);
+            let popup = world
+                .query::<(Entity, &TooltipPopup)>()
+                .iter(world)
+                .find(|(_, popup)| popup.owner == node)
+                .map(|(popup, _)| popup)
+                .unwrap();
+            (node, popup)
+        };
+
+        // like a cleanup despawning every UI root
+        let (node, popup) = spawn_shown_tooltip(&mut world, &mut schedule);
+        world.despawn(node);
+        world.despawn(popup);
+        schedule.run(&mut world);
+
+        // the hover ends once the popup is already gone
+        let (node, popup) = spawn_shown_tooltip(&mut world, &mut schedule);
+        world.despawn(popup);
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+        schedule.run(&mut world);
+        assert_eq!(world.query::<&TooltipPopup>().iter(&world).count(), 0);
+    }
+}
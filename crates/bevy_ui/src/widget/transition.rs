@@ -0,0 +1,391 @@
+use crate::{BackgroundColor, Disabled, Interaction, Style, UiEasing, UiSystem, Val};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_color::{Color, Mix};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    prelude::Component,
+    query::Has,
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfigs,
+    system::{Local, Query, Res},
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::Time;
+use std::time::Duration;
+
+/// Adds the system driving [`UiTransition`] nodes.
+///
+/// Added by [`UiPlugin`](crate::UiPlugin).
+pub struct UiTransitionPlugin;
+
+impl Plugin for UiTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<UiTransition>()
+            .register_type::<UiTransitionTarget>()
+            .add_systems(PreUpdate, ui_transition_system.in_set(UiSystem::Widgets));
+    }
+}
+
+/// Animates the [`BackgroundColor`], size and position of a node towards the values of its
+/// current [`Interaction`] state, and while it is [`Disabled`].
+///
+/// Each time the target of the node changes, [`ui_transition_system`] moves the properties from
+/// their current values to the new target over `duration`, along the `easing` curve. A transition
+/// interrupted halfway starts the next one from where it stopped. Fades can be made with the alpha
+/// of the background color.
+///
+/// The properties a state doesn't set use the value of `normal`, and the properties that `normal`
+/// doesn't set either are left alone.
+///
+/// ```
+/// # use bevy_color::Color;
+/// # use bevy_ui::{widget::{UiTransition, UiTransitionTarget}, Val};
+/// # use std::time::Duration;
+/// let transition = UiTransition::new(Duration::from_millis(200))
+///     .with_normal(UiTransitionTarget::default().with_background_color(Color::BLACK))
+///     .with_hovered(
+///         UiTransitionTarget::default()
+///             .with_background_color(Color::WHITE)
+///             .with_size(Val::Px(120.), Val::Px(40.)),
+///     );
+/// ```
+#[derive(Component, Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct UiTransition {
+    /// The target when the node isn't hovered or pressed
+    pub normal: UiTransitionTarget,
+    /// The target while the cursor is over the node
+    pub hovered: UiTransitionTarget,
+    /// The target while the node is pressed
+    pub pressed: UiTransitionTarget,
+    /// The target while the node is [`Disabled`], whatever its interaction
+    pub disabled: UiTransitionTarget,
+    /// How long a transition from one target to another takes
+    pub duration: Duration,
+    /// How the properties move towards their target over time
+    pub easing: UiEasing,
+}
+
+impl UiTransition {
+    const DEFAULT: Self = Self {
+        normal: UiTransitionTarget::DEFAULT,
+        hovered: UiTransitionTarget::DEFAULT,
+        pressed: UiTransitionTarget::DEFAULT,
+        disabled: UiTransitionTarget::DEFAULT,
+        duration: Duration::from_millis(150),
+        easing: UiEasing::EaseInOut,
+    };
+
+    /// Creates transitions taking `duration`, with the default [`UiEasing`] and no targets.
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Returns these transitions with a new [`UiEasing`].
+    pub const fn with_easing(mut self, easing: UiEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns these transitions with a new target when the node isn't hovered or pressed.
+    pub const fn with_normal(mut self, normal: UiTransitionTarget) -> Self {
+        self.normal = normal;
+        self
+    }
+
+    /// Returns these transitions with a new target while the cursor is over the node.
+    pub const fn with_hovered(mut self, hovered: UiTransitionTarget) -> Self {
+        self.hovered = hovered;
+        self
+    }
+
+    /// Returns these transitions with a new target while the node is pressed.
+    pub const fn with_pressed(mut self, pressed: UiTransitionTarget) -> Self {
+        self.pressed = pressed;
+        self
+    }
+
+    /// Returns these transitions with a new target while the node is [`Disabled`].
+    pub const fn with_disabled(mut self, disabled: UiTransitionTarget) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the target for the given state, with the properties it doesn't set taken from `normal`.
+    pub fn target(&self, interaction: Interaction, disabled: bool) -> UiTransitionTarget {
+        let target = if disabled {
+            self.disabled
+        } else {
+            match interaction {
+                Interaction::Pressed => self.pressed,
+                Interaction::Hovered => self.hovered,
+                Interaction::None => self.normal,
+            }
+        };
+        target.or(self.normal)
+    }
+}
+
+impl Default for UiTransition {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The values a [`UiTransition`] moves the properties of a node to, where `None` leaves a property
+/// alone.
+///
+/// Sizes and positions only move smoothly between values in the same unit. A [`Val`] in another
+/// unit than the current one, or [`Val::Auto`], is set at the start of the transition.
+#[derive(Copy, Clone, Default, PartialEq, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct UiTransitionTarget {
+    /// The [`BackgroundColor`] of the node
+    pub background_color: Option<Color>,
+    /// The [`Style::width`] of the node
+    pub width: Option<Val>,
+    /// The [`Style::height`] of the node
+    pub height: Option<Val>,
+    /// The [`Style::left`] of the node
+    pub left: Option<Val>,
+    /// The [`Style::top`] of the node
+    pub top: Option<Val>,
+}
+
+impl UiTransitionTarget {
+    const DEFAULT: Self = Self {
+        background_color: None,
+        width: None,
+        height: None,
+        left: None,
+        top: None,
+    };
+
+    /// Returns this target with a new [`BackgroundColor`].
+    pub const fn with_background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Returns this target with a new [`Style::width`] and [`Style::height`].
+    pub const fn with_size(mut self, width: Val, height: Val) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Returns this target with a new [`Style::left`] and [`Style::top`].
+    pub const fn with_position(mut self, left: Val, top: Val) -> Self {
+        self.left = Some(left);
+        self.top = Some(top);
+        self
+    }
+
+    /// Returns this target with the properties it doesn't set taken from `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            background_color: self.background_color.or(other.background_color),
+            width: self.width.or(other.width),
+            height: self.height.or(other.height),
+            left: self.left.or(other.left),
+            top: self.top.or(other.top),
+        }
+    }
+}
+
+/// Interpolates between two [`Val`]s in the same unit with [`Val::lerp`], or returns `to`.
+fn mix_val(from: Val, to: Val, t: f32) -> Val {
+    if std::mem::discriminant(&from) == std::mem::discriminant(&to) {
+        from.lerp(to, t)
+    } else {
+        to
+    }
+}
+
+/// Contains the transition of each [`UiTransition`] node
+#[derive(Default)]
+pub struct UiTransitionState {
+    transitions: EntityHashMap<TransitionProgress>,
+}
+
+/// A transition of a node towards a target
+struct TransitionProgress {
+    target: UiTransitionTarget,
+    /// The values of the properties when the transition started
+    from: UiTransitionTarget,
+    elapsed: Duration,
+}
+
+/// The system that moves the properties of [`UiTransition`] nodes towards the target of their
+/// [`Interaction`] state.
+///
+/// A node that is spawned starts with the values of its target.
+pub fn ui_transition_system(
+    mut state: Local<UiTransitionState>,
+    time: Res<Time>,
+    mut transition_query: Query<(
+        Entity,
+        &UiTransition,
+        &Interaction,
+        Has<Disabled>,
+        Option<&mut BackgroundColor>,
+        Option<&mut Style>,
+    )>,
+) {
+    for (entity, transition, interaction, disabled, background_color, style) in
+        &mut transition_query
+    {
+        let target = transition.target(*interaction, disabled);
+        let progress = state
+            .transitions
+            .entry(entity)
+            .or_insert(TransitionProgress {
+                target,
+                from: target,
+                elapsed: transition.duration,
+            });
+        if progress.target != target {
+            progress.target = target;
+            progress.from = UiTransitionTarget {
+                background_color: background_color.as_deref().map(|color| color.0),
+                width: style.as_deref().map(|style| style.width),
+                height: style.as_deref().map(|style| style.height),
+                left: style.as_deref().map(|style| style.left),
+                top: style.as_deref().map(|style| style.top),
+            };
+            progress.elapsed = Duration::ZERO;
+        }
+        progress.elapsed = (progress.elapsed + time.delta()).min(transition.duration);
+        let t = if transition.duration.is_zero() {
+            1.
+        } else {
+            progress.elapsed.as_secs_f32() / transition.duration.as_secs_f32()
+        };
+        let t = transition.easing.ease(t);
+        let from = progress.from;
+
+        if let (Some(mut background_color), Some(to)) = (background_color, target.background_color)
+        {
+            let color = from.background_color.map_or(to, |from| from.mix(&to, t));
+            if background_color.0 != color {
+                background_color.0 = color;
+            }
+        }
+        if let Some(mut style) = style {
+            let mix = |from: Option<Val>, to: Option<Val>| {
+                to.map(|to| from.map_or(to, |from| mix_val(from, to, t)))
+            };
+            if let Some(width) = mix(from.width, target.width) {
+                if style.width != width {
+                    style.width = width;
+                }
+            }
+            if let Some(height) = mix(from.height, target.height) {
+                if style.height != height {
+                    style.height = height;
+                }
+            }
+            if let Some(left) = mix(from.left, target.left) {
+                if style.left != left {
+                    style.left = left;
+                }
+            }
+            if let Some(top) = mix(from.top, target.top) {
+                if style.top != top {
+                    style.top = top;
+                }
+            }
+        }
+    }
+
+    // forget about despawned nodes
+    state
+        .transitions
+        .retain(|entity, _| transition_query.contains(*entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::{Color, Mix};
+    use bevy_ecs::{schedule::Schedule, world::World};
+    use bevy_time::Time;
+    use std::time::Duration;
+
+    use super::{ui_transition_system, UiTransition, UiTransitionTarget};
+    use crate::{BackgroundColor, Disabled, Interaction, Style, UiEasing, Val};
+
+    #[test]
+    fn properties_move_towards_the_target_of_the_interaction() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_transition_system);
+
+        let transition = UiTransition::new(Duration::from_millis(100))
+            .with_easing(UiEasing::Linear)
+            .with_normal(
+                UiTransitionTarget::default()
+                    .with_background_color(Color::BLACK)
+                    .with_size(Val::Px(100.), Val::Px(20.)),
+            )
+            .with_hovered(UiTransitionTarget::default().with_size(Val::Px(200.), Val::Px(40.)))
+            .with_pressed(UiTransitionTarget::default().with_background_color(Color::WHITE))
+            .with_disabled(UiTransitionTarget::default().with_size(Val::Percent(50.), Val::Auto));
+        let node = world
+            .spawn((
+                transition,
+                Interaction::None,
+                BackgroundColor(Color::NONE),
+                Style::default(),
+            ))
+            .id();
+        let mut advance = |world: &mut World, ms: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(ms));
+            schedule.run(world);
+            let style = world.get::<Style>(node).unwrap();
+            (
+                world.get::<BackgroundColor>(node).unwrap().0,
+                style.width,
+                style.height,
+            )
+        };
+
+        // spawned nodes start at their target
+        assert_eq!(
+            advance(&mut world, 0),
+            (Color::BLACK, Val::Px(100.), Val::Px(20.))
+        );
+
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::Hovered;
+        assert_eq!(
+            advance(&mut world, 50),
+            (Color::BLACK, Val::Px(150.), Val::Px(30.))
+        );
+
+        // interrupted halfway, the next transition starts from there
+        *world.get_mut::<Interaction>(node).unwrap() = Interaction::Pressed;
+        let (color, width, _) = advance(&mut world, 50);
+        assert_eq!(color, Color::BLACK.mix(&Color::WHITE, 0.5));
+        assert_eq!(width, Val::Px(125.));
+        assert_eq!(
+            advance(&mut world, 100),
+            (Color::WHITE, Val::Px(100.), Val::Px(20.))
+        );
+
+        // values in other units are set right away
+        world.entity_mut(node).insert(Disabled);
+        assert_eq!(
+            advance(&mut world, 10),
+            (
+                Color::WHITE.mix(&Color::BLACK, 0.1),
+                Val::Percent(50.),
+                Val::Auto
+            )
+        );
+    }
+}
@@ -96,6 +96,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     )],
                     justify: JustifyText::Left,
                     linebreak_behavior: BreakLineOn::WordBoundary,
+                    ..default()
                 },
                 text_2d_bounds: Text2dBounds {
                     // Wrap text in the rectangle
@@ -128,6 +129,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     )],
                     justify: JustifyText::Left,
                     linebreak_behavior: BreakLineOn::AnyCharacter,
+                    ..default()
                 },
                 text_2d_bounds: Text2dBounds {
                     // Wrap text in the rectangle
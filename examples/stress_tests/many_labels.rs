@@ -0,0 +1,111 @@
+//! UI text benchmark laying out many labels whose text is set every frame without changing,
+//! like a HUD that rewrites its labels each frame.
+//!
+//! Their layouts are reused from the `TextLayoutCache`. To lay them out again each frame instead, run
+//! `cargo run --example many_labels --release -- --no-cache`
+
+use argh::FromArgs;
+use bevy::{
+    diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
+    prelude::*,
+    ui::widget::TextLayoutCache,
+    window::{PresentMode, WindowResolution},
+    winit::{UpdateMode, WinitSettings},
+};
+
+#[derive(FromArgs, Resource)]
+/// `many_labels` UI text benchmark laying out many labels whose text is set every frame
+struct Args {
+    /// how many labels per row and column of the grid
+    #[argh(option, default = "60")]
+    labels: usize,
+
+    /// whether to disable the text layout cache
+    #[argh(switch)]
+    no_cache: bool,
+}
+
+fn main() {
+    // `from_env` panics on the web
+    #[cfg(not(target_arch = "wasm32"))]
+    let args: Args = argh::from_env();
+    #[cfg(target_arch = "wasm32")]
+    let args = Args::from_args(&[], &[]).unwrap();
+
+    // The cache has to hold every label to reuse their layouts each frame
+    let capacity = if args.no_cache {
+        0
+    } else {
+        args.labels * args.labels
+    };
+
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    present_mode: PresentMode::AutoNoVsync,
+                    resolution: WindowResolution::new(1920.0, 1080.0)
+                        .with_scale_factor_override(1.0),
+                    ..default()
+                }),
+                ..default()
+            }),
+            FrameTimeDiagnosticsPlugin,
+            LogDiagnosticsPlugin::default(),
+        ))
+        .insert_resource(WinitSettings {
+            focused_mode: UpdateMode::Continuous,
+            unfocused_mode: UpdateMode::Continuous,
+        })
+        .insert_resource(TextLayoutCache::new(capacity))
+        .insert_resource(args)
+        .add_systems(Startup, setup)
+        .add_systems(Update, set_labels)
+        .run();
+}
+
+#[derive(Component)]
+struct Label(usize);
+
+fn setup(mut commands: Commands, args: Res<Args>) {
+    warn!(include_str!("warning_string.txt"));
+
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                display: Display::Grid,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                grid_template_columns: RepeatedGridTrack::flex(args.labels as u16, 1.),
+                grid_template_rows: RepeatedGridTrack::flex(args.labels as u16, 1.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for i in 0..args.labels * args.labels {
+                parent.spawn((
+                    TextBundle::from_section(
+                        label_text(i),
+                        TextStyle {
+                            font_size: 7.0,
+                            ..default()
+                        },
+                    ),
+                    Label(i),
+                ));
+            }
+        });
+}
+
+fn label_text(i: usize) -> String {
+    format!("Label {i}")
+}
+
+// Sets each label to the text it already has
+fn set_labels(mut labels: Query<(&mut Text, &Label)>) {
+    for (mut text, label) in &mut labels {
+        text.sections[0].value = label_text(label.0);
+    }
+}
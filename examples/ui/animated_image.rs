@@ -0,0 +1,100 @@
+//! This example illustrates how to play an animation from a sprite sheet in a UI node
+//! using the [`AnimatedImage`] component.
+
+use bevy::{
+    prelude::*,
+    ui::widget::{AnimatedImage, AnimationFinished},
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest())) // prevents blurry sprites
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_pause, log_finished))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let texture = asset_server.load("textures/rpg/chars/gabe/gabe-idle-run.png");
+    let layout = TextureAtlasLayout::from_grid(UVec2::splat(24), 7, 1, None, None);
+    let texture_atlas_layout = texture_atlas_layouts.add(layout);
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        ..default()
+    };
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        column_gap: Val::Px(40.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // Use only the subset of sprites in the sheet that make up the run animation
+                    let animations = [
+                        AnimatedImage::new(1..=6, 10.0),
+                        AnimatedImage {
+                            looping: false,
+                            ..AnimatedImage::new(1..=6, 5.0)
+                        },
+                    ];
+                    for animation in animations {
+                        parent.spawn((
+                            ImageBundle {
+                                style: Style {
+                                    width: Val::Px(150.0),
+                                    height: Val::Px(150.0),
+                                    ..default()
+                                },
+                                image: UiImage::new(texture.clone()),
+                                ..default()
+                            },
+                            TextureAtlas::from(texture_atlas_layout.clone()),
+                            animation,
+                        ));
+                    }
+                });
+
+            parent.spawn(TextBundle::from_section(
+                "Press Space to pause the animations. The right one only plays once.",
+                text_style,
+            ));
+        });
+}
+
+fn toggle_pause(keyboard: Res<ButtonInput<KeyCode>>, mut query: Query<&mut AnimatedImage>) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        for mut animation in &mut query {
+            animation.paused = !animation.paused;
+        }
+    }
+}
+
+fn log_finished(mut animation_finished_events: EventReader<AnimationFinished>) {
+    for event in animation_finished_events.read() {
+        info!("The animation of {:?} is over", event.entity);
+    }
+}
@@ -1,5 +1,5 @@
 //! This example illustrates how to create a button that changes color and text based on its
-//! interaction state.
+//! interaction state, and how [`ButtonColors`] picks the color of each state.
 
 use bevy::{color::palettes::basic::*, prelude::*, winit::WinitSettings};
 
@@ -19,27 +19,24 @@ const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
 
 fn button_system(
     mut interaction_query: Query<
-        (&Interaction, &mut UiImage, &mut BorderColor, &Children),
+        (&Interaction, &mut BorderColor, &Children),
         (Changed<Interaction>, With<Button>),
     >,
     mut text_query: Query<&mut Text>,
 ) {
-    for (interaction, mut image, mut border_color, children) in &mut interaction_query {
+    for (interaction, mut border_color, children) in &mut interaction_query {
         let mut text = text_query.get_mut(children[0]).unwrap();
         match *interaction {
             Interaction::Pressed => {
                 text.sections[0].value = "Press".to_string();
-                image.color = PRESSED_BUTTON;
                 border_color.0 = RED.into();
             }
             Interaction::Hovered => {
                 text.sections[0].value = "Hover".to_string();
-                image.color = HOVERED_BUTTON;
                 border_color.0 = Color::WHITE;
             }
             Interaction::None => {
                 text.sections[0].value = "Button".to_string();
-                image.color = NORMAL_BUTTON;
                 border_color.0 = Color::BLACK;
             }
         }
@@ -62,22 +59,31 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         })
         .with_children(|parent| {
             parent
-                .spawn(ButtonBundle {
-                    style: Style {
-                        width: Val::Px(150.0),
-                        height: Val::Px(65.0),
-                        border: UiRect::all(Val::Px(5.0)),
-                        // horizontally center child text
-                        justify_content: JustifyContent::Center,
-                        // vertically center child text
-                        align_items: AlignItems::Center,
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(150.0),
+                            height: Val::Px(65.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            // horizontally center child text
+                            justify_content: JustifyContent::Center,
+                            // vertically center child text
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        border_color: BorderColor(Color::BLACK),
+                        border_radius: BorderRadius::MAX,
+                        image: UiImage::default().with_color(NORMAL_BUTTON),
+                        ..default()
+                    },
+                    // The button is tinted with the color of its interaction state, without a system of our own.
+                    ButtonColors {
+                        normal: NORMAL_BUTTON,
+                        hovered: HOVERED_BUTTON,
+                        pressed: PRESSED_BUTTON,
                         ..default()
                     },
-                    border_color: BorderColor(Color::BLACK),
-                    border_radius: BorderRadius::MAX,
-                    image: UiImage::default().with_color(NORMAL_BUTTON),
-                    ..default()
-                })
+                ))
                 .with_children(|parent| {
                     parent.spawn(TextBundle::from_section(
                         "Button",
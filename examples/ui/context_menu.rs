@@ -0,0 +1,107 @@
+//! This example illustrates how to open a menu at the cursor by right-clicking UI nodes
+//! using the [`ContextMenu`] component.
+
+use bevy::{
+    prelude::*,
+    ui::widget::{ContextMenu, ContextMenuItem, ContextMenuSelected},
+    winit::WinitSettings,
+};
+
+const COPY: u32 = 0;
+const PASTE: u32 = 1;
+const RED: u32 = 2;
+const GREEN: u32 = 3;
+const BLUE: u32 = 4;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .add_systems(Update, apply_selected_items)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        color: Color::srgb(0.9, 0.9, 0.9),
+    };
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_wrap: FlexWrap::Wrap,
+                align_content: AlignContent::SpaceEvenly,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for i in 0..4 {
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(200.0),
+                                height: Val::Px(65.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+                            ..default()
+                        },
+                        // The menu only opens on nodes with an `Interaction`
+                        Interaction::default(),
+                        ContextMenu::new([
+                            ContextMenuItem::new(COPY, "Copy color"),
+                            ContextMenuItem::new(PASTE, "Paste color"),
+                            // Items opening a submenu are never picked, so their id is unused
+                            ContextMenuItem::new(0, "Set color").with_submenu([
+                                ContextMenuItem::new(RED, "Red"),
+                                ContextMenuItem::new(GREEN, "Green"),
+                                ContextMenuItem::new(BLUE, "Blue"),
+                            ]),
+                        ]),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            format!("Right-click me #{}", i + 1),
+                            text_style.clone(),
+                        ));
+                    });
+            }
+        });
+}
+
+fn apply_selected_items(
+    mut events: EventReader<ContextMenuSelected>,
+    mut colors: Query<&mut BackgroundColor>,
+    mut copied: Local<Option<Color>>,
+) {
+    for event in events.read() {
+        let Ok(mut color) = colors.get_mut(event.entity) else {
+            continue;
+        };
+        match event.id {
+            COPY => *copied = Some(color.0),
+            PASTE => {
+                if let Some(copied) = *copied {
+                    color.0 = copied;
+                }
+            }
+            RED => color.0 = Color::srgb(0.6, 0.15, 0.15),
+            GREEN => color.0 = Color::srgb(0.15, 0.5, 0.15),
+            BLUE => color.0 = Color::srgb(0.15, 0.25, 0.6),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! This example illustrates sizing UI nodes from custom logic with a [`MeasureFunc`](bevy::ui::MeasureFunc),
+//! here to keep square minimaps as wide as their panels.
+
+use bevy::{prelude::*, ui::ContentSize, winit::WinitSettings};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// Measures a minimap as a square fitting the known width or height of its node.
+fn square_minimap(width: Option<f32>, height: Option<f32>) -> Vec2 {
+    // without constraints, the minimap is drawn at its default size
+    Vec2::splat(width.or(height).unwrap_or(100.0))
+}
+
+fn setup(mut commands: Commands) {
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Start,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            // panels of different widths, each showing a minimap as wide as the panel
+            for panel_width in [15.0, 25.0, 35.0] {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            width: Val::Percent(panel_width),
+                            padding: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        },
+                        background_color: Color::srgb(0.3, 0.3, 0.3).into(),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                background_color: Color::srgb(0.2, 0.5, 0.3).into(),
+                                ..default()
+                            },
+                            ContentSize::from_measure_func(square_minimap),
+                        ));
+                    });
+            }
+
+            // a minimap without a known width keeps the size it measures without constraints
+            parent.spawn((
+                NodeBundle {
+                    background_color: Color::srgb(0.2, 0.3, 0.5).into(),
+                    ..default()
+                },
+                ContentSize::from_measure_func(square_minimap),
+            ));
+        });
+}
@@ -0,0 +1,126 @@
+//! This example illustrates how to fill the background of UI nodes with a [`BackgroundGradient`].
+
+use bevy::{prelude::*, winit::WinitSettings};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            // header bar with a vertical gradient, drawn instead of its background color
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(80.0),
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(Val::Px(20.0)),
+                            ..default()
+                        },
+                        background_color: Color::BLACK.into(),
+                        ..default()
+                    },
+                    BackgroundGradient::linear(
+                        PI,
+                        [
+                            ColorStop::new(Color::srgb(0.35, 0.45, 0.85), 0.0),
+                            ColorStop::new(Color::srgb(0.2, 0.25, 0.6), 0.6),
+                            ColorStop::new(Color::srgb(0.1, 0.1, 0.3), 1.0),
+                        ],
+                    ),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Gradients",
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: 40.0,
+                            color: Color::WHITE,
+                        },
+                    ));
+                });
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        flex_wrap: FlexWrap::Wrap,
+                        align_content: AlignContent::SpaceEvenly,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    let rainbow = [
+                        ColorStop::new(Color::srgb(1.0, 0.0, 0.0), 0.0),
+                        ColorStop::new(Color::srgb(1.0, 1.0, 0.0), 0.25),
+                        ColorStop::new(Color::srgb(0.0, 1.0, 0.0), 0.5),
+                        ColorStop::new(Color::srgb(0.0, 0.0, 1.0), 0.75),
+                        ColorStop::new(Color::srgb(1.0, 0.0, 1.0), 1.0),
+                    ];
+                    let gradients = [
+                        // left to right
+                        BackgroundGradient::linear(FRAC_PI_2, rainbow),
+                        // from the bottom left corner to the top right one
+                        BackgroundGradient::linear(FRAC_PI_4, rainbow),
+                        // hard edges between stops sharing a position
+                        BackgroundGradient::linear(
+                            PI,
+                            [
+                                ColorStop::new(Color::WHITE, 0.0),
+                                ColorStop::new(Color::WHITE, 0.5),
+                                ColorStop::new(Color::BLACK, 0.5),
+                                ColorStop::new(Color::BLACK, 1.0),
+                            ],
+                        ),
+                        // fading out to transparent
+                        BackgroundGradient::radial(
+                            Vec2::splat(0.5),
+                            [
+                                ColorStop::new(Color::srgb(1.0, 0.8, 0.3), 0.0),
+                                ColorStop::new(Color::srgba(1.0, 0.4, 0.1, 0.0), 0.7),
+                            ],
+                        ),
+                        BackgroundGradient::radial(Vec2::new(0.25, 0.25), rainbow),
+                    ];
+                    for gradient in gradients {
+                        parent.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Px(200.0),
+                                    height: Val::Px(150.0),
+                                    border: UiRect::all(Val::Px(4.0)),
+                                    ..default()
+                                },
+                                border_color: Color::WHITE.into(),
+                                border_radius: BorderRadius::all(Val::Px(20.0)),
+                                ..default()
+                            },
+                            gradient,
+                        ));
+                    }
+                });
+        });
+}
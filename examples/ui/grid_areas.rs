@@ -0,0 +1,88 @@
+//! Demonstrates how to lay out a dashboard with named CSS Grid areas, using [`GridTemplateAreas`] and [`GridArea`].
+use bevy::{color::palettes::css::*, prelude::*};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                resolution: [800., 600.].into(),
+                title: "Bevy CSS Grid Areas Example".to_string(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Startup, spawn_layout)
+        .run();
+}
+
+fn spawn_layout(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    display: Display::Grid,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    grid_template_columns: vec![
+                        GridTrack::px(160.),
+                        GridTrack::flex(1.0),
+                        GridTrack::flex(1.0),
+                    ],
+                    grid_template_rows: vec![
+                        GridTrack::px(60.),
+                        GridTrack::px(120.),
+                        GridTrack::flex(1.0),
+                        GridTrack::px(40.),
+                    ],
+                    row_gap: Val::Px(8.0),
+                    column_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+                ..default()
+            },
+            // Each string is a row of the grid, and each word the name of the area covering a column.
+            // The children are placed by the name of their area, instead of by grid line indices.
+            GridTemplateAreas::new([
+                "header  header  header",
+                "sidebar visits  sales",
+                "sidebar chart   chart",
+                "footer  footer  footer",
+            ]),
+        ))
+        .with_children(|builder| {
+            for (area, color) in [
+                ("header", CRIMSON),
+                ("sidebar", DARK_SLATE_GRAY),
+                ("visits", ROYAL_BLUE),
+                ("sales", SEA_GREEN),
+                ("chart", DARK_ORANGE),
+                ("footer", DIM_GRAY),
+            ] {
+                builder
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                padding: UiRect::all(Val::Px(8.0)),
+                                ..default()
+                            },
+                            background_color: BackgroundColor(color.into()),
+                            ..default()
+                        },
+                        GridArea::new(area),
+                    ))
+                    .with_children(|builder| {
+                        builder.spawn(TextBundle::from_section(
+                            area,
+                            TextStyle {
+                                font_size: 20.0,
+                                ..default()
+                            },
+                        ));
+                    });
+            }
+        });
+}
@@ -0,0 +1,82 @@
+//! This example illustrates how to change the cursor icon while hovering UI nodes
+//! using the [`HoverCursor`] component.
+
+use bevy::{prelude::*, ui::HoverCursor, window::CursorIcon, winit::WinitSettings};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        color: Color::srgb(0.9, 0.9, 0.9),
+    };
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            // a button shows a pointer
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(200.0),
+                            height: Val::Px(65.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        image: UiImage::default().with_color(Color::srgb(0.15, 0.15, 0.15)),
+                        ..default()
+                    },
+                    HoverCursor(CursorIcon::Pointer),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section("Button", text_style.clone()));
+                });
+
+            // a text input shows a text cursor
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(400.0),
+                            height: Val::Px(50.0),
+                            padding: UiRect::horizontal(Val::Px(10.0)),
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        border_color: Color::srgb(0.5, 0.5, 0.5).into(),
+                        background_color: Color::srgb(0.05, 0.05, 0.05).into(),
+                        ..default()
+                    },
+                    // `HoverCursor` is only applied to nodes with an `Interaction`
+                    Interaction::default(),
+                    HoverCursor(CursorIcon::Text),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section("Text input", text_style));
+                });
+        });
+}
@@ -0,0 +1,99 @@
+//! This example illustrates how to react to the cursor entering and leaving UI nodes
+//! using the [`HoverEnter`] and [`HoverExit`] events.
+
+use bevy::{
+    prelude::*,
+    ui::{HoverEnter, HoverExit},
+    winit::WinitSettings,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .add_systems(Update, hover_system)
+        .run();
+}
+
+const NORMAL_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_COLOR: Color = Color::srgb(0.35, 0.35, 0.75);
+
+/// Displays the number of times the cursor has entered a node
+#[derive(Component, Default)]
+struct EnterCount(usize);
+
+fn hover_system(
+    mut hover_enter_events: EventReader<HoverEnter>,
+    mut hover_exit_events: EventReader<HoverExit>,
+    mut node_query: Query<(&mut BackgroundColor, &mut EnterCount, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for event in hover_enter_events.read() {
+        let Ok((mut background_color, mut enter_count, children)) =
+            node_query.get_mut(event.entity)
+        else {
+            continue;
+        };
+        background_color.0 = HOVERED_COLOR;
+        enter_count.0 += 1;
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            text.sections[0].value = format!("Entered {} times", enter_count.0);
+        }
+    }
+
+    for event in hover_exit_events.read() {
+        if let Ok((mut background_color, ..)) = node_query.get_mut(event.entity) {
+            background_color.0 = NORMAL_COLOR;
+        }
+    }
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for _ in 0..3 {
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(200.0),
+                                height: Val::Px(100.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: NORMAL_COLOR.into(),
+                            ..default()
+                        },
+                        // `HoverEnter` and `HoverExit` are only sent for nodes with an `Interaction`
+                        Interaction::default(),
+                        EnterCount::default(),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            "Hover me",
+                            TextStyle {
+                                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                font_size: 24.0,
+                                color: Color::srgb(0.9, 0.9, 0.9),
+                            },
+                        ));
+                    });
+            }
+        });
+}
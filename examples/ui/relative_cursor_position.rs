@@ -42,16 +42,16 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         })
         .with_children(|parent| {
             parent
-                .spawn(NodeBundle {
-                    style: Style {
-                        width: Val::Px(250.),
-                        height: Val::Px(250.),
-                        margin: UiRect::bottom(Val::Px(15.)),
-                        ..default()
-                    },
-                    background_color: Color::srgb(235., 35., 12.).into(),
-                    ..default()
-                })
+                .spawn(
+                    NodeBundle::default()
+                        .with_style(Style {
+                            width: Val::Px(250.),
+                            height: Val::Px(250.),
+                            margin: UiRect::bottom(Val::Px(15.)),
+                            ..default()
+                        })
+                        .with_background_color(Color::srgb(235., 35., 12.)),
+                )
                 .insert(RelativeCursorPosition::default());
 
             parent.spawn(TextBundle {
@@ -0,0 +1,73 @@
+//! This example illustrates how to scroll a long list of items with a [`ScrollViewBundle`].
+
+use bevy::{prelude::*, winit::WinitSettings};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(ScrollViewBundle {
+                    style: Style {
+                        width: Val::Px(300.0),
+                        height: Val::Percent(60.0),
+                        overflow: Overflow::scroll(),
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.1, 0.1, 0.1).into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // the scrolled content
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                width: Val::Percent(100.0),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for i in 0..50 {
+                                parent.spawn(
+                                    TextBundle::from_section(
+                                        format!("Item {i}"),
+                                        TextStyle {
+                                            font: font.clone(),
+                                            font_size: 24.0,
+                                            ..default()
+                                        },
+                                    )
+                                    .with_style(Style {
+                                        margin: UiRect::all(Val::Px(5.0)),
+                                        ..default()
+                                    }),
+                                );
+                            }
+                        });
+                });
+        });
+}
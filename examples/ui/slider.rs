@@ -0,0 +1,82 @@
+//! This example illustrates how to use a [`Slider`] to pick a value, here the brightness of the clear color.
+
+use bevy::{
+    prelude::*,
+    ui::widget::{SliderChanged, SliderHandle},
+    winit::WinitSettings,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .insert_resource(ClearColor(Color::srgb(0.5, 0.5, 0.5)))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_clear_color)
+        .run();
+}
+
+const HANDLE_SIZE: f32 = 30.0;
+
+fn update_clear_color(
+    mut slider_changed_events: EventReader<SliderChanged>,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    for event in slider_changed_events.read() {
+        clear_color.0 = Color::srgb(event.value, event.value, event.value);
+    }
+}
+
+fn setup(mut commands: Commands) {
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(SliderBundle {
+                    slider: Slider {
+                        value: 0.5,
+                        step: Some(0.05),
+                        ..default()
+                    },
+                    style: Style {
+                        width: Val::Px(400.0),
+                        height: Val::Px(10.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.1, 0.1, 0.1).into(),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                width: Val::Px(HANDLE_SIZE),
+                                height: Val::Px(HANDLE_SIZE),
+                                // center the handle on the value
+                                margin: UiRect::left(Val::Px(-HANDLE_SIZE / 2.0)),
+                                ..default()
+                            },
+                            background_color: Color::srgb(0.9, 0.9, 0.9).into(),
+                            border_radius: BorderRadius::MAX,
+                            ..default()
+                        },
+                        SliderHandle,
+                    ));
+                });
+        });
+}
@@ -0,0 +1,103 @@
+//! This example illustrates how to make parts of a text clickable, like links in a web page,
+//! using the [`TextLinks`] component.
+
+use bevy::{
+    prelude::*,
+    ui::{
+        widget::{LinkClicked, TextLink, TextLinks},
+        RelativeCursorPosition,
+    },
+    winit::WinitSettings,
+};
+
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const LINK_COLOR: Color = Color::srgb(0.4, 0.6, 1.0);
+const HOVERED_LINK_COLOR: Color = Color::srgb(0.7, 0.8, 1.0);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (highlight_hovered_links, show_clicked_links))
+        .run();
+}
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 32.0,
+        color: TEXT_COLOR,
+    };
+    let link_style = TextStyle {
+        color: LINK_COLOR,
+        ..text_style.clone()
+    };
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(40.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_sections([
+                    TextSection::new("To learn more about Bevy, ", text_style.clone()),
+                    TextSection::new("click here", link_style.clone()),
+                    TextSection::new(" or read ", text_style.clone()),
+                    TextSection::new("the quick start guide of the Bevy book", link_style),
+                    TextSection::new(", which wraps across lines.", text_style.clone()),
+                ])
+                .with_style(Style {
+                    max_width: Val::Px(500.0),
+                    ..default()
+                }),
+                TextLinks::new([
+                    TextLink::new([1], "https://bevyengine.org"),
+                    TextLink::new([3], "https://bevyengine.org/learn/quick-start"),
+                ]),
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+            ));
+            parent.spawn((TextBundle::from_section("Click a link", text_style), Status));
+        });
+}
+
+fn highlight_hovered_links(mut query: Query<(&TextLinks, &mut Text), Changed<TextLinks>>) {
+    for (text_links, mut text) in &mut query {
+        for (index, link) in text_links.links.iter().enumerate() {
+            let color = if text_links.hovered == Some(index) {
+                HOVERED_LINK_COLOR
+            } else {
+                LINK_COLOR
+            };
+            for &section in &link.sections {
+                text.sections[section].style.color = color;
+            }
+        }
+    }
+}
+
+fn show_clicked_links(
+    mut link_clicked_events: EventReader<LinkClicked>,
+    mut status_query: Query<&mut Text, With<Status>>,
+) {
+    for event in link_clicked_events.read() {
+        info!("Clicked {}", event.url);
+        status_query.single_mut().sections[0].value = format!("Clicked {}", event.url);
+    }
+}
@@ -0,0 +1,143 @@
+//! This example illustrates truncating text that doesn't fit inside its node with an ellipsis,
+//! using the [`TextOverflow`] component, and limiting text to a number of lines with [`TextLineClamp`].
+
+use bevy::{
+    prelude::*,
+    text::BreakLineOn,
+    ui::widget::{TextLineClamp, TextOverflow},
+    winit::WinitSettings,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        color: Color::srgb(0.9, 0.9, 0.9),
+    };
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            // single line button labels
+            for label in ["Play", "Continue the adventure", "Settings and controls"] {
+                parent
+                    .spawn(ButtonBundle {
+                        style: Style {
+                            width: Val::Px(200.0),
+                            height: Val::Px(50.0),
+                            padding: UiRect::horizontal(Val::Px(10.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        image: UiImage::default().with_color(Color::srgb(0.15, 0.15, 0.15)),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        let mut text = Text::from_section(label, text_style.clone());
+                        text.linebreak_behavior = BreakLineOn::NoWrap;
+                        parent.spawn((
+                            TextBundle {
+                                text,
+                                // the label takes the width of the button, instead of its text
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            TextOverflow::Ellipsis,
+                        ));
+                    });
+            }
+
+            // the last visible line of wrapped text is truncated
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(300.0),
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "This paragraph is too long for the three lines its node has room for, \
+                            so its end is replaced with an ellipsis.",
+                            text_style.clone(),
+                        )
+                        .with_style(Style {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(90.0),
+                            ..default()
+                        }),
+                        TextOverflow::Ellipsis,
+                    ));
+                });
+
+            // a card whose description is clamped to two lines, however long it is
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(300.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+                    border_radius: BorderRadius::all(Val::Px(8.0)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Sword of the Ancients",
+                        TextStyle {
+                            font_size: 28.0,
+                            ..text_style.clone()
+                        },
+                    ));
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "Forged in the fires of a long forgotten kingdom, this blade has been \
+                            passed from hero to hero for a thousand years.",
+                            TextStyle {
+                                font_size: 20.0,
+                                color: Color::srgb(0.7, 0.7, 0.7),
+                                ..text_style.clone()
+                            },
+                        )
+                        .with_style(Style {
+                            width: Val::Percent(100.0),
+                            ..default()
+                        }),
+                        TextLineClamp::new(2),
+                        TextOverflow::Ellipsis,
+                    ));
+                });
+        });
+}
@@ -0,0 +1,76 @@
+//! This example illustrates how to show a text next to the cursor while hovering UI nodes
+//! using the [`Tooltip`] component.
+
+use bevy::{prelude::*, ui::widget::Tooltip, winit::WinitSettings};
+use std::time::Duration;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        color: Color::srgb(0.9, 0.9, 0.9),
+    };
+
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_wrap: FlexWrap::Wrap,
+                align_content: AlignContent::SpaceEvenly,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            let tooltips = [
+                Tooltip::new("Shown after the default delay"),
+                Tooltip {
+                    text: "Shown right away".to_string(),
+                    delay: Duration::ZERO,
+                },
+                Tooltip {
+                    text: "Shown after a second".to_string(),
+                    delay: Duration::from_secs(1),
+                },
+                Tooltip::new(
+                    "A long tooltip stays inside the window, even when hovering a node at its edge",
+                ),
+            ];
+            for (i, tooltip) in tooltips.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(200.0),
+                                height: Val::Px(65.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            image: UiImage::default().with_color(Color::srgb(0.15, 0.15, 0.15)),
+                            ..default()
+                        },
+                        tooltip,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            format!("Hover me #{}", i + 1),
+                            text_style.clone(),
+                        ));
+                    });
+            }
+        });
+}
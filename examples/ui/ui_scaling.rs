@@ -1,6 +1,12 @@
-//! This example illustrates the [`UiScale`] resource from `bevy_ui`.
+//! This example illustrates the [`UiScale`] resource from `bevy_ui`, animated with a [`UiScaleTween`].
 
-use bevy::{color::palettes::css::*, prelude::*, text::TextSettings, utils::Duration};
+use bevy::{
+    color::palettes::css::*,
+    prelude::*,
+    text::TextSettings,
+    ui::{UiEasing, UiScaleTween},
+    utils::Duration,
+};
 
 const SCALE_TIME: u64 = 400;
 
@@ -11,16 +17,8 @@ fn main() {
             allow_dynamic_font_size: true,
             ..default()
         })
-        .insert_resource(TargetScale {
-            start_scale: 1.0,
-            target_scale: 1.0,
-            target_time: Timer::new(Duration::from_millis(SCALE_TIME), TimerMode::Once),
-        })
         .add_systems(Startup, setup)
-        .add_systems(
-            Update,
-            (change_scaling, apply_scaling.after(change_scaling)),
-        )
+        .add_systems(Update, change_scaling)
         .run();
 }
 
@@ -84,65 +82,24 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 }
 
 /// System that changes the scale of the ui when pressing up or down on the keyboard.
-fn change_scaling(input: Res<ButtonInput<KeyCode>>, mut ui_scale: ResMut<TargetScale>) {
-    if input.just_pressed(KeyCode::ArrowUp) {
-        let scale = (ui_scale.target_scale * 2.0).min(8.);
-        ui_scale.set_scale(scale);
-        info!("Scaling up! Scale: {}", ui_scale.target_scale);
-    }
-    if input.just_pressed(KeyCode::ArrowDown) {
-        let scale = (ui_scale.target_scale / 2.0).max(1. / 8.);
-        ui_scale.set_scale(scale);
-        info!("Scaling down! Scale: {}", ui_scale.target_scale);
-    }
-}
-
-#[derive(Resource)]
-struct TargetScale {
-    start_scale: f32,
-    target_scale: f32,
-    target_time: Timer,
-}
-
-impl TargetScale {
-    fn set_scale(&mut self, scale: f32) {
-        self.start_scale = self.current_scale();
-        self.target_scale = scale;
-        self.target_time.reset();
-    }
-
-    fn current_scale(&self) -> f32 {
-        let completion = self.target_time.fraction();
-        let t = ease_in_expo(completion);
-        self.start_scale.lerp(self.target_scale, t)
-    }
-
-    fn tick(&mut self, delta: Duration) -> &Self {
-        self.target_time.tick(delta);
-        self
-    }
-
-    fn already_completed(&self) -> bool {
-        self.target_time.finished() && !self.target_time.just_finished()
-    }
-}
-
-fn apply_scaling(
-    time: Res<Time>,
-    mut target_scale: ResMut<TargetScale>,
-    mut ui_scale: ResMut<UiScale>,
+fn change_scaling(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    ui_scale: Res<UiScale>,
+    tween: Option<Res<UiScaleTween>>,
 ) {
-    if target_scale.tick(time.delta()).already_completed() {
-        return;
-    }
-
-    ui_scale.0 = target_scale.current_scale();
-}
-
-fn ease_in_expo(x: f32) -> f32 {
-    if x == 0. {
-        0.
+    // While a tween is running, scale relative to where it's heading
+    let target_scale = tween.map_or(ui_scale.0, |tween| tween.to);
+    let scale = if input.just_pressed(KeyCode::ArrowUp) {
+        (target_scale * 2.0).min(8.)
+    } else if input.just_pressed(KeyCode::ArrowDown) {
+        (target_scale / 2.0).max(1. / 8.)
     } else {
-        2.0f32.powf(5. * x - 5.)
-    }
+        return;
+    };
+    info!("Scaling to {scale}");
+    commands.insert_resource(
+        UiScaleTween::new(ui_scale.0, scale, Duration::from_millis(SCALE_TIME))
+            .with_easing(UiEasing::EaseIn),
+    );
 }
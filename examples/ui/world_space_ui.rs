@@ -0,0 +1,105 @@
+//! This example illustrates how to pin a UI node to a point in a 3D scene with [`WorldSpaceUi`],
+//! here a nameplate floating above a moving cube.
+
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_cube, follow_cube).chain())
+        .run();
+}
+
+// Marks the moving cube
+#[derive(Component)]
+struct Cube;
+
+// Marks the nameplate following the cube
+#[derive(Component)]
+struct Nameplate;
+
+/// How high above the center of the cube the nameplate floats
+const NAMEPLATE_HEIGHT: f32 = 0.9;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 4.0, 9.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Plane3d::default().mesh().size(12.0, 12.0)),
+        material: materials.add(Color::srgb(0.3, 0.5, 0.3)),
+        transform: Transform::from_xyz(0.0, -0.5, 0.0),
+        ..default()
+    });
+
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::default()),
+            material: materials.add(Color::srgb(0.8, 0.7, 0.6)),
+            ..default()
+        },
+        Cube,
+    ));
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                ..default()
+            },
+            // The nameplate has its layout size when it is as far from the camera as the cube's starting point,
+            // and shrinks as the cube moves away.
+            WorldSpaceUi::new(Vec3::Y * NAMEPLATE_HEIGHT).with_reference_distance(9.0),
+            Nameplate,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Wandering Cube",
+                TextStyle {
+                    font_size: 24.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn move_cube(time: Res<Time>, mut cubes: Query<&mut Transform, With<Cube>>) {
+    let t = time.elapsed_seconds() * 0.6;
+    for mut transform in &mut cubes {
+        transform.translation = Vec3::new(t.cos() * 4.0, 0.0, t.sin() * 4.0 - 1.0);
+        transform.rotate_y(time.delta_seconds());
+    }
+}
+
+fn follow_cube(
+    cubes: Query<&Transform, With<Cube>>,
+    mut nameplates: Query<&mut WorldSpaceUi, With<Nameplate>>,
+) {
+    let Ok(cube_transform) = cubes.get_single() else {
+        return;
+    };
+    for mut world_space_ui in &mut nameplates {
+        world_space_ui.world_position = cube_transform.translation + Vec3::Y * NAMEPLATE_HEIGHT;
+    }
+}